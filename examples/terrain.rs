@@ -1,6 +1,6 @@
-use rand::random;
-
-use gridsystem::Grid;
+use gridsystem::generate::NoiseConfig;
+use gridsystem::pathfinding::{astar, path_cost};
+use gridsystem::{AsciiOverlay, Grid, Highlight, Navigable, navigable_cost};
 
 #[derive(Clone, Debug, Default, PartialEq)]
 enum TerrainType {
@@ -20,6 +20,12 @@ struct Tile {
     movement_cost: u8,
 }
 
+impl Navigable for Tile {
+    fn cost(&self) -> Option<u32> {
+        self.is_walkable.then_some(self.movement_cost as u32)
+    }
+}
+
 fn main() {
     let width = 20;
     let height = 10;
@@ -27,11 +33,20 @@ fn main() {
     println!("Creating a {width:?}x{height:?} terrain map...");
     let mut map: Grid<Tile> = Grid::new(width, height);
 
-    println!("Generating terrain using parallel processing...");
+    println!("Generating terrain from layered noise using parallel processing...");
+    let noise = NoiseConfig {
+        seed: 2024,
+        frequency: 1.0 / 8.0,
+        octaves: 4,
+        ..NoiseConfig::default()
+    };
+    let mut elevation = Grid::from_noise(width, height, noise).expect("no mask to mismatch");
+    // Spread heights evenly so the bands below are percentiles of the map.
+    elevation.equalize_histogram();
 
-    map.par_map_inplace(|_x, _y, tile| {
-        let rand_val: u8 = random::<u8>() % 100;
-        match rand_val {
+    map.par_map_inplace(|x, y, tile| {
+        let height_percent = (elevation[(x, y)] * 100.0) as u8;
+        match height_percent {
             0..=10 => {
                 tile.terrain = TerrainType::Water;
                 tile.elevation = 0;
@@ -65,32 +80,28 @@ fn main() {
         }
     });
 
-    println!("\nMap Visualization:");
-    println!("Legend: W Water, M Mountain, F Forest, G Grass, S Sand");
-    println!("-------------------------------------------------------");
+    let path = astar(&map, (0, 0), (width - 1, height - 1), navigable_cost);
+    let overlay = AsciiOverlay {
+        axes: true,
+        legend: true,
+        highlights: path
+            .iter()
+            .map(|path| Highlight::new('*', "Path", path.iter().copied()))
+            .collect(),
+    };
 
-    for y in 0..height {
-        for x in 0..width {
-            let tile = map.get(x, y);
-            match tile {
-                None => {
-                    print!("?");
-                    continue;
-                }
-                Some(t) => {
-                    let symbol = match t.terrain {
-                        TerrainType::Water => 'W',
-                        TerrainType::Mountain => 'M',
-                        TerrainType::Forest => 'F',
-                        TerrainType::Grass => 'G',
-                        TerrainType::Sand => 'S',
-                    };
-                    print!("{symbol}");
-                }
-            }
-        }
-        println!();
-    }
+    println!("\nMap Visualization:");
+    let text = map.render_ascii(
+        |t| match t.terrain {
+            TerrainType::Water => ('W', "Water"),
+            TerrainType::Mountain => ('M', "Mountain"),
+            TerrainType::Forest => ('F', "Forest"),
+            TerrainType::Grass => ('G', "Grass"),
+            TerrainType::Sand => ('S', "Sand"),
+        },
+        &overlay,
+    );
+    print!("{text}");
 
     println!("\nInspecting specific tiles:");
     let center_tile = map.get(width / 2, height / 2);
@@ -110,4 +121,15 @@ fn main() {
             t.terrain, t.elevation, t.is_walkable, t.movement_cost
         ),
     }
+
+    println!("\nFinding a path from corner to corner:");
+    match path {
+        None => println!("No walkable route between the corners."),
+        Some(path) => println!(
+            "Path of {} steps costing {}: {:?}",
+            path.len() - 1,
+            path_cost(&map, &path, navigable_cost).unwrap_or_default(),
+            path
+        ),
+    }
 }
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+use crate::{Grid, GridError};
+
+impl<T: Default + Clone + Sync> Grid<T> {
+    /// Folds this grid's values into one accumulator per region of `labels`.
+    ///
+    /// Every cell with a non-zero label is passed to `fold` together with
+    /// that label's accumulator, which starts out as `A::default()`. The grid
+    /// is split across threads and each thread builds its own partial map;
+    /// `merge` combines two partial accumulators for the same label, so it
+    /// must agree with `fold` (a sum merges by adding, a maximum by taking
+    /// the larger). Cells labelled 0 are treated as unlabelled and skipped.
+    ///
+    /// Returns a map from label to its accumulator; labels that never appear
+    /// are absent.
+    ///
+    /// Returns [`GridError::DimensionMismatch`] if `labels` is not the size
+    /// of the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let elevation = Grid::from_raw_parts(4, 1, vec![10.0, 20.0, 5.0, 7.0]).unwrap();
+    /// let territory = Grid::from_raw_parts(4, 1, vec![1, 1, 2, 0]).unwrap();
+    ///
+    /// // Mean elevation per territory, as (sum, count) pairs.
+    /// let totals = elevation
+    ///     .aggregate_by(
+    ///         &territory,
+    ///         |(sum, count): &mut (f64, u32), &h| {
+    ///             *sum += h;
+    ///             *count += 1;
+    ///         },
+    ///         |a, b| {
+    ///             a.0 += b.0;
+    ///             a.1 += b.1;
+    ///         },
+    ///     )
+    ///     .unwrap();
+    /// let mean = |label| totals[&label].0 / totals[&label].1 as f64;
+    /// assert_eq!(mean(1), 15.0);
+    /// assert_eq!(mean(2), 5.0);
+    /// assert!(!totals.contains_key(&0));
+    /// ```
+    pub fn aggregate_by<A, F, M>(
+        &self,
+        labels: &Grid<u32>,
+        fold: F,
+        merge: M,
+    ) -> Result<HashMap<u32, A>, GridError>
+    where
+        A: Default + Send,
+        F: Fn(&mut A, &T) + Send + Sync,
+        M: Fn(&mut A, A) + Send + Sync,
+    {
+        use rayon::prelude::*;
+        if self.width != labels.width || self.height != labels.height {
+            return Err(GridError::DimensionMismatch {
+                expected: (self.width, self.height),
+                found: (labels.width, labels.height),
+            });
+        }
+        Ok(self
+            .tiles
+            .par_iter()
+            .zip(labels.tiles.par_iter())
+            .fold(HashMap::new, |mut partial, (value, &label)| {
+                if label != 0 {
+                    fold(partial.entry(label).or_default(), value);
+                }
+                partial
+            })
+            .reduce(HashMap::new, |mut combined, partial| {
+                for (label, acc) in partial {
+                    match combined.entry(label) {
+                        Entry::Occupied(entry) => merge(entry.into_mut(), acc),
+                        Entry::Vacant(entry) => {
+                            entry.insert(acc);
+                        }
+                    }
+                }
+                combined
+            }))
+    }
+}
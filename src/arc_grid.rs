@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::{Grid, GridLike};
+
+/// An immutable grid behind an [`Arc`], cheap to clone and share.
+///
+/// Every clone points at the same cells, so a prefab or chunk template can
+/// be handed to hundreds of users for the cost of a pointer. All read-only
+/// [`Grid`] methods are available through `Deref`. Use
+/// [`ArcGrid::into_grid`] to get an owned, editable copy.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{ArcGrid, Grid};
+///
+/// let room = ArcGrid::new(Grid::with_value(8, 8, b'.'));
+/// let copy = room.clone();
+///
+/// assert!(ArcGrid::ptr_eq(&room, &copy));
+/// assert_eq!(copy.get(3, 3), Some(&b'.'));
+/// ```
+pub struct ArcGrid<T> {
+    inner: Arc<Grid<T>>,
+}
+
+impl<T> ArcGrid<T> {
+    /// Wraps a grid so it can be shared.
+    pub fn new(grid: Grid<T>) -> ArcGrid<T> {
+        ArcGrid {
+            inner: Arc::new(grid),
+        }
+    }
+
+    /// Returns `true` if both handles share the same cells.
+    pub fn ptr_eq(a: &ArcGrid<T>, b: &ArcGrid<T>) -> bool {
+        Arc::ptr_eq(&a.inner, &b.inner)
+    }
+
+    /// Returns the number of handles sharing these cells.
+    pub fn share_count(&self) -> usize {
+        Arc::strong_count(&self.inner)
+    }
+}
+
+impl<T: Clone> ArcGrid<T> {
+    /// Returns an owned grid, copying the cells only if they are still shared.
+    pub fn into_grid(self) -> Grid<T> {
+        Arc::try_unwrap(self.inner).unwrap_or_else(|shared| Grid {
+            width: shared.width,
+            height: shared.height,
+            tiles: shared.tiles.clone(),
+        })
+    }
+}
+
+impl<T> Clone for ArcGrid<T> {
+    fn clone(&self) -> Self {
+        ArcGrid {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T> Deref for ArcGrid<T> {
+    type Target = Grid<T>;
+
+    fn deref(&self) -> &Grid<T> {
+        &self.inner
+    }
+}
+
+impl<T> From<Grid<T>> for ArcGrid<T> {
+    fn from(grid: Grid<T>) -> ArcGrid<T> {
+        ArcGrid::new(grid)
+    }
+}
+
+impl<T: Default + Clone> GridLike<T> for ArcGrid<T> {
+    fn width(&self) -> u16 {
+        self.inner.width
+    }
+
+    fn height(&self) -> u16 {
+        self.inner.height
+    }
+
+    fn get(&self, x: u16, y: u16) -> Option<&T> {
+        self.inner.get(x, y)
+    }
+}
+
+/// Deduplicates identical grids so repeated content is stored once.
+///
+/// [`GridInterner::intern`] returns the [`ArcGrid`] already held for a grid
+/// with the same size and cells, or starts sharing the new one. Interned
+/// grids stay alive until [`GridInterner::prune`] finds them unused.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{ArcGrid, Grid, GridInterner};
+///
+/// let mut interner = GridInterner::new();
+/// let a = interner.intern(Grid::with_value(16, 16, 1u8));
+/// let b = interner.intern(Grid::with_value(16, 16, 1u8));
+/// let c = interner.intern(Grid::with_value(16, 16, 2u8));
+///
+/// assert!(ArcGrid::ptr_eq(&a, &b));
+/// assert!(!ArcGrid::ptr_eq(&a, &c));
+/// assert_eq!(interner.len(), 2);
+/// ```
+pub struct GridInterner<T> {
+    buckets: HashMap<u64, Vec<ArcGrid<T>>>,
+}
+
+impl<T: Hash + Eq> GridInterner<T> {
+    /// Creates an empty interner.
+    pub fn new() -> GridInterner<T> {
+        GridInterner {
+            buckets: HashMap::new(),
+        }
+    }
+
+    /// Returns the shared copy of `grid`, adding it if it is new.
+    pub fn intern(&mut self, grid: Grid<T>) -> ArcGrid<T> {
+        let bucket = self.buckets.entry(fingerprint(&grid)).or_default();
+        if let Some(existing) = bucket.iter().find(|shared| same_cells(shared, &grid)) {
+            return existing.clone();
+        }
+        let shared = ArcGrid::new(grid);
+        bucket.push(shared.clone());
+        shared
+    }
+
+    /// Returns the number of distinct grids held.
+    pub fn len(&self) -> usize {
+        self.buckets.values().map(Vec::len).sum()
+    }
+
+    /// Returns `true` if no grids are held.
+    pub fn is_empty(&self) -> bool {
+        self.buckets.is_empty()
+    }
+
+    /// Drops grids that are no longer used outside the interner.
+    ///
+    /// Returns the number of grids dropped.
+    pub fn prune(&mut self) -> usize {
+        let before = self.len();
+        for bucket in self.buckets.values_mut() {
+            bucket.retain(|shared| shared.share_count() > 1);
+        }
+        self.buckets.retain(|_, bucket| !bucket.is_empty());
+        before - self.len()
+    }
+}
+
+impl<T: Hash + Eq> Default for GridInterner<T> {
+    fn default() -> Self {
+        GridInterner::new()
+    }
+}
+
+fn fingerprint<T: Hash>(grid: &Grid<T>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    (grid.width, grid.height).hash(&mut hasher);
+    grid.tiles.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn same_cells<T: Eq>(a: &Grid<T>, b: &Grid<T>) -> bool {
+    (a.width, a.height) == (b.width, b.height) && a.tiles == b.tiles
+}
@@ -0,0 +1,205 @@
+use crate::{Grid, GridError, GridRef, GridRefMut};
+
+/// Many small grids carved out of one backing allocation.
+///
+/// Generation pipelines that build thousands of short-lived grids (room
+/// prefabs, kernels, particle buffers) can allocate them here instead of
+/// giving each its own `Vec`. [`GridArena::reset`] frees every grid at
+/// once but keeps the backing memory, so the next round of allocations
+/// does not touch the allocator at all.
+///
+/// Grids are addressed through [`ArenaGrid`] handles. A reset invalidates
+/// every handle issued before it, and using a stale handle returns `None`
+/// or an error instead of reading another grid's cells.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::GridArena;
+///
+/// let mut arena: GridArena<u8> = GridArena::with_capacity(1024);
+/// let room = arena.alloc(4, 3);
+/// let kernel = arena.alloc_with(3, 3, 1);
+///
+/// arena.set(room, 1, 2, 9).unwrap();
+/// assert_eq!(arena.get(room, 1, 2), Some(&9));
+/// assert_eq!(arena.cells(kernel).unwrap().iter().sum::<u8>(), 9);
+/// assert_eq!(arena.cells_used(), 21);
+///
+/// arena.reset();
+/// assert_eq!(arena.get(room, 1, 2), None);
+/// assert!(arena.capacity() >= 1024);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GridArena<T> {
+    storage: Vec<T>,
+    grids: Vec<Slot>,
+    generation: u32,
+}
+
+/// A handle to a grid allocated in a [`GridArena`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArenaGrid {
+    index: u32,
+    generation: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Slot {
+    start: usize,
+    width: u16,
+    height: u16,
+}
+
+impl<T: Default + Clone> GridArena<T> {
+    /// Creates an empty arena.
+    pub fn new() -> GridArena<T> {
+        GridArena {
+            storage: Vec::new(),
+            grids: Vec::new(),
+            generation: 0,
+        }
+    }
+
+    /// Creates an empty arena with room for `cells` cells before it must grow.
+    pub fn with_capacity(cells: usize) -> GridArena<T> {
+        GridArena {
+            storage: Vec::with_capacity(cells),
+            ..GridArena::new()
+        }
+    }
+
+    /// Allocates a `width × height` grid with every cell set to `T::default()`.
+    pub fn alloc(&mut self, width: u16, height: u16) -> ArenaGrid {
+        self.alloc_with(width, height, T::default())
+    }
+
+    /// Allocates a `width × height` grid with every cell set to `value`.
+    pub fn alloc_with(&mut self, width: u16, height: u16, value: T) -> ArenaGrid {
+        let start = self.storage.len();
+        self.storage
+            .resize(start + width as usize * height as usize, value);
+        self.grids.push(Slot {
+            start,
+            width,
+            height,
+        });
+        ArenaGrid {
+            index: self.grids.len() as u32 - 1,
+            generation: self.generation,
+        }
+    }
+
+    /// Allocates a copy of `grid`.
+    pub fn alloc_from(&mut self, grid: &Grid<T>) -> ArenaGrid {
+        let handle = self.alloc(grid.width, grid.height);
+        if let Some(cells) = self.cells_mut(handle) {
+            cells.clone_from_slice(&grid.tiles);
+        }
+        handle
+    }
+
+    /// Returns the width and height of the grid behind `handle`, or `None` if the handle is stale.
+    pub fn dimensions(&self, handle: ArenaGrid) -> Option<(u16, u16)> {
+        self.slot(handle).map(|slot| (slot.width, slot.height))
+    }
+
+    /// Returns the cells of the grid behind `handle` in row-major order.
+    pub fn cells(&self, handle: ArenaGrid) -> Option<&[T]> {
+        let slot = self.slot(handle)?;
+        Some(&self.storage[slot.start..slot.start + slot.width as usize * slot.height as usize])
+    }
+
+    /// Returns the cells of the grid behind `handle` mutably, in row-major order.
+    pub fn cells_mut(&mut self, handle: ArenaGrid) -> Option<&mut [T]> {
+        let slot = self.slot(handle)?;
+        Some(&mut self.storage[slot.start..slot.start + slot.width as usize * slot.height as usize])
+    }
+
+    /// Gets a reference to cell (x, y) of the grid behind `handle`.
+    ///
+    /// Returns `None` if the handle is stale or the coordinates are out of bounds.
+    pub fn get(&self, handle: ArenaGrid, x: u16, y: u16) -> Option<&T> {
+        let slot = self.slot(handle)?;
+        (x < slot.width && y < slot.height)
+            .then(|| &self.storage[slot.start + y as usize * slot.width as usize + x as usize])
+    }
+
+    /// Sets cell (x, y) of the grid behind `handle`.
+    ///
+    /// Returns [`GridError::InvalidArgument`] if the handle is stale, or
+    /// [`GridError::OutOfBounds`] if the coordinates are out of bounds.
+    pub fn set(&mut self, handle: ArenaGrid, x: u16, y: u16, value: T) -> Result<(), GridError> {
+        let slot = self.slot(handle).ok_or_else(|| {
+            GridError::InvalidArgument("Arena handle was invalidated by a reset".to_string())
+        })?;
+        if x >= slot.width || y >= slot.height {
+            return Err(GridError::OutOfBounds {
+                x,
+                y,
+                width: slot.width,
+                height: slot.height,
+            });
+        }
+        self.storage[slot.start + y as usize * slot.width as usize + x as usize] = value;
+        Ok(())
+    }
+
+    /// Copies the grid behind `handle` out of the arena.
+    pub fn to_grid(&self, handle: ArenaGrid) -> Option<Grid<T>> {
+        let (width, height) = self.dimensions(handle)?;
+        Some(Grid {
+            width,
+            height,
+            tiles: self.cells(handle)?.to_vec(),
+        })
+    }
+
+    /// Borrows the grid behind `handle`, or `None` if the handle is stale.
+    pub fn grid(&self, handle: ArenaGrid) -> Option<GridRef<'_, T>> {
+        let (width, height) = self.dimensions(handle)?;
+        GridRef::new(width, height, self.cells(handle)?).ok()
+    }
+
+    /// Mutably borrows the grid behind `handle`, or `None` if the handle is stale.
+    pub fn grid_mut(&mut self, handle: ArenaGrid) -> Option<GridRefMut<'_, T>> {
+        let (width, height) = self.dimensions(handle)?;
+        GridRefMut::new(width, height, self.cells_mut(handle)?).ok()
+    }
+
+    /// Frees every grid, keeping the backing allocation for reuse.
+    ///
+    /// Every handle issued so far becomes stale.
+    pub fn reset(&mut self) {
+        self.storage.clear();
+        self.grids.clear();
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Returns the number of live grids.
+    pub fn len(&self) -> usize {
+        self.grids.len()
+    }
+
+    /// Returns `true` if no grids have been allocated since the last reset.
+    pub fn is_empty(&self) -> bool {
+        self.grids.is_empty()
+    }
+
+    /// Returns the number of cells taken by live grids.
+    pub fn cells_used(&self) -> usize {
+        self.storage.len()
+    }
+
+    /// Returns the number of cells the arena can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.storage.capacity()
+    }
+
+    fn slot(&self, handle: ArenaGrid) -> Option<Slot> {
+        if handle.generation != self.generation {
+            return None;
+        }
+        self.grids.get(handle.index as usize).copied()
+    }
+}
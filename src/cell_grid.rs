@@ -0,0 +1,143 @@
+use std::cell::Cell;
+
+use crate::{Grid, GridError};
+
+/// A grid of [`Cell`]s, writable through a shared reference.
+///
+/// Callback-heavy code such as visitors that read neighbours while writing
+/// the current cell can hold `&CellGrid` everywhere instead of juggling a
+/// `&mut Grid` or buffering writes by index. Cells are read and written by
+/// copy. Like `Cell`, the grid is not `Sync`; convert it back with
+/// [`CellGrid::into_grid`] for parallel work.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{CellGrid, Grid};
+///
+/// let heat = CellGrid::from(Grid::from_raw_parts(3, 1, vec![0, 9, 0]).unwrap());
+/// let spread = |x: u16| {
+///     if let Some(h) = heat.get(x + 1, 0) {
+///         let _ = heat.set(x, 0, heat.get(x, 0).unwrap().max(h - 1));
+///     }
+/// };
+/// spread(0);
+/// assert_eq!(heat.into_grid().as_slice(), &[8, 9, 0]);
+/// ```
+pub struct CellGrid<T: Copy> {
+    width: u16,
+    height: u16,
+    tiles: Vec<Cell<T>>,
+}
+
+impl<T: Copy + Default> CellGrid<T> {
+    /// Creates a new grid with every cell set to `T::default()`.
+    pub fn new(width: u16, height: u16) -> CellGrid<T> {
+        CellGrid::with_value(width, height, T::default())
+    }
+}
+
+impl<T: Copy> CellGrid<T> {
+    /// Creates a new grid with every cell set to `value`.
+    pub fn with_value(width: u16, height: u16, value: T) -> CellGrid<T> {
+        CellGrid {
+            width,
+            height,
+            tiles: (0..width as usize * height as usize)
+                .map(|_| Cell::new(value))
+                .collect(),
+        }
+    }
+
+    /// Returns the width of the grid.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Returns the height of the grid.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Returns the [`Cell`] at (x, y), or `None` if out of bounds.
+    pub fn cell(&self, x: u16, y: u16) -> Option<&Cell<T>> {
+        (x < self.width && y < self.height)
+            .then(|| &self.tiles[y as usize * self.width as usize + x as usize])
+    }
+
+    /// Returns a copy of the value at (x, y), or `None` if out of bounds.
+    pub fn get(&self, x: u16, y: u16) -> Option<T> {
+        self.cell(x, y).map(Cell::get)
+    }
+
+    /// Sets the value at (x, y) through a shared reference.
+    ///
+    /// Returns [`GridError::OutOfBounds`] if the coordinates are out of bounds.
+    pub fn set(&self, x: u16, y: u16, value: T) -> Result<(), GridError> {
+        match self.cell(x, y) {
+            Some(cell) => {
+                cell.set(value);
+                Ok(())
+            }
+            None => Err(GridError::OutOfBounds {
+                x,
+                y,
+                width: self.width,
+                height: self.height,
+            }),
+        }
+    }
+
+    /// Replaces the value at (x, y) with `f(old)` and returns the old value.
+    ///
+    /// Returns `None` without calling `f` if the coordinates are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::CellGrid;
+    ///
+    /// let counts: CellGrid<u32> = CellGrid::new(2, 2);
+    /// counts.update(1, 1, |n| n + 1);
+    /// assert_eq!(counts.update(1, 1, |n| n + 1), Some(1));
+    /// assert_eq!(counts.get(1, 1), Some(2));
+    /// assert_eq!(counts.update(5, 5, |n| n + 1), None);
+    /// ```
+    pub fn update<F>(&self, x: u16, y: u16, f: F) -> Option<T>
+    where
+        F: FnOnce(T) -> T,
+    {
+        let cell = self.cell(x, y)?;
+        let old = cell.get();
+        cell.set(f(old));
+        Some(old)
+    }
+
+    /// Returns an iterator over `(x, y, &Cell<T>)` in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (u16, u16, &Cell<T>)> {
+        let width = self.width as usize;
+        self.tiles
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width) as u16, (i / width) as u16, cell))
+    }
+
+    /// Converts back into a plain [`Grid`].
+    pub fn into_grid(self) -> Grid<T> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            tiles: self.tiles.into_iter().map(Cell::into_inner).collect(),
+        }
+    }
+}
+
+impl<T: Copy> From<Grid<T>> for CellGrid<T> {
+    fn from(grid: Grid<T>) -> CellGrid<T> {
+        CellGrid {
+            width: grid.width,
+            height: grid.height,
+            tiles: grid.tiles.into_iter().map(Cell::new).collect(),
+        }
+    }
+}
@@ -0,0 +1,322 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::streaming::{forget_seams, stitch_seams};
+use crate::{Axis, ChunkCoord, Grid};
+
+type Generator<T> = Box<dyn FnMut(ChunkCoord, u16) -> Grid<T>>;
+
+/// An unbounded grid split into square chunks that are generated on first
+/// use.
+///
+/// Chunks are `chunk_size × chunk_size` [`Grid`]s keyed by [`ChunkCoord`].
+/// Reads through [`get`](ChunkedGrid::get) only see loaded chunks, while
+/// writes and [`load`](ChunkedGrid::load) call the generator for any chunk
+/// that is missing. Cell coordinates are `i32` and may be negative, so
+/// [`region`](ChunkedGrid::region) and [`paste`](ChunkedGrid::paste) can
+/// span any number of chunks. Unlike [`ChunkStreamer`](crate::ChunkStreamer)
+/// there is no viewer or background loading: the caller decides what stays
+/// resident and unloads the rest.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{ChunkedGrid, Grid};
+///
+/// let mut world = ChunkedGrid::new(16, |(cx, cy), size| {
+///     Grid::with_value(size, size, cx * 10 + cy)
+/// });
+/// assert_eq!(world.get(-1, 40), None);
+///
+/// world.set(-1, 40, 99);
+/// assert_eq!(world.chunk_of(-1, 40), (-1, 2));
+/// assert_eq!(world.get(-1, 40), Some(&99));
+/// assert_eq!(world.get(-16, 32), Some(&-8));
+/// assert_eq!(world.loaded_count(), 1);
+/// ```
+pub struct ChunkedGrid<T> {
+    chunk_size: u16,
+    chunks: HashMap<ChunkCoord, Grid<T>>,
+    /// Seams already passed to `stitch_borders`, keyed by the west or north chunk.
+    stitched: HashSet<(ChunkCoord, Axis)>,
+    generator: Generator<T>,
+}
+
+impl<T: Default + Clone> ChunkedGrid<T> {
+    /// Creates a grid with no loaded chunks.
+    ///
+    /// # Arguments
+    ///
+    /// * `chunk_size` - The width and height of every chunk in cells
+    /// * `generator` - Produces the chunk at the given chunk coordinates the first time it is needed
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn new<F>(chunk_size: u16, generator: F) -> ChunkedGrid<T>
+    where
+        F: FnMut(ChunkCoord, u16) -> Grid<T> + 'static,
+    {
+        assert!(chunk_size > 0, "chunk size must be non-zero");
+        ChunkedGrid {
+            chunk_size,
+            chunks: HashMap::new(),
+            stitched: HashSet::new(),
+            generator: Box::new(generator),
+        }
+    }
+
+    /// Returns the width and height of every chunk in cells.
+    pub fn chunk_size(&self) -> u16 {
+        self.chunk_size
+    }
+
+    /// Converts a cell position into the coordinates of the chunk containing it.
+    pub fn chunk_of(&self, x: i32, y: i32) -> ChunkCoord {
+        let size = self.chunk_size as i32;
+        (x.div_euclid(size), y.div_euclid(size))
+    }
+
+    /// Gets the cell at the given position if its chunk is loaded.
+    pub fn get(&self, x: i32, y: i32) -> Option<&T> {
+        let (coord, lx, ly) = self.locate(x, y);
+        self.chunks.get(&coord)?.get(lx, ly)
+    }
+
+    /// Returns a mutable reference to the cell at the given position,
+    /// generating its chunk if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generator returns a chunk of the wrong size.
+    pub fn get_mut(&mut self, x: i32, y: i32) -> &mut T {
+        let (coord, lx, ly) = self.locate(x, y);
+        let chunk = self.load(coord);
+        let index = chunk.index(lx, ly);
+        &mut chunk.tiles[index]
+    }
+
+    /// Sets the cell at the given position, generating its chunk if needed,
+    /// and returns the value it replaced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generator returns a chunk of the wrong size.
+    pub fn set(&mut self, x: i32, y: i32, value: T) -> T {
+        std::mem::replace(self.get_mut(x, y), value)
+    }
+
+    /// Returns the chunk at the given chunk coordinates, generating it if
+    /// it is not loaded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generator returns a chunk of the wrong size.
+    pub fn load(&mut self, coord: ChunkCoord) -> &mut Grid<T> {
+        let size = self.chunk_size;
+        let generator = &mut self.generator;
+        self.chunks.entry(coord).or_insert_with(|| {
+            let chunk = generator(coord, size);
+            assert!(
+                (chunk.width, chunk.height) == (size, size),
+                "generator returned a {}x{} chunk for {:?}, expected {}x{}",
+                chunk.width,
+                chunk.height,
+                coord,
+                size,
+                size
+            );
+            chunk
+        })
+    }
+
+    /// Returns the loaded chunk at the given chunk coordinates.
+    pub fn chunk(&self, coord: ChunkCoord) -> Option<&Grid<T>> {
+        self.chunks.get(&coord)
+    }
+
+    /// Returns a mutable reference to the loaded chunk at the given chunk coordinates.
+    pub fn chunk_mut(&mut self, coord: ChunkCoord) -> Option<&mut Grid<T>> {
+        self.chunks.get_mut(&coord)
+    }
+
+    /// Returns `true` if the chunk is loaded.
+    pub fn is_loaded(&self, coord: ChunkCoord) -> bool {
+        self.chunks.contains_key(&coord)
+    }
+
+    /// Returns the number of loaded chunks.
+    pub fn loaded_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Returns an iterator over all loaded chunks and their coordinates, in
+    /// no particular order.
+    pub fn loaded_chunks(&self) -> impl Iterator<Item = (ChunkCoord, &Grid<T>)> {
+        self.chunks.iter().map(|(&coord, chunk)| (coord, chunk))
+    }
+
+    /// Returns a mutable iterator over all loaded chunks and their
+    /// coordinates, in no particular order.
+    pub fn loaded_chunks_mut(&mut self) -> impl Iterator<Item = (ChunkCoord, &mut Grid<T>)> {
+        self.chunks.iter_mut().map(|(&coord, chunk)| (coord, chunk))
+    }
+
+    /// Removes the chunk at the given chunk coordinates and returns it, e.g.
+    /// to persist it. The next access generates it afresh.
+    pub fn unload(&mut self, coord: ChunkCoord) -> Option<Grid<T>> {
+        let chunk = self.chunks.remove(&coord)?;
+        forget_seams(&mut self.stitched, coord);
+        Some(chunk)
+    }
+
+    /// Unloads every chunk more than `radius` chunks away from the chunk
+    /// containing cell (x, y), returning them with their coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{ChunkedGrid, Grid};
+    ///
+    /// let mut world = ChunkedGrid::new(8, |_, size| Grid::<u8>::new(size, size));
+    /// for cx in -3..=3 {
+    ///     world.load((cx, 0));
+    /// }
+    /// let evicted = world.unload_distant(0, 0, 2);
+    /// assert_eq!(evicted.len(), 2);
+    /// assert_eq!(world.loaded_count(), 5);
+    /// ```
+    pub fn unload_distant(&mut self, x: i32, y: i32, radius: u32) -> Vec<(ChunkCoord, Grid<T>)> {
+        let (vx, vy) = self.chunk_of(x, y);
+        let r = radius as i64;
+        let distant: Vec<ChunkCoord> = self
+            .chunks
+            .keys()
+            .copied()
+            .filter(|&(cx, cy)| {
+                (cx as i64 - vx as i64).abs() > r || (cy as i64 - vy as i64).abs() > r
+            })
+            .collect();
+        distant
+            .into_iter()
+            .filter_map(|coord| self.unload(coord).map(|chunk| (coord, chunk)))
+            .collect()
+    }
+
+    /// Unloads every chunk, returning them with their coordinates.
+    pub fn unload_all(&mut self) -> Vec<(ChunkCoord, Grid<T>)> {
+        self.stitched.clear();
+        self.chunks.drain().collect()
+    }
+
+    /// Copies the `width × height` region whose top-left cell is (x, y)
+    /// into a dense grid, generating any chunks it touches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{ChunkedGrid, Grid};
+    ///
+    /// let mut world = ChunkedGrid::new(4, |(cx, _), size| Grid::with_value(size, size, cx));
+    /// let strip = world.region(-2, 0, 4, 1);
+    /// assert_eq!(strip.as_slice(), &[-1, -1, 0, 0]);
+    /// assert_eq!(world.loaded_count(), 2);
+    /// ```
+    pub fn region(&mut self, x: i32, y: i32, width: u16, height: u16) -> Grid<T> {
+        let mut grid = Grid::new(width, height);
+        self.for_each_span(x, y, width, height, |chunk, lx, ly, gx, gy, len| {
+            let from = chunk.index(lx, ly);
+            let to = grid.index(gx, gy);
+            grid.tiles[to..to + len].clone_from_slice(&chunk.tiles[from..from + len]);
+        });
+        grid
+    }
+
+    /// Writes every cell of `grid` into this one with its top-left cell at
+    /// (x, y), generating any chunks it touches.
+    pub fn paste(&mut self, grid: &Grid<T>, x: i32, y: i32) {
+        self.for_each_span(
+            x,
+            y,
+            grid.width,
+            grid.height,
+            |chunk, lx, ly, gx, gy, len| {
+                let from = grid.index(gx, gy);
+                let to = chunk.index(lx, ly);
+                chunk.tiles[to..to + len].clone_from_slice(&grid.tiles[from..from + len]);
+            },
+        );
+    }
+
+    /// Reconciles the cells facing each other across chunk seams.
+    ///
+    /// Works like [`ChunkStreamer::stitch_borders`](crate::ChunkStreamer::stitch_borders):
+    /// every seam between two loaded, edge-adjacent chunks is passed to
+    /// `resolver` once, west (or north) cell first, and becomes eligible
+    /// again when either chunk is unloaded.
+    ///
+    /// Returns the number of seams stitched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{ChunkedGrid, Grid};
+    ///
+    /// let mut world = ChunkedGrid::new(4, |(cx, _), size| Grid::with_value(size, size, cx as f32));
+    /// world.load((0, 0));
+    /// world.load((1, 0));
+    ///
+    /// let blend = |a: &mut f32, b: &mut f32| {
+    ///     let mean = (*a + *b) / 2.0;
+    ///     (*a, *b) = (mean, mean);
+    /// };
+    /// assert_eq!(world.stitch_borders(blend), 1);
+    /// assert_eq!(world.get(3, 2), Some(&0.5));
+    /// assert_eq!(world.get(4, 2), Some(&0.5));
+    /// assert_eq!(world.stitch_borders(blend), 0);
+    /// ```
+    pub fn stitch_borders<F>(&mut self, mut resolver: F) -> usize
+    where
+        F: FnMut(&mut T, &mut T),
+    {
+        stitch_seams(
+            &mut self.chunks,
+            &mut self.stitched,
+            self.chunk_size,
+            &mut resolver,
+        )
+    }
+
+    /// Splits a cell position into its chunk and the local position inside it.
+    fn locate(&self, x: i32, y: i32) -> (ChunkCoord, u16, u16) {
+        let size = self.chunk_size as i32;
+        let coord = self.chunk_of(x, y);
+        (coord, x.rem_euclid(size) as u16, y.rem_euclid(size) as u16)
+    }
+
+    /// Calls `f(chunk, lx, ly, gx, gy, len)` for every horizontal run of a
+    /// `width × height` region that lies inside one chunk, where (lx, ly) is
+    /// the run's start in the chunk and (gx, gy) its start in the region.
+    fn for_each_span<F>(&mut self, x: i32, y: i32, width: u16, height: u16, mut f: F)
+    where
+        F: FnMut(&mut Grid<T>, u16, u16, u16, u16, usize),
+    {
+        let size = self.chunk_size as i64;
+        for gy in 0..height {
+            let wy = y as i64 + gy as i64;
+            let mut gx = 0;
+            while gx < width {
+                let wx = x as i64 + gx as i64;
+                let (Ok(cx), Ok(cy)) = (
+                    i32::try_from(wx.div_euclid(size)),
+                    i32::try_from(wy.div_euclid(size)),
+                ) else {
+                    break;
+                };
+                let (lx, ly) = (wx.rem_euclid(size) as u16, wy.rem_euclid(size) as u16);
+                let len = (self.chunk_size - lx).min(width - gx);
+                f(self.load((cx, cy)), lx, ly, gx, gy, len as usize);
+                gx += len;
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+/// Fixed-size binary encoding of a cell value, used by the grid's binary formats.
+///
+/// Every value of a type must encode to exactly [`CellCodec::SIZE`] bytes, so
+/// readers can skip cells and rows without decoding them. Integers and floats
+/// are stored little-endian.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::CellCodec;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// enum Terrain {
+///     Grass,
+///     Water,
+/// }
+///
+/// impl CellCodec for Terrain {
+///     const SIZE: usize = 1;
+///
+///     fn encode(&self, out: &mut Vec<u8>) {
+///         out.push(*self as u8);
+///     }
+///
+///     fn decode(bytes: &[u8]) -> Option<Self> {
+///         match bytes[0] {
+///             0 => Some(Terrain::Grass),
+///             1 => Some(Terrain::Water),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// let mut bytes = Vec::new();
+/// Terrain::Water.encode(&mut bytes);
+/// assert_eq!(Terrain::decode(&bytes), Some(Terrain::Water));
+/// ```
+pub trait CellCodec: Sized {
+    /// The number of bytes every encoded value occupies.
+    const SIZE: usize;
+
+    /// Appends exactly `SIZE` bytes representing the value.
+    fn encode(&self, out: &mut Vec<u8>);
+
+    /// Decodes a value from exactly `SIZE` bytes.
+    ///
+    /// Returns `None` if the bytes do not represent a valid value.
+    fn decode(bytes: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_cell_codec_for_number {
+    ($($t:ty),*) => {
+        $(
+            impl CellCodec for $t {
+                const SIZE: usize = std::mem::size_of::<$t>();
+
+                fn encode(&self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn decode(bytes: &[u8]) -> Option<Self> {
+                    Some(<$t>::from_le_bytes(bytes.try_into().ok()?))
+                }
+            }
+        )*
+    };
+}
+
+impl_cell_codec_for_number!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+impl CellCodec for bool {
+    const SIZE: usize = 1;
+
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        match bytes.first()? {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+}
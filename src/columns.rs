@@ -0,0 +1,294 @@
+use crate::{Grid, GridError};
+
+/// A grid flattened into parallel `x`, `y` and `value` columns.
+///
+/// This is the long ("tidy") layout that columnar engines expect: each
+/// vector maps directly onto one column of an Arrow record batch or a
+/// Parquet row group, and the three columns always have the same length.
+/// With the `arrow` feature, [`GridColumns::to_record_batch`] builds that
+/// record batch, and with the `parquet` feature
+/// [`GridColumns::write_parquet`] writes it to a file.
+/// For a fixed-size-list layout instead, use [`Grid::as_slice`] with
+/// [`Grid::width`] as the list size.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GridColumns<T> {
+    pub x: Vec<u16>,
+    pub y: Vec<u16>,
+    pub values: Vec<T>,
+}
+
+impl<T> GridColumns<T> {
+    /// Returns the number of rows in the table.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the table has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Flattens every cell into `x`, `y` and `value` columns in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut grid: Grid<f32> = Grid::new(2, 2);
+    /// let _ = grid.set(1, 0, 3.5);
+    ///
+    /// let columns = grid.to_columns();
+    /// assert_eq!(columns.x, vec![0, 1, 0, 1]);
+    /// assert_eq!(columns.y, vec![0, 0, 1, 1]);
+    /// assert_eq!(columns.values, vec![0.0, 3.5, 0.0, 0.0]);
+    /// ```
+    pub fn to_columns(&self) -> GridColumns<T> {
+        self.to_columns_where(|_, _, _| true)
+    }
+
+    /// Flattens the cells matching a predicate into `x`, `y` and `value` columns.
+    ///
+    /// Useful for exporting sparse simulation output without the empty cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Called with `(x, y, &value)`; cells returning `false` are left out
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut grid: Grid<u32> = Grid::new(100, 100);
+    /// let _ = grid.set(40, 7, 12);
+    ///
+    /// let columns = grid.to_columns_where(|_, _, &v| v != 0);
+    /// assert_eq!(columns.len(), 1);
+    /// assert_eq!((columns.x[0], columns.y[0], columns.values[0]), (40, 7, 12));
+    /// ```
+    pub fn to_columns_where<F>(&self, predicate: F) -> GridColumns<T>
+    where
+        F: Fn(u16, u16, &T) -> bool,
+    {
+        let mut columns = GridColumns {
+            x: Vec::new(),
+            y: Vec::new(),
+            values: Vec::new(),
+        };
+        for (x, y, value) in self.iter() {
+            if predicate(x, y, value) {
+                columns.x.push(x);
+                columns.y.push(y);
+                columns.values.push(value.clone());
+            }
+        }
+        columns
+    }
+
+    /// Rebuilds a grid from columns, filling cells without a row with `T::default()`.
+    ///
+    /// Returns [`GridError::LengthMismatch`] if the columns differ in length,
+    /// or [`GridError::OutOfBounds`] if a coordinate lies outside the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut grid: Grid<i8> = Grid::new(3, 3);
+    /// let _ = grid.set(2, 1, -4);
+    ///
+    /// let columns = grid.to_columns_where(|_, _, &v| v != 0);
+    /// let restored = Grid::from_columns(3, 3, &columns).unwrap();
+    /// assert_eq!(restored.as_slice(), grid.as_slice());
+    /// ```
+    pub fn from_columns(
+        width: u16,
+        height: u16,
+        columns: &GridColumns<T>,
+    ) -> Result<Grid<T>, GridError> {
+        if columns.x.len() != columns.values.len() || columns.y.len() != columns.values.len() {
+            let found = if columns.x.len() != columns.values.len() {
+                columns.x.len()
+            } else {
+                columns.y.len()
+            };
+            return Err(GridError::LengthMismatch {
+                expected: columns.values.len(),
+                found,
+            });
+        }
+        let mut grid = Grid::new(width, height);
+        for ((&x, &y), value) in columns.x.iter().zip(&columns.y).zip(&columns.values) {
+            grid.set(x, y, value.clone())?;
+        }
+        Ok(grid)
+    }
+}
+
+#[cfg(feature = "arrow")]
+mod arrow {
+    use std::sync::Arc;
+
+    use arrow_array::types::{
+        Float32Type, Float64Type, Int8Type, Int16Type, Int32Type, Int64Type, UInt8Type, UInt16Type,
+        UInt32Type, UInt64Type,
+    };
+    use arrow_array::{ArrayRef, BooleanArray, PrimitiveArray, RecordBatch};
+    use arrow_schema::{ArrowError, DataType, Field, Schema};
+
+    use super::GridColumns;
+    use crate::Grid;
+
+    /// A cell type that can be stored as an Arrow column.
+    ///
+    /// Implemented for the integer and float primitives and `bool`.
+    ///
+    /// Requires the `arrow` feature.
+    pub trait ArrowCell: Clone {
+        /// The Arrow type of the `value` column.
+        fn data_type() -> DataType;
+
+        /// Builds the `value` column from owned values.
+        fn into_array(values: Vec<Self>) -> ArrayRef;
+    }
+
+    macro_rules! primitive_cell {
+        ($($ty:ty => $arrow:ty, $data_type:ident;)*) => {$(
+            impl ArrowCell for $ty {
+                fn data_type() -> DataType {
+                    DataType::$data_type
+                }
+
+                fn into_array(values: Vec<$ty>) -> ArrayRef {
+                    Arc::new(PrimitiveArray::<$arrow>::from(values))
+                }
+            }
+        )*};
+    }
+
+    primitive_cell! {
+        u8 => UInt8Type, UInt8;
+        u16 => UInt16Type, UInt16;
+        u32 => UInt32Type, UInt32;
+        u64 => UInt64Type, UInt64;
+        i8 => Int8Type, Int8;
+        i16 => Int16Type, Int16;
+        i32 => Int32Type, Int32;
+        i64 => Int64Type, Int64;
+        f32 => Float32Type, Float32;
+        f64 => Float64Type, Float64;
+    }
+
+    impl ArrowCell for bool {
+        fn data_type() -> DataType {
+            DataType::Boolean
+        }
+
+        fn into_array(values: Vec<bool>) -> ArrayRef {
+            Arc::new(BooleanArray::from(values))
+        }
+    }
+
+    impl<T: ArrowCell> GridColumns<T> {
+        /// Returns the schema of [`to_record_batch`](Self::to_record_batch):
+        /// non-nullable `x` and `y` as `UInt16` and `value` as `T`'s Arrow type.
+        pub fn arrow_schema() -> Schema {
+            Schema::new(vec![
+                Field::new("x", DataType::UInt16, false),
+                Field::new("y", DataType::UInt16, false),
+                Field::new("value", T::data_type(), false),
+            ])
+        }
+
+        /// Copies the columns into an Arrow record batch with `x`, `y` and
+        /// `value` columns.
+        ///
+        /// Returns an error if the columns differ in length.
+        ///
+        /// Requires the `arrow` feature.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use gridsystem::Grid;
+        ///
+        /// let mut grid: Grid<f32> = Grid::new(4, 4);
+        /// let _ = grid.set(2, 3, 1.5);
+        ///
+        /// let batch = grid.to_columns_where(|_, _, &v| v != 0.0).to_record_batch().unwrap();
+        /// assert_eq!(batch.num_rows(), 1);
+        /// assert_eq!(batch.schema().field(2).name(), "value");
+        /// ```
+        pub fn to_record_batch(&self) -> Result<RecordBatch, ArrowError> {
+            let x: ArrayRef = Arc::new(PrimitiveArray::<UInt16Type>::from(self.x.clone()));
+            let y: ArrayRef = Arc::new(PrimitiveArray::<UInt16Type>::from(self.y.clone()));
+            let values = T::into_array(self.values.clone());
+            RecordBatch::try_new(Arc::new(Self::arrow_schema()), vec![x, y, values])
+        }
+    }
+
+    impl<T: Default + ArrowCell> Grid<T> {
+        /// Flattens every cell into an Arrow record batch with `x`, `y` and
+        /// `value` columns in row-major order.
+        ///
+        /// Requires the `arrow` feature.
+        pub fn to_record_batch(&self) -> RecordBatch {
+            self.to_columns()
+                .to_record_batch()
+                .expect("grid columns have equal lengths")
+        }
+    }
+
+    #[cfg(feature = "parquet")]
+    mod parquet_export {
+        use std::io::{self, Write};
+
+        use parquet::arrow::ArrowWriter;
+
+        use super::ArrowCell;
+        use crate::{Grid, GridColumns};
+
+        impl<T: ArrowCell> GridColumns<T> {
+            /// Writes the columns as a Parquet file with `x`, `y` and `value`
+            /// columns, using the default writer properties.
+            ///
+            /// Requires the `parquet` feature.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use gridsystem::Grid;
+            ///
+            /// let grid: Grid<u8> = Grid::with_value(16, 16, 3);
+            /// let mut file = Vec::new();
+            /// grid.to_columns().write_parquet(&mut file).unwrap();
+            /// assert_eq!(&file[..4], b"PAR1");
+            /// ```
+            pub fn write_parquet<W: Write + Send>(&self, writer: W) -> io::Result<()> {
+                let batch = self.to_record_batch().map_err(io::Error::other)?;
+                let mut writer =
+                    ArrowWriter::try_new(writer, batch.schema(), None).map_err(io::Error::other)?;
+                writer.write(&batch).map_err(io::Error::other)?;
+                writer.close().map_err(io::Error::other)?;
+                Ok(())
+            }
+        }
+
+        impl<T: Default + ArrowCell> Grid<T> {
+            /// Writes every cell as a Parquet file with `x`, `y` and `value`
+            /// columns in row-major order.
+            ///
+            /// Requires the `parquet` feature.
+            pub fn write_parquet<W: Write + Send>(&self, writer: W) -> io::Result<()> {
+                self.to_columns().write_parquet(writer)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "arrow")]
+pub use arrow::ArrowCell;
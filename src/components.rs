@@ -0,0 +1,145 @@
+use crate::{Grid, Rect};
+
+/// Per-component statistics returned by [`Grid::connected_components`].
+///
+/// Component labels start at 1; entry `label - 1` of each vector describes
+/// component `label`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentSummary {
+    /// The number of cells in each component.
+    pub sizes: Vec<u32>,
+    /// The smallest rectangle containing each component.
+    pub bounds: Vec<Rect>,
+}
+
+impl ComponentSummary {
+    /// Returns the number of components.
+    pub fn count(&self) -> u32 {
+        self.sizes.len() as u32
+    }
+
+    /// Returns the number of cells in component `label`.
+    pub fn size(&self, label: u32) -> Option<u32> {
+        self.sizes.get((label as usize).checked_sub(1)?).copied()
+    }
+
+    /// Returns the bounding box of component `label`.
+    pub fn bounds(&self, label: u32) -> Option<Rect> {
+        self.bounds.get((label as usize).checked_sub(1)?).copied()
+    }
+
+    /// Returns the label of the component with the most cells; ties go to
+    /// the lower label.
+    pub fn largest(&self) -> Option<u32> {
+        let mut best: Option<(u32, u32)> = None;
+        for (i, &size) in self.sizes.iter().enumerate() {
+            if best.is_none_or(|(_, most)| size > most) {
+                best = Some((i as u32 + 1, size));
+            }
+        }
+        best.map(|(label, _)| label)
+    }
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Labels the 4-connected components of the grid.
+    ///
+    /// Two neighbouring cells belong to the same component when
+    /// `same(a, b)` returns `true` for their values; `same` should be an
+    /// equivalence, such as equality of terrain types. Every cell ends up in
+    /// exactly one component. Labels start at 1 and are numbered in the
+    /// row-major order of each component's first cell.
+    ///
+    /// Uses union-find over a single pass, so the cost is linear in the
+    /// number of cells whatever the shapes of the components.
+    ///
+    /// Returns the label grid and a [`ComponentSummary`] with the size and
+    /// bounding box of each component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Grid, Rect};
+    ///
+    /// // Two islands in the sea.
+    /// let map = Grid::from_raw_parts(5, 3, vec![
+    ///     0, 1, 0, 0, 0,
+    ///     0, 1, 0, 1, 1,
+    ///     0, 0, 0, 0, 1,
+    /// ]).unwrap();
+    /// let (labels, summary) = map.connected_components(|a, b| a == b);
+    ///
+    /// assert_eq!(summary.count(), 3);
+    /// assert_eq!(labels.get(1, 1), Some(&2));
+    /// assert_eq!(summary.size(3), Some(3));
+    /// assert_eq!(summary.bounds(3), Some(Rect::new(3, 1, 2, 2)));
+    /// assert_eq!(summary.largest(), Some(1));
+    /// ```
+    pub fn connected_components<F>(&self, same: F) -> (Grid<u32>, ComponentSummary)
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        let width = self.width as usize;
+        let mut parent: Vec<usize> = (0..self.tiles.len()).collect();
+        for (i, value) in self.tiles.iter().enumerate() {
+            if i % width > 0 && same(&self.tiles[i - 1], value) {
+                union(&mut parent, i - 1, i);
+            }
+            if i >= width && same(&self.tiles[i - width], value) {
+                union(&mut parent, i - width, i);
+            }
+        }
+
+        let mut labels: Grid<u32> = Grid::new(self.width, self.height);
+        let mut summary = ComponentSummary {
+            sizes: Vec::new(),
+            bounds: Vec::new(),
+        };
+        // Labels are assigned to roots in the order their components first appear.
+        let mut root_label = vec![0u32; self.tiles.len()];
+        for i in 0..self.tiles.len() {
+            let root = find(&mut parent, i);
+            let (x, y) = self.coords(i);
+            if root_label[root] == 0 {
+                summary.sizes.push(0);
+                summary.bounds.push(Rect::new(x, y, 1, 1));
+                root_label[root] = summary.sizes.len() as u32;
+            }
+            let label = root_label[root];
+            labels.tiles[i] = label;
+            let slot = label as usize - 1;
+            summary.sizes[slot] += 1;
+            summary.bounds[slot] = grow(summary.bounds[slot], x, y);
+        }
+        (labels, summary)
+    }
+}
+
+fn find(parent: &mut [usize], mut node: usize) -> usize {
+    while parent[node] != node {
+        parent[node] = parent[parent[node]];
+        node = parent[node];
+    }
+    node
+}
+
+fn union(parent: &mut [usize], a: usize, b: usize) {
+    let (root_a, root_b) = (find(parent, a), find(parent, b));
+    if root_a != root_b {
+        parent[root_b] = root_a;
+    }
+}
+
+/// Extends `rect` to cover cell (x, y).
+fn grow(rect: Rect, x: u16, y: u16) -> Rect {
+    let left = rect.x.min(x);
+    let top = rect.y.min(y);
+    let right = rect.right().max(x as u32 + 1);
+    let bottom = rect.bottom().max(y as u32 + 1);
+    Rect::new(
+        left,
+        top,
+        (right - left as u32) as u16,
+        (bottom - top as u32) as u16,
+    )
+}
@@ -0,0 +1,331 @@
+use std::collections::BTreeMap;
+
+use crate::Grid;
+
+/// A closed outline with holes, traced around a region of solid cells.
+///
+/// Points are in cell units: cell (x, y) covers `[x, x + 1] × [y, y + 1]`.
+/// With y pointing down, `exterior` runs clockwise on screen and every hole
+/// runs counter-clockwise; flipped to y-up physics coordinates that is the
+/// usual counter-clockwise exterior and clockwise holes. The first point is
+/// not repeated at the end.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Polygon {
+    /// The outer boundary of the region.
+    pub exterior: Vec<(f32, f32)>,
+    /// The boundaries of empty areas enclosed by the region.
+    pub holes: Vec<Vec<(f32, f32)>>,
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Traces the outlines of solid regions with marching squares.
+    ///
+    /// Samples are taken at cell centres and the area outside the grid
+    /// counts as empty, so every returned ring is closed. Outlines pass
+    /// through the midpoints of the edges between solid and empty cells;
+    /// diagonal neighbours are treated as separate regions. Exterior rings
+    /// have positive shoelace area in grid coordinates, holes negative (see
+    /// [`Polygon`] for the winding).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut grid: Grid<bool> = Grid::new(3, 3);
+    /// let _ = grid.set(1, 1, true);
+    ///
+    /// let rings = grid.contours(|&solid| solid);
+    /// assert_eq!(rings, vec![vec![(1.0, 1.5), (1.5, 1.0), (2.0, 1.5), (1.5, 2.0)]]);
+    /// ```
+    pub fn contours<F>(&self, solid: F) -> Vec<Vec<(f32, f32)>>
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.trace_rings(&solid)
+            .into_iter()
+            .map(|ring| to_points(&ring))
+            .collect()
+    }
+
+    /// Builds simplified collider polygons for every solid region.
+    ///
+    /// Runs [`Grid::contours`], pairs each hole with the smallest exterior
+    /// that encloses it, and simplifies every ring with Douglas–Peucker.
+    /// Rings that collapse to fewer than three points are dropped. A
+    /// `tolerance` of 0 only removes collinear points, so straight walls
+    /// become single edges.
+    ///
+    /// # Arguments
+    ///
+    /// * `solid` - Decides which cells are part of a collider
+    /// * `tolerance` - The maximum distance, in cells, a simplified edge may stray from the traced outline
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// // A 4x4 block with the middle 2x2 carved out.
+    /// let mut grid: Grid<u8> = Grid::new(6, 6);
+    /// grid.map_inplace(|x, y, v| {
+    ///     let ring = |c: u16| c == 1 || c == 4;
+    ///     *v = ((1..5).contains(&x) && (1..5).contains(&y) && (ring(x) || ring(y))) as u8;
+    /// });
+    ///
+    /// let colliders = grid.colliders(|&v| v == 1, 0.0);
+    /// assert_eq!(colliders.len(), 1);
+    /// assert_eq!(colliders[0].exterior.len(), 8);
+    /// assert_eq!(colliders[0].holes.len(), 1);
+    /// ```
+    pub fn colliders<F>(&self, solid: F, tolerance: f32) -> Vec<Polygon>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let (exteriors, holes): (Vec<_>, Vec<_>) = self
+            .trace_rings(&solid)
+            .into_iter()
+            .partition(|ring| doubled_area(ring) > 0);
+
+        let mut polygons: Vec<Polygon> = exteriors
+            .iter()
+            .map(|ring| Polygon {
+                exterior: simplify_ring(&to_points(ring), tolerance),
+                holes: Vec::new(),
+            })
+            .collect();
+        for hole in &holes {
+            let probe = hole[0];
+            let owner = exteriors
+                .iter()
+                .enumerate()
+                .filter(|(_, ring)| contains(ring, probe))
+                .min_by_key(|(_, ring)| doubled_area(ring))
+                .map(|(i, _)| i);
+            if let Some(i) = owner {
+                let simplified = simplify_ring(&to_points(hole), tolerance);
+                if simplified.len() >= 3 {
+                    polygons[i].holes.push(simplified);
+                }
+            }
+        }
+        polygons.retain(|polygon| polygon.exterior.len() >= 3);
+        polygons
+    }
+
+    /// Runs marching squares and chains the segments into closed rings.
+    ///
+    /// Points are in doubled coordinates so that edge midpoints are integers.
+    fn trace_rings(&self, solid: &dyn Fn(&T) -> bool) -> Vec<Vec<(i32, i32)>> {
+        let (w, h) = (self.width as i32, self.height as i32);
+        let sample = |i: i32, j: i32| {
+            // Sample (i, j) is the centre of cell (i - 1, j - 1); the border is empty.
+            i >= 1
+                && j >= 1
+                && i <= w
+                && j <= h
+                && solid(&self.tiles[self.index((i - 1) as u16, (j - 1) as u16)])
+        };
+
+        let mut next = BTreeMap::new();
+        for j in 0..=h {
+            for i in 0..=w {
+                let corners = [
+                    sample(i, j),
+                    sample(i + 1, j),
+                    sample(i + 1, j + 1),
+                    sample(i, j + 1),
+                ];
+                let case = corners.iter().fold(0, |acc, &c| acc << 1 | c as usize);
+                for &(a, b) in SEGMENTS[case] {
+                    let (p, q) = (edge_midpoint(i, j, a), edge_midpoint(i, j, b));
+                    // Orient every segment so the solid corner of edge `a` lies on its right.
+                    let c = if corners[a] {
+                        corner(i, j, a)
+                    } else {
+                        corner(i, j, (a + 1) % 4)
+                    };
+                    let cross = (q.0 - p.0) * (c.1 - p.1) - (q.1 - p.1) * (c.0 - p.0);
+                    if cross > 0 {
+                        next.insert(p, q);
+                    } else {
+                        next.insert(q, p);
+                    }
+                }
+            }
+        }
+
+        let mut rings = Vec::new();
+        while let Some((start, mut to)) = next.pop_first() {
+            let mut ring = vec![start];
+            while to != start {
+                ring.push(to);
+                to = next
+                    .remove(&to)
+                    .expect("marching squares segments form closed rings");
+            }
+            rings.push(ring);
+        }
+        rings
+    }
+}
+
+/// Edge pairs crossed by the outline for each corner case.
+///
+/// Corners are numbered top-left, top-right, bottom-right, bottom-left, with
+/// the top-left corner as the highest bit; edge `n` runs from corner `n` to
+/// corner `n + 1` (top, right, bottom, left). The saddle cases 5 and 10 keep
+/// the two solid corners apart.
+const SEGMENTS: [&[(usize, usize)]; 16] = [
+    &[],
+    &[(3, 2)],
+    &[(2, 1)],
+    &[(3, 1)],
+    &[(1, 0)],
+    &[(1, 0), (3, 2)],
+    &[(0, 2)],
+    &[(3, 0)],
+    &[(0, 3)],
+    &[(0, 2)],
+    &[(0, 3), (2, 1)],
+    &[(0, 1)],
+    &[(3, 1)],
+    &[(2, 1)],
+    &[(3, 2)],
+    &[],
+];
+
+/// Returns corner `n` of square (i, j) in doubled coordinates.
+fn corner(i: i32, j: i32, n: usize) -> (i32, i32) {
+    let (dx, dy) = [(-1, -1), (1, -1), (1, 1), (-1, 1)][n];
+    (2 * i + dx, 2 * j + dy)
+}
+
+/// Returns the midpoint of edge `n` of square (i, j) in doubled coordinates.
+fn edge_midpoint(i: i32, j: i32, n: usize) -> (i32, i32) {
+    let (a, b) = (corner(i, j, n), corner(i, j, (n + 1) % 4));
+    ((a.0 + b.0) / 2, (a.1 + b.1) / 2)
+}
+
+fn to_points(ring: &[(i32, i32)]) -> Vec<(f32, f32)> {
+    ring.iter()
+        .map(|&(x, y)| (x as f32 / 2.0, y as f32 / 2.0))
+        .collect()
+}
+
+/// Returns twice the signed shoelace area of a ring.
+fn doubled_area(ring: &[(i32, i32)]) -> i64 {
+    let n = ring.len();
+    (0..n)
+        .map(|k| {
+            let (a, b) = (ring[k], ring[(k + 1) % n]);
+            a.0 as i64 * b.1 as i64 - b.0 as i64 * a.1 as i64
+        })
+        .sum()
+}
+
+/// Even-odd test; `point` never lies on another ring's edge.
+fn contains(ring: &[(i32, i32)], point: (i32, i32)) -> bool {
+    let (px, py) = (point.0 as i64, point.1 as i64);
+    let mut inside = false;
+    for k in 0..ring.len() {
+        let (ax, ay) = (ring[k].0 as i64, ring[k].1 as i64);
+        let (bx, by) = {
+            let b = ring[(k + 1) % ring.len()];
+            (b.0 as i64, b.1 as i64)
+        };
+        if (ay > py) != (by > py) {
+            // Compare px against the crossing x without dividing.
+            let lhs = (px - ax) * (by - ay);
+            let rhs = (bx - ax) * (py - ay);
+            if (lhs < rhs) == (by > ay) {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// Simplifies an open polyline with the Douglas–Peucker algorithm.
+///
+/// The endpoints are always kept; an interior point is kept only if
+/// dropping it would move the line by more than `tolerance`.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::simplify_polyline;
+///
+/// let line = [(0.0, 0.0), (1.0, 0.1), (2.0, -0.1), (3.0, 5.0), (4.0, 6.0)];
+/// assert_eq!(
+///     simplify_polyline(&line, 0.5),
+///     vec![(0.0, 0.0), (2.0, -0.1), (3.0, 5.0), (4.0, 6.0)]
+/// );
+/// ```
+pub fn simplify_polyline(points: &[(f32, f32)], tolerance: f32) -> Vec<(f32, f32)> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+    let mut stack = vec![(0, points.len() - 1)];
+    while let Some((first, last)) = stack.pop() {
+        let farthest = (first + 1..last)
+            .map(|k| (k, segment_distance(points[k], points[first], points[last])))
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((k, distance)) = farthest
+            && distance > tolerance
+        {
+            keep[k] = true;
+            stack.push((first, k));
+            stack.push((k, last));
+        }
+    }
+    points
+        .iter()
+        .zip(keep)
+        .filter(|&(_, kept)| kept)
+        .map(|(&point, _)| point)
+        .collect()
+}
+
+/// Simplifies a closed ring by splitting it at the point farthest from its start.
+fn simplify_ring(ring: &[(f32, f32)], tolerance: f32) -> Vec<(f32, f32)> {
+    let start = ring[0];
+    let split = (1..ring.len())
+        .max_by(|&a, &b| {
+            let da = squared(ring[a], start);
+            let db = squared(ring[b], start);
+            da.total_cmp(&db)
+        })
+        .unwrap_or(0);
+    let mut closed = ring.to_vec();
+    closed.push(start);
+    let mut simplified = simplify_polyline(&closed[..=split], tolerance);
+    let second = simplify_polyline(&closed[split..], tolerance);
+    simplified.extend_from_slice(&second[1..second.len() - 1]);
+    // The start itself may sit in the middle of a straight wall.
+    if simplified.len() > 3 {
+        let n = simplified.len();
+        if segment_distance(simplified[0], simplified[n - 1], simplified[1]) <= tolerance {
+            simplified.remove(0);
+        }
+    }
+    simplified
+}
+
+fn squared(a: (f32, f32), b: (f32, f32)) -> f32 {
+    (a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)
+}
+
+/// Distance from `p` to the segment from `a` to `b`.
+fn segment_distance(p: (f32, f32), a: (f32, f32), b: (f32, f32)) -> f32 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length = dx * dx + dy * dy;
+    if length == 0.0 {
+        return squared(p, a).sqrt();
+    }
+    let t = (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / length).clamp(0.0, 1.0);
+    squared(p, (a.0 + t * dx, a.1 + t * dy)).sqrt()
+}
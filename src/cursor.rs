@@ -0,0 +1,191 @@
+use crate::{Direction, Grid};
+
+/// How a [`GridCursor`] treats moves that would leave the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EdgePolicy {
+    /// The move is rejected and the cursor stays put.
+    #[default]
+    Block,
+    /// The cursor stops at the nearest edge cell.
+    Clamp,
+    /// The cursor re-enters from the opposite edge.
+    Wrap,
+}
+
+/// A position on a grid that can be moved relative to itself.
+///
+/// The cursor mutably borrows the grid, so cells can be read and written at
+/// the current position while navigating, which suits turtle-style generation
+/// and agent movement code.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{Direction, Grid, GridCursor};
+///
+/// let mut grid: Grid<char> = Grid::with_value(5, 5, '.');
+/// let mut cursor = GridCursor::new(&mut grid, 0, 0).unwrap();
+/// for _ in 0..3 {
+///     cursor.set('#');
+///     cursor.move_dir(Direction::SouthEast);
+/// }
+/// assert_eq!(cursor.position(), (3, 3));
+/// assert_eq!(grid.get(2, 2), Some(&'#'));
+/// ```
+pub struct GridCursor<'a, T> {
+    grid: &'a mut Grid<T>,
+    x: u16,
+    y: u16,
+    policy: EdgePolicy,
+}
+
+impl<'a, T: Default + Clone> GridCursor<'a, T> {
+    /// Creates a cursor at (x, y) using [`EdgePolicy::Block`].
+    ///
+    /// Returns `None` if the starting position is out of bounds.
+    pub fn new(grid: &'a mut Grid<T>, x: u16, y: u16) -> Option<GridCursor<'a, T>> {
+        if x < grid.width() && y < grid.height() {
+            Some(GridCursor {
+                grid,
+                x,
+                y,
+                policy: EdgePolicy::Block,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Sets how moves that leave the grid are handled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{EdgePolicy, Grid, GridCursor};
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(4, 4);
+    /// let mut cursor = GridCursor::new(&mut grid, 3, 0).unwrap().with_policy(EdgePolicy::Wrap);
+    /// cursor.move_by(2, -1);
+    /// assert_eq!(cursor.position(), (1, 3));
+    /// ```
+    pub fn with_policy(mut self, policy: EdgePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Returns the edge policy of this cursor.
+    pub fn policy(&self) -> EdgePolicy {
+        self.policy
+    }
+
+    /// Returns the current (x, y) position.
+    pub fn position(&self) -> (u16, u16) {
+        (self.x, self.y)
+    }
+
+    /// Moves the cursor to an absolute position.
+    ///
+    /// Returns `false` and leaves the cursor unchanged if (x, y) is out of bounds.
+    pub fn move_to(&mut self, x: u16, y: u16) -> bool {
+        if x < self.grid.width() && y < self.grid.height() {
+            self.x = x;
+            self.y = y;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Moves the cursor by (dx, dy) according to its edge policy.
+    ///
+    /// Returns `true` if the cursor ended up on a different cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{EdgePolicy, Grid, GridCursor};
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(4, 4);
+    /// let mut cursor = GridCursor::new(&mut grid, 1, 1).unwrap();
+    /// assert!(!cursor.move_by(5, 0));
+    /// assert_eq!(cursor.position(), (1, 1));
+    ///
+    /// let mut cursor = cursor.with_policy(EdgePolicy::Clamp);
+    /// assert!(cursor.move_by(5, 0));
+    /// assert_eq!(cursor.position(), (3, 1));
+    /// ```
+    pub fn move_by(&mut self, dx: i32, dy: i32) -> bool {
+        match self.resolve(dx, dy) {
+            Some((x, y)) if (x, y) != (self.x, self.y) => {
+                self.x = x;
+                self.y = y;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Moves the cursor one step in the given direction.
+    ///
+    /// Returns `true` if the cursor ended up on a different cell.
+    pub fn move_dir(&mut self, direction: Direction) -> bool {
+        let (dx, dy) = direction.offset();
+        self.move_by(dx, dy)
+    }
+
+    /// Returns the cell one step away in the given direction without moving.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Direction, Grid, GridCursor};
+    ///
+    /// let mut grid: Grid<i32> = Grid::new(3, 3);
+    /// let _ = grid.set(1, 0, 5);
+    /// let cursor = GridCursor::new(&mut grid, 1, 1).unwrap();
+    /// assert_eq!(cursor.peek(Direction::North), Some(&5));
+    /// ```
+    pub fn peek(&self, direction: Direction) -> Option<&T> {
+        let (dx, dy) = direction.offset();
+        let (x, y) = self.resolve(dx, dy)?;
+        self.grid.get(x, y)
+    }
+
+    /// Returns the cell under the cursor.
+    pub fn get(&self) -> &T {
+        &self.grid.as_slice()[self.flat_index()]
+    }
+
+    /// Returns a mutable reference to the cell under the cursor.
+    pub fn get_mut(&mut self) -> &mut T {
+        let index = self.flat_index();
+        &mut self.grid.as_mut_slice()[index]
+    }
+
+    /// Replaces the cell under the cursor, returning the previous value.
+    pub fn set(&mut self, value: T) -> T {
+        std::mem::replace(self.get_mut(), value)
+    }
+
+    fn flat_index(&self) -> usize {
+        (self.y as usize) * (self.grid.width() as usize) + (self.x as usize)
+    }
+
+    fn resolve(&self, dx: i32, dy: i32) -> Option<(u16, u16)> {
+        let width = self.grid.width() as i64;
+        let height = self.grid.height() as i64;
+        let x = self.x as i64 + dx as i64;
+        let y = self.y as i64 + dy as i64;
+        let (x, y) = match self.policy {
+            EdgePolicy::Block => {
+                if x < 0 || y < 0 || x >= width || y >= height {
+                    return None;
+                }
+                (x, y)
+            }
+            EdgePolicy::Clamp => (x.clamp(0, width - 1), y.clamp(0, height - 1)),
+            EdgePolicy::Wrap => (x.rem_euclid(width), y.rem_euclid(height)),
+        };
+        Some((x as u16, y as u16))
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt::{Debug, Write};
+
+use crate::Grid;
+
+/// Differing cells listed individually before the report is cut short.
+const MAX_LISTED: usize = 20;
+/// Largest side, in cells, for which the report draws a map of the differences.
+const MAX_MAP_SIDE: u16 = 100;
+
+impl<T: Default + Clone + PartialEq + Debug> Grid<T> {
+    /// Describes how this grid differs from `other`, or returns `None` if they are equal.
+    ///
+    /// A size mismatch is reported on its own. Otherwise the report counts
+    /// the differing cells, draws a map with `X` on every differing cell
+    /// and `.` elsewhere (for grids up to 100 cells on each side), and lists
+    /// the first 20 differences as `(x, y): left != right` using `Debug`.
+    /// See also [`assert_grids_eq!`](crate::assert_grids_eq).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let expected: Grid<u8> = Grid::new(3, 2);
+    /// let mut actual = Grid::new(3, 2);
+    /// assert_eq!(actual.diff_report(&expected), None);
+    ///
+    /// let _ = actual.set(2, 1, 7);
+    /// let report = actual.diff_report(&expected).unwrap();
+    /// assert!(report.starts_with("1 of 6 cells differ"));
+    /// assert!(report.contains("...\n..X\n"));
+    /// assert!(report.contains("(2, 1): 7 != 0"));
+    /// ```
+    pub fn diff_report(&self, other: &Grid<T>) -> Option<String> {
+        if (self.width, self.height) != (other.width, other.height) {
+            return Some(format!(
+                "Grid sizes differ: {}x{} vs {}x{}",
+                self.width, self.height, other.width, other.height
+            ));
+        }
+        let differing: Vec<usize> = (0..self.tiles.len())
+            .filter(|&i| self.tiles[i] != other.tiles[i])
+            .collect();
+        if differing.is_empty() {
+            return None;
+        }
+
+        let mut report = format!("{} of {} cells differ\n", differing.len(), self.tiles.len());
+        if self.width <= MAX_MAP_SIDE && self.height <= MAX_MAP_SIDE {
+            let mut map = vec![b'.'; self.tiles.len()];
+            for &i in &differing {
+                map[i] = b'X';
+            }
+            report.push('\n');
+            for row in map.chunks(self.width as usize) {
+                report.push_str(std::str::from_utf8(row).unwrap_or_default());
+                report.push('\n');
+            }
+            report.push('\n');
+        }
+        for &i in differing.iter().take(MAX_LISTED) {
+            let (x, y) = self.coords(i);
+            let _ = writeln!(
+                report,
+                "({x}, {y}): {:?} != {:?}",
+                self.tiles[i], other.tiles[i]
+            );
+        }
+        if differing.len() > MAX_LISTED {
+            let _ = writeln!(report, "... and {} more", differing.len() - MAX_LISTED);
+        }
+        Some(report)
+    }
+}
+
+/// Asserts that two grids are equal, panicking with a [`Grid::diff_report`] if not.
+///
+/// Accepts an optional format string and arguments like `assert_eq!`,
+/// which are printed above the report.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{Grid, assert_grids_eq};
+///
+/// let mut a: Grid<char> = Grid::with_value(4, 4, '#');
+/// let b = Grid::with_value(4, 4, '#');
+/// assert_grids_eq!(a, b);
+///
+/// let _ = a.set(1, 2, '.');
+/// let failed = std::panic::catch_unwind(|| assert_grids_eq!(a, b, "seed {}", 7));
+/// assert!(failed.is_err());
+/// ```
+#[macro_export]
+macro_rules! assert_grids_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        if let Some(report) = $crate::Grid::diff_report(&$left, &$right) {
+            panic!("assertion failed: grids differ\n{}", report);
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        if let Some(report) = $crate::Grid::diff_report(&$left, &$right) {
+            panic!("assertion failed: grids differ: {}\n{}", format_args!($($arg)+), report);
+        }
+    };
+}
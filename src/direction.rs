@@ -0,0 +1,87 @@
+/// One of the eight compass directions on a grid.
+///
+/// The y axis grows downwards (row-major order), so `North` points towards
+/// row 0.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Direction;
+///
+/// assert_eq!(Direction::North.offset(), (0, -1));
+/// assert_eq!(Direction::East.opposite(), Direction::West);
+/// assert_eq!(Direction::North.rotate_cw(), Direction::NorthEast);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The four orthogonal directions, clockwise from north.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// Returns the (dx, dy) step for this direction.
+    pub fn offset(self) -> (i32, i32) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+
+    /// Returns the direction pointing the other way.
+    pub fn opposite(self) -> Direction {
+        self.rotate(4)
+    }
+
+    /// Returns the next direction clockwise (45 degrees).
+    pub fn rotate_cw(self) -> Direction {
+        self.rotate(1)
+    }
+
+    /// Returns the next direction counter-clockwise (45 degrees).
+    pub fn rotate_ccw(self) -> Direction {
+        self.rotate(7)
+    }
+
+    /// Returns `true` for the four diagonal directions.
+    pub fn is_diagonal(self) -> bool {
+        let (dx, dy) = self.offset();
+        dx != 0 && dy != 0
+    }
+
+    fn rotate(self, steps: usize) -> Direction {
+        let index = Direction::ALL.iter().position(|&d| d == self).unwrap_or(0);
+        Direction::ALL[(index + steps) % 8]
+    }
+}
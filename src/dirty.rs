@@ -0,0 +1,174 @@
+use crate::{BitGrid, GridError, Rect};
+
+/// Per-cell dirty flags that aggregate into a coarse grid of blocks.
+///
+/// Marking a cell also marks the `block_size × block_size` block containing
+/// it, so a consumer such as a renderer, autotiler or path cache can ask
+/// "did anything in this block change?" without scanning cells, and only
+/// looks at individual cells inside blocks that are dirty. Each system
+/// that consumes changes at its own pace can keep its own `DirtyGrid`.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{DirtyGrid, Rect};
+///
+/// let mut dirty = DirtyGrid::new(64, 64, 16).unwrap();
+/// dirty.mark(20, 5).unwrap();
+///
+/// assert!(dirty.is_block_dirty(1, 0));
+/// assert_eq!(dirty.dirty_blocks().collect::<Vec<_>>(), vec![(1, 0)]);
+/// assert!(dirty.any_dirty_in(&Rect::new(18, 0, 4, 8)));
+/// assert!(!dirty.any_dirty_in(&Rect::new(16, 6, 16, 10)));
+///
+/// dirty.clear();
+/// assert!(dirty.is_clean());
+/// ```
+#[derive(Debug, Clone)]
+pub struct DirtyGrid {
+    cells: BitGrid,
+    blocks: BitGrid,
+    block_size: u16,
+}
+
+impl DirtyGrid {
+    /// Creates clean flags for a `width × height` grid grouped into
+    /// `block_size × block_size` blocks; blocks on the right and bottom
+    /// edges may be smaller.
+    ///
+    /// Returns [`GridError::InvalidArgument`] if `block_size` is 0.
+    pub fn new(width: u16, height: u16, block_size: u16) -> Result<DirtyGrid, GridError> {
+        if block_size == 0 {
+            return Err(GridError::InvalidArgument(
+                "Block size must be at least 1".to_string(),
+            ));
+        }
+        Ok(DirtyGrid {
+            cells: BitGrid::new(width, height),
+            blocks: BitGrid::new(width.div_ceil(block_size), height.div_ceil(block_size)),
+            block_size,
+        })
+    }
+
+    /// Returns the width of the tracked grid.
+    pub fn width(&self) -> u16 {
+        self.cells.width()
+    }
+
+    /// Returns the height of the tracked grid.
+    pub fn height(&self) -> u16 {
+        self.cells.height()
+    }
+
+    /// Returns the side length of a block in cells.
+    pub fn block_size(&self) -> u16 {
+        self.block_size
+    }
+
+    /// Returns the number of blocks along x and y.
+    pub fn block_dimensions(&self) -> (u16, u16) {
+        (self.blocks.width(), self.blocks.height())
+    }
+
+    /// Returns the block containing cell (x, y).
+    pub fn block_of(&self, x: u16, y: u16) -> (u16, u16) {
+        (x / self.block_size, y / self.block_size)
+    }
+
+    /// Returns the cells covered by block (bx, by), or `None` if it is out of bounds.
+    pub fn block_rect(&self, bx: u16, by: u16) -> Option<Rect> {
+        self.blocks.get(bx, by)?;
+        let (x, y) = (bx * self.block_size, by * self.block_size);
+        Some(Rect::new(
+            x,
+            y,
+            self.block_size.min(self.width() - x),
+            self.block_size.min(self.height() - y),
+        ))
+    }
+
+    /// Marks cell (x, y) and its block dirty.
+    ///
+    /// Returns [`GridError::OutOfBounds`] if the coordinates are out of bounds.
+    pub fn mark(&mut self, x: u16, y: u16) -> Result<(), GridError> {
+        self.cells.set(x, y, true)?;
+        let (bx, by) = self.block_of(x, y);
+        self.blocks.set(bx, by, true)
+    }
+
+    /// Marks every cell of `rect` that lies inside the grid dirty.
+    pub fn mark_rect(&mut self, rect: &Rect) {
+        let bounds = Rect::new(0, 0, self.width(), self.height());
+        if let Some(area) = rect.intersection(&bounds) {
+            for (x, y) in area.cells() {
+                let _ = self.mark(x, y);
+            }
+        }
+    }
+
+    /// Returns `true` if cell (x, y) has been marked since the last clear.
+    pub fn is_dirty(&self, x: u16, y: u16) -> bool {
+        self.cells.get(x, y) == Some(true)
+    }
+
+    /// Returns `true` if any cell in block (bx, by) has been marked since the last clear.
+    pub fn is_block_dirty(&self, bx: u16, by: u16) -> bool {
+        self.blocks.get(bx, by) == Some(true)
+    }
+
+    /// Returns `true` if nothing has been marked since the last clear.
+    pub fn is_clean(&self) -> bool {
+        self.blocks.count_ones() == 0
+    }
+
+    /// Returns `true` if any cell inside `rect` is dirty.
+    ///
+    /// Only blocks overlapping `rect` are consulted, and cells are checked
+    /// only where a dirty block is partly covered.
+    pub fn any_dirty_in(&self, rect: &Rect) -> bool {
+        let bounds = Rect::new(0, 0, self.width(), self.height());
+        let Some(area) = rect.intersection(&bounds) else {
+            return false;
+        };
+        let (first_x, first_y) = self.block_of(area.x, area.y);
+        let (last_x, last_y) = self.block_of((area.right() - 1) as u16, (area.bottom() - 1) as u16);
+        (first_y..=last_y).any(|by| {
+            (first_x..=last_x).any(|bx| {
+                self.is_block_dirty(bx, by)
+                    && self
+                        .block_rect(bx, by)
+                        .and_then(|block| block.intersection(&area))
+                        .is_some_and(|part| part.cells().any(|(x, y)| self.is_dirty(x, y)))
+            })
+        })
+    }
+
+    /// Returns an iterator over the dirty blocks in row-major order.
+    pub fn dirty_blocks(&self) -> impl Iterator<Item = (u16, u16)> + '_ {
+        self.blocks.iter_ones()
+    }
+
+    /// Returns an iterator over the dirty cells, block by block.
+    pub fn dirty_cells(&self) -> impl Iterator<Item = (u16, u16)> + '_ {
+        self.dirty_blocks()
+            .filter_map(|(bx, by)| self.block_rect(bx, by))
+            .flat_map(|block| block.cells())
+            .filter(|&(x, y)| self.is_dirty(x, y))
+    }
+
+    /// Clears every flag.
+    pub fn clear(&mut self) {
+        self.cells = BitGrid::new(self.width(), self.height());
+        self.blocks = BitGrid::new(self.blocks.width(), self.blocks.height());
+    }
+
+    /// Clears the flags of block (bx, by) and the cells in it.
+    pub fn clear_block(&mut self, bx: u16, by: u16) {
+        if let Some(block) = self.block_rect(bx, by) {
+            for (x, y) in block.cells() {
+                let _ = self.cells.set(x, y, false);
+            }
+            let _ = self.blocks.set(bx, by, false);
+        }
+    }
+}
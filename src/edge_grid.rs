@@ -0,0 +1,193 @@
+use crate::pathfinding::MoveRules;
+use crate::{Direction, GridError, LineIter};
+
+/// Data stored on the edges between orthogonally adjacent cells.
+///
+/// A companion to a [`Grid`](crate::Grid) of the same size for things that
+/// live between cells rather than in them: thin walls, doors, rivers,
+/// fences. Each edge is addressed by the two cells it separates, in either
+/// order. Edges on the outer border of the grid are not stored.
+///
+/// `EdgeGrid<bool>` implements [`MoveRules`] with `true` meaning "wall", so
+/// pathfinding can consult it through
+/// [`astar_with_rules`](crate::pathfinding::astar_with_rules) and
+/// [`movement_range_with_rules`](crate::pathfinding::movement_range_with_rules).
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{Direction, EdgeGrid};
+///
+/// let mut walls: EdgeGrid<bool> = EdgeGrid::new(4, 4);
+/// walls.set_between((1, 1), (2, 1), true).unwrap();
+///
+/// assert_eq!(walls.edge_between((2, 1), (1, 1)), Some(&true));
+/// assert_eq!(walls.edge(1, 1, Direction::East), Some(&true));
+/// assert_eq!(walls.edge_between((1, 1), (1, 2)), Some(&false));
+/// // Not neighbours, so there is no edge between them.
+/// assert_eq!(walls.edge_between((1, 1), (2, 2)), None);
+/// ```
+pub struct EdgeGrid<E> {
+    width: u16,
+    height: u16,
+    // Edge between (x, y) and (x + 1, y), row-major over (width - 1) × height.
+    east: Vec<E>,
+    // Edge between (x, y) and (x, y + 1), row-major over width × (height - 1).
+    south: Vec<E>,
+}
+
+impl<E: Default + Clone> EdgeGrid<E> {
+    /// Creates edges for a `width × height` grid, all set to `E::default()`.
+    pub fn new(width: u16, height: u16) -> EdgeGrid<E> {
+        EdgeGrid::with_value(width, height, E::default())
+    }
+}
+
+impl<E: Clone> EdgeGrid<E> {
+    /// Creates edges for a `width × height` grid, all set to `value`.
+    pub fn with_value(width: u16, height: u16, value: E) -> EdgeGrid<E> {
+        let (w, h) = (width as usize, height as usize);
+        EdgeGrid {
+            width,
+            height,
+            east: vec![value.clone(); w.saturating_sub(1) * h],
+            south: vec![value; w * h.saturating_sub(1)],
+        }
+    }
+}
+
+impl<E> EdgeGrid<E> {
+    /// Returns the width of the cell grid.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Returns the height of the cell grid.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Returns the edge between cells `a` and `b`.
+    ///
+    /// Returns `None` if either cell is out of bounds or they are not
+    /// orthogonal neighbours.
+    pub fn edge_between(&self, a: (u16, u16), b: (u16, u16)) -> Option<&E> {
+        match self.slot(a, b)? {
+            Slot::East(i) => Some(&self.east[i]),
+            Slot::South(i) => Some(&self.south[i]),
+        }
+    }
+
+    /// Returns a mutable reference to the edge between cells `a` and `b`.
+    pub fn edge_between_mut(&mut self, a: (u16, u16), b: (u16, u16)) -> Option<&mut E> {
+        match self.slot(a, b)? {
+            Slot::East(i) => Some(&mut self.east[i]),
+            Slot::South(i) => Some(&mut self.south[i]),
+        }
+    }
+
+    /// Sets the edge between cells `a` and `b`.
+    ///
+    /// Returns [`GridError::OutOfBounds`] if either cell is out of bounds,
+    /// or [`GridError::InvalidArgument`] if they are not orthogonal
+    /// neighbours.
+    pub fn set_between(&mut self, a: (u16, u16), b: (u16, u16), value: E) -> Result<(), GridError> {
+        for (x, y) in [a, b] {
+            if x >= self.width || y >= self.height {
+                return Err(GridError::OutOfBounds {
+                    x,
+                    y,
+                    width: self.width,
+                    height: self.height,
+                });
+            }
+        }
+        match self.edge_between_mut(a, b) {
+            Some(edge) => {
+                *edge = value;
+                Ok(())
+            }
+            None => Err(GridError::InvalidArgument(format!(
+                "Cells {a:?} and {b:?} are not orthogonal neighbours"
+            ))),
+        }
+    }
+
+    /// Returns the edge on the `direction` side of cell (x, y).
+    ///
+    /// Returns `None` for diagonal directions and for sides on the grid border.
+    pub fn edge(&self, x: u16, y: u16, direction: Direction) -> Option<&E> {
+        if direction.is_diagonal() {
+            return None;
+        }
+        let (dx, dy) = direction.offset();
+        let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+        if nx < 0 || ny < 0 {
+            return None;
+        }
+        self.edge_between((x, y), (nx as u16, ny as u16))
+    }
+
+    /// Returns `true` if the straight line between the centres of `a` and `b`
+    /// crosses an edge for which `blocks` returns `true`.
+    ///
+    /// The line is walked as a supercover, so it is checked against every
+    /// edge it crosses. A line passing exactly through a corner is blocked by
+    /// any of the four edges meeting there. Use this to let line of sight and
+    /// field of view respect thin walls.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::EdgeGrid;
+    ///
+    /// let mut walls: EdgeGrid<bool> = EdgeGrid::new(5, 5);
+    /// walls.set_between((2, 0), (2, 1), true).unwrap();
+    ///
+    /// assert!(walls.line_blocked((2, 0), (2, 4), |&wall| wall));
+    /// assert!(!walls.line_blocked((0, 0), (4, 0), |&wall| wall));
+    /// ```
+    pub fn line_blocked(&self, a: (u16, u16), b: (u16, u16), blocks: impl Fn(&E) -> bool) -> bool {
+        let mut recent: [Option<(u16, u16)>; 2] = [None, None];
+        for cell in LineIter::supercover(a, b) {
+            let crosses = recent
+                .iter()
+                .flatten()
+                .filter_map(|&previous| self.edge_between(previous, cell));
+            for edge in crosses {
+                if blocks(edge) {
+                    return true;
+                }
+            }
+            recent = [Some(cell), recent[0]];
+        }
+        false
+    }
+
+    fn slot(&self, a: (u16, u16), b: (u16, u16)) -> Option<Slot> {
+        let (first, second) = if a <= b { (a, b) } else { (b, a) };
+        if second.0 >= self.width || second.1 >= self.height {
+            return None;
+        }
+        let (x, y) = (first.0 as usize, first.1 as usize);
+        if first.1 == second.1 && first.0 + 1 == second.0 {
+            Some(Slot::East(y * (self.width as usize - 1) + x))
+        } else if first.0 == second.0 && first.1 + 1 == second.1 {
+            Some(Slot::South(y * self.width as usize + x))
+        } else {
+            None
+        }
+    }
+}
+
+enum Slot {
+    East(usize),
+    South(usize),
+}
+
+/// `true` edges are walls that movement cannot cross.
+impl MoveRules for EdgeGrid<bool> {
+    fn blocks(&self, from: (u16, u16), to: (u16, u16)) -> bool {
+        self.edge_between(from, to) == Some(&true)
+    }
+}
@@ -0,0 +1,100 @@
+use std::fmt;
+
+/// Errors reported by grid operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    /// The coordinates lie outside a `width × height` grid.
+    OutOfBounds {
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+    },
+    /// Row `y` does not exist in a `width × height` grid.
+    RowOutOfBounds { y: u16, width: u16, height: u16 },
+    /// Column `x` does not exist in a `width × height` grid.
+    ColumnOutOfBounds { x: u16, width: u16, height: u16 },
+    /// Two grids (or a grid and a mask) that must have the same size do not.
+    ///
+    /// Sizes are `(width, height)`.
+    DimensionMismatch {
+        expected: (u16, u16),
+        found: (u16, u16),
+    },
+    /// A buffer holds a different number of elements than the operation needs.
+    LengthMismatch { expected: usize, found: usize },
+    /// The operation needs at least one cell.
+    EmptyGrid,
+    /// A `width × height` grid would exceed the limit of `max_cells` cells.
+    TooLarge {
+        width: u16,
+        height: u16,
+        max_cells: usize,
+    },
+    /// The allocator could not provide memory for a `width × height` grid.
+    AllocationFailed { width: u16, height: u16 },
+    /// An argument is outside the range the operation accepts.
+    InvalidArgument(String),
+    /// Serialized grid data could not be decoded.
+    InvalidData(String),
+    /// The operation was stopped through a [`CancellationToken`](crate::CancellationToken).
+    Cancelled,
+    /// A user-supplied callback, such as a chunk loader, panicked with the
+    /// given message.
+    Panicked(String),
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::OutOfBounds {
+                x,
+                y,
+                width,
+                height,
+            } => write!(
+                f,
+                "Coordinates ({}, {}) out of bounds (grid is {}x{})",
+                x, y, width, height
+            ),
+            GridError::RowOutOfBounds { y, width, height } => {
+                write!(f, "Row {} out of bounds (grid is {}x{})", y, width, height)
+            }
+            GridError::ColumnOutOfBounds { x, width, height } => {
+                write!(
+                    f,
+                    "Column {} out of bounds (grid is {}x{})",
+                    x, width, height
+                )
+            }
+            GridError::DimensionMismatch { expected, found } => write!(
+                f,
+                "Grid dimensions differ ({}x{} vs {}x{})",
+                expected.0, expected.1, found.0, found.1
+            ),
+            GridError::LengthMismatch { expected, found } => {
+                write!(f, "Expected {} elements, got {}", expected, found)
+            }
+            GridError::EmptyGrid => write!(f, "Grid has no cells"),
+            GridError::TooLarge {
+                width,
+                height,
+                max_cells,
+            } => write!(
+                f,
+                "A {}x{} grid exceeds the limit of {} cells",
+                width, height, max_cells
+            ),
+            GridError::AllocationFailed { width, height } => {
+                write!(f, "Failed to allocate a {}x{} grid", width, height)
+            }
+            GridError::InvalidArgument(message) | GridError::InvalidData(message) => {
+                f.write_str(message)
+            }
+            GridError::Cancelled => write!(f, "Operation was cancelled"),
+            GridError::Panicked(message) => write!(f, "Callback panicked: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
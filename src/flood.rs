@@ -0,0 +1,145 @@
+use std::collections::VecDeque;
+
+use crate::{BitGrid, Direction, Grid};
+
+/// A lazy breadth-first walk over a 4-connected region of matching cells.
+///
+/// Created by [`Grid::iter_flood`]. Cells are discovered only as the
+/// iterator is advanced, so stopping early skips the rest of the region.
+pub struct FloodIter<'a, T, P> {
+    grid: &'a Grid<T>,
+    predicate: P,
+    queue: VecDeque<(u16, u16)>,
+    seen: BitGrid,
+}
+
+impl<'a, T, P> Iterator for FloodIter<'a, T, P>
+where
+    T: Default + Clone,
+    P: Fn(&T) -> bool,
+{
+    type Item = (u16, u16, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y) = self.queue.pop_front()?;
+        for direction in Direction::CARDINAL {
+            let (dx, dy) = direction.offset();
+            let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+            if nx < 0 || ny < 0 {
+                continue;
+            }
+            let (nx, ny) = (nx as u16, ny as u16);
+            if self.seen.get(nx, ny) == Some(false)
+                && let Some(value) = self.grid.get(nx, ny)
+                && (self.predicate)(value)
+            {
+                let _ = self.seen.set(nx, ny, true);
+                self.queue.push_back((nx, ny));
+            }
+        }
+        Some((x, y, &self.grid.tiles[self.grid.index(x, y)]))
+    }
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Returns a lazy iterator over the region of matching cells connected to (x, y).
+    ///
+    /// Cells are yielded as `(x, y, &value)` in breadth-first order, so they
+    /// come in non-decreasing 4-connected step distance from the start. The
+    /// iterator is empty if the start is out of bounds or does not match.
+    ///
+    /// # Arguments
+    ///
+    /// * `x`, `y` - The cell to start from
+    /// * `predicate` - Decides which cells belong to the region
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut map: Grid<char> = Grid::with_value(50, 50, '.');
+    /// let _ = map.set(3, 4, '$');
+    ///
+    /// // Stops as soon as the chest is found instead of mapping the whole cave.
+    /// let chest = map
+    ///     .iter_flood(0, 0, |&c| c != '#')
+    ///     .take(50)
+    ///     .find(|&(_, _, &c)| c == '$');
+    /// assert_eq!(chest.map(|(x, y, _)| (x, y)), Some((3, 4)));
+    /// ```
+    pub fn iter_flood<P>(&self, x: u16, y: u16, predicate: P) -> FloodIter<'_, T, P>
+    where
+        P: Fn(&T) -> bool,
+    {
+        let mut seen = BitGrid::new(self.width, self.height);
+        let mut queue = VecDeque::new();
+        if let Some(value) = self.get(x, y)
+            && predicate(value)
+        {
+            let _ = seen.set(x, y, true);
+            queue.push_back((x, y));
+        }
+        FloodIter {
+            grid: self,
+            predicate,
+            queue,
+            seen,
+        }
+    }
+
+    /// Returns every cell of the 4-connected region of matching cells around (x, y).
+    ///
+    /// Cells are listed in breadth-first order from the start. The result is
+    /// empty if the start is out of bounds or does not match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut cave: Grid<char> = Grid::with_value(5, 3, '#');
+    /// for x in 0..3 {
+    ///     let _ = cave.set(x, 1, '.');
+    /// }
+    ///
+    /// let region = cave.flood_region(0, 1, |&c| c == '.');
+    /// assert_eq!(region, vec![(0, 1), (1, 1), (2, 1)]);
+    /// assert!(cave.flood_region(4, 1, |&c| c == '.').is_empty());
+    /// ```
+    pub fn flood_region<P>(&self, x: u16, y: u16, predicate: P) -> Vec<(u16, u16)>
+    where
+        P: Fn(&T) -> bool,
+    {
+        self.iter_flood(x, y, predicate)
+            .map(|(x, y, _)| (x, y))
+            .collect()
+    }
+
+    /// Sets every cell of the 4-connected region of matching cells around (x, y) to `new_value`.
+    ///
+    /// The region is found before anything is written, so `new_value` may
+    /// itself match `predicate`. Returns the number of cells filled, which is
+    /// 0 if the start is out of bounds or does not match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut canvas = Grid::from_raw_parts(4, 1, vec![1, 1, 2, 1]).unwrap();
+    /// assert_eq!(canvas.flood_fill(0, 0, |&c| c == 1, 7), 2);
+    /// assert_eq!(canvas.as_slice(), &[7, 7, 2, 1]);
+    /// ```
+    pub fn flood_fill<P>(&mut self, x: u16, y: u16, predicate: P, new_value: T) -> usize
+    where
+        P: Fn(&T) -> bool,
+    {
+        let region = self.flood_region(x, y, predicate);
+        for &(x, y) in &region {
+            let index = self.index(x, y);
+            self.tiles[index] = new_value.clone();
+        }
+        region.len()
+    }
+}
@@ -0,0 +1,233 @@
+use crate::{EdgeGrid, Grid, GridLike};
+
+/// Returns the cells visible from `origin` within `radius`, using symmetric
+/// shadowcasting.
+///
+/// A cell is visible if it lies within Euclidean distance `radius` and an
+/// unobstructed line joins the centre of `origin` to it. Opaque cells are
+/// visible themselves but hide what is behind them, and cells outside the
+/// grid count as opaque. Visibility is symmetric between floor cells: if
+/// `b` is visible from `a`, then `a` is visible from `b`. The origin is
+/// always visible; every cell is hidden if it is out of bounds.
+///
+/// Generic over [`GridLike`], so it also runs on views and borrowed grids,
+/// and sight wraps around the edges of a [`TorusGrid`](crate::TorusGrid);
+/// see [`Grid::field_of_view`] for the method form.
+///
+/// # Arguments
+///
+/// * `grid` - The map to look across
+/// * `origin` - The viewer's cell
+/// * `radius` - The furthest distance that can be seen
+/// * `opaque` - Returns `true` for cells that block sight
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Grid;
+/// use gridsystem::fov::field_of_view;
+///
+/// // A pillar at (3, 2) casts a shadow away from the viewer.
+/// let mut walls: Grid<bool> = Grid::new(7, 5);
+/// let _ = walls.set(3, 2, true);
+///
+/// let seen = field_of_view(&walls, (1, 2), 10, |&wall| wall);
+/// assert_eq!(seen.get(3, 2), Some(&true));
+/// assert_eq!(seen.get(5, 2), Some(&false));
+/// assert_eq!(seen.get(5, 0), Some(&true));
+/// ```
+pub fn field_of_view<T, G, F>(grid: &G, origin: (u16, u16), radius: u16, opaque: F) -> Grid<bool>
+where
+    G: GridLike<T>,
+    F: Fn(&T) -> bool,
+{
+    let mut visible: Grid<bool> = Grid::new(grid.width(), grid.height());
+    if !grid.in_bounds(origin.0, origin.1) {
+        return visible;
+    }
+    let _ = visible.set(origin.0, origin.1, true);
+    let radius_sq = radius as i64 * radius as i64;
+    for quadrant in [
+        Quadrant::North,
+        Quadrant::East,
+        Quadrant::South,
+        Quadrant::West,
+    ] {
+        let cell = |depth: i64, col: i64| {
+            let (x, y) = quadrant.transform(origin, depth, col);
+            let (dx, dy) = (x - origin.0 as i64, y - origin.1 as i64);
+            grid.offset(origin.0, origin.1, dx as i32, dy as i32)
+        };
+        let is_wall = |depth: i64, col: i64| {
+            cell(depth, col)
+                .and_then(|(x, y)| grid.get(x, y))
+                .is_none_or(&opaque)
+        };
+
+        let mut rows = vec![Row {
+            depth: 1,
+            start: Slope::new(-1, 1),
+            end: Slope::new(1, 1),
+        }];
+        while let Some(mut row) = rows.pop() {
+            if row.depth > radius as i64 {
+                continue;
+            }
+            let mut previous_wall: Option<bool> = None;
+            for col in row.start.round_ties_up(row.depth)..=row.end.round_ties_down(row.depth) {
+                let wall = is_wall(row.depth, col);
+                if (wall || row.is_symmetric(col))
+                    && col * col + row.depth * row.depth <= radius_sq
+                    && let Some((x, y)) = cell(row.depth, col)
+                {
+                    let _ = visible.set(x, y, true);
+                }
+                if previous_wall == Some(true) && !wall {
+                    row.start = Slope::new(2 * col - 1, 2 * row.depth);
+                }
+                if previous_wall == Some(false) && wall {
+                    rows.push(Row {
+                        depth: row.depth + 1,
+                        start: row.start,
+                        end: Slope::new(2 * col - 1, 2 * row.depth),
+                    });
+                }
+                previous_wall = Some(wall);
+            }
+            if previous_wall == Some(false) {
+                rows.push(Row {
+                    depth: row.depth + 1,
+                    ..row
+                });
+            }
+        }
+    }
+    visible
+}
+
+/// Returns the cells visible from `origin`, also blocked by thin walls.
+///
+/// Behaves like [`field_of_view`], but a cell is hidden if the straight
+/// line from the centre of `origin` to its centre crosses an edge marked
+/// `true` in `walls`, as decided by [`EdgeGrid::line_blocked`].
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{EdgeGrid, Grid};
+/// use gridsystem::fov::field_of_view_with_edges;
+///
+/// let open: Grid<u8> = Grid::new(5, 5);
+/// let mut walls: EdgeGrid<bool> = EdgeGrid::new(5, 5);
+/// for y in 0..5 {
+///     walls.set_between((2, y), (3, y), true).unwrap();
+/// }
+///
+/// let seen = field_of_view_with_edges(&open, (0, 2), 10, &walls, |_| false);
+/// assert_eq!(seen.get(2, 4), Some(&true));
+/// assert_eq!(seen.get(3, 2), Some(&false));
+/// ```
+pub fn field_of_view_with_edges<T, G, F>(
+    grid: &G,
+    origin: (u16, u16),
+    radius: u16,
+    walls: &EdgeGrid<bool>,
+    opaque: F,
+) -> Grid<bool>
+where
+    G: GridLike<T>,
+    F: Fn(&T) -> bool,
+{
+    let mut visible = field_of_view(grid, origin, radius, opaque);
+    visible.map_inplace(|x, y, seen| {
+        if *seen && walls.line_blocked(origin, (x, y), |&wall| wall) {
+            *seen = false;
+        }
+    });
+    visible
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Returns the cells visible from `origin` within `radius`.
+    ///
+    /// See [`field_of_view`] for the rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let room: Grid<char> = Grid::with_value(9, 9, '.');
+    /// let seen = room.field_of_view((4, 4), 2, |&c| c == '#');
+    ///
+    /// assert_eq!(seen.get(6, 4), Some(&true));
+    /// assert_eq!(seen.get(6, 6), Some(&false));
+    /// ```
+    pub fn field_of_view<F>(&self, origin: (u16, u16), radius: u16, opaque: F) -> Grid<bool>
+    where
+        F: Fn(&T) -> bool,
+    {
+        field_of_view(self, origin, radius, opaque)
+    }
+}
+
+/// One of the four 90° sectors scanned from the origin.
+#[derive(Clone, Copy)]
+enum Quadrant {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Quadrant {
+    /// Maps a (depth, column) position in this sector to grid coordinates.
+    fn transform(self, origin: (u16, u16), depth: i64, col: i64) -> (i64, i64) {
+        let (ox, oy) = (origin.0 as i64, origin.1 as i64);
+        match self {
+            Quadrant::North => (ox + col, oy - depth),
+            Quadrant::South => (ox + col, oy + depth),
+            Quadrant::East => (ox + depth, oy + col),
+            Quadrant::West => (ox - depth, oy + col),
+        }
+    }
+}
+
+/// An exact slope `num / den` with `den > 0`.
+#[derive(Clone, Copy)]
+struct Slope {
+    num: i64,
+    den: i64,
+}
+
+impl Slope {
+    fn new(num: i64, den: i64) -> Slope {
+        Slope { num, den }
+    }
+
+    /// Returns `depth * slope` rounded to the nearest integer, halves up.
+    fn round_ties_up(self, depth: i64) -> i64 {
+        (2 * depth * self.num + self.den).div_euclid(2 * self.den)
+    }
+
+    /// Returns `depth * slope` rounded to the nearest integer, halves down.
+    fn round_ties_down(self, depth: i64) -> i64 {
+        -(-2 * depth * self.num + self.den).div_euclid(2 * self.den)
+    }
+}
+
+/// The cells at one depth of a sector that lie between two slopes.
+#[derive(Clone, Copy)]
+struct Row {
+    depth: i64,
+    start: Slope,
+    end: Slope,
+}
+
+impl Row {
+    /// Returns `true` if the centre of column `col` lies within the row's slopes.
+    fn is_symmetric(&self, col: i64) -> bool {
+        col * self.start.den >= self.depth * self.start.num
+            && col * self.end.den <= self.depth * self.end.num
+    }
+}
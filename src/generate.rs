@@ -0,0 +1,555 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::{BitGrid, Grid, GridError, LineIter, Rect};
+
+/// Settings for [`islands`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct IslandConfig {
+    /// Width of the generated map in cells.
+    pub width: u16,
+    /// Height of the generated map in cells.
+    pub height: u16,
+    /// Seed for the noise; the same config always produces the same map.
+    pub seed: u64,
+    /// Noise frequency of the first octave, in features per cell.
+    pub frequency: f32,
+    /// Number of noise layers summed together.
+    pub octaves: u32,
+    /// Frequency multiplier between octaves.
+    pub lacunarity: f32,
+    /// Amplitude multiplier between octaves.
+    pub persistence: f32,
+    /// How strongly heights are pulled down towards the map edge.
+    ///
+    /// At 1 or more the outermost ring of cells is always sea.
+    pub falloff: f32,
+    /// Cells with a height above this are land.
+    pub sea_level: f32,
+    /// Land masses smaller than this many cells are sunk.
+    pub min_island_cells: usize,
+    /// Enclosed lakes smaller than this many cells are filled in.
+    pub min_lake_cells: usize,
+    /// Where land may appear; cells outside it stay sea with height 0.
+    pub mask: Option<BitGrid>,
+}
+
+impl Default for IslandConfig {
+    fn default() -> Self {
+        IslandConfig {
+            width: 128,
+            height: 128,
+            seed: 0,
+            frequency: 1.0 / 32.0,
+            octaves: 5,
+            lacunarity: 2.0,
+            persistence: 0.5,
+            falloff: 1.0,
+            sea_level: 0.35,
+            min_island_cells: 16,
+            min_lake_cells: 8,
+            mask: None,
+        }
+    }
+}
+
+/// The output of [`islands`].
+pub struct Islands {
+    /// `true` for land, `false` for sea, after small islands and lakes are cleaned up.
+    pub land: Grid<bool>,
+    /// Heights in `0.0..=1.0` before thresholding and cleanup.
+    pub heights: Grid<f32>,
+}
+
+/// Generates a landmass map from layered noise.
+///
+/// The pipeline sums `octaves` layers of gradient noise (fBm), subtracts a
+/// square radial falloff scaled by `falloff` so land gathers towards the
+/// centre, thresholds the result at `sea_level`, and finally sinks islands
+/// smaller than `min_island_cells` and fills enclosed lakes smaller than
+/// `min_lake_cells` (4-connected). Sea touching the map border is never
+/// filled. With a `mask`, heights are only generated and land only placed
+/// where the mask is set, so a pass can be layered onto an earlier one.
+///
+/// Returns [`GridError::DimensionMismatch`] if the mask does not match
+/// `width × height`.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::generate::{IslandConfig, islands};
+///
+/// let config = IslandConfig { width: 64, height: 48, seed: 7, ..IslandConfig::default() };
+/// let map = islands(&config).unwrap();
+///
+/// assert_eq!((map.land.width(), map.land.height()), (64, 48));
+/// assert!(map.land.iter().any(|(_, _, &land)| land));
+/// assert!((0..64).all(|x| map.land.get(x, 0) == Some(&false)));
+/// assert_eq!(islands(&config).unwrap().heights.as_slice(), map.heights.as_slice());
+/// ```
+pub fn islands(config: &IslandConfig) -> Result<Islands, GridError> {
+    let mut heights: Grid<f32> = Grid::new(config.width, config.height);
+    let (half_w, half_h) = (config.width as f32 / 2.0, config.height as f32 / 2.0);
+    let mask = config.mask.as_ref();
+    let noise_config = NoiseConfig {
+        seed: config.seed,
+        frequency: config.frequency,
+        octaves: config.octaves,
+        lacunarity: config.lacunarity,
+        persistence: config.persistence,
+        mask: None,
+    };
+    heights.update_masked(mask, |x, y, h| {
+        let (cx, cy) = (x as f32 + 0.5, y as f32 + 0.5);
+        let noise = fbm(cx, cy, &noise_config) * 0.5 + 0.5;
+        // 0 at the centre, 1 on the outermost ring of cells.
+        let edge = ((cx - half_w).abs() / (half_w - 0.5).max(0.5))
+            .max((cy - half_h).abs() / (half_h - 0.5).max(0.5))
+            .min(1.0);
+        *h = (noise - config.falloff * edge * edge).clamp(0.0, 1.0);
+    })?;
+
+    let mut land = heights.map(|_, _, &h| h > config.sea_level);
+    remove_small_regions(&mut land, true, config.min_island_cells, true);
+    remove_small_regions(&mut land, false, config.min_lake_cells, false);
+    if let Some(mask) = mask {
+        // Lake filling must not spill land outside the mask.
+        land.update_masked(None, |x, y, cell| *cell &= mask.get(x, y) == Some(true))?;
+    }
+    Ok(Islands { land, heights })
+}
+
+/// Settings for [`Grid::from_noise`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoiseConfig {
+    /// Seed for the noise; the same config always produces the same field.
+    pub seed: u64,
+    /// Noise frequency of the first octave, in features per cell.
+    pub frequency: f32,
+    /// Number of noise layers summed together.
+    pub octaves: u32,
+    /// Frequency multiplier between octaves.
+    pub lacunarity: f32,
+    /// Amplitude multiplier between octaves.
+    pub persistence: f32,
+    /// Where noise is generated; cells outside it stay 0.
+    pub mask: Option<BitGrid>,
+}
+
+impl Default for NoiseConfig {
+    fn default() -> Self {
+        NoiseConfig {
+            seed: 0,
+            frequency: 1.0 / 32.0,
+            octaves: 5,
+            lacunarity: 2.0,
+            persistence: 0.5,
+            mask: None,
+        }
+    }
+}
+
+impl Grid<f32> {
+    /// Creates a grid of layered gradient noise in `0.0..=1.0`.
+    ///
+    /// Each cell samples `octaves` layers of seeded Perlin noise (fBm) at its
+    /// centre: every layer has `lacunarity` times the frequency and
+    /// `persistence` times the amplitude of the one before, so low octaves
+    /// shape large features and high ones add detail. The noise is
+    /// continuous across cells and depends only on the config, so the same
+    /// seed always gives the same field. With a `mask`, only cells where it
+    /// is set are filled.
+    ///
+    /// Returns [`GridError::DimensionMismatch`] if the mask does not match
+    /// `width × height`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    /// use gridsystem::generate::NoiseConfig;
+    ///
+    /// let config = NoiseConfig { seed: 42, octaves: 4, ..NoiseConfig::default() };
+    /// let elevation = Grid::from_noise(64, 32, config.clone()).unwrap();
+    ///
+    /// assert!(elevation.iter().all(|(_, _, &h)| (0.0..=1.0).contains(&h)));
+    /// // Neighbouring cells have similar heights.
+    /// assert!((elevation[(10, 10)] - elevation[(11, 10)]).abs() < 0.2);
+    /// assert_eq!(Grid::from_noise(64, 32, config).unwrap().as_slice(), elevation.as_slice());
+    /// ```
+    pub fn from_noise(
+        width: u16,
+        height: u16,
+        config: NoiseConfig,
+    ) -> Result<Grid<f32>, GridError> {
+        let mut field: Grid<f32> = Grid::new(width, height);
+        field.update_masked(config.mask.as_ref(), |x, y, value| {
+            let noise = fbm(x as f32 + 0.5, y as f32 + 0.5, &config);
+            *value = (noise * 0.5 + 0.5).clamp(0.0, 1.0);
+        })?;
+        Ok(field)
+    }
+}
+
+/// Flips 4-connected regions of `target` smaller than `min_cells`; regions
+/// touching the border are only flipped if `include_border` is set.
+fn remove_small_regions(
+    mask: &mut Grid<bool>,
+    target: bool,
+    min_cells: usize,
+    include_border: bool,
+) {
+    // One labelling pass keeps this linear in the map size however many
+    // regions there are.
+    let (labels, summary) = mask.connected_components(|a, b| a == b);
+    let (width, height) = (mask.width as u32, mask.height as u32);
+    let flip: Vec<bool> = summary
+        .sizes
+        .iter()
+        .zip(&summary.bounds)
+        .map(|(&size, bounds)| {
+            let touches_border = bounds.x == 0
+                || bounds.y == 0
+                || bounds.right() == width
+                || bounds.bottom() == height;
+            (size as usize) < min_cells && (include_border || !touches_border)
+        })
+        .collect();
+    for (cell, &label) in mask.tiles.iter_mut().zip(&labels.tiles) {
+        if *cell == target && flip[label as usize - 1] {
+            *cell = !target;
+        }
+    }
+}
+
+/// Fractal Brownian motion: octaves of gradient noise, normalised to about `-1.0..=1.0`.
+fn fbm(x: f32, y: f32, config: &NoiseConfig) -> f32 {
+    let (mut frequency, mut amplitude) = (config.frequency, 1.0);
+    let (mut sum, mut total) = (0.0, 0.0);
+    for octave in 0..config.octaves.max(1) {
+        let seed = config.seed ^ (octave as u64).wrapping_mul(0xD1B5_4A32_D192_ED03);
+        sum += amplitude * gradient_noise(x * frequency, y * frequency, seed);
+        total += amplitude;
+        frequency *= config.lacunarity;
+        amplitude *= config.persistence;
+    }
+    sum / total
+}
+
+/// Seeded 2D Perlin-style gradient noise in about `-1.0..=1.0`.
+fn gradient_noise(x: f32, y: f32, seed: u64) -> f32 {
+    let (x0, y0) = (x.floor(), y.floor());
+    let (fx, fy) = (x - x0, y - y0);
+    let (ix, iy) = (x0 as i64, y0 as i64);
+    let corner = |dx: i64, dy: i64| {
+        let angle = (hash(ix + dx, iy + dy, seed) >> 40) as f32 / (1u64 << 24) as f32
+            * std::f32::consts::TAU;
+        let (gx, gy) = (angle.cos(), angle.sin());
+        gx * (fx - dx as f32) + gy * (fy - dy as f32)
+    };
+    let fade = |t: f32| t * t * t * (t * (t * 6.0 - 15.0) + 10.0);
+    let (u, v) = (fade(fx), fade(fy));
+    let top = corner(0, 0) + u * (corner(1, 0) - corner(0, 0));
+    let bottom = corner(0, 1) + u * (corner(1, 1) - corner(0, 1));
+    // Gradient noise peaks at about ±0.71; scale it to fill the range.
+    (top + v * (bottom - top)) * std::f32::consts::SQRT_2
+}
+
+/// SplitMix64 over the lattice point and seed.
+fn hash(x: i64, y: i64, seed: u64) -> u64 {
+    let mut z = seed
+        ^ (x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (y as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// What a cell of a generated dungeon holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DungeonTile {
+    /// Solid rock.
+    #[default]
+    Wall,
+    /// Floor inside a room.
+    Room,
+    /// Floor carved by a corridor.
+    Corridor,
+}
+
+/// Settings for [`dungeon`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DungeonConfig {
+    /// Width of the generated map in cells.
+    pub width: u16,
+    /// Height of the generated map in cells.
+    pub height: u16,
+    /// The most rooms to place; fewer are placed if the map fills up.
+    pub max_rooms: usize,
+    /// Smallest room side length, walls excluded.
+    pub min_room_size: u16,
+    /// Largest room side length, walls excluded.
+    pub max_room_size: u16,
+    /// Chance of adding each candidate extra connection that closes a loop.
+    pub loop_chance: f32,
+}
+
+impl Default for DungeonConfig {
+    fn default() -> Self {
+        DungeonConfig {
+            width: 80,
+            height: 50,
+            max_rooms: 12,
+            min_room_size: 4,
+            max_room_size: 10,
+            loop_chance: 0.2,
+        }
+    }
+}
+
+/// The output of [`dungeon`].
+pub struct Dungeon {
+    /// The carved map.
+    pub tiles: Grid<DungeonTile>,
+    /// The floor area of every room.
+    pub rooms: Vec<Rect>,
+    /// Pairs of indices into `rooms` joined by a corridor, each listed once.
+    pub connections: Vec<(usize, usize)>,
+}
+
+impl Dungeon {
+    /// Returns the rooms directly connected to `room` by a corridor.
+    pub fn neighbours(&self, room: usize) -> impl Iterator<Item = usize> + '_ {
+        self.connections
+            .iter()
+            .filter_map(move |&(a, b)| match room {
+                r if r == a => Some(b),
+                r if r == b => Some(a),
+                _ => None,
+            })
+    }
+}
+
+/// Generates rooms joined by corridors, plus the room connectivity graph.
+///
+/// Rooms are placed at random without overlapping, keeping at least one
+/// wall cell between them and around the map edge. They are joined along a
+/// minimum spanning tree of room-centre distances, so every room is
+/// reachable, and each room's two nearest unconnected neighbours are then
+/// joined with probability `loop_chance` to create loops. Corridors are
+/// L-shaped and may cut through other rooms; `connections` lists the
+/// corridors that were dug, which is what quest and key-lock logic needs.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::generate::{DungeonConfig, DungeonTile, dungeon};
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// let map = dungeon(&DungeonConfig::default(), &mut StdRng::seed_from_u64(3));
+///
+/// assert!(map.rooms.len() >= 2);
+/// assert!(map.connections.len() >= map.rooms.len() - 1);
+/// let (x, y) = (map.rooms[0].x, map.rooms[0].y);
+/// assert_eq!(map.tiles.get(x, y), Some(&DungeonTile::Room));
+/// ```
+pub fn dungeon<R: Rng + ?Sized>(config: &DungeonConfig, rng: &mut R) -> Dungeon {
+    let mut tiles: Grid<DungeonTile> = Grid::new(config.width, config.height);
+    let min_size = config.min_room_size.max(1);
+    let max_size = config.max_room_size.max(min_size);
+
+    let mut rooms: Vec<Rect> = Vec::new();
+    for _ in 0..config.max_rooms * 8 {
+        if rooms.len() == config.max_rooms {
+            break;
+        }
+        let width = rng.random_range(min_size..=max_size);
+        let height = rng.random_range(min_size..=max_size);
+        if width + 2 > config.width || height + 2 > config.height {
+            continue;
+        }
+        let x = rng.random_range(1..=config.width - width - 1);
+        let y = rng.random_range(1..=config.height - height - 1);
+        let room = Rect::new(x, y, width, height);
+        // Growing the new room by one keeps a wall between neighbours.
+        let padded = Rect::new(x - 1, y - 1, width + 2, height + 2);
+        if rooms
+            .iter()
+            .all(|other| padded.intersection(other).is_none())
+        {
+            rooms.push(room);
+        }
+    }
+    for room in &rooms {
+        for (x, y) in room.cells() {
+            let _ = tiles.set(x, y, DungeonTile::Room);
+        }
+    }
+
+    let centre = |room: &Rect| (room.x + room.width / 2, room.y + room.height / 2);
+    let distance = |a: usize, b: usize| {
+        let ((ax, ay), (bx, by)) = (centre(&rooms[a]), centre(&rooms[b]));
+        ax.abs_diff(bx) as u32 + ay.abs_diff(by) as u32
+    };
+
+    // Prim's algorithm over the complete graph of rooms.
+    let mut connections = Vec::new();
+    let mut in_tree = vec![false; rooms.len()];
+    let mut best: Vec<(u32, usize)> = vec![(u32::MAX, 0); rooms.len()];
+    if !rooms.is_empty() {
+        best[0] = (0, 0);
+    }
+    for _ in 0..rooms.len() {
+        let Some(next) = (0..rooms.len())
+            .filter(|&i| !in_tree[i])
+            .min_by_key(|&i| best[i].0)
+        else {
+            break;
+        };
+        in_tree[next] = true;
+        if next != 0 {
+            connections.push((best[next].1.min(next), best[next].1.max(next)));
+        }
+        for other in 0..rooms.len() {
+            if !in_tree[other] && distance(next, other) < best[other].0 {
+                best[other] = (distance(next, other), next);
+            }
+        }
+    }
+
+    for room in 0..rooms.len() {
+        let mut nearest: Vec<usize> = (0..rooms.len()).filter(|&other| other != room).collect();
+        nearest.sort_by_key(|&other| distance(room, other));
+        for &other in nearest.iter().take(2) {
+            let pair = (room.min(other), room.max(other));
+            if !connections.contains(&pair) && rng.random::<f32>() < config.loop_chance {
+                connections.push(pair);
+            }
+        }
+    }
+
+    for &(a, b) in &connections {
+        let (from, to) = (centre(&rooms[a]), centre(&rooms[b]));
+        let corner = if rng.random::<bool>() {
+            (to.0, from.1)
+        } else {
+            (from.0, to.1)
+        };
+        for leg in [(from, corner), (corner, to)] {
+            for (x, y) in LineIter::new(leg.0, leg.1) {
+                if tiles.get(x, y) == Some(&DungeonTile::Wall) {
+                    let _ = tiles.set(x, y, DungeonTile::Corridor);
+                }
+            }
+        }
+    }
+    Dungeon {
+        tiles,
+        rooms,
+        connections,
+    }
+}
+
+/// A square tile with a label on each edge, for [`wang_tiling`].
+///
+/// Two tiles may sit next to each other when the touching edges carry equal
+/// labels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WangTile<E> {
+    /// Label of the top edge.
+    pub north: E,
+    /// Label of the right edge.
+    pub east: E,
+    /// Label of the bottom edge.
+    pub south: E,
+    /// Label of the left edge.
+    pub west: E,
+}
+
+impl<E> WangTile<E> {
+    /// Creates a tile from its edge labels, clockwise from north.
+    pub fn new(north: E, east: E, south: E, west: E) -> WangTile<E> {
+        WangTile {
+            north,
+            east,
+            south,
+            west,
+        }
+    }
+}
+
+/// Fills a `width × height` grid with tiles whose touching edges all match.
+///
+/// Cells are filled in row-major order, each picking a random tile that
+/// matches its western and northern neighbours, and the solver backtracks
+/// when a cell has no candidate. The map edges are unconstrained.
+///
+/// Returns the index into `tiles` for every cell, or `None` if the tile set
+/// cannot tile the grid or the solver backtracked more than
+/// `max_backtracks` times.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::generate::{WangTile, wang_tiling};
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// // Two-colour edges: a tile set that can tile any rectangle.
+/// let tiles: Vec<_> = (0..16u8)
+///     .map(|bits| WangTile::new(bits & 1, bits >> 1 & 1, bits >> 2 & 1, bits >> 3 & 1))
+///     .collect();
+/// let tiling = wang_tiling(12, 8, &tiles, 1000, &mut StdRng::seed_from_u64(5)).unwrap();
+///
+/// for (x, y, &i) in tiling.iter() {
+///     if let Some(&right) = tiling.get(x + 1, y) {
+///         assert_eq!(tiles[i].east, tiles[right].west);
+///     }
+/// }
+/// ```
+pub fn wang_tiling<E, R>(
+    width: u16,
+    height: u16,
+    tiles: &[WangTile<E>],
+    max_backtracks: usize,
+    rng: &mut R,
+) -> Option<Grid<usize>>
+where
+    E: PartialEq,
+    R: Rng + ?Sized,
+{
+    let mut placed: Grid<usize> = Grid::new(width, height);
+    let area = placed.tiles.len();
+    // Shuffled candidates still to try for each cell on the current branch.
+    let mut candidates: Vec<Vec<usize>> = Vec::with_capacity(area);
+    let mut backtracks = 0;
+
+    while candidates.len() < area {
+        let cell = candidates.len();
+        let (x, y) = placed.coords(cell);
+        let west = (x > 0).then(|| &tiles[placed.tiles[cell - 1]]);
+        let north = (y > 0).then(|| &tiles[placed.tiles[cell - width as usize]]);
+        let mut options: Vec<usize> = (0..tiles.len())
+            .filter(|&i| west.is_none_or(|w| w.east == tiles[i].west))
+            .filter(|&i| north.is_none_or(|n| n.south == tiles[i].north))
+            .collect();
+        options.shuffle(rng);
+        candidates.push(options);
+
+        // Take the next candidate here, unwinding cells that have run out.
+        loop {
+            let last = candidates.len() - 1;
+            if let Some(tile) = candidates[last].pop() {
+                placed.tiles[last] = tile;
+                break;
+            }
+            candidates.pop();
+            backtracks += 1;
+            if candidates.is_empty() || backtracks > max_backtracks {
+                return None;
+            }
+        }
+    }
+    Some(placed)
+}
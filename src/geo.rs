@@ -0,0 +1,423 @@
+use crate::Grid;
+
+/// An affine mapping from cell coordinates to georeferenced coordinates.
+///
+/// Uses the GDAL convention: the cell corner at column `c`, row `r` maps to
+///
+/// ```text
+/// X = origin_x + c * pixel_width  + r * row_rotation
+/// Y = origin_y + c * column_rotation + r * pixel_height
+/// ```
+///
+/// For the usual north-up raster both rotations are zero and `pixel_height`
+/// is negative.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoTransform {
+    pub origin_x: f64,
+    pub pixel_width: f64,
+    pub row_rotation: f64,
+    pub origin_y: f64,
+    pub column_rotation: f64,
+    pub pixel_height: f64,
+}
+
+impl GeoTransform {
+    /// Creates a north-up transform from the top-left corner and cell size.
+    ///
+    /// `cell_height` is given as a positive size; rows advance southwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::GeoTransform;
+    ///
+    /// let t = GeoTransform::north_up(10.0, 50.0, 0.5, 0.25);
+    /// assert_eq!(t.apply(2.0, 4.0), (11.0, 49.0));
+    /// ```
+    pub fn north_up(west: f64, north: f64, cell_width: f64, cell_height: f64) -> GeoTransform {
+        GeoTransform {
+            origin_x: west,
+            pixel_width: cell_width,
+            row_rotation: 0.0,
+            origin_y: north,
+            column_rotation: 0.0,
+            pixel_height: -cell_height,
+        }
+    }
+
+    /// Creates a transform from the six coefficients in GDAL order.
+    pub fn from_gdal(gt: [f64; 6]) -> GeoTransform {
+        GeoTransform {
+            origin_x: gt[0],
+            pixel_width: gt[1],
+            row_rotation: gt[2],
+            origin_y: gt[3],
+            column_rotation: gt[4],
+            pixel_height: gt[5],
+        }
+    }
+
+    /// Returns the six coefficients in GDAL order.
+    pub fn to_gdal(&self) -> [f64; 6] {
+        [
+            self.origin_x,
+            self.pixel_width,
+            self.row_rotation,
+            self.origin_y,
+            self.column_rotation,
+            self.pixel_height,
+        ]
+    }
+
+    /// Maps continuous cell coordinates (column, row) to georeferenced coordinates.
+    pub fn apply(&self, column: f64, row: f64) -> (f64, f64) {
+        (
+            self.origin_x + column * self.pixel_width + row * self.row_rotation,
+            self.origin_y + column * self.column_rotation + row * self.pixel_height,
+        )
+    }
+
+    /// Maps georeferenced coordinates back to continuous cell coordinates.
+    ///
+    /// Returns `None` if the transform is singular.
+    pub fn invert(&self, x: f64, y: f64) -> Option<(f64, f64)> {
+        let det = self.pixel_width * self.pixel_height - self.row_rotation * self.column_rotation;
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let (dx, dy) = (x - self.origin_x, y - self.origin_y);
+        Some((
+            (self.pixel_height * dx - self.row_rotation * dy) / det,
+            (self.pixel_width * dy - self.column_rotation * dx) / det,
+        ))
+    }
+}
+
+/// A grid anchored in a geographic or projected coordinate system.
+///
+/// Pairs a [`Grid`] with a [`GeoTransform`] and a free-form CRS label such as
+/// `"EPSG:4326"`, so raster cells can be addressed by longitude and latitude
+/// (or easting and northing).
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{GeoGrid, GeoTransform, Grid};
+///
+/// // 1° cells covering 10°E..14°E, 50°N..47°N.
+/// let grid: Grid<f32> = Grid::new(4, 3);
+/// let mut geo = GeoGrid::new(grid, GeoTransform::north_up(10.0, 50.0, 1.0, 1.0), "EPSG:4326");
+///
+/// assert_eq!(geo.lonlat_to_cell(12.3, 48.9), Some((2, 1)));
+/// assert_eq!(geo.cell_to_lonlat(2, 1), (12.5, 48.5));
+/// assert_eq!(geo.lonlat_to_cell(9.9, 48.0), None);
+///
+/// let _ = geo.grid_mut().set(2, 1, 312.0);
+/// assert_eq!(geo.get_lonlat(12.3, 48.9), Some(&312.0));
+/// ```
+pub struct GeoGrid<T> {
+    grid: Grid<T>,
+    transform: GeoTransform,
+    crs: String,
+}
+
+impl<T: Default + Clone> GeoGrid<T> {
+    /// Creates a georeferenced grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The cell data
+    /// * `transform` - Maps cell coordinates to the CRS
+    /// * `crs` - A label identifying the coordinate reference system
+    pub fn new(grid: Grid<T>, transform: GeoTransform, crs: impl Into<String>) -> GeoGrid<T> {
+        GeoGrid {
+            grid,
+            transform,
+            crs: crs.into(),
+        }
+    }
+
+    /// Returns the underlying grid.
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    /// Returns the underlying grid mutably.
+    pub fn grid_mut(&mut self) -> &mut Grid<T> {
+        &mut self.grid
+    }
+
+    /// Consumes the wrapper and returns the underlying grid.
+    pub fn into_grid(self) -> Grid<T> {
+        self.grid
+    }
+
+    /// Returns the geotransform.
+    pub fn transform(&self) -> &GeoTransform {
+        &self.transform
+    }
+
+    /// Returns the CRS label.
+    pub fn crs(&self) -> &str {
+        &self.crs
+    }
+
+    /// Returns the georeferenced coordinates of the centre of cell (x, y).
+    pub fn cell_to_lonlat(&self, x: u16, y: u16) -> (f64, f64) {
+        self.transform.apply(x as f64 + 0.5, y as f64 + 0.5)
+    }
+
+    /// Returns the cell containing the given georeferenced point, or `None`
+    /// if it lies outside the grid.
+    pub fn lonlat_to_cell(&self, lon: f64, lat: f64) -> Option<(u16, u16)> {
+        let (column, row) = self.transform.invert(lon, lat)?;
+        let (column, row) = (column.floor(), row.floor());
+        if column >= 0.0
+            && row >= 0.0
+            && column < self.grid.width() as f64
+            && row < self.grid.height() as f64
+        {
+            Some((column as u16, row as u16))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value of the cell containing the given georeferenced point.
+    pub fn get_lonlat(&self, lon: f64, lat: f64) -> Option<&T> {
+        let (x, y) = self.lonlat_to_cell(lon, lat)?;
+        self.grid.get(x, y)
+    }
+}
+
+#[cfg(feature = "geotiff")]
+mod geotiff {
+    use std::io::{self, Cursor, Read, Write};
+
+    use tiff::decoder::{Decoder, DecodingResult, Limits};
+    use tiff::encoder::{TiffEncoder, colortype};
+    use tiff::tags::Tag;
+    use tiff::{ColorType, TiffError};
+
+    use super::{GeoGrid, GeoTransform};
+    use crate::Grid;
+
+    const GT_MODEL_TYPE: u16 = 1024;
+    const GT_RASTER_TYPE: u16 = 1025;
+    const GT_CITATION: u16 = 1026;
+    const GEOGRAPHIC_TYPE: u16 = 2048;
+    const PROJECTED_CS_TYPE: u16 = 3072;
+
+    /// Whether a CRS is geographic (degrees of longitude and latitude) or
+    /// projected (planar easting and northing).
+    ///
+    /// Decides the GeoTIFF model type and which CRS key an `EPSG:<code>`
+    /// label is written to.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum CrsModel {
+        /// A geographic CRS such as EPSG:4326, EPSG:4269 or EPSG:4258.
+        Geographic,
+        /// A projected CRS such as a UTM zone.
+        Projected,
+    }
+
+    fn invalid(message: impl Into<String>) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, message.into())
+    }
+
+    fn tiff_error(error: TiffError) -> io::Error {
+        match error {
+            TiffError::IoError(error) => error,
+            other => invalid(other.to_string()),
+        }
+    }
+
+    impl GeoGrid<f32> {
+        /// Writes the grid as a single-band, uncompressed 32-bit float GeoTIFF.
+        ///
+        /// The geotransform is stored as a `ModelTransformationTag`, the CRS
+        /// label as the GeoTIFF citation and `model` as the model type. A label
+        /// of the form `EPSG:<code>` additionally sets the geographic or
+        /// projected CRS key, as chosen by `model`.
+        ///
+        /// Returns an `InvalidInput` error if the image does not fit in a
+        /// classic (4 GiB) TIFF.
+        ///
+        /// Requires the `geotiff` feature.
+        pub fn write_geotiff<W: Write>(&self, mut writer: W, model: CrsModel) -> io::Result<()> {
+            let (width, height) = (self.grid.width() as u32, self.grid.height() as u32);
+            // Image data plus a generous allowance for the header and tags.
+            let fits = width
+                .checked_mul(height)
+                .and_then(|cells| cells.checked_mul(4))
+                .and_then(|len| len.checked_add(64 * 1024))
+                .is_some();
+            if !fits {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{}x{} grid is too large for a classic TIFF", width, height),
+                ));
+            }
+            let t = &self.transform;
+            let matrix = [
+                t.pixel_width,
+                t.row_rotation,
+                0.0,
+                t.origin_x,
+                t.column_rotation,
+                t.pixel_height,
+                0.0,
+                t.origin_y,
+                0.0,
+                0.0,
+                0.0,
+                0.0,
+                0.0,
+                0.0,
+                0.0,
+                1.0,
+            ];
+
+            let geographic = model == CrsModel::Geographic;
+            let mut keys: Vec<[u16; 4]> = Vec::new();
+            keys.push([GT_MODEL_TYPE, 0, 1, if geographic { 2 } else { 1 }]);
+            keys.push([GT_RASTER_TYPE, 0, 1, 1]);
+            let citation = format!("{}|", self.crs);
+            let citation_len = u16::try_from(citation.len())
+                .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "CRS label too long"))?;
+            keys.push([
+                GT_CITATION,
+                Tag::GeoAsciiParamsTag.to_u16(),
+                citation_len,
+                0,
+            ]);
+            let epsg = self
+                .crs
+                .strip_prefix("EPSG:")
+                .and_then(|code| code.parse::<u16>().ok());
+            if let Some(code) = epsg {
+                let key = if geographic {
+                    GEOGRAPHIC_TYPE
+                } else {
+                    PROJECTED_CS_TYPE
+                };
+                keys.push([key, 0, 1, code]);
+            }
+            let mut directory = vec![1, 1, 0, keys.len() as u16];
+            directory.extend(keys.iter().flatten());
+
+            // The encoder needs to seek, so the file is assembled in memory.
+            let mut bytes = Cursor::new(Vec::new());
+            let mut encoder = TiffEncoder::new(&mut bytes).map_err(tiff_error)?;
+            let mut image = encoder
+                .new_image::<colortype::Gray32Float>(width, height)
+                .map_err(tiff_error)?;
+            let tags = image.encoder();
+            tags.write_tag(Tag::ModelTransformationTag, &matrix[..])
+                .map_err(tiff_error)?;
+            tags.write_tag(Tag::GeoKeyDirectoryTag, &directory[..])
+                .map_err(tiff_error)?;
+            tags.write_tag(Tag::GeoAsciiParamsTag, citation.as_str())
+                .map_err(tiff_error)?;
+            image.write_data(self.grid.as_slice()).map_err(tiff_error)?;
+            writer.write_all(bytes.get_ref())?;
+            writer.flush()
+        }
+
+        /// Reads a single-band 32-bit float GeoTIFF.
+        ///
+        /// Any layout and compression the `tiff` crate decodes is accepted,
+        /// with the georeferencing given either as a `ModelTransformationTag`
+        /// or as `ModelPixelScaleTag` plus `ModelTiepointTag`. The CRS label
+        /// is taken from the GeoTIFF citation, falling back to `EPSG:<code>`
+        /// from the CRS keys.
+        ///
+        /// Returns an `InvalidData` error for unsupported or malformed files.
+        ///
+        /// Requires the `geotiff` feature.
+        pub fn read_geotiff<R: Read>(mut reader: R) -> io::Result<GeoGrid<f32>> {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes)?;
+            let mut limits = Limits::default();
+            limits.decoding_buffer_size = u16::MAX as usize * u16::MAX as usize * 4;
+            let mut decoder = Decoder::new(Cursor::new(bytes))
+                .map_err(tiff_error)?
+                .with_limits(limits);
+
+            let (width, height) = decoder.dimensions().map_err(tiff_error)?;
+            let (Ok(width), Ok(height)) = (u16::try_from(width), u16::try_from(height)) else {
+                return Err(invalid(format!(
+                    "{}x{} image is too large for a grid",
+                    width, height
+                )));
+            };
+            if decoder.colortype().map_err(tiff_error)? != ColorType::Gray(32) {
+                return Err(invalid("only single-band float32 images are supported"));
+            }
+            let DecodingResult::F32(cells) = decoder.read_image().map_err(tiff_error)? else {
+                return Err(invalid("only single-band float32 images are supported"));
+            };
+            let grid = Grid::from_raw_parts(width, height, cells)
+                .map_err(|error| invalid(error.to_string()))?;
+
+            let mut field = |tag: Tag| -> io::Result<Vec<f64>> {
+                match decoder.find_tag(tag).map_err(tiff_error)? {
+                    Some(value) => value.into_f64_vec().map_err(tiff_error),
+                    None => Ok(Vec::new()),
+                }
+            };
+            let matrix = field(Tag::ModelTransformationTag)?;
+            let transform = if matrix.len() == 16 {
+                GeoTransform::from_gdal([
+                    matrix[3], matrix[0], matrix[1], matrix[7], matrix[4], matrix[5],
+                ])
+            } else {
+                let scale = field(Tag::ModelPixelScaleTag)?;
+                let tie = field(Tag::ModelTiepointTag)?;
+                if scale.len() < 2 || tie.len() < 6 {
+                    return Err(invalid("missing georeferencing tags"));
+                }
+                GeoTransform::from_gdal([
+                    tie[3] - tie[0] * scale[0],
+                    scale[0],
+                    0.0,
+                    tie[4] + tie[1] * scale[1],
+                    0.0,
+                    -scale[1],
+                ])
+            };
+
+            let ascii = match decoder
+                .find_tag(Tag::GeoAsciiParamsTag)
+                .map_err(tiff_error)?
+            {
+                Some(value) => value.into_string().map_err(tiff_error)?,
+                None => String::new(),
+            };
+            let keys = match decoder
+                .find_tag(Tag::GeoKeyDirectoryTag)
+                .map_err(tiff_error)?
+            {
+                Some(value) => value.into_u16_vec().map_err(tiff_error)?,
+                None => return Err(invalid("missing GeoKeyDirectoryTag")),
+            };
+            let mut crs = String::new();
+            for key in keys.get(4..).unwrap_or_default().chunks_exact(4) {
+                let (id, location, count, value) =
+                    (key[0], key[1], key[2] as usize, key[3] as usize);
+                if id == GT_CITATION && location == Tag::GeoAsciiParamsTag.to_u16() {
+                    let text = ascii.get(value..value + count).unwrap_or_default();
+                    crs = text.trim_end_matches('|').to_string();
+                    break;
+                }
+                if (id == GEOGRAPHIC_TYPE || id == PROJECTED_CS_TYPE) && location == 0 {
+                    crs = format!("EPSG:{}", value);
+                }
+            }
+            Ok(GeoGrid::new(grid, transform, crs))
+        }
+    }
+}
+
+#[cfg(feature = "geotiff")]
+pub use geotiff::CrsModel;
@@ -0,0 +1,59 @@
+use crate::Grid;
+
+impl Grid<f32> {
+    /// Computes the surface gradient `(dz/dx, dz/dy)` of a heightmap.
+    ///
+    /// Uses Horn's 3×3 weighted finite differences, the method most GIS tools
+    /// use for slope and shading. Cells on the border reuse their nearest
+    /// in-bounds neighbour. `cell_size` is the horizontal distance between
+    /// cell centres in the same unit as the heights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// // A ramp rising by 2 per cell towards +x.
+    /// let mut heights: Grid<f32> = Grid::new(5, 5);
+    /// heights.map_inplace(|x, _, h| *h = x as f32 * 2.0);
+    ///
+    /// let gradient = heights.gradient(1.0);
+    /// assert_eq!(gradient.get(2, 2), Some(&(2.0, 0.0)));
+    /// ```
+    pub fn gradient(&self, cell_size: f32) -> Grid<(f32, f32)> {
+        let width = self.width as i32;
+        let height = self.height as i32;
+        let at = |x: i32, y: i32| {
+            let x = x.clamp(0, width - 1);
+            let y = y.clamp(0, height - 1);
+            self.tiles[(y as usize) * (width as usize) + (x as usize)]
+        };
+        let scale = 8.0 * cell_size;
+        self.map(|x, y, _| {
+            let (x, y) = (x as i32, y as i32);
+            let (a, b, c) = (at(x - 1, y - 1), at(x, y - 1), at(x + 1, y - 1));
+            let (d, f) = (at(x - 1, y), at(x + 1, y));
+            let (g, h, i) = (at(x - 1, y + 1), at(x, y + 1), at(x + 1, y + 1));
+            let dz_dx = ((c + 2.0 * f + i) - (a + 2.0 * d + g)) / scale;
+            let dz_dy = ((g + 2.0 * h + i) - (a + 2.0 * b + c)) / scale;
+            (dz_dx, dz_dy)
+        })
+    }
+
+    /// Computes the slope angle of every cell in radians (0 is flat).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut heights: Grid<f32> = Grid::new(4, 4);
+    /// heights.map_inplace(|_, y, h| *h = y as f32);
+    /// let slope = heights.slope(1.0);
+    /// assert!((slope.get(1, 1).unwrap() - std::f32::consts::FRAC_PI_4).abs() < 1e-6);
+    /// ```
+    pub fn slope(&self, cell_size: f32) -> Grid<f32> {
+        self.gradient(cell_size)
+            .map(|_, _, &(dx, dy)| (dx * dx + dy * dy).sqrt().atan())
+    }
+}
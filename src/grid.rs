@@ -1,3 +1,7 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{GridError, Rect};
+
 /// A generic 2D grid structure using a flat vector with row-major order.
 ///
 /// The `Grid` stores elements of type `T` in a contiguous vector, providing
@@ -17,10 +21,11 @@
 /// let _ = grid.set(5, 3, 42);
 /// assert_eq!(grid.get(5, 3), Some(&42));
 /// ```
+#[derive(Debug, Clone)]
 pub struct Grid<T> {
-    width: u16,
-    height: u16,
-    tiles: Vec<T>, // Flat vector in row-major order
+    pub(crate) width: u16,
+    pub(crate) height: u16,
+    pub(crate) tiles: Vec<T>, // Flat vector in row-major order
 }
 
 impl<T: Default + Clone> Grid<T> {
@@ -74,17 +79,83 @@ impl<T: Default + Clone> Grid<T> {
         }
     }
 
+    /// Creates a new grid filled with default values, failing instead of
+    /// aborting when the allocation is impossible.
+    ///
+    /// Returns [`GridError::TooLarge`] if `width × height` cells of `T` would
+    /// exceed `isize::MAX` bytes, or [`GridError::AllocationFailed`] if the
+    /// allocator refuses the request.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let grid: Grid<u8> = Grid::try_new(100, 100).unwrap();
+    /// assert_eq!(grid.area(), 10_000);
+    /// ```
+    pub fn try_new(width: u16, height: u16) -> Result<Grid<T>, GridError> {
+        Self::try_new_with_budget(width, height, usize::MAX)
+    }
+
+    /// Like [`Grid::try_new`], but also fails if the grid would have more than
+    /// `max_cells` cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// assert!(Grid::<u8>::try_new_with_budget(100, 100, 10_000).is_ok());
+    /// assert!(Grid::<u8>::try_new_with_budget(100, 101, 10_000).is_err());
+    /// ```
+    pub fn try_new_with_budget(
+        width: u16,
+        height: u16,
+        max_cells: usize,
+    ) -> Result<Grid<T>, GridError> {
+        let capacity = (width as usize) * (height as usize);
+        if capacity > max_cells {
+            return Err(GridError::TooLarge {
+                width,
+                height,
+                max_cells,
+            });
+        }
+        let too_large = capacity
+            .checked_mul(std::mem::size_of::<T>())
+            .is_none_or(|bytes| bytes > isize::MAX as usize);
+        if too_large {
+            return Err(GridError::TooLarge {
+                width,
+                height,
+                max_cells: isize::MAX as usize / std::mem::size_of::<T>(),
+            });
+        }
+
+        let mut tiles = Vec::new();
+        tiles
+            .try_reserve_exact(capacity)
+            .map_err(|_| GridError::AllocationFailed { width, height })?;
+        tiles.resize(capacity, T::default());
+        Ok(Grid {
+            width,
+            height,
+            tiles,
+        })
+    }
+
     /// Converts (x, y) coordinates to a flat index.
     ///
     /// This uses row-major order: index = y * width + x
     #[inline]
-    fn index(&self, x: u16, y: u16) -> usize {
+    pub(crate) fn index(&self, x: u16, y: u16) -> usize {
         (y as usize) * (self.width as usize) + (x as usize)
     }
 
     /// Converts a flat index to (x, y) coordinates.
     #[inline]
-    fn coords(&self, index: usize) -> (u16, u16) {
+    pub(crate) fn coords(&self, index: usize) -> (u16, u16) {
         let x = (index % self.width as usize) as u16;
         let y = (index / self.width as usize) as u16;
         (x, y)
@@ -135,10 +206,43 @@ impl<T: Default + Clone> Grid<T> {
         }
     }
 
+    /// Gets simultaneous mutable references to several distinct cells.
+    ///
+    /// Returns `None` if any coordinate is out of bounds or if the same cell
+    /// is requested more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut grid: Grid<i32> = Grid::new(5, 5);
+    /// let _ = grid.set(0, 0, 1);
+    /// let _ = grid.set(4, 4, 2);
+    ///
+    /// if let Some([a, b]) = grid.get_many_mut([(0, 0), (4, 4)]) {
+    ///     std::mem::swap(a, b);
+    /// }
+    /// assert_eq!(grid.get(0, 0), Some(&2));
+    /// assert_eq!(grid.get(4, 4), Some(&1));
+    ///
+    /// assert!(grid.get_many_mut([(1, 1), (1, 1)]).is_none());
+    /// ```
+    pub fn get_many_mut<const N: usize>(&mut self, coords: [(u16, u16); N]) -> Option<[&mut T; N]> {
+        let mut indices = [0; N];
+        for (slot, &(x, y)) in indices.iter_mut().zip(coords.iter()) {
+            if x >= self.width || y >= self.height {
+                return None;
+            }
+            *slot = self.index(x, y);
+        }
+        self.tiles.get_disjoint_mut(indices).ok()
+    }
+
     /// Sets the element at (x, y) to the provided value.
     ///
-    /// Returns `Ok(())` if successful, or an `Err` with a descriptive message
-    /// if the coordinates are out of bounds.
+    /// Returns `Ok(())` if successful, or [`GridError::OutOfBounds`] if the
+    /// coordinates are out of bounds.
     ///
     /// # Examples
     ///
@@ -149,16 +253,59 @@ impl<T: Default + Clone> Grid<T> {
     /// assert!(grid.set(2, 2, 42).is_ok());
     /// assert!(grid.set(10, 10, 42).is_err());
     /// ```
-    pub fn set(&mut self, x: u16, y: u16, tile: T) -> Result<(), String> {
+    pub fn set(&mut self, x: u16, y: u16, tile: T) -> Result<(), GridError> {
         if x < self.width && y < self.height {
             let idx = self.index(x, y);
             self.tiles[idx] = tile;
             Ok(())
         } else {
-            Err(format!(
-                "Coordinates ({}, {}) out of bounds (grid is {}x{})",
-                x, y, self.width, self.height
-            ))
+            Err(GridError::OutOfBounds {
+                x,
+                y,
+                width: self.width,
+                height: self.height,
+            })
+        }
+    }
+
+    /// Applies a batch of writes, skipping any that are out of bounds.
+    ///
+    /// Every in-bounds write is applied even if others fail. Returns `Ok(())`
+    /// if all writes succeeded, or an `Err` listing the position of each
+    /// failed entry in the input together with its error.
+    ///
+    /// # Arguments
+    ///
+    /// * `writes` - `(x, y, value)` triples, applied in order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Grid, GridError};
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(4, 4);
+    /// let result = grid.set_many([(0, 0, 1), (9, 9, 2), (3, 3, 3)]);
+    ///
+    /// let failures = result.unwrap_err();
+    /// assert_eq!(failures.len(), 1);
+    /// assert_eq!(failures[0].0, 1);
+    /// assert!(matches!(failures[0].1, GridError::OutOfBounds { x: 9, y: 9, .. }));
+    /// assert_eq!(grid.get(3, 3), Some(&3));
+    /// ```
+    pub fn set_many<I>(&mut self, writes: I) -> Result<(), Vec<(usize, GridError)>>
+    where
+        I: IntoIterator<Item = (u16, u16, T)>,
+    {
+        let mut failures = Vec::new();
+        for (i, (x, y, tile)) in writes.into_iter().enumerate() {
+            if let Err(error) = self.set(x, y, tile) {
+                failures.push((i, error));
+            }
+        }
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(failures)
         }
     }
 
@@ -176,6 +323,20 @@ impl<T: Default + Clone> Grid<T> {
         (self.width as u32) * (self.height as u32)
     }
 
+    /// Returns `true` if the grid has no cells, i.e. its width or height is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// assert!(Grid::<u8>::new(0, 5).is_empty());
+    /// assert!(!Grid::<u8>::new(1, 1).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
     /// Returns the width of the grid.
     pub fn width(&self) -> u16 {
         self.width
@@ -188,7 +349,8 @@ impl<T: Default + Clone> Grid<T> {
 
     /// Returns an iterator over all elements with their coordinates.
     ///
-    /// Each item is a tuple of (x, y, &T).
+    /// Each item is a tuple of (x, y, &T). The iterator knows its exact length
+    /// and can be walked from both ends.
     ///
     /// # Examples
     ///
@@ -198,8 +360,10 @@ impl<T: Default + Clone> Grid<T> {
     /// let grid: Grid<i32> = Grid::new(3, 3);
     /// let coords: Vec<_> = grid.iter().map(|(x, y, _)| (x, y)).collect();
     /// assert_eq!(coords.len(), 9);
+    /// assert_eq!(grid.iter().len(), 9);
+    /// assert_eq!(grid.iter().next_back(), Some((2, 2, &0)));
     /// ```
-    pub fn iter(&self) -> impl Iterator<Item = (u16, u16, &T)> {
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (u16, u16, &T)> + ExactSizeIterator {
         self.tiles.iter().enumerate().map(move |(i, tile)| {
             let (x, y) = self.coords(i);
             (x, y, tile)
@@ -209,7 +373,9 @@ impl<T: Default + Clone> Grid<T> {
     /// Returns a mutable iterator over all elements with their coordinates.
     ///
     /// Each item is a tuple of (x, y, &mut T).
-    pub fn iter_mut(&mut self) -> impl Iterator<Item = (u16, u16, &mut T)> {
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = (u16, u16, &mut T)> + ExactSizeIterator {
         let width = self.width;
         self.tiles.iter_mut().enumerate().map(move |(i, tile)| {
             let x = (i % width as usize) as u16;
@@ -287,6 +453,298 @@ impl<T: Default + Clone> Grid<T> {
     pub fn as_mut_slice(&mut self) -> &mut [T] {
         &mut self.tiles
     }
+
+    /// Consumes the grid and returns the underlying row-major vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let grid: Grid<u8> = Grid::with_value(2, 2, 1);
+    /// assert_eq!(grid.into_vec(), vec![1, 1, 1, 1]);
+    /// ```
+    pub fn into_vec(self) -> Vec<T> {
+        self.tiles
+    }
+
+    /// Consumes the grid and returns its width, height and row-major vector.
+    ///
+    /// The parts can be turned back into a grid with [`Grid::from_raw_parts`].
+    pub fn into_raw_parts(self) -> (u16, u16, Vec<T>) {
+        (self.width, self.height, self.tiles)
+    }
+
+    /// Creates a grid from a row-major vector without copying it.
+    ///
+    /// Returns [`GridError::LengthMismatch`] if `tiles.len()` is not
+    /// `width * height`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let grid = Grid::from_raw_parts(3, 2, vec![0, 1, 2, 3, 4, 5]).unwrap();
+    /// assert_eq!(grid.get(0, 1), Some(&3));
+    ///
+    /// let (width, height, tiles) = grid.into_raw_parts();
+    /// assert_eq!((width, height, tiles.len()), (3, 2, 6));
+    ///
+    /// assert!(Grid::from_raw_parts(3, 3, vec![0; 4]).is_err());
+    /// ```
+    pub fn from_raw_parts(width: u16, height: u16, tiles: Vec<T>) -> Result<Grid<T>, GridError> {
+        let expected = (width as usize) * (height as usize);
+        if tiles.len() == expected {
+            Ok(Grid {
+                width,
+                height,
+                tiles,
+            })
+        } else {
+            Err(GridError::LengthMismatch {
+                expected,
+                found: tiles.len(),
+            })
+        }
+    }
+
+    /// Returns row `y` as a contiguous slice of the underlying data.
+    ///
+    /// Returns `None` if `y` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut grid: Grid<i32> = Grid::new(3, 2);
+    /// let _ = grid.set(2, 1, 7);
+    /// assert_eq!(grid.row(1), Some(&[0, 0, 7][..]));
+    /// assert!(grid.row(2).is_none());
+    /// ```
+    pub fn row(&self, y: u16) -> Option<&[T]> {
+        if y < self.height {
+            let start = self.index(0, y);
+            Some(&self.tiles[start..start + self.width as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Returns row `y` as a contiguous mutable slice of the underlying data.
+    ///
+    /// Returns `None` if `y` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(4, 2);
+    /// grid.row_mut(0).unwrap().copy_from_slice(&[1, 2, 3, 4]);
+    /// assert_eq!(grid.get(3, 0), Some(&4));
+    /// ```
+    pub fn row_mut(&mut self, y: u16) -> Option<&mut [T]> {
+        if y < self.height {
+            let start = self.index(0, y);
+            let end = start + self.width as usize;
+            Some(&mut self.tiles[start..end])
+        } else {
+            None
+        }
+    }
+
+    /// Swaps two rows in place.
+    ///
+    /// Returns [`GridError::RowOutOfBounds`] if either row is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut grid: Grid<i32> = Grid::new(2, 3);
+    /// grid.row_mut(0).unwrap().copy_from_slice(&[1, 2]);
+    /// assert!(grid.swap_rows(0, 2).is_ok());
+    /// assert_eq!(grid.row(2), Some(&[1, 2][..]));
+    /// assert!(grid.swap_rows(0, 3).is_err());
+    /// ```
+    pub fn swap_rows(&mut self, a: u16, b: u16) -> Result<(), GridError> {
+        self.check_row(a)?;
+        self.check_row(b)?;
+        if a != b {
+            let width = self.width as usize;
+            let (low, high) = (a.min(b), a.max(b));
+            let split = self.index(0, high);
+            let (head, tail) = self.tiles.split_at_mut(split);
+            let start = low as usize * width;
+            head[start..start + width].swap_with_slice(&mut tail[..width]);
+        }
+        Ok(())
+    }
+
+    /// Swaps two columns in place.
+    ///
+    /// Returns [`GridError::ColumnOutOfBounds`] if either column is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut grid: Grid<i32> = Grid::new(3, 2);
+    /// let _ = grid.set(0, 1, 5);
+    /// assert!(grid.swap_cols(0, 2).is_ok());
+    /// assert_eq!(grid.get(2, 1), Some(&5));
+    /// ```
+    pub fn swap_cols(&mut self, a: u16, b: u16) -> Result<(), GridError> {
+        self.check_col(a)?;
+        self.check_col(b)?;
+        if a != b {
+            for y in 0..self.height {
+                let (i, j) = (self.index(a, y), self.index(b, y));
+                self.tiles.swap(i, j);
+            }
+        }
+        Ok(())
+    }
+
+    /// Cyclically shifts row `y` by `n` cells; positive `n` shifts towards higher x.
+    ///
+    /// Returns [`GridError::RowOutOfBounds`] if the row is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut grid: Grid<i32> = Grid::new(4, 1);
+    /// grid.row_mut(0).unwrap().copy_from_slice(&[1, 2, 3, 4]);
+    /// assert!(grid.rotate_row(0, 1).is_ok());
+    /// assert_eq!(grid.row(0), Some(&[4, 1, 2, 3][..]));
+    /// assert!(grid.rotate_row(0, -2).is_ok());
+    /// assert_eq!(grid.row(0), Some(&[2, 3, 4, 1][..]));
+    /// ```
+    pub fn rotate_row(&mut self, y: u16, n: i32) -> Result<(), GridError> {
+        self.check_row(y)?;
+        let width = self.width as i64;
+        let shift = (n as i64).rem_euclid(width.max(1)) as usize;
+        let start = self.index(0, y);
+        self.tiles[start..start + self.width as usize].rotate_right(shift);
+        Ok(())
+    }
+
+    /// Cyclically shifts column `x` by `n` cells; positive `n` shifts towards higher y.
+    ///
+    /// Returns [`GridError::ColumnOutOfBounds`] if the column is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut grid: Grid<i32> = Grid::new(1, 3);
+    /// let _ = grid.set(0, 0, 7);
+    /// assert!(grid.rotate_col(0, 1).is_ok());
+    /// assert_eq!(grid.get(0, 1), Some(&7));
+    /// assert!(grid.rotate_col(0, -2).is_ok());
+    /// assert_eq!(grid.get(0, 2), Some(&7));
+    /// ```
+    pub fn rotate_col(&mut self, x: u16, n: i32) -> Result<(), GridError> {
+        self.check_col(x)?;
+        let height = self.height as i64;
+        let shift = (n as i64).rem_euclid(height.max(1)) as u16;
+        // Rotate right by `shift` via three reversals, swapping cells in place.
+        self.reverse_col(x, 0, self.height);
+        self.reverse_col(x, 0, shift);
+        self.reverse_col(x, shift, self.height);
+        Ok(())
+    }
+
+    fn reverse_col(&mut self, x: u16, from: u16, to: u16) {
+        let (mut low, mut high) = (from, to);
+        while low + 1 < high {
+            high -= 1;
+            let (i, j) = (self.index(x, low), self.index(x, high));
+            self.tiles.swap(i, j);
+            low += 1;
+        }
+    }
+
+    fn check_row(&self, y: u16) -> Result<(), GridError> {
+        if y < self.height {
+            Ok(())
+        } else {
+            Err(GridError::RowOutOfBounds {
+                y,
+                width: self.width,
+                height: self.height,
+            })
+        }
+    }
+
+    fn check_col(&self, x: u16) -> Result<(), GridError> {
+        if x < self.width {
+            Ok(())
+        } else {
+            Err(GridError::ColumnOutOfBounds {
+                x,
+                width: self.width,
+                height: self.height,
+            })
+        }
+    }
+
+    /// Returns the rectangle covering the whole grid.
+    pub fn bounds(&self) -> Rect {
+        Rect::new(0, 0, self.width, self.height)
+    }
+
+    /// Copies a rectangular block of cells from `src` into this grid.
+    ///
+    /// The block `src_rect` is written with its top-left corner at `dst_pos`.
+    /// Parts of the block that fall outside either grid are clipped. Each row
+    /// span is copied with a single `copy_from_slice`.
+    ///
+    /// Returns the destination rectangle that was actually written, or `None`
+    /// if nothing overlapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Grid, Rect};
+    ///
+    /// let src: Grid<u8> = Grid::with_value(4, 4, 9);
+    /// let mut dst: Grid<u8> = Grid::new(6, 6);
+    ///
+    /// let written = dst.copy_region(&src, Rect::new(0, 0, 3, 2), (4, 1));
+    /// assert_eq!(written, Some(Rect::new(4, 1, 2, 2)));
+    /// assert_eq!(dst.get(5, 2), Some(&9));
+    /// assert_eq!(dst.get(3, 1), Some(&0));
+    /// ```
+    pub fn copy_region(
+        &mut self,
+        src: &Grid<T>,
+        src_rect: Rect,
+        dst_pos: (u16, u16),
+    ) -> Option<Rect>
+    where
+        T: Copy,
+    {
+        let src_rect = src_rect.intersection(&src.bounds())?;
+        let (dst_x, dst_y) = dst_pos;
+        let dst_rect = Rect::new(dst_x, dst_y, src_rect.width, src_rect.height)
+            .intersection(&self.bounds())?;
+
+        let span = dst_rect.width as usize;
+        for row in 0..dst_rect.height {
+            let from = src.index(src_rect.x, src_rect.y + row);
+            let to = self.index(dst_rect.x, dst_rect.y + row);
+            self.tiles[to..to + span].copy_from_slice(&src.tiles[from..from + span]);
+        }
+        Some(dst_rect)
+    }
 }
 
 // Parallel implementations using the rayon data-parallelism library.
@@ -294,6 +752,8 @@ impl<T: Send + Sync> Grid<T> {
     /// Returns a parallel iterator over all elements with their coordinates.
     ///
     /// This method requires `T` to implement `Send` and `Sync` for safe parallel access.
+    /// The iterator is indexed, so it can be zipped with another grid's iterator
+    /// and splits deterministically.
     ///
     /// # Examples
     ///
@@ -307,7 +767,7 @@ impl<T: Send + Sync> Grid<T> {
     ///     .sum();
     /// assert_eq!(sum, 50000);
     /// ```
-    pub fn par_iter(&self) -> impl rayon::iter::ParallelIterator<Item = (u16, u16, &T)> {
+    pub fn par_iter(&self) -> impl rayon::iter::IndexedParallelIterator<Item = (u16, u16, &T)> {
         use rayon::prelude::*;
         let width = self.width;
         self.tiles.par_iter().enumerate().map(move |(i, tile)| {
@@ -333,7 +793,7 @@ impl<T: Send + Sync> Grid<T> {
     /// ```
     pub fn par_iter_mut(
         &mut self,
-    ) -> impl rayon::iter::ParallelIterator<Item = (u16, u16, &mut T)> {
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = (u16, u16, &mut T)> {
         use rayon::prelude::*;
         let width = self.width;
         self.tiles.par_iter_mut().enumerate().map(move |(i, tile)| {
@@ -381,6 +841,122 @@ impl<T: Default + Clone + Send + Sync> Grid<T> {
         }
     }
 
+    /// Generates a new grid by combining this grid with another of the same
+    /// size cell by cell, in parallel.
+    ///
+    /// Returns [`GridError::DimensionMismatch`] if the dimensions differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let height: Grid<f32> = Grid::with_value(10, 10, 2.0);
+    /// let water: Grid<f32> = Grid::with_value(10, 10, 0.5);
+    /// let surface = height.par_zip_map(&water, |_, _, &h, &w| h + w).unwrap();
+    /// assert_eq!(surface.get(3, 3), Some(&2.5));
+    /// ```
+    pub fn par_zip_map<U, F, R>(&self, other: &Grid<U>, f: F) -> Result<Grid<R>, GridError>
+    where
+        U: Send + Sync,
+        F: Fn(u16, u16, &T, &U) -> R + Send + Sync,
+        R: Default + Clone + Send,
+    {
+        use rayon::prelude::*;
+        if self.width != other.width || self.height != other.height {
+            return Err(GridError::DimensionMismatch {
+                expected: (self.width, self.height),
+                found: (other.width, other.height),
+            });
+        }
+        let tiles: Vec<R> = self
+            .par_iter()
+            .zip(other.tiles.par_iter())
+            .map(|((x, y, a), b)| f(x, y, a, b))
+            .collect();
+
+        Ok(Grid {
+            width: self.width,
+            height: self.height,
+            tiles,
+        })
+    }
+
+    /// Folds all cells into a single value in parallel.
+    ///
+    /// Each rayon task folds its share of cells starting from `identity()`,
+    /// and the partial results are merged pairwise with `combine_fn` in a
+    /// tree. `combine_fn` must be associative for the result to be
+    /// deterministic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let grid: Grid<u32> = Grid::with_value(100, 100, 3);
+    /// let (count, total) = grid.par_fold(
+    ///     || (0u64, 0u64),
+    ///     |(count, total), _, _, &value| (count + 1, total + value as u64),
+    ///     |a, b| (a.0 + b.0, a.1 + b.1),
+    /// );
+    /// assert_eq!((count, total), (10_000, 30_000));
+    /// ```
+    pub fn par_fold<A, ID, F, C>(&self, identity: ID, fold_fn: F, combine_fn: C) -> A
+    where
+        A: Send,
+        ID: Fn() -> A + Send + Sync,
+        F: Fn(A, u16, u16, &T) -> A + Send + Sync,
+        C: Fn(A, A) -> A + Send + Sync,
+    {
+        use rayon::prelude::*;
+        self.par_iter()
+            .fold(&identity, |acc, (x, y, tile)| fold_fn(acc, x, y, tile))
+            .reduce(&identity, &combine_fn)
+    }
+
+    /// Returns the minimum and maximum cell values, computed in one parallel pass.
+    ///
+    /// Values that cannot be compared with themselves (such as `NaN`) are
+    /// ignored. Returns `None` if there are no comparable values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut grid: Grid<f32> = Grid::new(50, 50);
+    /// grid.map_inplace(|x, y, v| *v = x as f32 - y as f32);
+    /// assert_eq!(grid.par_min_max(), Some((-49.0, 49.0)));
+    /// ```
+    pub fn par_min_max(&self) -> Option<(T, T)>
+    where
+        T: PartialOrd,
+    {
+        fn merge<T: PartialOrd>(a: Option<(T, T)>, b: Option<(T, T)>) -> Option<(T, T)> {
+            match (a, b) {
+                (Some((min_a, max_a)), Some((min_b, max_b))) => {
+                    let min = if min_b < min_a { min_b } else { min_a };
+                    let max = if max_b > max_a { max_b } else { max_a };
+                    Some((min, max))
+                }
+                (a, None) => a,
+                (None, b) => b,
+            }
+        }
+
+        self.par_fold(
+            || None,
+            |acc, _, _, value| {
+                if value.partial_cmp(value).is_none() {
+                    return acc;
+                }
+                merge(acc, Some((value.clone(), value.clone())))
+            },
+            merge,
+        )
+    }
+
     /// Modifies each tile in the grid, replacing its previous value.
     ///
     /// # Examples
@@ -407,3 +983,72 @@ impl<T: Default + Clone + Send + Sync> Grid<T> {
         });
     }
 }
+
+impl<T: Default + Clone> Index<(u16, u16)> for Grid<T> {
+    type Output = T;
+
+    /// Returns a reference to the element at `(x, y)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the same message as [`Grid::set`]'s error if the
+    /// coordinates are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let grid: Grid<i32> = Grid::with_value(4, 4, 7);
+    /// assert_eq!(grid[(3, 2)], 7);
+    /// ```
+    fn index(&self, (x, y): (u16, u16)) -> &T {
+        match self.get(x, y) {
+            Some(tile) => tile,
+            None => panic!(
+                "{}",
+                GridError::OutOfBounds {
+                    x,
+                    y,
+                    width: self.width,
+                    height: self.height,
+                }
+            ),
+        }
+    }
+}
+
+impl<T: Default + Clone> IndexMut<(u16, u16)> for Grid<T> {
+    /// Returns a mutable reference to the element at `(x, y)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the same message as [`Grid::set`]'s error if the
+    /// coordinates are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut grid: Grid<i32> = Grid::new(4, 4);
+    /// grid[(1, 3)] = 5;
+    /// grid[(1, 3)] += 1;
+    /// assert_eq!(grid.get(1, 3), Some(&6));
+    /// ```
+    fn index_mut(&mut self, (x, y): (u16, u16)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(x, y) {
+            Some(tile) => tile,
+            None => panic!(
+                "{}",
+                GridError::OutOfBounds {
+                    x,
+                    y,
+                    width,
+                    height,
+                }
+            ),
+        }
+    }
+}
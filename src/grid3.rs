@@ -0,0 +1,292 @@
+use crate::{Grid, GridError, GridRef, GridRefMut};
+
+/// A 3D grid stored as a flat vector.
+///
+/// Cells are laid out x fastest, then y, then z, so every z-layer is a
+/// contiguous `width × height` block in the same row-major order as a
+/// [`Grid`]. That makes a layer available as a borrowed 2D grid through
+/// [`layer`](Grid3::layer) without copying.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Grid3;
+///
+/// let mut voxels: Grid3<u8> = Grid3::new(4, 4, 3);
+/// voxels.set(1, 2, 2, 7).unwrap();
+/// assert_eq!(voxels.get(1, 2, 2), Some(&7));
+///
+/// // The top layer reads like any 2D grid.
+/// let top = voxels.layer(2).unwrap();
+/// assert_eq!(top.get(1, 2), Some(&7));
+/// assert!(voxels.set(0, 0, 3, 1).is_err());
+/// ```
+pub struct Grid3<T> {
+    width: u16,
+    height: u16,
+    depth: u16,
+    tiles: Vec<T>,
+}
+
+impl<T: Default + Clone> Grid3<T> {
+    /// Creates a `width × height × depth` grid filled with `T::default()`.
+    pub fn new(width: u16, height: u16, depth: u16) -> Grid3<T> {
+        Grid3::with_value(width, height, depth, T::default())
+    }
+
+    /// Creates a grid filled with `value`.
+    pub fn with_value(width: u16, height: u16, depth: u16, value: T) -> Grid3<T> {
+        Grid3 {
+            width,
+            height,
+            depth,
+            tiles: vec![value; width as usize * height as usize * depth as usize],
+        }
+    }
+
+    /// Creates a grid from cells laid out x fastest, then y, then z.
+    ///
+    /// Returns [`GridError::LengthMismatch`] unless `tiles` holds exactly
+    /// `width * height * depth` cells.
+    pub fn from_raw_parts(
+        width: u16,
+        height: u16,
+        depth: u16,
+        tiles: Vec<T>,
+    ) -> Result<Grid3<T>, GridError> {
+        let expected = width as usize * height as usize * depth as usize;
+        if tiles.len() != expected {
+            return Err(GridError::LengthMismatch {
+                expected,
+                found: tiles.len(),
+            });
+        }
+        Ok(Grid3 {
+            width,
+            height,
+            depth,
+            tiles,
+        })
+    }
+
+    /// Stacks equally sized 2D grids into layers, the first at z = 0.
+    ///
+    /// Returns [`GridError::DimensionMismatch`] if the layers differ in
+    /// size, or [`GridError::InvalidArgument`] if there are more than
+    /// `u16::MAX` of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Grid, Grid3};
+    ///
+    /// let floors = vec![Grid::with_value(3, 2, 'a'), Grid::with_value(3, 2, 'b')];
+    /// let building = Grid3::from_layers(floors).unwrap();
+    /// assert_eq!(building.depth(), 2);
+    /// assert_eq!(building.get(2, 1, 1), Some(&'b'));
+    /// assert_eq!(building.layer_to_grid(0).unwrap().as_slice(), &['a'; 6]);
+    /// ```
+    pub fn from_layers(layers: Vec<Grid<T>>) -> Result<Grid3<T>, GridError> {
+        let depth = u16::try_from(layers.len()).map_err(|_| {
+            GridError::InvalidArgument(format!(
+                "{} layers exceed the limit of {}",
+                layers.len(),
+                u16::MAX
+            ))
+        })?;
+        let (width, height) = layers.first().map_or((0, 0), |l| (l.width, l.height));
+        let mut tiles = Vec::with_capacity(width as usize * height as usize * depth as usize);
+        for layer in layers {
+            if (layer.width, layer.height) != (width, height) {
+                return Err(GridError::DimensionMismatch {
+                    expected: (width, height),
+                    found: (layer.width, layer.height),
+                });
+            }
+            tiles.extend(layer.tiles);
+        }
+        Grid3::from_raw_parts(width, height, depth, tiles)
+    }
+
+    /// Returns the size along x.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Returns the size along y.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Returns the number of z-layers.
+    pub fn depth(&self) -> u16 {
+        self.depth
+    }
+
+    /// Returns the value at (x, y, z), or `None` if out of bounds.
+    pub fn get(&self, x: u16, y: u16, z: u16) -> Option<&T> {
+        self.index(x, y, z).map(|i| &self.tiles[i])
+    }
+
+    /// Returns a mutable reference to the value at (x, y, z), or `None` if
+    /// out of bounds.
+    pub fn get_mut(&mut self, x: u16, y: u16, z: u16) -> Option<&mut T> {
+        self.index(x, y, z).map(|i| &mut self.tiles[i])
+    }
+
+    /// Sets the value at (x, y, z).
+    ///
+    /// Returns [`GridError::InvalidArgument`] if the position is out of
+    /// bounds.
+    pub fn set(&mut self, x: u16, y: u16, z: u16, value: T) -> Result<(), GridError> {
+        let Some(index) = self.index(x, y, z) else {
+            return Err(GridError::InvalidArgument(format!(
+                "Coordinates ({}, {}, {}) out of bounds (grid is {}x{}x{})",
+                x, y, z, self.width, self.height, self.depth
+            )));
+        };
+        self.tiles[index] = value;
+        Ok(())
+    }
+
+    /// Returns an iterator over all cells with their coordinates, layer by
+    /// layer.
+    ///
+    /// Each item is a tuple of (x, y, z, &T).
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (u16, u16, u16, &T)> + ExactSizeIterator {
+        let (width, height) = (self.width as usize, self.height as usize);
+        self.tiles.iter().enumerate().map(move |(i, tile)| {
+            let (x, y, z) = coords(i, width, height);
+            (x, y, z, tile)
+        })
+    }
+
+    /// Returns a mutable iterator over all cells with their coordinates,
+    /// layer by layer.
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = (u16, u16, u16, &mut T)> + ExactSizeIterator {
+        let (width, height) = (self.width as usize, self.height as usize);
+        self.tiles.iter_mut().enumerate().map(move |(i, tile)| {
+            let (x, y, z) = coords(i, width, height);
+            (x, y, z, tile)
+        })
+    }
+
+    /// Creates a new grid of the same size by applying `f` to every cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid3;
+    ///
+    /// let density: Grid3<f32> = Grid3::with_value(2, 2, 4, 0.5);
+    /// let solid = density.map(|_, _, z, &d| d * (z as f32) > 1.0);
+    /// assert_eq!(solid.get(0, 0, 3), Some(&true));
+    /// assert_eq!(solid.get(0, 0, 2), Some(&false));
+    /// ```
+    pub fn map<F, R>(&self, f: F) -> Grid3<R>
+    where
+        F: Fn(u16, u16, u16, &T) -> R,
+        R: Default + Clone,
+    {
+        Grid3 {
+            width: self.width,
+            height: self.height,
+            depth: self.depth,
+            tiles: self
+                .iter()
+                .map(|(x, y, z, value)| f(x, y, z, value))
+                .collect(),
+        }
+    }
+
+    /// Like [`map`](Grid3::map), but computes the cells in parallel.
+    pub fn par_map<F, R>(&self, f: F) -> Grid3<R>
+    where
+        T: Sync,
+        F: Fn(u16, u16, u16, &T) -> R + Send + Sync,
+        R: Default + Clone + Send,
+    {
+        use rayon::prelude::*;
+        let (width, height) = (self.width as usize, self.height as usize);
+        let tiles = self
+            .tiles
+            .par_iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let (x, y, z) = coords(i, width, height);
+                f(x, y, z, value)
+            })
+            .collect();
+        Grid3 {
+            width: self.width,
+            height: self.height,
+            depth: self.depth,
+            tiles,
+        }
+    }
+
+    /// Borrows z-layer `z` as a 2D grid, or `None` if it does not exist.
+    pub fn layer(&self, z: u16) -> Option<GridRef<'_, T>> {
+        let range = self.layer_range(z)?;
+        GridRef::new(self.width, self.height, &self.tiles[range]).ok()
+    }
+
+    /// Mutably borrows z-layer `z` as a 2D grid, or `None` if it does not
+    /// exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid3;
+    ///
+    /// let mut world: Grid3<u8> = Grid3::new(3, 3, 2);
+    /// world.layer_mut(1).unwrap().fill(4);
+    /// assert_eq!(world.get(2, 2, 1), Some(&4));
+    /// assert_eq!(world.get(2, 2, 0), Some(&0));
+    /// ```
+    pub fn layer_mut(&mut self, z: u16) -> Option<GridRefMut<'_, T>> {
+        let range = self.layer_range(z)?;
+        GridRefMut::new(self.width, self.height, &mut self.tiles[range]).ok()
+    }
+
+    /// Copies z-layer `z` into an owned 2D grid, or `None` if it does not
+    /// exist.
+    pub fn layer_to_grid(&self, z: u16) -> Option<Grid<T>> {
+        let range = self.layer_range(z)?;
+        Grid::from_raw_parts(self.width, self.height, self.tiles[range].to_vec()).ok()
+    }
+
+    /// Returns an iterator over the z-layers as borrowed 2D grids, from
+    /// z = 0 upwards.
+    pub fn layers(&self) -> impl Iterator<Item = GridRef<'_, T>> + '_ {
+        (0..self.depth).filter_map(|z| self.layer(z))
+    }
+
+    /// Returns the cells as a flat slice, laid out x fastest, then y, then z.
+    pub fn as_slice(&self) -> &[T] {
+        &self.tiles
+    }
+
+    /// Returns the cells as a flat mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.tiles
+    }
+
+    fn index(&self, x: u16, y: u16, z: u16) -> Option<usize> {
+        (x < self.width && y < self.height && z < self.depth).then(|| {
+            (z as usize * self.height as usize + y as usize) * self.width as usize + x as usize
+        })
+    }
+
+    fn layer_range(&self, z: u16) -> Option<std::ops::Range<usize>> {
+        let area = self.width as usize * self.height as usize;
+        (z < self.depth).then(|| z as usize * area..(z as usize + 1) * area)
+    }
+}
+
+fn coords(index: usize, width: usize, height: usize) -> (u16, u16, u16) {
+    let (x, rest) = (index % width, index / width);
+    (x as u16, (rest % height) as u16, (rest / height) as u16)
+}
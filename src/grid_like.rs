@@ -0,0 +1,134 @@
+use crate::{Grid, GridError};
+
+/// Read access shared by every grid storage variant.
+///
+/// Algorithms that only need to inspect cells (pathfinding, field of view,
+/// cellular automata rules) take `&impl GridLike<T>` so they work on dense
+/// grids, views, sparse and toroidal grids and other storages alike.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{Grid, GridLike};
+///
+/// fn count_matching<G: GridLike<i32>>(grid: &G, value: i32) -> usize {
+///     let mut count = 0;
+///     for y in 0..grid.height() {
+///         for x in 0..grid.width() {
+///             if grid.get(x, y) == Some(&value) {
+///                 count += 1;
+///             }
+///         }
+///     }
+///     count
+/// }
+///
+/// let grid: Grid<i32> = Grid::with_value(4, 3, 7);
+/// assert_eq!(count_matching(&grid, 7), 12);
+/// ```
+pub trait GridLike<T> {
+    /// Returns the width of the grid.
+    fn width(&self) -> u16;
+
+    /// Returns the height of the grid.
+    fn height(&self) -> u16;
+
+    /// Gets an immutable reference to the element at (x, y).
+    ///
+    /// Returns `None` if the coordinates are out of bounds.
+    fn get(&self, x: u16, y: u16) -> Option<&T>;
+
+    /// Returns `true` if (x, y) lies inside the grid.
+    fn in_bounds(&self, x: u16, y: u16) -> bool {
+        x < self.width() && y < self.height()
+    }
+
+    /// Returns the cell `(dx, dy)` steps away from (x, y), or `None` if that
+    /// lies off the grid.
+    ///
+    /// Pathfinding and field of view find neighbours through this, so a
+    /// storage with another topology, such as [`TorusGrid`](crate::TorusGrid),
+    /// overrides it together with [`distance`](GridLike::distance).
+    fn offset(&self, x: u16, y: u16, dx: i32, dy: i32) -> Option<(u16, u16)> {
+        let (nx, ny) = (x as i64 + dx as i64, y as i64 + dy as i64);
+        (nx >= 0 && ny >= 0 && nx < self.width() as i64 && ny < self.height() as i64)
+            .then_some((nx as u16, ny as u16))
+    }
+
+    /// Returns the fewest orthogonal steps between `a` and `b`, ignoring
+    /// cell contents.
+    ///
+    /// Pathfinding heuristics use this as their lower bound on path length.
+    fn distance(&self, a: (u16, u16), b: (u16, u16)) -> u32 {
+        a.0.abs_diff(b.0) as u32 + a.1.abs_diff(b.1) as u32
+    }
+}
+
+/// Write access shared by every mutable grid storage variant.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{Grid, GridLikeMut};
+///
+/// fn fill<G: GridLikeMut<u8>>(grid: &mut G, value: u8) {
+///     for y in 0..grid.height() {
+///         for x in 0..grid.width() {
+///             let _ = grid.set(x, y, value);
+///         }
+///     }
+/// }
+///
+/// let mut grid: Grid<u8> = Grid::new(3, 3);
+/// fill(&mut grid, 9);
+/// assert_eq!(grid.get(2, 2), Some(&9));
+/// ```
+pub trait GridLikeMut<T>: GridLike<T> {
+    /// Gets a mutable reference to the element at (x, y).
+    ///
+    /// Returns `None` if the coordinates are out of bounds.
+    fn get_mut(&mut self, x: u16, y: u16) -> Option<&mut T>;
+
+    /// Sets the element at (x, y) to the provided value.
+    ///
+    /// Returns [`GridError::OutOfBounds`] if the coordinates are out of bounds.
+    fn set(&mut self, x: u16, y: u16, value: T) -> Result<(), GridError> {
+        let (width, height) = (self.width(), self.height());
+        match self.get_mut(x, y) {
+            Some(cell) => {
+                *cell = value;
+                Ok(())
+            }
+            None => Err(GridError::OutOfBounds {
+                x,
+                y,
+                width,
+                height,
+            }),
+        }
+    }
+}
+
+impl<T: Default + Clone> GridLike<T> for Grid<T> {
+    fn width(&self) -> u16 {
+        Grid::width(self)
+    }
+
+    fn height(&self) -> u16 {
+        Grid::height(self)
+    }
+
+    fn get(&self, x: u16, y: u16) -> Option<&T> {
+        Grid::get(self, x, y)
+    }
+}
+
+impl<T: Default + Clone> GridLikeMut<T> for Grid<T> {
+    fn get_mut(&mut self, x: u16, y: u16) -> Option<&mut T> {
+        Grid::get_mut(self, x, y)
+    }
+
+    fn set(&mut self, x: u16, y: u16, value: T) -> Result<(), GridError> {
+        Grid::set(self, x, y, value)
+    }
+}
@@ -0,0 +1,287 @@
+use crate::{Grid, GridError, GridLike, GridLikeMut};
+
+/// A grid borrowed from memory the crate does not own.
+///
+/// Wraps a row-major `&[T]` plus its dimensions, so framebuffers, FFI
+/// buffers and memory-mapped data can be read through the grid API and
+/// passed to anything taking a [`GridLike`] without copying into a `Vec`.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::GridRef;
+///
+/// let pixels = [0u8, 1, 2, 3, 4, 5];
+/// let image = GridRef::new(3, 2, &pixels).unwrap();
+///
+/// assert_eq!(image.get(2, 1), Some(&5));
+/// assert_eq!(image.row(1), Some(&pixels[3..]));
+/// assert!(GridRef::new(4, 2, &pixels).is_err());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct GridRef<'a, T> {
+    width: u16,
+    height: u16,
+    cells: &'a [T],
+}
+
+/// A mutably borrowed grid over memory the crate does not own.
+///
+/// The writable counterpart of [`GridRef`]; implements [`GridLikeMut`].
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::GridRefMut;
+///
+/// let mut framebuffer = vec![0u32; 4 * 2];
+/// let mut screen = GridRefMut::new(4, 2, &mut framebuffer).unwrap();
+/// screen.set(3, 1, 0xff0000).unwrap();
+/// if let Some(row) = screen.row_mut(0) {
+///     row.fill(7);
+/// }
+///
+/// assert_eq!(framebuffer, [7, 7, 7, 7, 0, 0, 0, 0xff0000]);
+/// ```
+#[derive(Debug)]
+pub struct GridRefMut<'a, T> {
+    width: u16,
+    height: u16,
+    cells: &'a mut [T],
+}
+
+fn check_len(width: u16, height: u16, len: usize) -> Result<(), GridError> {
+    let expected = width as usize * height as usize;
+    if len == expected {
+        Ok(())
+    } else {
+        Err(GridError::LengthMismatch {
+            expected,
+            found: len,
+        })
+    }
+}
+
+impl<'a, T> GridRef<'a, T> {
+    /// Wraps `cells` as a `width × height` grid in row-major order.
+    ///
+    /// Returns [`GridError::LengthMismatch`] if `cells.len() != width * height`.
+    pub fn new(width: u16, height: u16, cells: &'a [T]) -> Result<GridRef<'a, T>, GridError> {
+        check_len(width, height, cells.len())?;
+        Ok(GridRef {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Returns the width of the grid.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Returns the height of the grid.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Gets a reference to the element at (x, y).
+    pub fn get(&self, x: u16, y: u16) -> Option<&'a T> {
+        (x < self.width && y < self.height)
+            .then(|| &self.cells[y as usize * self.width as usize + x as usize])
+    }
+
+    /// Returns row `y` as a slice.
+    pub fn row(&self, y: u16) -> Option<&'a [T]> {
+        let width = self.width as usize;
+        (y < self.height).then(|| &self.cells[y as usize * width..(y as usize + 1) * width])
+    }
+
+    /// Returns an iterator over `(x, y, &value)` in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (u16, u16, &'a T)> + use<'a, T> {
+        let width = (self.width as usize).max(1);
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, value)| ((i % width) as u16, (i / width) as u16, value))
+    }
+
+    /// Returns the underlying cells in row-major order.
+    pub fn as_slice(&self) -> &'a [T] {
+        self.cells
+    }
+}
+
+impl<T: Clone> GridRef<'_, T> {
+    /// Copies the cells into a new, owned grid.
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            tiles: self.cells.to_vec(),
+        }
+    }
+}
+
+impl<'a, T> GridRefMut<'a, T> {
+    /// Wraps `cells` as a writable `width × height` grid in row-major order.
+    ///
+    /// Returns [`GridError::LengthMismatch`] if `cells.len() != width * height`.
+    pub fn new(
+        width: u16,
+        height: u16,
+        cells: &'a mut [T],
+    ) -> Result<GridRefMut<'a, T>, GridError> {
+        check_len(width, height, cells.len())?;
+        Ok(GridRefMut {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Returns the width of the grid.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Returns the height of the grid.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Gets a reference to the element at (x, y).
+    pub fn get(&self, x: u16, y: u16) -> Option<&T> {
+        self.as_grid_ref().get(x, y)
+    }
+
+    /// Gets a mutable reference to the element at (x, y).
+    pub fn get_mut(&mut self, x: u16, y: u16) -> Option<&mut T> {
+        (x < self.width && y < self.height)
+            .then(|| &mut self.cells[y as usize * self.width as usize + x as usize])
+    }
+
+    /// Sets the element at (x, y).
+    ///
+    /// Returns [`GridError::OutOfBounds`] if the coordinates are out of bounds.
+    pub fn set(&mut self, x: u16, y: u16, value: T) -> Result<(), GridError> {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(x, y) {
+            Some(cell) => {
+                *cell = value;
+                Ok(())
+            }
+            None => Err(GridError::OutOfBounds {
+                x,
+                y,
+                width,
+                height,
+            }),
+        }
+    }
+
+    /// Returns row `y` as a slice.
+    pub fn row(&self, y: u16) -> Option<&[T]> {
+        self.as_grid_ref().row(y)
+    }
+
+    /// Returns row `y` as a mutable slice.
+    pub fn row_mut(&mut self, y: u16) -> Option<&mut [T]> {
+        let width = self.width as usize;
+        (y < self.height).then(|| &mut self.cells[y as usize * width..(y as usize + 1) * width])
+    }
+
+    /// Returns an iterator over `(x, y, &value)` in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (u16, u16, &T)> {
+        self.as_grid_ref().iter()
+    }
+
+    /// Returns an iterator over `(x, y, &mut value)` in row-major order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (u16, u16, &mut T)> {
+        let width = (self.width as usize).max(1);
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, value)| ((i % width) as u16, (i / width) as u16, value))
+    }
+
+    /// Returns the underlying cells in row-major order.
+    pub fn as_slice(&self) -> &[T] {
+        self.cells
+    }
+
+    /// Returns the underlying cells mutably, in row-major order.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.cells
+    }
+
+    /// Borrows the grid read-only.
+    pub fn as_grid_ref(&self) -> GridRef<'_, T> {
+        GridRef {
+            width: self.width,
+            height: self.height,
+            cells: self.cells,
+        }
+    }
+}
+
+impl<T: Clone> GridRefMut<'_, T> {
+    /// Sets every cell to `value`.
+    pub fn fill(&mut self, value: T) {
+        self.cells.fill(value);
+    }
+}
+
+impl<'a, T> From<&'a Grid<T>> for GridRef<'a, T> {
+    fn from(grid: &'a Grid<T>) -> GridRef<'a, T> {
+        GridRef {
+            width: grid.width,
+            height: grid.height,
+            cells: &grid.tiles,
+        }
+    }
+}
+
+impl<'a, T> From<&'a mut Grid<T>> for GridRefMut<'a, T> {
+    fn from(grid: &'a mut Grid<T>) -> GridRefMut<'a, T> {
+        GridRefMut {
+            width: grid.width,
+            height: grid.height,
+            cells: &mut grid.tiles,
+        }
+    }
+}
+
+impl<T> GridLike<T> for GridRef<'_, T> {
+    fn width(&self) -> u16 {
+        self.width
+    }
+
+    fn height(&self) -> u16 {
+        self.height
+    }
+
+    fn get(&self, x: u16, y: u16) -> Option<&T> {
+        GridRef::get(self, x, y)
+    }
+}
+
+impl<T> GridLike<T> for GridRefMut<'_, T> {
+    fn width(&self) -> u16 {
+        self.width
+    }
+
+    fn height(&self) -> u16 {
+        self.height
+    }
+
+    fn get(&self, x: u16, y: u16) -> Option<&T> {
+        GridRefMut::get(self, x, y)
+    }
+}
+
+impl<T> GridLikeMut<T> for GridRefMut<'_, T> {
+    fn get_mut(&mut self, x: u16, y: u16) -> Option<&mut T> {
+        GridRefMut::get_mut(self, x, y)
+    }
+}
@@ -0,0 +1,275 @@
+use std::ops::{Add, Sub};
+
+use crate::{Grid, GridError};
+
+/// A hexagon position in axial coordinates.
+///
+/// `q` counts columns and `r` counts rows of pointy-top hexagons; the
+/// implied third cube coordinate is `s = -q - r`. Axial coordinates make
+/// neighbours, distances and rings plain arithmetic, while
+/// [`HexGrid`] stores cells in "odd-r" offset rows (odd rows shifted half a
+/// hexagon right) so a rectangular map packs into a flat vector.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Hex;
+///
+/// let a = Hex::new(0, 0);
+/// let b = Hex::new(2, -1);
+/// assert_eq!(a.distance(b), 2);
+/// assert_eq!(b.cube(), (2, -1, -1));
+/// assert_eq!(Hex::from_offset(1, 3).to_offset(), (1, 3));
+/// assert!(a.neighbors().contains(&Hex::new(1, -1)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Hex {
+    pub q: i32,
+    pub r: i32,
+}
+
+impl Hex {
+    /// The six neighbour offsets, counter-clockwise from east.
+    pub const DIRECTIONS: [Hex; 6] = [
+        Hex { q: 1, r: 0 },
+        Hex { q: 1, r: -1 },
+        Hex { q: 0, r: -1 },
+        Hex { q: -1, r: 0 },
+        Hex { q: -1, r: 1 },
+        Hex { q: 0, r: 1 },
+    ];
+
+    /// Creates a hex from its axial coordinates.
+    pub fn new(q: i32, r: i32) -> Hex {
+        Hex { q, r }
+    }
+
+    /// Creates a hex from cube coordinates, or `None` unless
+    /// `q + r + s == 0`.
+    pub fn from_cube(q: i32, r: i32, s: i32) -> Option<Hex> {
+        (q + r + s == 0).then_some(Hex { q, r })
+    }
+
+    /// Returns the cube coordinates `(q, r, s)`.
+    pub fn cube(self) -> (i32, i32, i32) {
+        (self.q, self.r, -self.q - self.r)
+    }
+
+    /// Creates a hex from "odd-r" offset coordinates (column, row).
+    pub fn from_offset(col: i32, row: i32) -> Hex {
+        Hex {
+            q: col - (row - (row & 1)) / 2,
+            r: row,
+        }
+    }
+
+    /// Returns the "odd-r" offset coordinates (column, row).
+    pub fn to_offset(self) -> (i32, i32) {
+        (self.q + (self.r - (self.r & 1)) / 2, self.r)
+    }
+
+    /// Returns the six adjacent hexes, counter-clockwise from east.
+    pub fn neighbors(self) -> [Hex; 6] {
+        Hex::DIRECTIONS.map(|d| self + d)
+    }
+
+    /// Returns the number of steps between two hexes.
+    pub fn distance(self, other: Hex) -> u32 {
+        let (dq, dr, ds) = (self - other).cube();
+        (dq.unsigned_abs() + dr.unsigned_abs() + ds.unsigned_abs()) / 2
+    }
+
+    /// Returns the hexes exactly `radius` steps away, walking the ring
+    /// counter-clockwise.
+    ///
+    /// A ring of radius 0 is the hex itself; larger rings hold `6 * radius`
+    /// hexes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Hex;
+    ///
+    /// let centre = Hex::new(3, -2);
+    /// let ring: Vec<Hex> = centre.ring(2).collect();
+    /// assert_eq!(ring.len(), 12);
+    /// assert!(ring.iter().all(|&h| h.distance(centre) == 2));
+    /// ```
+    pub fn ring(self, radius: u32) -> impl Iterator<Item = Hex> {
+        let radius = radius as i32;
+        (0..(6 * radius).max(1)).map(move |i| {
+            let (side, step) = (i / radius.max(1), i % radius.max(1));
+            let corner = Hex::DIRECTIONS[(side as usize + 4) % 6];
+            let walk = Hex::DIRECTIONS[side as usize];
+            Hex {
+                q: self.q + corner.q * radius + walk.q * step,
+                r: self.r + corner.r * radius + walk.r * step,
+            }
+        })
+    }
+
+    /// Returns every hex within `radius` steps, ring by ring outward from
+    /// this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Hex;
+    ///
+    /// let area: Vec<Hex> = Hex::new(0, 0).spiral(2).collect();
+    /// assert_eq!(area.len(), 19);
+    /// assert_eq!(area[0], Hex::new(0, 0));
+    /// ```
+    pub fn spiral(self, radius: u32) -> impl Iterator<Item = Hex> {
+        (0..=radius).flat_map(move |r| self.ring(r))
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, other: Hex) -> Hex {
+        Hex::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, other: Hex) -> Hex {
+        Hex::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+/// A rectangular map of pointy-top hexagons addressed by [`Hex`].
+///
+/// Cells are stored row by row in a [`Grid`] using "odd-r" offset
+/// coordinates, so a `width × height` map holds exactly `width * height`
+/// cells. Hexes outside that rectangle are out of bounds.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{Hex, HexGrid};
+///
+/// let mut map: HexGrid<u8> = HexGrid::new(5, 4);
+/// let centre = Hex::from_offset(2, 1);
+/// map.set(centre, 9).unwrap();
+///
+/// for (hex, value) in map.neighbors(centre) {
+///     assert_eq!(hex.distance(centre), 1);
+///     assert_eq!(*value, 0);
+/// }
+/// assert_eq!(map.neighbors(centre).count(), 6);
+/// assert_eq!(map.get(centre), Some(&9));
+/// assert_eq!(map.get(Hex::new(-1, 0)), None);
+/// ```
+pub struct HexGrid<T> {
+    cells: Grid<T>,
+}
+
+impl<T: Default + Clone> HexGrid<T> {
+    /// Creates a map `width` hexes wide and `height` rows tall, filled with
+    /// `T::default()`.
+    pub fn new(width: u16, height: u16) -> HexGrid<T> {
+        HexGrid {
+            cells: Grid::new(width, height),
+        }
+    }
+
+    /// Creates a map filled with `value`.
+    pub fn with_value(width: u16, height: u16, value: T) -> HexGrid<T> {
+        HexGrid {
+            cells: Grid::with_value(width, height, value),
+        }
+    }
+
+    /// Wraps a grid whose (column, row) positions are "odd-r" offset
+    /// coordinates.
+    pub fn from_grid(cells: Grid<T>) -> HexGrid<T> {
+        HexGrid { cells }
+    }
+
+    /// Returns the number of hexes per row.
+    pub fn width(&self) -> u16 {
+        self.cells.width
+    }
+
+    /// Returns the number of rows.
+    pub fn height(&self) -> u16 {
+        self.cells.height
+    }
+
+    /// Returns `true` if `hex` lies on the map.
+    pub fn contains(&self, hex: Hex) -> bool {
+        self.offset(hex).is_some()
+    }
+
+    /// Returns the value at `hex`, or `None` if it is off the map.
+    pub fn get(&self, hex: Hex) -> Option<&T> {
+        let (col, row) = self.offset(hex)?;
+        self.cells.get(col, row)
+    }
+
+    /// Returns a mutable reference to the value at `hex`, or `None` if it is
+    /// off the map.
+    pub fn get_mut(&mut self, hex: Hex) -> Option<&mut T> {
+        let (col, row) = self.offset(hex)?;
+        self.cells.get_mut(col, row)
+    }
+
+    /// Sets the value at `hex`.
+    ///
+    /// Returns [`GridError::InvalidArgument`] if the hex is off the map.
+    pub fn set(&mut self, hex: Hex, value: T) -> Result<(), GridError> {
+        let Some(cell) = self.get_mut(hex) else {
+            return Err(GridError::InvalidArgument(format!(
+                "Hex ({}, {}) is outside a {}x{} hex map",
+                hex.q,
+                hex.r,
+                self.width(),
+                self.height()
+            )));
+        };
+        *cell = value;
+        Ok(())
+    }
+
+    /// Returns the on-map neighbours of `hex` with their values.
+    pub fn neighbors(&self, hex: Hex) -> impl Iterator<Item = (Hex, &T)> + '_ {
+        hex.neighbors()
+            .into_iter()
+            .filter_map(|n| self.get(n).map(|value| (n, value)))
+    }
+
+    /// Returns an iterator over every hex and its value, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Hex, &T)> + '_ {
+        self.cells
+            .iter()
+            .map(|(col, row, value)| (Hex::from_offset(col as i32, row as i32), value))
+    }
+
+    /// Returns a mutable iterator over every hex and its value, row by row.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Hex, &mut T)> + '_ {
+        self.cells
+            .iter_mut()
+            .map(|(col, row, value)| (Hex::from_offset(col as i32, row as i32), value))
+    }
+
+    /// Returns the underlying grid, indexed by offset coordinates.
+    pub fn as_grid(&self) -> &Grid<T> {
+        &self.cells
+    }
+
+    /// Consumes the map, returning the underlying grid.
+    pub fn into_grid(self) -> Grid<T> {
+        self.cells
+    }
+
+    fn offset(&self, hex: Hex) -> Option<(u16, u16)> {
+        let (col, row) = hex.to_offset();
+        let inside =
+            (0..self.width() as i32).contains(&col) && (0..self.height() as i32).contains(&row);
+        inside.then_some((col as u16, row as u16))
+    }
+}
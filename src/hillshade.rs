@@ -0,0 +1,47 @@
+use crate::Grid;
+
+impl Grid<f32> {
+    /// Computes standard hillshade illumination for a heightmap.
+    ///
+    /// `azimuth` is the compass direction the light comes from in degrees
+    /// (0 = north/up, 90 = east) and `altitude` its angle above the horizon
+    /// in degrees. Heights are assumed to be in cell units; scale them first
+    /// for exaggerated relief. Returns brightness in `0.0..=1.0`, where flat
+    /// terrain receives `sin(altitude)`.
+    ///
+    /// The slope and aspect come from [`Grid::gradient`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// // A hill in the middle of a plain, lit from the north-west.
+    /// let mut heights: Grid<f32> = Grid::new(9, 9);
+    /// heights.map_inplace(|x, y, h| {
+    ///     let d = (x as f32 - 4.0).hypot(y as f32 - 4.0);
+    ///     *h = (4.0 - d).max(0.0);
+    /// });
+    /// let shade = heights.hillshade(315.0, 45.0);
+    ///
+    /// let north_west = *shade.get(2, 2).unwrap();
+    /// let south_east = *shade.get(6, 6).unwrap();
+    /// assert!(north_west > south_east);
+    /// ```
+    pub fn hillshade(&self, azimuth: f32, altitude: f32) -> Grid<f32> {
+        let zenith = (90.0 - altitude).to_radians();
+        // Convert the compass bearing to a counter-clockwise angle from +x.
+        let azimuth = (450.0 - azimuth).rem_euclid(360.0).to_radians();
+        let (sin_zenith, cos_zenith) = zenith.sin_cos();
+
+        self.gradient(1.0).map(|_, _, &(dz_dx, dz_dy)| {
+            let slope = (dz_dx * dz_dx + dz_dy * dz_dy).sqrt().atan();
+            // Downhill direction as a counter-clockwise angle from +x with north up;
+            // rows grow southwards, so dz/dy already points the right way.
+            let aspect = dz_dy.atan2(-dz_dx);
+            let shade =
+                cos_zenith * slope.cos() + sin_zenith * slope.sin() * (azimuth - aspect).cos();
+            shade.clamp(0.0, 1.0)
+        })
+    }
+}
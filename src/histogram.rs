@@ -0,0 +1,91 @@
+use crate::{Grid, GridError};
+
+impl Grid<f32> {
+    /// Spreads values evenly over `0.0..=1.0` by histogram equalization.
+    ///
+    /// Each value is replaced by the fraction of cells at or below it,
+    /// rescaled so the smallest value maps to 0 and the largest to 1. Equal
+    /// values stay equal and order is preserved, but clusters of similar
+    /// values are pulled apart, which makes thresholds on noisy generated
+    /// data behave like percentiles. NaN cells are ignored and left as NaN;
+    /// if all other values are equal they become 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut field = Grid::from_raw_parts(5, 1, vec![0.0, 0.1, 0.11, 0.12, 5.0]).unwrap();
+    /// field.equalize_histogram();
+    /// assert_eq!(field.as_slice(), &[0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// ```
+    pub fn equalize_histogram(&mut self) {
+        let sorted = sorted_non_nan(&self.tiles);
+        let Some(&lowest) = sorted.first() else {
+            return;
+        };
+        let at_or_below = |v: f32| sorted.partition_point(|&s| s <= v);
+        let base = at_or_below(lowest);
+        let span = (sorted.len() - base).max(1) as f32;
+        for value in self.tiles.iter_mut().filter(|v| !v.is_nan()) {
+            *value = (at_or_below(*value) - base) as f32 / span;
+        }
+    }
+
+    /// Linearly stretches the `p_low`..`p_high` percentile range to `0.0..=1.0`.
+    ///
+    /// Percentiles are in `0.0..=100.0` and use the nearest rank among the
+    /// non-NaN values. Values outside the range are clamped, so a few
+    /// outliers no longer squash the rest of the field into a narrow band,
+    /// as they would with a plain min/max rescale. If both percentiles land
+    /// on the same value, cells at or below it become 0 and the rest 1. NaN
+    /// cells are left as NaN.
+    ///
+    /// Returns [`GridError::InvalidArgument`] unless
+    /// `0 <= p_low < p_high <= 100`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut field: Grid<f32> = Grid::new(11, 1);
+    /// field.map_inplace(|x, _, v| *v = x as f32);
+    /// let _ = field.set(10, 0, 1000.0);
+    ///
+    /// field.stretch_contrast(10.0, 90.0).unwrap();
+    /// assert_eq!(field.get(1, 0), Some(&0.0));
+    /// assert_eq!(field.get(5, 0), Some(&0.5));
+    /// assert_eq!(field.get(10, 0), Some(&1.0));
+    /// assert!(field.stretch_contrast(50.0, 50.0).is_err());
+    /// ```
+    pub fn stretch_contrast(&mut self, p_low: f32, p_high: f32) -> Result<(), GridError> {
+        if !(0.0..=100.0).contains(&p_low) || !(0.0..=100.0).contains(&p_high) || p_low >= p_high {
+            return Err(GridError::InvalidArgument(format!(
+                "Invalid percentile range {}..{} (expected 0 <= low < high <= 100)",
+                p_low, p_high
+            )));
+        }
+        let sorted = sorted_non_nan(&self.tiles);
+        if sorted.is_empty() {
+            return Ok(());
+        }
+        let percentile = |p: f32| sorted[(p / 100.0 * (sorted.len() - 1) as f32).round() as usize];
+        let (low, high) = (percentile(p_low), percentile(p_high));
+        for value in self.tiles.iter_mut().filter(|v| !v.is_nan()) {
+            *value = if high > low {
+                ((*value - low) / (high - low)).clamp(0.0, 1.0)
+            } else {
+                (*value > low) as u8 as f32
+            };
+        }
+        Ok(())
+    }
+}
+
+/// Returns the non-NaN values in ascending order.
+fn sorted_non_nan(values: &[f32]) -> Vec<f32> {
+    let mut sorted: Vec<f32> = values.iter().copied().filter(|v| !v.is_nan()).collect();
+    sorted.sort_by(f32::total_cmp);
+    sorted
+}
@@ -0,0 +1,75 @@
+use std::collections::VecDeque;
+
+use crate::{Direction, Grid};
+
+impl<T: Default + Clone> Grid<T> {
+    /// Replaces enclosed regions of matching cells with `value`.
+    ///
+    /// A region is a 4-connected group of cells for which `predicate`
+    /// returns `true`. Regions that touch the grid border are kept; every
+    /// other region is a hole and each of its cells is set to `value`. Use
+    /// it to turn lakes fully enclosed by land into land, or to solidify
+    /// pockets inside cave walls.
+    ///
+    /// Returns the number of cells filled.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Decides which cells can form a hole
+    /// * `value` - Written into every hole cell
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let rows = ["~~~~~~", "~##~~~", "~#~#~~", "~###~~"];
+    /// let cells = rows.iter().flat_map(|r| r.chars()).collect();
+    /// let mut map = Grid::from_raw_parts(6, 4, cells).unwrap();
+    ///
+    /// // The lake at (2, 2) is enclosed; the sea touches the border.
+    /// assert_eq!(map.fill_holes(|&c| c == '~', '#'), 1);
+    /// assert_eq!(map.get(2, 2), Some(&'#'));
+    /// assert_eq!(map.get(3, 1), Some(&'~'));
+    /// ```
+    pub fn fill_holes<P>(&mut self, predicate: P, value: T) -> usize
+    where
+        P: Fn(&T) -> bool,
+    {
+        let (width, height) = (self.width, self.height);
+        // Flood every matching region that touches the border at once.
+        let mut open = vec![false; self.tiles.len()];
+        let mut queue = VecDeque::new();
+        for (i, tile) in self.tiles.iter().enumerate() {
+            let (x, y) = self.coords(i);
+            let border = x == 0 || y == 0 || x == width - 1 || y == height - 1;
+            if border && predicate(tile) {
+                open[i] = true;
+                queue.push_back((x, y));
+            }
+        }
+        while let Some((x, y)) = queue.pop_front() {
+            for direction in Direction::CARDINAL {
+                let (dx, dy) = direction.offset();
+                let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+                if nx < 0 || ny < 0 || nx >= width as i32 || ny >= height as i32 {
+                    continue;
+                }
+                let next = self.index(nx as u16, ny as u16);
+                if !open[next] && predicate(&self.tiles[next]) {
+                    open[next] = true;
+                    queue.push_back((nx as u16, ny as u16));
+                }
+            }
+        }
+
+        let mut filled = 0;
+        for (tile, open) in self.tiles.iter_mut().zip(open) {
+            if !open && predicate(tile) {
+                *tile = value.clone();
+                filled += 1;
+            }
+        }
+        filled
+    }
+}
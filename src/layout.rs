@@ -0,0 +1,150 @@
+use crate::{Grid, GridError};
+
+/// An order in which a grid's cells can be laid out in a flat buffer.
+///
+/// A [`Grid`] always stores its cells row-major; the other layouts are for
+/// exchanging data with systems that expect them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Layout {
+    /// Rows one after another, `x` varying fastest.
+    #[default]
+    RowMajor,
+    /// Columns one after another, `y` varying fastest.
+    ColumnMajor,
+    /// Z-order: cells sorted by their Morton code, the interleaved bits of
+    /// `x` and `y`, which keeps nearby cells close in memory. On grids that
+    /// are not square powers of two the curve is compacted, skipping codes
+    /// that fall outside the grid.
+    Morton,
+}
+
+impl Layout {
+    /// Returns the Morton code of a cell: the bits of `x` and `y` interleaved, `x` lowest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Layout;
+    ///
+    /// assert_eq!(Layout::morton_code(0b11, 0b00), 0b0101);
+    /// assert_eq!(Layout::morton_code(0b00, 0b11), 0b1010);
+    /// ```
+    pub fn morton_code(x: u16, y: u16) -> u32 {
+        fn spread(v: u16) -> u32 {
+            let mut v = v as u32;
+            v = (v | (v << 8)) & 0x00FF_00FF;
+            v = (v | (v << 4)) & 0x0F0F_0F0F;
+            v = (v | (v << 2)) & 0x3333_3333;
+            (v | (v << 1)) & 0x5555_5555
+        }
+        spread(x) | (spread(y) << 1)
+    }
+
+    /// Returns the row-major indices of a `width × height` grid in this layout's order.
+    fn order(self, width: u16, height: u16) -> Vec<usize> {
+        let (w, h) = (width as usize, height as usize);
+        match self {
+            Layout::RowMajor => (0..w * h).collect(),
+            Layout::ColumnMajor => (0..w)
+                .flat_map(|x| (0..h).map(move |y| y * w + x))
+                .collect(),
+            Layout::Morton => {
+                let mut order: Vec<usize> = (0..w * h).collect();
+                order.sort_by_key(|&i| Layout::morton_code((i % w) as u16, (i / w) as u16));
+                order
+            }
+        }
+    }
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Returns the cells in the given layout's order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Grid, Layout};
+    ///
+    /// let grid = Grid::from_raw_parts(2, 2, vec!['a', 'b', 'c', 'd']).unwrap();
+    /// assert_eq!(grid.to_layout(Layout::ColumnMajor), vec!['a', 'c', 'b', 'd']);
+    /// ```
+    pub fn to_layout(&self, layout: Layout) -> Vec<T> {
+        match layout {
+            Layout::RowMajor => self.tiles.clone(),
+            _ => layout
+                .order(self.width, self.height)
+                .into_iter()
+                .map(|i| self.tiles[i].clone())
+                .collect(),
+        }
+    }
+
+    /// Writes the cells in the given layout's order into `out`'s cell buffer.
+    ///
+    /// Like [`Grid::to_layout`], but reuses `out`'s allocation when the same
+    /// conversion runs every frame; read the result with
+    /// [`as_slice`](Grid::as_slice). Returns [`GridError::DimensionMismatch`]
+    /// if `out` is not the same size as this grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Grid, Layout};
+    ///
+    /// let grid = Grid::from_raw_parts(4, 2, (0..8).collect()).unwrap();
+    /// let mut buffer = Grid::new(4, 2);
+    /// grid.reorder_into(&mut buffer, Layout::Morton).unwrap();
+    /// assert_eq!(buffer.as_slice(), &[0, 1, 4, 5, 2, 3, 6, 7]);
+    ///
+    /// assert!(grid.reorder_into(&mut Grid::new(2, 4), Layout::Morton).is_err());
+    /// ```
+    pub fn reorder_into(&self, out: &mut Grid<T>, layout: Layout) -> Result<(), GridError> {
+        if (out.width, out.height) != (self.width, self.height) {
+            return Err(GridError::DimensionMismatch {
+                expected: (self.width, self.height),
+                found: (out.width, out.height),
+            });
+        }
+        match layout {
+            Layout::RowMajor => out.tiles.clone_from_slice(&self.tiles),
+            _ => {
+                let order = layout.order(self.width, self.height);
+                for (slot, i) in out.tiles.iter_mut().zip(order) {
+                    slot.clone_from(&self.tiles[i]);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds a grid from cells given in the given layout's order.
+    ///
+    /// This is the inverse of [`Grid::to_layout`]. Returns
+    /// [`GridError::LengthMismatch`] if `cells` does not hold exactly
+    /// `width × height` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Grid, Layout};
+    ///
+    /// let grid = Grid::from_layout(2, 3, vec![1, 2, 3, 4, 5, 6], Layout::ColumnMajor).unwrap();
+    /// assert_eq!(grid.as_slice(), &[1, 4, 2, 5, 3, 6]);
+    /// assert_eq!(grid.to_layout(Layout::ColumnMajor), vec![1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn from_layout(
+        width: u16,
+        height: u16,
+        cells: Vec<T>,
+        layout: Layout,
+    ) -> Result<Grid<T>, GridError> {
+        if layout == Layout::RowMajor {
+            return Grid::from_raw_parts(width, height, cells);
+        }
+        let mut grid = Grid::from_raw_parts(width, height, vec![T::default(); cells.len()])?;
+        for (i, cell) in layout.order(width, height).into_iter().zip(cells) {
+            grid.tiles[i] = cell;
+        }
+        Ok(grid)
+    }
+}
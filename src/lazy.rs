@@ -0,0 +1,215 @@
+use crate::{Grid, GridError};
+
+/// A per-cell computation that a [`Lazy`] expression evaluates on demand.
+///
+/// Implemented by the nodes a [`Lazy`] builder chains together; each one
+/// produces the value of a single cell from its row-major index, so a
+/// whole chain runs in one traversal.
+pub trait Expr {
+    /// The value produced for each cell.
+    type Item;
+
+    /// Computes the value of the cell at row-major `index`.
+    fn at(&self, index: usize) -> Self::Item;
+}
+
+/// Reads cells straight from a grid.
+pub struct Source<'a, T> {
+    tiles: &'a [T],
+}
+
+impl<'a, T> Expr for Source<'a, T> {
+    type Item = &'a T;
+
+    fn at(&self, index: usize) -> &'a T {
+        &self.tiles[index]
+    }
+}
+
+/// Applies a function to another expression's values.
+pub struct Map<E, F> {
+    expr: E,
+    f: F,
+}
+
+impl<E: Expr, F, R> Expr for Map<E, F>
+where
+    F: Fn(E::Item) -> R,
+{
+    type Item = R;
+
+    fn at(&self, index: usize) -> R {
+        (self.f)(self.expr.at(index))
+    }
+}
+
+/// Pairs the values of two expressions over grids of the same size.
+pub struct Zip<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A: Expr, B: Expr> Expr for Zip<A, B> {
+    type Item = (A::Item, B::Item);
+
+    fn at(&self, index: usize) -> Self::Item {
+        (self.a.at(index), self.b.at(index))
+    }
+}
+
+/// A chain of element-wise operations that runs only when evaluated.
+///
+/// Created by [`Grid::lazy`]. Each [`map`](Lazy::map) and
+/// [`zip`](Lazy::zip) wraps the expression instead of producing a grid, and
+/// [`eval`](Lazy::eval) or [`par_eval`](Lazy::par_eval) then computes every
+/// cell through the whole chain in a single pass, allocating only the
+/// result.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Grid;
+///
+/// let height: Grid<f32> = Grid::with_value(4, 4, 2.0);
+/// let water: Grid<f32> = Grid::with_value(4, 4, 0.5);
+///
+/// let depth = height
+///     .lazy()
+///     .map(|&h| h * 10.0)
+///     .zip(&water)
+///     .map(|(h, &w)| h + w)
+///     .eval()
+///     .unwrap();
+/// assert_eq!(depth.get(1, 2), Some(&20.5));
+/// ```
+pub struct Lazy<E> {
+    width: u16,
+    height: u16,
+    expr: E,
+    mismatch: Option<(u16, u16)>,
+}
+
+impl<'a, T> From<&'a Grid<T>> for Lazy<Source<'a, T>> {
+    fn from(grid: &'a Grid<T>) -> Self {
+        Lazy {
+            width: grid.width,
+            height: grid.height,
+            expr: Source { tiles: &grid.tiles },
+            mismatch: None,
+        }
+    }
+}
+
+impl<E: Expr> Lazy<E> {
+    /// Returns the width of the grid the expression will produce.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Returns the height of the grid the expression will produce.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Applies `f` to every value of the expression.
+    pub fn map<F, R>(self, f: F) -> Lazy<Map<E, F>>
+    where
+        F: Fn(E::Item) -> R,
+    {
+        Lazy {
+            width: self.width,
+            height: self.height,
+            expr: Map { expr: self.expr, f },
+            mismatch: self.mismatch,
+        }
+    }
+
+    /// Pairs every value with the matching value of `other`, which may be a
+    /// grid or another lazy expression.
+    ///
+    /// The sizes are checked when the expression is evaluated.
+    pub fn zip<B: Expr>(self, other: impl Into<Lazy<B>>) -> Lazy<Zip<E, B>> {
+        let other = other.into();
+        let found = (other.width, other.height);
+        let mismatch = self
+            .mismatch
+            .or(other.mismatch)
+            .or(((self.width, self.height) != found).then_some(found));
+        Lazy {
+            width: self.width,
+            height: self.height,
+            expr: Zip {
+                a: self.expr,
+                b: other.expr,
+            },
+            mismatch,
+        }
+    }
+
+    /// Computes every cell in row-major order.
+    ///
+    /// Returns [`GridError::DimensionMismatch`] if a zipped grid is not the
+    /// size of the first one.
+    pub fn eval(self) -> Result<Grid<E::Item>, GridError> {
+        self.check()?;
+        let tiles = (0..self.width as usize * self.height as usize)
+            .map(|i| self.expr.at(i))
+            .collect();
+        Ok(Grid {
+            width: self.width,
+            height: self.height,
+            tiles,
+        })
+    }
+
+    /// Computes every cell in parallel.
+    ///
+    /// Returns [`GridError::DimensionMismatch`] if a zipped grid is not the
+    /// size of the first one.
+    pub fn par_eval(self) -> Result<Grid<E::Item>, GridError>
+    where
+        E: Sync,
+        E::Item: Send,
+    {
+        use rayon::prelude::*;
+        self.check()?;
+        let tiles = (0..self.width as usize * self.height as usize)
+            .into_par_iter()
+            .map(|i| self.expr.at(i))
+            .collect();
+        Ok(Grid {
+            width: self.width,
+            height: self.height,
+            tiles,
+        })
+    }
+
+    fn check(&self) -> Result<(), GridError> {
+        match self.mismatch {
+            Some(found) => Err(GridError::DimensionMismatch {
+                expected: (self.width, self.height),
+                found,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Starts a lazy chain of element-wise operations over this grid.
+    ///
+    /// See [`Lazy`] for the operations available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let grid: Grid<u8> = Grid::with_value(3, 2, 4);
+    /// let scaled = grid.lazy().map(|&v| v as u32 * 100).par_eval().unwrap();
+    /// assert_eq!(scaled.as_slice(), &[400; 6]);
+    /// ```
+    pub fn lazy(&self) -> Lazy<Source<'_, T>> {
+        Lazy::from(self)
+    }
+}
@@ -1,3 +1,133 @@
+pub mod aggregate;
+pub mod arc_grid;
+pub mod arena;
+pub mod cell_grid;
+pub mod chunked;
+pub mod codec;
+pub mod columns;
+pub mod components;
+pub mod contour;
+pub mod cursor;
+pub mod diff;
+pub mod direction;
+pub mod dirty;
+pub mod edge_grid;
+pub mod error;
+pub mod flood;
+pub mod fov;
+pub mod generate;
+pub mod geo;
+pub mod gradient;
 pub mod grid;
+pub mod grid3;
+pub mod grid_like;
+pub mod grid_ref;
+pub mod hex;
+pub mod hillshade;
+pub mod histogram;
+pub mod holes;
+pub mod layout;
+pub mod lazy;
+pub mod line;
+pub mod mask;
+pub mod mesh;
+pub mod meshing;
+pub mod navigable;
+pub mod occupancy;
+pub mod packed;
+pub mod path_cache;
+pub mod pathfinding;
+pub mod placement;
+pub mod points_csv;
+pub mod progress;
+pub mod prune;
+pub mod pvs;
+pub mod pyramid;
+pub mod quadtree;
+pub mod quantized;
+pub mod random;
+pub mod raster;
+pub mod rect;
+pub mod registry;
+pub mod render;
+pub mod rows;
+#[cfg(feature = "serde")]
+mod serde_support;
+pub mod shapes;
+pub mod simulation;
+#[cfg(feature = "snapshot")]
+pub mod snapshot;
+pub mod sparse;
+pub mod split;
+pub mod stencil;
+pub mod streaming;
+pub mod symmetry;
+pub mod terrain_los;
+pub mod territory;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod timeslice;
+pub mod torus;
+pub mod trace;
+pub mod transform;
+pub mod update_queue;
+pub mod vertex_grid;
+pub mod view;
+pub mod viewport;
+pub mod watershed;
+pub mod wfc;
 
+pub use arc_grid::{ArcGrid, GridInterner};
+pub use arena::{ArenaGrid, GridArena};
+pub use cell_grid::CellGrid;
+pub use chunked::ChunkedGrid;
+pub use codec::CellCodec;
+#[cfg(feature = "arrow")]
+pub use columns::ArrowCell;
+pub use columns::GridColumns;
+pub use components::ComponentSummary;
+pub use contour::{Polygon, simplify_polyline};
+pub use cursor::{EdgePolicy, GridCursor};
+pub use direction::Direction;
+pub use dirty::DirtyGrid;
+pub use edge_grid::EdgeGrid;
+pub use error::GridError;
+pub use flood::FloodIter;
+#[cfg(feature = "geotiff")]
+pub use geo::CrsModel;
+pub use geo::{GeoGrid, GeoTransform};
 pub use grid::Grid;
+pub use grid_like::{GridLike, GridLikeMut};
+pub use grid_ref::{GridRef, GridRefMut};
+pub use grid3::Grid3;
+pub use hex::{Hex, HexGrid};
+pub use layout::Layout;
+pub use lazy::Lazy;
+pub use line::LineIter;
+pub use mesh::{MeshFormat, MeshOptions, export_mesh};
+pub use navigable::{Navigable, navigable_cost};
+pub use occupancy::OccupancyGrid;
+pub use packed::{BitGrid, PackedCell, PackedGrid};
+pub use path_cache::PathCache;
+pub use points_csv::{read_points_csv, write_points_csv};
+pub use progress::{CancellationToken, ProgressSink};
+pub use pvs::PotentiallyVisibleSet;
+pub use quantized::{Quantization, QuantizedGrid};
+pub use random::RandomOrder;
+pub use raster::FillRule;
+pub use rect::Rect;
+pub use registry::TileRegistry;
+pub use render::{AsciiOverlay, Highlight};
+pub use rows::{RowReader, RowWriter};
+pub use simulation::Simulation;
+pub use sparse::{SparseGrid, SparseWindow};
+pub use stencil::{Boundary, Neighborhood};
+pub use streaming::{ChunkCoord, ChunkStreamer};
+pub use symmetry::Axis;
+pub use timeslice::IterState;
+pub use torus::TorusGrid;
+pub use transform::{Affine, Interpolation, Lerp};
+pub use update_queue::UpdateQueue;
+pub use vertex_grid::VertexGrid;
+pub use view::{GridView, GridViewMut, StridedView, StridedViewMut};
+pub use viewport::{GridTransform, WorldRect};
@@ -0,0 +1,236 @@
+use crate::Grid;
+
+/// An iterator over the cells on a straight line between two cells.
+///
+/// [`LineIter::new`] walks a Bresenham line: 8-connected, one cell per step
+/// along the major axis. [`LineIter::supercover`] yields every cell the
+/// segment between the two cell centres passes through, including both side
+/// cells where the segment crosses a corner exactly; every cell shares an
+/// edge with one of the two cells before it. Both include the endpoints and are symmetric in length,
+/// but not necessarily in the cells chosen, when `a` and `b` are swapped.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::LineIter;
+///
+/// let cells: Vec<_> = LineIter::new((0, 0), (4, 2)).collect();
+/// assert_eq!(cells, vec![(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)]);
+///
+/// let cover: Vec<_> = LineIter::supercover((0, 0), (2, 2)).collect();
+/// assert_eq!(cover, vec![(0, 0), (1, 0), (0, 1), (1, 1), (2, 1), (1, 2), (2, 2)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineIter {
+    x: i32,
+    y: i32,
+    sx: i32,
+    sy: i32,
+    nx: i32,
+    ny: i32,
+    // Bresenham error term, or steps taken along each axis for supercover.
+    err: i32,
+    ix: i32,
+    iy: i32,
+    supercover: bool,
+    pending: [Option<(u16, u16)>; 2],
+    done: bool,
+}
+
+impl LineIter {
+    /// Creates a Bresenham line from `a` to `b`.
+    pub fn new(a: (u16, u16), b: (u16, u16)) -> LineIter {
+        LineIter::start(a, b, false)
+    }
+
+    /// Creates a supercover line from `a` to `b`.
+    pub fn supercover(a: (u16, u16), b: (u16, u16)) -> LineIter {
+        LineIter::start(a, b, true)
+    }
+
+    fn start(a: (u16, u16), b: (u16, u16), supercover: bool) -> LineIter {
+        let dx = b.0 as i32 - a.0 as i32;
+        let dy = b.1 as i32 - a.1 as i32;
+        LineIter {
+            x: a.0 as i32,
+            y: a.1 as i32,
+            sx: dx.signum(),
+            sy: dy.signum(),
+            nx: dx.abs(),
+            ny: dy.abs(),
+            err: dx.abs() - dy.abs(),
+            ix: 0,
+            iy: 0,
+            supercover,
+            pending: [None, None],
+            done: false,
+        }
+    }
+
+    fn step_bresenham(&mut self) -> bool {
+        if self.ix == self.nx && self.iy == self.ny {
+            return false;
+        }
+        let e2 = 2 * self.err;
+        if e2 >= -self.ny {
+            self.err -= self.ny;
+            self.x += self.sx;
+            self.ix += 1;
+        }
+        if e2 <= self.nx {
+            self.err += self.nx;
+            self.y += self.sy;
+            self.iy += 1;
+        }
+        true
+    }
+
+    fn step_supercover(&mut self) -> bool {
+        if self.ix == self.nx && self.iy == self.ny {
+            return false;
+        }
+        // Compare where the segment next crosses a vertical and a horizontal cell edge.
+        let decision = (1 + 2 * self.ix) * self.ny - (1 + 2 * self.iy) * self.nx;
+        if decision == 0 {
+            self.pending = [
+                Some(((self.x + self.sx) as u16, self.y as u16)),
+                Some((self.x as u16, (self.y + self.sy) as u16)),
+            ];
+            self.x += self.sx;
+            self.y += self.sy;
+            self.ix += 1;
+            self.iy += 1;
+        } else if decision < 0 {
+            self.x += self.sx;
+            self.ix += 1;
+        } else {
+            self.y += self.sy;
+            self.iy += 1;
+        }
+        true
+    }
+}
+
+impl Iterator for LineIter {
+    type Item = (u16, u16);
+
+    fn next(&mut self) -> Option<(u16, u16)> {
+        for slot in 0..2 {
+            if let Some(cell) = self.pending[slot].take() {
+                return Some(cell);
+            }
+        }
+        if self.done {
+            return None;
+        }
+        let current = (self.x as u16, self.y as u16);
+        let advanced = if self.supercover {
+            self.step_supercover()
+        } else {
+            self.step_bresenham()
+        };
+        self.done = !advanced;
+        Some(current)
+    }
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Returns an iterator over the cells on a Bresenham line from `a` to `b`.
+    ///
+    /// Yields `(x, y, &value)` in order from `a`, skipping cells outside the
+    /// grid. Nothing is written; see [`LineIter`] for the coordinates alone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(8, 8);
+    /// let _ = grid.set(3, 3, 1);
+    ///
+    /// let first_hit = grid.iter_line((0, 0), (7, 7)).find(|&(_, _, &v)| v != 0);
+    /// assert_eq!(first_hit, Some((3, 3, &1)));
+    /// ```
+    pub fn iter_line(
+        &self,
+        a: (u16, u16),
+        b: (u16, u16),
+    ) -> impl Iterator<Item = (u16, u16, &T)> + '_ {
+        self.cells_on(LineIter::new(a, b))
+    }
+
+    /// Returns an iterator over every cell touched by the segment between the
+    /// centres of `a` and `b`.
+    ///
+    /// Behaves like [`Grid::iter_line`] but uses [`LineIter::supercover`], so
+    /// a thin diagonal wall cannot be slipped through.
+    pub fn iter_line_supercover(
+        &self,
+        a: (u16, u16),
+        b: (u16, u16),
+    ) -> impl Iterator<Item = (u16, u16, &T)> + '_ {
+        self.cells_on(LineIter::supercover(a, b))
+    }
+
+    /// Returns an iterator over the coordinates on a Bresenham line from
+    /// (x0, y0) to (x1, y1).
+    ///
+    /// Coordinates outside the grid are skipped, so the endpoints may lie
+    /// off the grid and the line is clipped to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let grid: Grid<u8> = Grid::new(3, 3);
+    /// let cells: Vec<_> = grid.line(0, 0, 5, 5).collect();
+    /// assert_eq!(cells, vec![(0, 0), (1, 1), (2, 2)]);
+    /// ```
+    pub fn line(
+        &self,
+        x0: u16,
+        y0: u16,
+        x1: u16,
+        y1: u16,
+    ) -> impl Iterator<Item = (u16, u16)> + '_ {
+        LineIter::new((x0, y0), (x1, y1)).filter(|&(x, y)| x < self.width && y < self.height)
+    }
+
+    /// Returns `true` if no cell strictly between `a` and `b` on a
+    /// Bresenham line is blocking.
+    ///
+    /// The endpoints never block, so a wall can be seen from an open cell.
+    /// Bresenham lines are not symmetric, so in rare cases `b` can be seen
+    /// from `a` but not the other way round; use
+    /// [`Grid::iter_line_supercover`] when that matters. Returns `false` if
+    /// either endpoint is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut walls: Grid<bool> = Grid::new(6, 3);
+    /// let _ = walls.set(3, 1, true);
+    ///
+    /// assert!(!walls.has_line_of_sight((0, 1), (5, 1), |&wall| wall));
+    /// assert!(walls.has_line_of_sight((0, 0), (5, 0), |&wall| wall));
+    /// assert!(walls.has_line_of_sight((0, 1), (3, 1), |&wall| wall));
+    /// ```
+    pub fn has_line_of_sight<B>(&self, a: (u16, u16), b: (u16, u16), blocking: B) -> bool
+    where
+        B: Fn(&T) -> bool,
+    {
+        if self.get(a.0, a.1).is_none() || self.get(b.0, b.1).is_none() {
+            return false;
+        }
+        self.iter_line(a, b)
+            .filter(|&(x, y, _)| (x, y) != a && (x, y) != b)
+            .all(|(_, _, value)| !blocking(value))
+    }
+
+    fn cells_on(&self, line: LineIter) -> impl Iterator<Item = (u16, u16, &T)> + '_ {
+        line.filter_map(move |(x, y)| self.get(x, y).map(|value| (x, y, value)))
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{BitGrid, Grid, GridError};
+
+impl<T: Default + Clone> Grid<T> {
+    /// Calls `f` with a mutable reference to every cell the mask allows.
+    ///
+    /// With `Some(mask)` only cells whose mask bit is set are visited; with
+    /// `None` every cell is. Generators write through this so passes can be
+    /// layered, e.g. carving caves only where a mountain mask is set, with
+    /// no filtering afterwards.
+    ///
+    /// Returns [`GridError::DimensionMismatch`] if the mask and the grid
+    /// differ in size; nothing is written in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{BitGrid, Grid};
+    ///
+    /// let mut map: Grid<u8> = Grid::new(4, 1);
+    /// let mut mountains = BitGrid::new(4, 1);
+    /// let _ = mountains.set(2, 0, true);
+    ///
+    /// map.update_masked(Some(&mountains), |_, _, cell| *cell = 9).unwrap();
+    /// assert_eq!(map.as_slice(), &[0, 0, 9, 0]);
+    ///
+    /// map.update_masked(None, |x, _, cell| *cell += x as u8).unwrap();
+    /// assert_eq!(map.as_slice(), &[0, 1, 11, 3]);
+    /// ```
+    pub fn update_masked<F>(&mut self, mask: Option<&BitGrid>, mut f: F) -> Result<(), GridError>
+    where
+        F: FnMut(u16, u16, &mut T),
+    {
+        if let Some(mask) = mask
+            && (mask.width(), mask.height()) != (self.width, self.height)
+        {
+            return Err(GridError::DimensionMismatch {
+                expected: (self.width, self.height),
+                found: (mask.width(), mask.height()),
+            });
+        }
+        for (i, cell) in self.tiles.iter_mut().enumerate() {
+            let (x, y) = (
+                (i % self.width as usize) as u16,
+                (i / self.width as usize) as u16,
+            );
+            if mask.is_none_or(|mask| mask.get(x, y) == Some(true)) {
+                f(x, y, cell);
+            }
+        }
+        Ok(())
+    }
+}
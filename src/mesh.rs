@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use crate::Grid;
+
+/// File format written by [`export_mesh`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MeshFormat {
+    /// Wavefront OBJ text.
+    #[default]
+    Obj,
+    /// Self-contained glTF 2.0 JSON with the geometry embedded as a base64 buffer.
+    Gltf,
+}
+
+/// Options for [`export_mesh`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MeshOptions {
+    /// The output format.
+    pub format: MeshFormat,
+    /// Merge flat areas into large quads using [`Grid::greedy_rects`].
+    ///
+    /// Simplified meshes contain T-junctions along the border of merged
+    /// areas. The surface has no gaps, but some tools may show shading seams.
+    pub simplify: bool,
+}
+
+/// Writes a heightmap as a triangulated mesh.
+///
+/// Every cell becomes a vertex at `(x * cell_size, height, y * cell_size)`
+/// (Y up), and every 2×2 block of cells becomes a quad of two triangles with
+/// counter-clockwise winding when viewed from above.
+///
+/// Returns an `InvalidInput` error if the heightmap is smaller than 2×2.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{Grid, MeshOptions, export_mesh};
+///
+/// let heights: Grid<f32> = Grid::with_value(3, 3, 1.0);
+/// let mut obj = Vec::new();
+/// export_mesh(&heights, 2.0, &MeshOptions::default(), &mut obj).unwrap();
+///
+/// let text = String::from_utf8(obj).unwrap();
+/// assert_eq!(text.lines().filter(|l| l.starts_with("v ")).count(), 9);
+/// assert_eq!(text.lines().filter(|l| l.starts_with("f ")).count(), 8);
+/// ```
+pub fn export_mesh<W: Write>(
+    heights: &Grid<f32>,
+    cell_size: f32,
+    options: &MeshOptions,
+    mut writer: W,
+) -> io::Result<()> {
+    if heights.width() < 2 || heights.height() < 2 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "heightmap must be at least 2x2 to form a mesh (got {}x{})",
+                heights.width(),
+                heights.height()
+            ),
+        ));
+    }
+
+    let mesh = if options.simplify {
+        build_simplified(heights, cell_size)
+    } else {
+        build_full(heights, cell_size)
+    };
+    match options.format {
+        MeshFormat::Obj => write_obj(&mesh, &mut writer),
+        MeshFormat::Gltf => write_gltf(&mesh, &mut writer),
+    }
+}
+
+struct Mesh {
+    positions: Vec<[f32; 3]>,
+    indices: Vec<u32>,
+}
+
+/// Deduplicates grid corner vertices while triangles are being emitted.
+struct MeshBuilder<'a> {
+    heights: &'a Grid<f32>,
+    cell_size: f32,
+    vertex_ids: HashMap<(u16, u16), u32>,
+    mesh: Mesh,
+}
+
+impl MeshBuilder<'_> {
+    fn vertex(&mut self, x: u16, y: u16) -> u32 {
+        if let Some(&id) = self.vertex_ids.get(&(x, y)) {
+            return id;
+        }
+        let id = self.mesh.positions.len() as u32;
+        let height = self.heights.get(x, y).copied().unwrap_or_default();
+        self.mesh
+            .positions
+            .push([x as f32 * self.cell_size, height, y as f32 * self.cell_size]);
+        self.vertex_ids.insert((x, y), id);
+        id
+    }
+
+    /// Emits the quad spanning corners (x0, y0) to (x1, y1).
+    fn quad(&mut self, x0: u16, y0: u16, x1: u16, y1: u16) {
+        let a = self.vertex(x0, y0);
+        let b = self.vertex(x0, y1);
+        let c = self.vertex(x1, y0);
+        let d = self.vertex(x1, y1);
+        self.mesh.indices.extend_from_slice(&[a, b, c, c, b, d]);
+    }
+}
+
+fn build_full(heights: &Grid<f32>, cell_size: f32) -> Mesh {
+    let width = heights.width();
+    let height = heights.height();
+    // Every vertex is used, so emit them in row-major order up front.
+    let mut positions = Vec::with_capacity(heights.area() as usize);
+    for (x, y, &h) in heights.iter() {
+        positions.push([x as f32 * cell_size, h, y as f32 * cell_size]);
+    }
+    let mut indices = Vec::with_capacity((width as usize - 1) * (height as usize - 1) * 6);
+    let id = |x: u16, y: u16| (y as u32) * (width as u32) + (x as u32);
+    for y in 0..height - 1 {
+        for x in 0..width - 1 {
+            let (a, b, c, d) = (id(x, y), id(x, y + 1), id(x + 1, y), id(x + 1, y + 1));
+            indices.extend_from_slice(&[a, b, c, c, b, d]);
+        }
+    }
+    Mesh { positions, indices }
+}
+
+fn build_simplified(heights: &Grid<f32>, cell_size: f32) -> Mesh {
+    // One entry per quad: the bit pattern of its height if all four corners agree.
+    let mut quads: Grid<Option<u32>> = Grid::new(heights.width() - 1, heights.height() - 1);
+    quads.map_inplace(|x, y, quad| {
+        let h = heights.get(x, y).copied().unwrap_or_default();
+        let flat = [(1, 0), (0, 1), (1, 1)]
+            .iter()
+            .all(|&(dx, dy)| heights.get(x + dx, y + dy) == Some(&h));
+        *quad = flat.then(|| h.to_bits());
+    });
+
+    let mut builder = MeshBuilder {
+        heights,
+        cell_size,
+        vertex_ids: HashMap::new(),
+        mesh: Mesh {
+            positions: Vec::new(),
+            indices: Vec::new(),
+        },
+    };
+    for (rect, flat) in quads.greedy_rects(|a, b| a == b) {
+        let (right, bottom) = (rect.right() as u16, rect.bottom() as u16);
+        if flat.is_some() {
+            builder.quad(rect.x, rect.y, right, bottom);
+        } else {
+            for (x, y) in rect.cells() {
+                builder.quad(x, y, x + 1, y + 1);
+            }
+        }
+    }
+    builder.mesh
+}
+
+fn write_obj<W: Write>(mesh: &Mesh, writer: &mut W) -> io::Result<()> {
+    writeln!(writer, "# gridsystem heightmap mesh")?;
+    for [x, y, z] in &mesh.positions {
+        writeln!(writer, "v {} {} {}", x, y, z)?;
+    }
+    for triangle in mesh.indices.chunks_exact(3) {
+        // OBJ indices are 1-based.
+        writeln!(
+            writer,
+            "f {} {} {}",
+            triangle[0] + 1,
+            triangle[1] + 1,
+            triangle[2] + 1
+        )?;
+    }
+    Ok(())
+}
+
+fn write_gltf<W: Write>(mesh: &Mesh, writer: &mut W) -> io::Result<()> {
+    let mut min = [f32::INFINITY; 3];
+    let mut max = [f32::NEG_INFINITY; 3];
+    let mut buffer = Vec::with_capacity(mesh.positions.len() * 12 + mesh.indices.len() * 4);
+    for position in &mesh.positions {
+        for axis in 0..3 {
+            min[axis] = min[axis].min(position[axis]);
+            max[axis] = max[axis].max(position[axis]);
+            buffer.extend_from_slice(&position[axis].to_le_bytes());
+        }
+    }
+    let positions_len = buffer.len();
+    for index in &mesh.indices {
+        buffer.extend_from_slice(&index.to_le_bytes());
+    }
+    let indices_len = buffer.len() - positions_len;
+
+    write!(
+        writer,
+        concat!(
+            "{{\"asset\":{{\"version\":\"2.0\",\"generator\":\"gridsystem\"}},",
+            "\"scene\":0,\"scenes\":[{{\"nodes\":[0]}}],\"nodes\":[{{\"mesh\":0}}],",
+            "\"meshes\":[{{\"primitives\":[{{\"attributes\":{{\"POSITION\":0}},\"indices\":1,\"mode\":4}}]}}],",
+            "\"accessors\":[",
+            "{{\"bufferView\":0,\"componentType\":5126,\"count\":{},\"type\":\"VEC3\",",
+            "\"min\":[{},{},{}],\"max\":[{},{},{}]}},",
+            "{{\"bufferView\":1,\"componentType\":5125,\"count\":{},\"type\":\"SCALAR\"}}],",
+            "\"bufferViews\":[",
+            "{{\"buffer\":0,\"byteOffset\":0,\"byteLength\":{},\"target\":34962}},",
+            "{{\"buffer\":0,\"byteOffset\":{},\"byteLength\":{},\"target\":34963}}],",
+            "\"buffers\":[{{\"byteLength\":{},\"uri\":\"data:application/octet-stream;base64,{}\"}}]}}\n"
+        ),
+        mesh.positions.len(),
+        min[0],
+        min[1],
+        min[2],
+        max[0],
+        max[1],
+        max[2],
+        mesh.indices.len(),
+        positions_len,
+        positions_len,
+        indices_len,
+        buffer.len(),
+        base64(&buffer),
+    )
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
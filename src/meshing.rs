@@ -0,0 +1,67 @@
+use crate::{Grid, Rect};
+
+impl<T: Default + Clone> Grid<T> {
+    /// Merges runs of equal cells into maximal rectangles (greedy meshing).
+    ///
+    /// Cells are scanned in row-major order; each unvisited cell starts a
+    /// rectangle that is first grown to the right and then downwards for as
+    /// long as `eq_fn` reports the cells equal to the starting one. Every cell
+    /// ends up in exactly one rectangle, returned together with the value of
+    /// its top-left cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Grid, Rect};
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(4, 3);
+    /// for x in 0..4 {
+    ///     let _ = grid.set(x, 2, 1);
+    /// }
+    /// let rects = grid.greedy_rects(|a, b| a == b);
+    /// assert_eq!(rects, vec![(Rect::new(0, 0, 4, 2), 0), (Rect::new(0, 2, 4, 1), 1)]);
+    /// ```
+    pub fn greedy_rects<F>(&self, eq_fn: F) -> Vec<(Rect, T)>
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        let width = self.width as usize;
+        let height = self.height as usize;
+        let mut visited = vec![false; self.tiles.len()];
+        let mut rects = Vec::new();
+
+        for y in 0..height {
+            for x in 0..width {
+                let start = y * width + x;
+                if visited[start] {
+                    continue;
+                }
+                let value = &self.tiles[start];
+                let matches = |i: usize| !visited[i] && eq_fn(value, &self.tiles[i]);
+
+                let mut w = 1;
+                while x + w < width && matches(start + w) {
+                    w += 1;
+                }
+                let mut h = 1;
+                while y + h < height {
+                    let row = start + h * width;
+                    if !(row..row + w).all(matches) {
+                        break;
+                    }
+                    h += 1;
+                }
+
+                for row in 0..h {
+                    let first = start + row * width;
+                    visited[first..first + w].fill(true);
+                }
+                rects.push((
+                    Rect::new(x as u16, y as u16, w as u16, h as u16),
+                    value.clone(),
+                ));
+            }
+        }
+        rects
+    }
+}
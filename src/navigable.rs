@@ -0,0 +1,65 @@
+/// A tile type that knows its own movement cost.
+///
+/// Implement this for a map's cell type and pass [`navigable_cost`] as the
+/// `cost` argument of any search that takes `(x, y, &value)` costs, such
+/// as [`astar`](crate::pathfinding::astar) or
+/// [`Grid::expand_territories`](crate::Grid::expand_territories), instead
+/// of writing the same adapter closure at every call site.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{Grid, Navigable, navigable_cost};
+/// use gridsystem::pathfinding::astar;
+///
+/// #[derive(Clone, Default)]
+/// struct Tile {
+///     walkable: bool,
+///     movement_cost: u8,
+/// }
+///
+/// impl Navigable for Tile {
+///     fn cost(&self) -> Option<u32> {
+///         self.walkable.then_some(self.movement_cost as u32)
+///     }
+/// }
+///
+/// let map = Grid::with_value(4, 1, Tile { walkable: true, movement_cost: 2 });
+/// let path = astar(&map, (0, 0), (3, 0), navigable_cost).unwrap();
+/// assert_eq!(path.len(), 4);
+/// ```
+pub trait Navigable {
+    /// Returns the cost of entering this tile, or `None` if it is impassable.
+    fn cost(&self) -> Option<u32>;
+}
+
+impl<N: Navigable> Navigable for Option<N> {
+    fn cost(&self) -> Option<u32> {
+        self.as_ref().and_then(N::cost)
+    }
+}
+
+/// Reads the cost of a cell from its [`Navigable`] implementation.
+///
+/// Has the `(x, y, &value)` shape every cost callback in this crate uses,
+/// so it can be passed wherever a cost closure is expected.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{Navigable, navigable_cost};
+///
+/// struct Floor(u32);
+///
+/// impl Navigable for Floor {
+///     fn cost(&self) -> Option<u32> {
+///         Some(self.0)
+///     }
+/// }
+///
+/// assert_eq!(navigable_cost(0, 0, &Floor(3)), Some(3));
+/// assert_eq!(navigable_cost(0, 0, &None::<Floor>), None);
+/// ```
+pub fn navigable_cost<T: Navigable>(_x: u16, _y: u16, value: &T) -> Option<u32> {
+    value.cost()
+}
@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::{Grid, GridError, Rect};
+
+/// Tracks which entity occupies each cell, with rectangular footprints.
+///
+/// Every entity covers one [`Rect`] of cells (a unit covers 1×1, a
+/// building 2×2 or larger), and no cell is covered by two entities.
+/// [`claim`](OccupancyGrid::claim), [`relocate`](OccupancyGrid::relocate)
+/// and [`release`](OccupancyGrid::release) act on whole footprints: a
+/// claim that would overlap another entity or leave the grid changes
+/// nothing.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{OccupancyGrid, Rect};
+///
+/// let mut town: OccupancyGrid<u32> = OccupancyGrid::new(10, 10);
+/// town.claim(1, Rect::new(2, 2, 2, 2)).unwrap();
+///
+/// // A second building may not overlap the first.
+/// assert!(town.claim(2, Rect::new(3, 3, 2, 2)).is_err());
+/// assert_eq!(town.occupants_in(&Rect::new(3, 3, 2, 2)), vec![1]);
+/// town.claim(2, Rect::new(4, 2, 2, 2)).unwrap();
+///
+/// assert_eq!(town.occupant(5, 3), Some(2));
+/// assert_eq!(town.release(1), Some(Rect::new(2, 2, 2, 2)));
+/// assert!(town.is_free(&Rect::new(2, 2, 2, 2)));
+/// ```
+pub struct OccupancyGrid<E> {
+    cells: Grid<Option<E>>,
+    footprints: HashMap<E, Rect>,
+}
+
+impl<E: Copy + Eq + Hash + Debug> OccupancyGrid<E> {
+    /// Creates an empty `width × height` occupancy grid.
+    pub fn new(width: u16, height: u16) -> OccupancyGrid<E> {
+        OccupancyGrid {
+            cells: Grid::new(width, height),
+            footprints: HashMap::new(),
+        }
+    }
+
+    /// Returns the width of the grid.
+    pub fn width(&self) -> u16 {
+        self.cells.width
+    }
+
+    /// Returns the height of the grid.
+    pub fn height(&self) -> u16 {
+        self.cells.height
+    }
+
+    /// Returns the number of placed entities.
+    pub fn len(&self) -> usize {
+        self.footprints.len()
+    }
+
+    /// Returns `true` if no entities are placed.
+    pub fn is_empty(&self) -> bool {
+        self.footprints.is_empty()
+    }
+
+    /// Returns the entity covering (x, y), if any.
+    pub fn occupant(&self, x: u16, y: u16) -> Option<E> {
+        self.cells.get(x, y).copied().flatten()
+    }
+
+    /// Returns the footprint of `entity`, if it is placed.
+    pub fn footprint(&self, entity: E) -> Option<Rect> {
+        self.footprints.get(&entity).copied()
+    }
+
+    /// Returns an iterator over the placed entities and their footprints,
+    /// in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (E, Rect)> + '_ {
+        self.footprints
+            .iter()
+            .map(|(&entity, &rect)| (entity, rect))
+    }
+
+    /// Returns the distinct entities covering any cell of `area`, in
+    /// row-major order of the first cell each one covers.
+    ///
+    /// The part of `area` outside the grid is ignored.
+    pub fn occupants_in(&self, area: &Rect) -> Vec<E> {
+        let bounds = Rect::new(0, 0, self.width(), self.height());
+        let mut found = Vec::new();
+        if let Some(area) = area.intersection(&bounds) {
+            for (x, y) in area.cells() {
+                if let Some(entity) = self.occupant(x, y)
+                    && !found.contains(&entity)
+                {
+                    found.push(entity);
+                }
+            }
+        }
+        found
+    }
+
+    /// Returns `true` if `area` lies inside the grid and no entity covers
+    /// any of its cells.
+    pub fn is_free(&self, area: &Rect) -> bool {
+        self.fits(area) && area.cells().all(|(x, y)| self.occupant(x, y).is_none())
+    }
+
+    /// Places `entity` on every cell of `footprint`.
+    ///
+    /// Returns [`GridError::InvalidArgument`] if the entity is already
+    /// placed, the footprint is empty or does not fit inside the grid, or
+    /// any of its cells is occupied; the grid is unchanged in that case.
+    pub fn claim(&mut self, entity: E, footprint: Rect) -> Result<(), GridError> {
+        if let Some(current) = self.footprint(entity) {
+            return Err(GridError::InvalidArgument(format!(
+                "Entity {:?} is already placed at {:?}",
+                entity, current
+            )));
+        }
+        self.check_free(entity, &footprint)?;
+        self.fill(&footprint, Some(entity));
+        self.footprints.insert(entity, footprint);
+        Ok(())
+    }
+
+    /// Moves `entity` to `footprint` in one step, returning its old
+    /// footprint.
+    ///
+    /// The new footprint may overlap the entity's current one, so a unit can
+    /// step one cell sideways.
+    ///
+    /// Returns [`GridError::InvalidArgument`] if the entity is not placed,
+    /// or if the new footprint is empty, leaves the grid or overlaps
+    /// another entity; the grid is unchanged in that case.
+    pub fn relocate(&mut self, entity: E, footprint: Rect) -> Result<Rect, GridError> {
+        let Some(current) = self.footprint(entity) else {
+            return Err(GridError::InvalidArgument(format!(
+                "Entity {:?} is not placed",
+                entity
+            )));
+        };
+        self.check_free(entity, &footprint)?;
+        self.fill(&current, None);
+        self.fill(&footprint, Some(entity));
+        self.footprints.insert(entity, footprint);
+        Ok(current)
+    }
+
+    /// Removes `entity` from the grid, returning the footprint it covered.
+    pub fn release(&mut self, entity: E) -> Option<Rect> {
+        let footprint = self.footprints.remove(&entity)?;
+        self.fill(&footprint, None);
+        Some(footprint)
+    }
+
+    /// Removes every entity.
+    pub fn clear(&mut self) {
+        self.cells.tiles.fill(None);
+        self.footprints.clear();
+    }
+
+    /// Returns the occupant of every cell as a grid.
+    pub fn as_grid(&self) -> &Grid<Option<E>> {
+        &self.cells
+    }
+
+    fn fits(&self, area: &Rect) -> bool {
+        !area.is_empty()
+            && area.right() <= self.width() as u32
+            && area.bottom() <= self.height() as u32
+    }
+
+    /// Fails unless every cell of `area` is inside the grid and free or
+    /// already covered by `entity`.
+    fn check_free(&self, entity: E, area: &Rect) -> Result<(), GridError> {
+        if !self.fits(area) {
+            return Err(GridError::InvalidArgument(format!(
+                "Footprint {:?} does not fit in a {}x{} grid",
+                area,
+                self.width(),
+                self.height()
+            )));
+        }
+        if let Some(other) = self.occupants_in(area).into_iter().find(|&e| e != entity) {
+            return Err(GridError::InvalidArgument(format!(
+                "Footprint {:?} overlaps entity {:?}",
+                area, other
+            )));
+        }
+        Ok(())
+    }
+
+    fn fill(&mut self, area: &Rect, occupant: Option<E>) {
+        for (x, y) in area.cells() {
+            let index = self.cells.index(x, y);
+            self.cells.tiles[index] = occupant;
+        }
+    }
+}
@@ -0,0 +1,287 @@
+use std::marker::PhantomData;
+
+use crate::{Grid, GridError, GridLike};
+
+/// A cell type with a small, known number of states that can be bit-packed.
+///
+/// Each value maps to an integer below `2^BITS`. [`PackedGrid`] only passes
+/// values previously produced by [`PackedCell::to_bits`] back to
+/// [`PackedCell::from_bits`].
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{PackedCell, PackedGrid};
+///
+/// #[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// enum Terrain {
+///     #[default]
+///     Grass,
+///     Water,
+///     Rock,
+/// }
+///
+/// impl PackedCell for Terrain {
+///     const BITS: u32 = 2;
+///
+///     fn to_bits(&self) -> u32 {
+///         *self as u32
+///     }
+///
+///     fn from_bits(bits: u32) -> Self {
+///         match bits {
+///             1 => Terrain::Water,
+///             2 => Terrain::Rock,
+///             _ => Terrain::Grass,
+///         }
+///     }
+/// }
+///
+/// let mut map: PackedGrid<Terrain> = PackedGrid::new(4000, 4000);
+/// let _ = map.set(10, 20, Terrain::Rock);
+/// assert_eq!(map.get(10, 20), Some(Terrain::Rock));
+/// assert_eq!(map.memory_bytes(), 4_000_000);
+/// ```
+pub trait PackedCell: Sized {
+    /// The number of bits per value, between 1 and 32.
+    const BITS: u32;
+
+    /// Returns the value's bit pattern; only the low `BITS` bits are stored.
+    fn to_bits(&self) -> u32;
+
+    /// Rebuilds a value from a bit pattern returned by [`PackedCell::to_bits`].
+    fn from_bits(bits: u32) -> Self;
+}
+
+impl PackedCell for bool {
+    const BITS: u32 = 1;
+
+    fn to_bits(&self) -> u32 {
+        *self as u32
+    }
+
+    fn from_bits(bits: u32) -> bool {
+        bits != 0
+    }
+}
+
+/// A 2D grid storing each cell in [`PackedCell::BITS`] bits.
+///
+/// Cells are packed row-major into 64-bit words without straddling word
+/// boundaries, so a 4-bit type stores 16 cells per word. Values are returned
+/// by copy, since there is no addressable `T` in memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackedGrid<T: PackedCell> {
+    width: u16,
+    height: u16,
+    words: Vec<u64>,
+    marker: PhantomData<T>,
+}
+
+impl<T: PackedCell + Default> PackedGrid<T> {
+    const PER_WORD: usize = 64 / T::BITS as usize;
+    const MASK: u64 = (1u64 << T::BITS) - 1;
+
+    /// Creates a new packed grid filled with `T::default()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the grid
+    /// * `height` - The height of the grid
+    pub fn new(width: u16, height: u16) -> PackedGrid<T> {
+        Self::with_value(width, height, T::default())
+    }
+
+    /// Creates a new packed grid filled with the provided value.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the grid
+    /// * `height` - The height of the grid
+    /// * `value` - The value to fill the grid with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::PackedGrid;
+    ///
+    /// let grid: PackedGrid<bool> = PackedGrid::with_value(100, 3, true);
+    /// assert_eq!(grid.get(99, 2), Some(true));
+    /// assert_eq!(grid.memory_bytes(), 40);
+    /// ```
+    pub fn with_value(width: u16, height: u16, value: T) -> PackedGrid<T> {
+        assert!(
+            (1..=32).contains(&T::BITS),
+            "PackedCell::BITS must be between 1 and 32"
+        );
+        let bits = value.to_bits() as u64 & Self::MASK;
+        let word = (0..Self::PER_WORD).fold(0u64, |word, i| word | bits << (i as u32 * T::BITS));
+        let area = width as usize * height as usize;
+        let mut words = vec![word; area.div_ceil(Self::PER_WORD)];
+        // Keep the padding past the last cell zeroed.
+        let used = area % Self::PER_WORD;
+        if let Some(last) = words.last_mut()
+            && used > 0
+        {
+            *last &= (1u64 << (used as u32 * T::BITS)) - 1;
+        }
+        PackedGrid {
+            width,
+            height,
+            words,
+            marker: PhantomData,
+        }
+    }
+
+    /// Packs an existing grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Grid, PackedGrid};
+    ///
+    /// let mut grid: Grid<bool> = Grid::new(3, 3);
+    /// let _ = grid.set(1, 2, true);
+    ///
+    /// let packed = PackedGrid::from_grid(&grid);
+    /// assert_eq!(packed.get(1, 2), Some(true));
+    /// assert_eq!(packed.to_grid().as_slice(), grid.as_slice());
+    /// ```
+    pub fn from_grid(grid: &Grid<T>) -> PackedGrid<T>
+    where
+        T: Clone,
+    {
+        let mut packed = PackedGrid::new(grid.width(), grid.height());
+        for (i, value) in grid.as_slice().iter().enumerate() {
+            packed.write(i, value);
+        }
+        packed
+    }
+
+    /// Unpacks the grid into a regular [`Grid`].
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut grid = Grid::new(self.width, self.height);
+        for (i, cell) in grid.as_mut_slice().iter_mut().enumerate() {
+            *cell = self.read(i);
+        }
+        grid
+    }
+
+    /// Returns the cell at (x, y), or `None` if out of bounds.
+    pub fn get(&self, x: u16, y: u16) -> Option<T> {
+        if x < self.width && y < self.height {
+            Some(self.read(self.index(x, y)))
+        } else {
+            None
+        }
+    }
+
+    /// Sets the cell at (x, y).
+    ///
+    /// Returns `Ok(())` if successful, or [`GridError::OutOfBounds`] if the
+    /// coordinates are out of bounds.
+    pub fn set(&mut self, x: u16, y: u16, value: T) -> Result<(), GridError> {
+        if x < self.width && y < self.height {
+            let idx = self.index(x, y);
+            self.write(idx, &value);
+            Ok(())
+        } else {
+            Err(GridError::OutOfBounds {
+                x,
+                y,
+                width: self.width,
+                height: self.height,
+            })
+        }
+    }
+
+    /// Returns an iterator over all cells as `(x, y, value)` in row-major order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (u16, u16, T)> + '_ {
+        (0..self.area() as usize).map(move |i| {
+            let x = (i % self.width as usize) as u16;
+            let y = (i / self.width as usize) as u16;
+            (x, y, self.read(i))
+        })
+    }
+
+    /// Returns the width of the grid.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Returns the height of the grid.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Returns the total area (width × height) of the grid.
+    pub fn area(&self) -> u32 {
+        self.width as u32 * self.height as u32
+    }
+
+    /// Returns the number of bytes used by the packed cell storage.
+    pub fn memory_bytes(&self) -> usize {
+        self.words.len() * size_of::<u64>()
+    }
+
+    fn index(&self, x: u16, y: u16) -> usize {
+        y as usize * self.width as usize + x as usize
+    }
+
+    fn read(&self, i: usize) -> T {
+        let shift = (i % Self::PER_WORD) as u32 * T::BITS;
+        T::from_bits(((self.words[i / Self::PER_WORD] >> shift) & Self::MASK) as u32)
+    }
+
+    fn write(&mut self, i: usize, value: &T) {
+        let shift = (i % Self::PER_WORD) as u32 * T::BITS;
+        let word = &mut self.words[i / Self::PER_WORD];
+        *word = (*word & !(Self::MASK << shift)) | ((value.to_bits() as u64 & Self::MASK) << shift);
+    }
+}
+
+/// A grid of flags stored at one bit per cell.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::BitGrid;
+///
+/// let mut mask = BitGrid::new(100, 100);
+/// let _ = mask.set(3, 4, true);
+/// assert_eq!(mask.count_ones(), 1);
+/// assert_eq!(mask.iter_ones().collect::<Vec<_>>(), vec![(3, 4)]);
+/// ```
+pub type BitGrid = PackedGrid<bool>;
+
+impl PackedGrid<bool> {
+    /// Returns the number of set cells.
+    pub fn count_ones(&self) -> u32 {
+        self.words.iter().map(|word| word.count_ones()).sum()
+    }
+
+    /// Returns an iterator over the coordinates of all set cells in row-major order.
+    pub fn iter_ones(&self) -> impl Iterator<Item = (u16, u16)> + '_ {
+        self.iter()
+            .filter(|&(_, _, set)| set)
+            .map(|(x, y, _)| (x, y))
+    }
+}
+
+impl GridLike<bool> for PackedGrid<bool> {
+    fn width(&self) -> u16 {
+        self.width
+    }
+
+    fn height(&self) -> u16 {
+        self.height
+    }
+
+    fn get(&self, x: u16, y: u16) -> Option<&bool> {
+        // Bits have no address of their own, so hand out references to constants.
+        PackedGrid::get(self, x, y).map(|set| if set { &true } else { &false })
+    }
+}
@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+use crate::pathfinding::{Path, PathQuery, astar};
+use crate::{DirtyGrid, GridLike, Rect};
+
+/// Recently planned paths, kept until the cells they cross change.
+///
+/// Holds up to `capacity` results keyed by `(start, goal)` and evicts the
+/// least recently used one when full. [`PathCache::invalidate`] drops only
+/// the paths that cross a dirty cell, plus every cached failure since any
+/// change may have opened a route, so unchanged routes are not re-planned
+/// each tick. A change elsewhere that makes a cheaper route possible does
+/// not invalidate a cached path, which stays valid but may no longer be
+/// optimal.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{DirtyGrid, Grid, PathCache};
+///
+/// let mut walls: Grid<bool> = Grid::new(8, 8);
+/// let passable = |_: u16, _: u16, &wall: &bool| (!wall).then_some(1);
+/// let mut cache = PathCache::new(64);
+///
+/// let first = cache.find(&walls, (0, 0), (7, 0), passable).unwrap().to_vec();
+/// assert_eq!(cache.find(&walls, (0, 0), (7, 0), passable).unwrap(), first);
+/// assert_eq!((cache.hits(), cache.misses()), (1, 1));
+///
+/// // A wall appears on the route; only routes through it are dropped.
+/// let mut dirty = DirtyGrid::new(8, 8, 4).unwrap();
+/// let _ = walls.set(3, 0, true);
+/// dirty.mark(3, 0).unwrap();
+/// assert_eq!(cache.invalidate(&dirty), 1);
+/// assert!(!cache.find(&walls, (0, 0), (7, 0), passable).unwrap().contains(&(3, 0)));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PathCache {
+    capacity: usize,
+    entries: HashMap<PathQuery, Entry>,
+    clock: u64,
+    hits: u64,
+    misses: u64,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    path: Option<Path>,
+    bounds: Rect,
+    last_used: u64,
+}
+
+impl PathCache {
+    /// Creates an empty cache holding at most `capacity` results, and
+    /// always at least one.
+    pub fn new(capacity: usize) -> PathCache {
+        PathCache {
+            capacity: capacity.max(1),
+            ..PathCache::default()
+        }
+    }
+
+    /// Returns the cached path from `start` to `goal`, planning it with
+    /// [`astar`] on a miss.
+    pub fn find<T, G, F>(
+        &mut self,
+        grid: &G,
+        start: (u16, u16),
+        goal: (u16, u16),
+        cost: F,
+    ) -> Option<&[(u16, u16)]>
+    where
+        G: GridLike<T>,
+        F: Fn(u16, u16, &T) -> Option<u32>,
+    {
+        self.get_or_insert_with(start, goal, || astar(grid, start, goal, cost))
+    }
+
+    /// Returns the cached path from `start` to `goal`, calling `plan` on a miss.
+    ///
+    /// Use this to cache searches other than plain [`astar`], such as paths
+    /// using portals or movement rules. `plan` should return the path
+    /// including both endpoints, or `None` if there is none.
+    pub fn get_or_insert_with(
+        &mut self,
+        start: (u16, u16),
+        goal: (u16, u16),
+        plan: impl FnOnce() -> Option<Path>,
+    ) -> Option<&[(u16, u16)]> {
+        self.clock += 1;
+        let key = (start, goal);
+        if self.entries.contains_key(&key) {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+            if self.entries.len() >= self.capacity {
+                self.evict_oldest();
+            }
+            let path = plan();
+            let bounds = path.as_deref().map_or(Rect::default(), bounding_rect);
+            self.entries.insert(
+                key,
+                Entry {
+                    path,
+                    bounds,
+                    last_used: 0,
+                },
+            );
+        }
+        let entry = self.entries.get_mut(&key)?;
+        entry.last_used = self.clock;
+        entry.path.as_deref()
+    }
+
+    /// Drops every cached path that crosses a cell marked in `dirty`, and
+    /// every cached failure if anything is marked. Returns the number of
+    /// results dropped.
+    pub fn invalidate(&mut self, dirty: &DirtyGrid) -> usize {
+        if dirty.is_clean() {
+            return 0;
+        }
+        let before = self.entries.len();
+        self.entries.retain(|_, entry| match &entry.path {
+            None => false,
+            Some(path) => {
+                !dirty.any_dirty_in(&entry.bounds)
+                    || !path.iter().any(|&(x, y)| dirty.is_dirty(x, y))
+            }
+        });
+        before - self.entries.len()
+    }
+
+    /// Drops every cached result.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the number of cached results, failures included.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if nothing is cached.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns how many lookups were answered from the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Returns how many lookups had to plan a path.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    fn evict_oldest(&mut self) {
+        let oldest = self
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(&key, _)| key);
+        if let Some(key) = oldest {
+            self.entries.remove(&key);
+        }
+    }
+}
+
+/// Returns the smallest rectangle containing every cell of a non-empty path.
+fn bounding_rect(path: &[(u16, u16)]) -> Rect {
+    if path.is_empty() {
+        return Rect::default();
+    }
+    let (mut min_x, mut min_y, mut max_x, mut max_y) = (u16::MAX, u16::MAX, 0, 0);
+    for &(x, y) in path {
+        (min_x, min_y) = (min_x.min(x), min_y.min(y));
+        (max_x, max_y) = (max_x.max(x), max_y.max(y));
+    }
+    Rect::new(
+        min_x,
+        min_y,
+        (max_x - min_x).saturating_add(1),
+        (max_y - min_y).saturating_add(1),
+    )
+}
@@ -0,0 +1,1399 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{BitGrid, CancellationToken, Direction, Grid, GridError, GridLike, ProgressSink};
+
+/// A sequence of cells from start to goal, both included.
+pub type Path = Vec<(u16, u16)>;
+
+/// A `(start, goal)` pair for [`astar_batch`].
+pub type PathQuery = ((u16, u16), (u16, u16));
+
+/// Search internals captured by [`astar_debug`].
+pub struct SearchDebug {
+    /// Cells that were expanded (popped from the open set).
+    pub visited: BitGrid,
+    /// The best known cost from the start for every discovered cell.
+    pub g_scores: Grid<Option<u32>>,
+    /// Expanded cells in the order the search processed them.
+    pub expansion_order: Vec<(u16, u16)>,
+}
+
+/// Finds the cheapest 4-connected path from `start` to `goal` with A*.
+///
+/// `cost` returns the cost of entering a cell, or `None` if the cell is
+/// impassable; the start cell's own cost is never charged. The Manhattan
+/// heuristic ([`GridLike::distance`], which wraps on a
+/// [`TorusGrid`](crate::TorusGrid)) assumes every step costs at least 1,
+/// so cells costing 0 can yield paths that are valid but not optimal.
+///
+/// Returns the path including both endpoints, or `None` if the goal cannot
+/// be reached or either endpoint is out of bounds.
+///
+/// # Arguments
+///
+/// * `grid` - The map to search
+/// * `start` - The starting cell
+/// * `goal` - The cell to reach
+/// * `cost` - Called with `(x, y, &value)` for each cell the search enters
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Grid;
+/// use gridsystem::pathfinding::astar;
+///
+/// let mut walls: Grid<bool> = Grid::new(5, 3);
+/// for y in 0..2 {
+///     let _ = walls.set(2, y, true);
+/// }
+///
+/// let path = astar(&walls, (0, 0), (4, 0), |_, _, &wall| (!wall).then_some(1)).unwrap();
+/// assert_eq!(path.len(), 9);
+/// assert_eq!(path[4], (2, 2));
+/// ```
+pub fn astar<T, G, F>(grid: &G, start: (u16, u16), goal: (u16, u16), cost: F) -> Option<Path>
+where
+    G: GridLike<T>,
+    F: Fn(u16, u16, &T) -> Option<u32>,
+{
+    search(
+        grid,
+        start,
+        &[goal],
+        Moves::new(&Portals::new()),
+        SearchPolicy::AStar,
+        cost,
+        &mut (),
+    )
+    .map(|(_, path)| path)
+}
+
+/// Returns the total cost of walking `path` under `cost`.
+///
+/// Sums the entry cost of every cell after the first, the same way the
+/// searches in this module charge them, so for a path returned by [`astar`]
+/// this is the cost it minimised.
+///
+/// Returns `None` if any step after the first is impassable or out of
+/// bounds, or if consecutive cells are not 4-connected neighbours. An empty
+/// or single-cell path costs 0.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Grid;
+/// use gridsystem::pathfinding::{astar, path_cost};
+///
+/// let terrain = Grid::from_raw_parts(4, 1, vec![9, 2, 3, 1]).unwrap();
+/// let cost = |_, _, &c: &u32| Some(c);
+///
+/// let path = astar(&terrain, (0, 0), (3, 0), cost).unwrap();
+/// assert_eq!(path_cost(&terrain, &path, cost), Some(6));
+/// assert_eq!(path_cost(&terrain, &[(0, 0), (2, 0)], cost), None);
+/// ```
+pub fn path_cost<T, G, F>(grid: &G, path: &[(u16, u16)], cost: F) -> Option<u32>
+where
+    G: GridLike<T>,
+    F: Fn(u16, u16, &T) -> Option<u32>,
+{
+    path.windows(2).try_fold(0u32, |total, step| {
+        let ((ax, ay), (bx, by)) = (step[0], step[1]);
+        if grid.distance((ax, ay), (bx, by)) != 1 {
+            return None;
+        }
+        let step_cost = cost(bx, by, grid.get(bx, by)?)?;
+        Some(total.saturating_add(step_cost))
+    })
+}
+
+/// Finds the cheapest path from `start` to whichever of `goals` is nearest.
+///
+/// Runs a single A* search guided by the Manhattan distance to the closest
+/// goal and stops at the first goal it reaches, which is the cheapest one
+/// to get to. This replaces running [`astar`] once per candidate and
+/// keeping the best result. Goals outside the grid are ignored; `cost`
+/// follows the same rules as in [`astar`].
+///
+/// Returns the index of the chosen goal in `goals` together with the path,
+/// or `None` if no goal can be reached. If a goal is listed more than once
+/// its first index is reported.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Grid;
+/// use gridsystem::pathfinding::astar_to_any;
+///
+/// let mut walls: Grid<bool> = Grid::new(10, 3);
+/// for y in 0..2 {
+///     let _ = walls.set(5, y, true);
+/// }
+///
+/// // The exit at (7, 0) is closer as the crow flies, but behind the wall.
+/// let exits = [(7, 0), (0, 2)];
+/// let (chosen, path) = astar_to_any(&walls, (3, 0), &exits, |_, _, &wall| (!wall).then_some(1)).unwrap();
+/// assert_eq!(chosen, 1);
+/// assert_eq!(path.len(), 6);
+/// ```
+pub fn astar_to_any<T, G, F>(
+    grid: &G,
+    start: (u16, u16),
+    goals: &[(u16, u16)],
+    cost: F,
+) -> Option<(usize, Path)>
+where
+    G: GridLike<T>,
+    F: Fn(u16, u16, &T) -> Option<u32>,
+{
+    search(
+        grid,
+        start,
+        goals,
+        Moves::new(&Portals::new()),
+        SearchPolicy::AStar,
+        cost,
+        &mut (),
+    )
+}
+
+/// How a search trades path quality for speed.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::pathfinding::SearchPolicy;
+///
+/// assert_eq!(SearchPolicy::default(), SearchPolicy::AStar);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SearchPolicy {
+    /// Plain A*: always finds the cheapest path.
+    #[default]
+    AStar,
+    /// Weighted A*: inflates the heuristic by the given factor (at least 1).
+    ///
+    /// Paths cost at most that factor times the optimum, while far fewer
+    /// cells are usually expanded on large open maps.
+    Weighted(f32),
+    /// Greedy best-first: always expands the cell closest to the goal.
+    ///
+    /// The fastest option on open maps, with no bound on path cost.
+    Greedy,
+}
+
+impl SearchPolicy {
+    /// Returns the open-set priority for a cell with cost `g` so far and heuristic `h`.
+    fn priority(self, g: u32, h: u32) -> u32 {
+        match self {
+            SearchPolicy::AStar => g.saturating_add(h),
+            SearchPolicy::Weighted(weight) => {
+                let inflated = (h as f64 * weight.max(1.0) as f64).ceil();
+                g.saturating_add(inflated.min(u32::MAX as f64) as u32)
+            }
+            SearchPolicy::Greedy => h,
+        }
+    }
+}
+
+/// Finds a path from `start` to `goal` using the given [`SearchPolicy`].
+///
+/// With [`SearchPolicy::AStar`] this is [`astar`]; the other policies
+/// expand fewer cells in exchange for possibly longer paths. `cost`
+/// follows the same rules as in [`astar`].
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::pathfinding::{SearchPolicy, astar_debug, astar_with_policy};
+/// use gridsystem::Grid;
+///
+/// let open: Grid<u8> = Grid::new(64, 64);
+/// let step = |_: u16, _: u16, _: &u8| Some(1);
+///
+/// let greedy = astar_with_policy(&open, (0, 0), (63, 40), SearchPolicy::Greedy, step).unwrap();
+/// let weighted = astar_with_policy(&open, (0, 0), (63, 40), SearchPolicy::Weighted(2.0), step).unwrap();
+/// assert!(greedy.len() <= 2 * 104);
+/// assert_eq!(weighted.len(), 104);
+/// ```
+pub fn astar_with_policy<T, G, F>(
+    grid: &G,
+    start: (u16, u16),
+    goal: (u16, u16),
+    policy: SearchPolicy,
+    cost: F,
+) -> Option<Path>
+where
+    G: GridLike<T>,
+    F: Fn(u16, u16, &T) -> Option<u32>,
+{
+    search(
+        grid,
+        start,
+        &[goal],
+        Moves::new(&Portals::new()),
+        policy,
+        cost,
+        &mut (),
+    )
+    .map(|(_, path)| path)
+}
+
+/// A portal's exit cell and the cost of taking it.
+type PortalExit = ((u16, u16), u32);
+
+/// Extra one-way edges between arbitrary cells, such as stairs, doors
+/// between floors or teleporters.
+///
+/// Searches that accept portals may step from a portal's entry cell
+/// straight to its exit cell for the portal's cost, in addition to the
+/// usual moves to neighbouring cells. The exit cell's own entry cost is
+/// not charged on top, and the exit must still be passable.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::pathfinding::Portals;
+///
+/// let mut portals = Portals::new();
+/// portals.add((0, 0), (9, 9), 3);
+/// portals.add_two_way((1, 0), (5, 5), 2);
+///
+/// assert_eq!(portals.len(), 3);
+/// assert_eq!(portals.exits((5, 5)).collect::<Vec<_>>(), vec![((1, 0), 2)]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Portals {
+    edges: HashMap<(u16, u16), Vec<PortalExit>>,
+    count: usize,
+}
+
+impl Portals {
+    /// Creates an empty set of portals.
+    pub fn new() -> Portals {
+        Portals::default()
+    }
+
+    /// Adds a one-way portal from `from` to `to` costing `cost` to take.
+    pub fn add(&mut self, from: (u16, u16), to: (u16, u16), cost: u32) {
+        self.edges.entry(from).or_default().push((to, cost));
+        self.count += 1;
+    }
+
+    /// Adds portals in both directions between `a` and `b`.
+    pub fn add_two_way(&mut self, a: (u16, u16), b: (u16, u16), cost: u32) {
+        self.add(a, b, cost);
+        self.add(b, a, cost);
+    }
+
+    /// Removes every portal leaving `from`.
+    pub fn remove_from(&mut self, from: (u16, u16)) {
+        if let Some(removed) = self.edges.remove(&from) {
+            self.count -= removed.len();
+        }
+    }
+
+    /// Returns the exits and costs of the portals leaving `from`.
+    pub fn exits(&self, from: (u16, u16)) -> impl Iterator<Item = PortalExit> + '_ {
+        self.edges.get(&from).into_iter().flatten().copied()
+    }
+
+    /// Returns the number of one-way portals.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if no portals have been added.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns a lower bound on the cost of reaching one of `targets` from
+    /// `cell` when portals may be used: either walk straight there, or walk
+    /// to some portal entry and finish from the cheapest portal exit.
+    fn heuristic<T, G: GridLike<T>>(
+        &self,
+        grid: &G,
+        cell: (u16, u16),
+        targets: &[(u16, u16)],
+        cheapest_exit: u32,
+    ) -> u32 {
+        let direct = distance_to_nearest(grid, cell, targets);
+        if self.edges.is_empty() {
+            return direct;
+        }
+        let to_entry = self
+            .edges
+            .keys()
+            .map(|&entry| distance_to_nearest(grid, cell, &[entry]))
+            .min()
+            .unwrap_or(u32::MAX);
+        direct.min(to_entry.saturating_add(cheapest_exit))
+    }
+
+    /// Returns the cheapest portal cost plus the distance from its exit to the nearest target.
+    fn cheapest_exit<T, G: GridLike<T>>(&self, grid: &G, targets: &[(u16, u16)]) -> u32 {
+        self.edges
+            .values()
+            .flatten()
+            .map(|&(to, cost)| cost.saturating_add(distance_to_nearest(grid, to, targets)))
+            .min()
+            .unwrap_or(u32::MAX)
+    }
+}
+
+/// Finds the cheapest path from `start` to `goal`, also using `portals`.
+///
+/// Behaves like [`astar`], but from a portal's entry cell the search may
+/// also jump to its exit for the portal's cost. The heuristic accounts for
+/// portals, so the result stays optimal however far a portal jumps.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Grid;
+/// use gridsystem::pathfinding::{Portals, astar_with_portals};
+///
+/// // Two floors side by side, joined only by a staircase.
+/// let mut walls: Grid<bool> = Grid::new(9, 3);
+/// for y in 0..3 {
+///     let _ = walls.set(4, y, true);
+/// }
+/// let mut stairs = Portals::new();
+/// stairs.add_two_way((3, 0), (5, 2), 4);
+///
+/// let passable = |_: u16, _: u16, &wall: &bool| (!wall).then_some(1);
+/// let path = astar_with_portals(&walls, (0, 0), (8, 2), &stairs, passable).unwrap();
+/// assert_eq!(path, vec![(0, 0), (1, 0), (2, 0), (3, 0), (5, 2), (6, 2), (7, 2), (8, 2)]);
+/// ```
+pub fn astar_with_portals<T, G, F>(
+    grid: &G,
+    start: (u16, u16),
+    goal: (u16, u16),
+    portals: &Portals,
+    cost: F,
+) -> Option<Path>
+where
+    G: GridLike<T>,
+    F: Fn(u16, u16, &T) -> Option<u32>,
+{
+    search(
+        grid,
+        start,
+        &[goal],
+        Moves::new(portals),
+        SearchPolicy::AStar,
+        cost,
+        &mut (),
+    )
+    .map(|(_, path)| path)
+}
+
+/// Returns the cheapest cost of reaching every cell within `budget` of `origin`.
+///
+/// Runs Dijkstra from `origin` and stops expanding once every remaining
+/// cell would cost more than `budget`, which is exactly a tactics game's
+/// movement range. `cost` follows the same rules as in [`astar`]; the
+/// origin costs 0.
+///
+/// Returns a grid the size of `grid` holding `Some(cost)` for reachable
+/// cells and `None` elsewhere. Every cell is `None` if `origin` is out of
+/// bounds.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Grid;
+/// use gridsystem::pathfinding::movement_range;
+///
+/// // Swamp (cost 3) on the right half of the map.
+/// let mut terrain: Grid<u32> = Grid::with_value(7, 1, 1);
+/// for x in 4..7 {
+///     let _ = terrain.set(x, 0, 3);
+/// }
+/// let range = movement_range(&terrain, (2, 0), 4, |_, _, &c| Some(c));
+/// assert_eq!(
+///     range.as_slice(),
+///     &[Some(2), Some(1), Some(0), Some(1), Some(4), None, None]
+/// );
+/// ```
+pub fn movement_range<T, G, F>(
+    grid: &G,
+    origin: (u16, u16),
+    budget: u32,
+    cost: F,
+) -> Grid<Option<u32>>
+where
+    G: GridLike<T>,
+    F: Fn(u16, u16, &T) -> Option<u32>,
+{
+    movement_range_with_portals(grid, origin, budget, &Portals::new(), cost)
+}
+
+/// Returns the movement range from `origin`, also using `portals`.
+///
+/// Behaves like [`movement_range`], but from a portal's entry cell a mover
+/// may also jump to its exit for the portal's cost.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Grid;
+/// use gridsystem::pathfinding::{Portals, movement_range_with_portals};
+///
+/// let open: Grid<u8> = Grid::new(10, 1);
+/// let mut teleporter = Portals::new();
+/// teleporter.add((1, 0), (8, 0), 1);
+///
+/// let range = movement_range_with_portals(&open, (0, 0), 3, &teleporter, |_, _, _| Some(1));
+/// assert_eq!(range.get(8, 0), Some(&Some(2)));
+/// assert_eq!(range.get(9, 0), Some(&Some(3)));
+/// assert_eq!(range.get(5, 0), Some(&None));
+/// ```
+pub fn movement_range_with_portals<T, G, F>(
+    grid: &G,
+    origin: (u16, u16),
+    budget: u32,
+    portals: &Portals,
+    cost: F,
+) -> Grid<Option<u32>>
+where
+    G: GridLike<T>,
+    F: Fn(u16, u16, &T) -> Option<u32>,
+{
+    reachable(grid, &[origin], budget, Moves::new(portals), cost)
+}
+
+/// Movement constraints that per-cell costs cannot express.
+///
+/// Tactics games need these for zones of control: a unit may walk into a
+/// cell next to an enemy but must stop there, and may not slip between two
+/// adjacent enemies even though both cells on either side are open. Both
+/// methods default to "no constraint", so implementors override only what
+/// they need. A [`BitGrid`] marks the cells that end movement and an
+/// [`EdgeGrid<bool>`](crate::EdgeGrid) the walls between cells.
+///
+/// The start of a search is never treated as ending movement, so a unit
+/// already inside a zone of control can still walk out of it.
+pub trait MoveRules {
+    /// Returns `true` if a mover entering (x, y) must stop there.
+    fn ends_movement(&self, _x: u16, _y: u16) -> bool {
+        false
+    }
+
+    /// Returns `true` if a mover may not step directly from `from` to `to`.
+    fn blocks(&self, _from: (u16, u16), _to: (u16, u16)) -> bool {
+        false
+    }
+}
+
+/// No constraints.
+impl MoveRules for () {}
+
+impl MoveRules for BitGrid {
+    fn ends_movement(&self, x: u16, y: u16) -> bool {
+        self.get(x, y) == Some(true)
+    }
+}
+
+/// Finds the cheapest path from `start` to `goal` that obeys `rules`.
+///
+/// Behaves like [`astar`], but the path never passes through a cell that
+/// ends movement (it may still finish on one) and never takes a step that
+/// `rules` blocks.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Grid;
+/// use gridsystem::pathfinding::{MoveRules, astar_with_rules};
+///
+/// // A fence runs between rows 0 and 1, except at the far right.
+/// struct Fence;
+/// impl MoveRules for Fence {
+///     fn blocks(&self, from: (u16, u16), to: (u16, u16)) -> bool {
+///         from.0 == to.0 && from.0 < 4 && from.1.min(to.1) == 0 && from.1 != to.1
+///     }
+/// }
+///
+/// let open: Grid<u8> = Grid::new(5, 2);
+/// let path = astar_with_rules(&open, (0, 0), (0, 1), &Fence, |_, _, _| Some(1)).unwrap();
+/// assert_eq!(path.len(), 10);
+/// ```
+pub fn astar_with_rules<T, G, R, F>(
+    grid: &G,
+    start: (u16, u16),
+    goal: (u16, u16),
+    rules: &R,
+    cost: F,
+) -> Option<Path>
+where
+    G: GridLike<T>,
+    R: MoveRules,
+    F: Fn(u16, u16, &T) -> Option<u32>,
+{
+    search(
+        grid,
+        start,
+        &[goal],
+        Moves {
+            portals: &Portals::new(),
+            rules,
+        },
+        SearchPolicy::AStar,
+        cost,
+        &mut (),
+    )
+    .map(|(_, path)| path)
+}
+
+/// Returns the movement range from `origin` under `rules`.
+///
+/// Behaves like [`movement_range`], but cells that end movement are
+/// reachable without being passed through, and blocked steps are never
+/// taken.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{BitGrid, Grid};
+/// use gridsystem::pathfinding::movement_range_with_rules;
+///
+/// // An enemy at (3, 1) controls the cells next to it.
+/// let open: Grid<u8> = Grid::new(7, 3);
+/// let mut threatened = BitGrid::new(7, 3);
+/// for (x, y) in [(2, 1), (4, 1), (3, 0), (3, 2)] {
+///     let _ = threatened.set(x, y, true);
+/// }
+/// let passable = |x: u16, y: u16, _: &u8| ((x, y) != (3, 1)).then_some(1);
+///
+/// let range = movement_range_with_rules(&open, (0, 1), 6, &threatened, passable);
+/// assert_eq!(range.get(2, 1), Some(&Some(2)));
+/// assert_eq!(range.get(3, 0), Some(&Some(4)));
+/// // Every way past the enemy leads through its zone of control.
+/// assert_eq!(range.get(4, 0), Some(&None));
+/// ```
+pub fn movement_range_with_rules<T, G, R, F>(
+    grid: &G,
+    origin: (u16, u16),
+    budget: u32,
+    rules: &R,
+    cost: F,
+) -> Grid<Option<u32>>
+where
+    G: GridLike<T>,
+    R: MoveRules,
+    F: Fn(u16, u16, &T) -> Option<u32>,
+{
+    reachable(
+        grid,
+        &[origin],
+        budget,
+        Moves {
+            portals: &Portals::new(),
+            rules,
+        },
+        cost,
+    )
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Returns the cheapest cost of reaching every cell from its nearest source.
+    ///
+    /// Runs a single multi-source Dijkstra, so the cost is the same as the
+    /// cheapest of [`astar`] from each source, at the price of one search.
+    /// `cost` follows the same rules as in [`astar`]; sources cost 0 and
+    /// sources outside the grid are ignored.
+    ///
+    /// Returns `Some(cost)` for reachable cells and `None` elsewhere. An
+    /// agent anywhere on the map can walk to the nearest source by stepping
+    /// to its cheapest neighbour, which makes this the basis of a flow field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut walls: Grid<bool> = Grid::new(5, 1);
+    /// let _ = walls.set(3, 0, true);
+    ///
+    /// let field = walls.distance_field(&[(0, 0), (4, 0)], |_, _, &wall| (!wall).then_some(1));
+    /// assert_eq!(field.as_slice(), &[Some(0), Some(1), Some(2), None, Some(0)]);
+    /// ```
+    pub fn distance_field<F>(&self, sources: &[(u16, u16)], cost: F) -> Grid<Option<u32>>
+    where
+        F: Fn(u16, u16, &T) -> Option<u32>,
+    {
+        self.distance_field_with_portals(sources, &Portals::new(), cost)
+    }
+
+    /// Returns the distance field from `sources`, also using `portals`.
+    ///
+    /// Behaves like [`Grid::distance_field`], but from a portal's entry cell
+    /// the search may also jump to its exit for the portal's cost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    /// use gridsystem::pathfinding::Portals;
+    ///
+    /// let open: Grid<u8> = Grid::new(8, 1);
+    /// let mut shortcut = Portals::new();
+    /// shortcut.add_two_way((0, 0), (7, 0), 1);
+    ///
+    /// let field = open.distance_field_with_portals(&[(0, 0)], &shortcut, |_, _, _| Some(1));
+    /// assert_eq!(field.get(6, 0), Some(&Some(2)));
+    /// ```
+    pub fn distance_field_with_portals<F>(
+        &self,
+        sources: &[(u16, u16)],
+        portals: &Portals,
+        cost: F,
+    ) -> Grid<Option<u32>>
+    where
+        F: Fn(u16, u16, &T) -> Option<u32>,
+    {
+        reachable(self, sources, u32::MAX, Moves::new(portals), cost)
+    }
+}
+
+/// Multi-source Dijkstra from `origins` up to `budget`, shared by the
+/// movement range and distance field queries. Origins outside the grid are
+/// ignored.
+fn reachable<T, G, F>(
+    grid: &G,
+    origins: &[(u16, u16)],
+    budget: u32,
+    moves: Moves<'_>,
+    cost: F,
+) -> Grid<Option<u32>>
+where
+    G: GridLike<T>,
+    F: Fn(u16, u16, &T) -> Option<u32>,
+{
+    let mut range: Grid<Option<u32>> = Grid::new(grid.width(), grid.height());
+    let width = grid.width() as usize;
+    let index = |(x, y): (u16, u16)| y as usize * width + x as usize;
+    let coords = |i: usize| ((i % width) as u16, (i / width) as u16);
+    let starts: Vec<usize> = origins
+        .iter()
+        .filter(|&&(x, y)| grid.in_bounds(x, y))
+        .map(|&origin| index(origin))
+        .collect();
+    search_nodes(
+        range.tiles.len(),
+        &starts,
+        |_| false,
+        Guide {
+            policy: SearchPolicy::AStar,
+            heuristic: |_| 0,
+            budget,
+        },
+        |node, push| {
+            expand_cell(
+                grid,
+                &moves,
+                &cost,
+                origins,
+                coords(node),
+                &mut |next, step| push(index(next), step),
+            )
+        },
+        coords,
+        &mut range,
+    );
+    range
+}
+
+/// Finds the cheapest path when entry costs depend on heading and turns cost extra.
+///
+/// The search state is a cell plus the direction of the move that entered
+/// it, so `cost` can make a cell cheap, expensive or impassable depending
+/// on the heading (one-way conveyors, slopes). Every 90° change of heading
+/// adds `turn_penalty` on top, and reversing counts as two turns, which
+/// keeps vehicle routes, pipes and wires from zig-zagging. The first move
+/// is only penalised relative to `start_heading`, if one is given.
+///
+/// Returns the path including both endpoints, or `None` if the goal cannot
+/// be reached or either endpoint is out of bounds.
+///
+/// # Arguments
+///
+/// * `grid` - The map to search
+/// * `start` - The starting cell
+/// * `start_heading` - The direction the mover initially faces
+/// * `goal` - The cell to reach
+/// * `turn_penalty` - The extra cost of each 90° turn
+/// * `cost` - Called with `(x, y, &value, heading)` for each cell the search enters
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Grid;
+/// use gridsystem::pathfinding::astar_directional;
+///
+/// let open: Grid<u8> = Grid::new(6, 6);
+/// let path = astar_directional(&open, (0, 0), None, (5, 5), 10, |_, _, _, _| Some(1)).unwrap();
+///
+/// // With a turn penalty the route bends exactly once.
+/// let turns = path
+///     .windows(3)
+///     .filter(|w| (w[1].0 - w[0].0, w[1].1 - w[0].1) != (w[2].0 - w[1].0, w[2].1 - w[1].1))
+///     .count();
+/// assert_eq!(path.len(), 11);
+/// assert_eq!(turns, 1);
+/// ```
+pub fn astar_directional<T, G, F>(
+    grid: &G,
+    start: (u16, u16),
+    start_heading: Option<Direction>,
+    goal: (u16, u16),
+    turn_penalty: u32,
+    cost: F,
+) -> Option<Path>
+where
+    G: GridLike<T>,
+    F: Fn(u16, u16, &T, Direction) -> Option<u32>,
+{
+    if !grid.in_bounds(start.0, start.1) || !grid.in_bounds(goal.0, goal.1) {
+        return None;
+    }
+    // Node = cell * 5 + heading, where heading 4 means "not moved yet".
+    const STILL: usize = 4;
+    let width = grid.width() as usize;
+    let area = width * grid.height() as usize;
+    let index = |(x, y): (u16, u16)| y as usize * width + x as usize;
+    let coords = |node: usize| {
+        let cell = node / 5;
+        ((cell % width) as u16, (cell / width) as u16)
+    };
+    let heading_of = |direction: Option<Direction>| {
+        direction
+            .and_then(|d| Direction::CARDINAL.iter().position(|&c| c == d))
+            .unwrap_or(STILL)
+    };
+
+    let nodes = search_nodes(
+        area * 5,
+        &[index(start) * 5 + heading_of(start_heading)],
+        |node| coords(node) == goal,
+        Guide {
+            policy: SearchPolicy::AStar,
+            heuristic: |node| distance_to_nearest(grid, coords(node), &[goal]),
+            budget: u32::MAX,
+        },
+        |node, push| {
+            let heading = node % 5;
+            for (turn, direction) in Direction::CARDINAL.into_iter().enumerate() {
+                let (dx, dy) = direction.offset();
+                let (x, y) = coords(node);
+                let Some(next) = grid.offset(x, y, dx, dy) else {
+                    continue;
+                };
+                let Some(value) = grid.get(next.0, next.1) else {
+                    continue;
+                };
+                let Some(step_cost) = cost(next.0, next.1, value, direction) else {
+                    continue;
+                };
+                let quarter_turns = match heading {
+                    STILL => 0,
+                    h => (turn + 4 - h) % 4,
+                };
+                let penalty =
+                    turn_penalty.saturating_mul(quarter_turns.min(4 - quarter_turns) as u32);
+                push(index(next) * 5 + turn, step_cost.saturating_add(penalty));
+            }
+        },
+        coords,
+        &mut (),
+    )?;
+    Some(nodes.into_iter().map(coords).collect())
+}
+
+/// Finds the cheapest path by searching from both endpoints at once.
+///
+/// One A* search runs forwards from `start` and another backwards from
+/// `goal`, always advancing the one with the smaller frontier, and the
+/// path is joined where they meet. On long paths across large maps, where
+/// a single search fans out widely around obstacles near the goal, this
+/// usually expands fewer cells than [`astar`] and returns a path of the
+/// same cost; `cost` follows the same rules as in [`astar`].
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Grid;
+/// use gridsystem::pathfinding::{astar, astar_bidirectional};
+///
+/// let mut walls: Grid<bool> = Grid::new(200, 50);
+/// for y in 0..45 {
+///     let _ = walls.set(100, y, true);
+/// }
+/// let step = |_: u16, _: u16, &wall: &bool| (!wall).then_some(1);
+///
+/// let path = astar_bidirectional(&walls, (0, 0), (199, 0), step).unwrap();
+/// let reference = astar(&walls, (0, 0), (199, 0), step).unwrap();
+/// assert_eq!(path.len(), reference.len());
+/// assert_eq!((path[0], path[path.len() - 1]), ((0, 0), (199, 0)));
+/// ```
+pub fn astar_bidirectional<T, G, F>(
+    grid: &G,
+    start: (u16, u16),
+    goal: (u16, u16),
+    cost: F,
+) -> Option<Path>
+where
+    G: GridLike<T>,
+    F: Fn(u16, u16, &T) -> Option<u32>,
+{
+    if !grid.in_bounds(start.0, start.1) || !grid.in_bounds(goal.0, goal.1) {
+        return None;
+    }
+    if start == goal {
+        return Some(vec![start]);
+    }
+    let width = grid.width() as usize;
+    let area = width * grid.height() as usize;
+    let index = |(x, y): (u16, u16)| y as usize * width + x as usize;
+    let coords = |i: usize| ((i % width) as u16, (i / width) as u16);
+    let entry_cost = |i: usize| {
+        let (x, y) = coords(i);
+        grid.get(x, y).and_then(|value| cost(x, y, value))
+    };
+
+    let mut sides = [
+        Frontier::new(
+            area,
+            index(start),
+            distance_to_nearest(grid, start, &[goal]),
+        ),
+        Frontier::new(area, index(goal), distance_to_nearest(grid, goal, &[start])),
+    ];
+    let targets = [goal, start];
+    let mut best = u32::MAX;
+    let mut meeting = usize::MAX;
+
+    while let (Some(forward_f), Some(backward_f)) = (sides[0].min_f(), sides[1].min_f()) {
+        // Each frontier's smallest f bounds every path not yet found.
+        if forward_f.max(backward_f) >= best {
+            break;
+        }
+        let side = if sides[0].open.len() <= sides[1].open.len() {
+            0
+        } else {
+            1
+        };
+        let Some(current) = sides[side].pop() else {
+            break;
+        };
+        let (x, y) = coords(current);
+        for (next, _) in neighbours(grid, (x, y)) {
+            let next = index(next);
+            // Forwards the step costs entering `next`; backwards it is the
+            // forward step from `next` into `current`.
+            let step = if side == 0 {
+                entry_cost(next)
+            } else {
+                entry_cost(current)
+            };
+            let Some(step) = step else {
+                continue;
+            };
+            let g = sides[side].g[current].saturating_add(step);
+            let h = distance_to_nearest(grid, coords(next), &[targets[side]]);
+            if sides[side].relax(next, current, g, h) {
+                let other = sides[1 - side].g[next];
+                if other != u32::MAX && g.saturating_add(other) < best {
+                    best = g.saturating_add(other);
+                    meeting = next;
+                }
+            }
+        }
+    }
+    if meeting == usize::MAX {
+        return None;
+    }
+    let mut path = sides[0].trace(meeting);
+    path.reverse();
+    path.extend(sides[1].trace(meeting).into_iter().skip(1));
+    Some(path.into_iter().map(coords).collect())
+}
+
+/// One direction of [`astar_bidirectional`].
+struct Frontier {
+    g: Vec<u32>,
+    came_from: Vec<usize>,
+    closed: Vec<bool>,
+    open: BinaryHeap<Reverse<(u32, u32, usize)>>,
+}
+
+impl Frontier {
+    fn new(area: usize, origin: usize, h: u32) -> Frontier {
+        let mut frontier = Frontier {
+            g: vec![u32::MAX; area],
+            came_from: vec![usize::MAX; area],
+            closed: vec![false; area],
+            open: BinaryHeap::new(),
+        };
+        frontier.g[origin] = 0;
+        frontier.open.push(Reverse((h, h, origin)));
+        frontier
+    }
+
+    /// Drops stale entries and returns the smallest f in the open set.
+    fn min_f(&mut self) -> Option<u32> {
+        while let Some(&Reverse((f, _, node))) = self.open.peek() {
+            if !self.closed[node] {
+                return Some(f);
+            }
+            self.open.pop();
+        }
+        None
+    }
+
+    fn pop(&mut self) -> Option<usize> {
+        let Reverse((_, _, node)) = self.open.pop()?;
+        self.closed[node] = true;
+        Some(node)
+    }
+
+    /// Records a cheaper way to reach `node`, returning `true` if it was.
+    fn relax(&mut self, node: usize, from: usize, g: u32, h: u32) -> bool {
+        if self.closed[node] || g >= self.g[node] {
+            return false;
+        }
+        self.g[node] = g;
+        self.came_from[node] = from;
+        self.open.push(Reverse((g.saturating_add(h), h, node)));
+        true
+    }
+
+    /// Returns the nodes from `node` back to this frontier's origin.
+    fn trace(&self, node: usize) -> Vec<usize> {
+        let mut nodes = vec![node];
+        let mut step = node;
+        while self.came_from[step] != usize::MAX {
+            step = self.came_from[step];
+            nodes.push(step);
+        }
+        nodes
+    }
+}
+
+/// Runs [`astar`] and also returns the search internals.
+///
+/// The visited set, g-scores and expansion order make it possible to
+/// visualise how far a heuristic lets the search wander. Recording only
+/// happens in this variant; [`astar`] runs the same search without it.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Grid;
+/// use gridsystem::pathfinding::astar_debug;
+///
+/// let open: Grid<u8> = Grid::new(8, 8);
+/// let (path, debug) = astar_debug(&open, (0, 0), (3, 0), |_, _, _| Some(1));
+///
+/// assert_eq!(path.unwrap().len(), 4);
+/// assert_eq!(debug.expansion_order.first(), Some(&(0, 0)));
+/// assert_eq!(debug.g_scores.get(3, 0), Some(&Some(3)));
+/// assert!(debug.visited.get(2, 0).unwrap());
+/// ```
+pub fn astar_debug<T, G, F>(
+    grid: &G,
+    start: (u16, u16),
+    goal: (u16, u16),
+    cost: F,
+) -> (Option<Path>, SearchDebug)
+where
+    G: GridLike<T>,
+    F: Fn(u16, u16, &T) -> Option<u32>,
+{
+    let (width, height) = (grid.width(), grid.height());
+    let mut debug = SearchDebug {
+        visited: BitGrid::new(width, height),
+        g_scores: Grid::new(width, height),
+        expansion_order: Vec::new(),
+    };
+    let path = search(
+        grid,
+        start,
+        &[goal],
+        Moves::new(&Portals::new()),
+        SearchPolicy::AStar,
+        cost,
+        &mut debug,
+    )
+    .map(|(_, path)| path);
+    (path, debug)
+}
+
+/// Hooks the search calls at each step; the `()` implementation compiles away.
+trait Recorder {
+    fn discovered(&mut self, x: u16, y: u16, g: u32);
+    fn expanded(&mut self, x: u16, y: u16);
+}
+
+impl Recorder for () {
+    #[inline(always)]
+    fn discovered(&mut self, _: u16, _: u16, _: u32) {}
+
+    #[inline(always)]
+    fn expanded(&mut self, _: u16, _: u16) {}
+}
+
+/// Records the cheapest known cost of every discovered cell. Costs only
+/// ever improve, so the last value written for a cell is its best.
+impl Recorder for Grid<Option<u32>> {
+    fn discovered(&mut self, x: u16, y: u16, g: u32) {
+        let _ = self.set(x, y, Some(g));
+    }
+
+    fn expanded(&mut self, _: u16, _: u16) {}
+}
+
+impl Recorder for SearchDebug {
+    fn discovered(&mut self, x: u16, y: u16, g: u32) {
+        let _ = self.g_scores.set(x, y, Some(g));
+    }
+
+    fn expanded(&mut self, x: u16, y: u16) {
+        let _ = self.visited.set(x, y, true);
+        self.expansion_order.push((x, y));
+    }
+}
+
+/// Searches from `start` until any of `goals` is expanded, returning that
+/// goal's index and the path to it.
+fn search<T, G, F, R>(
+    grid: &G,
+    start: (u16, u16),
+    goals: &[(u16, u16)],
+    moves: Moves<'_>,
+    policy: SearchPolicy,
+    cost: F,
+    recorder: &mut R,
+) -> Option<(usize, Path)>
+where
+    G: GridLike<T>,
+    F: Fn(u16, u16, &T) -> Option<u32>,
+    R: Recorder,
+{
+    let targets: Vec<(u16, u16)> = goals
+        .iter()
+        .copied()
+        .filter(|&(x, y)| grid.in_bounds(x, y))
+        .collect();
+    if !grid.in_bounds(start.0, start.1) || targets.is_empty() {
+        return None;
+    }
+    let width = grid.width() as usize;
+    let area = width * grid.height() as usize;
+    let index = |(x, y): (u16, u16)| y as usize * width + x as usize;
+    let coords = |i: usize| ((i % width) as u16, (i / width) as u16);
+    let mut goal_slot = vec![usize::MAX; area];
+    for (slot, &goal) in goals.iter().enumerate().rev() {
+        if grid.in_bounds(goal.0, goal.1) {
+            goal_slot[index(goal)] = slot;
+        }
+    }
+
+    let portals = moves.portals;
+    let cheapest_exit = portals.cheapest_exit(grid, &targets);
+    let nodes = search_nodes(
+        area,
+        &[index(start)],
+        |node| goal_slot[node] != usize::MAX,
+        Guide {
+            policy,
+            heuristic: |node| portals.heuristic(grid, coords(node), &targets, cheapest_exit),
+            budget: u32::MAX,
+        },
+        |node, push| {
+            expand_cell(
+                grid,
+                &moves,
+                &cost,
+                &[start],
+                coords(node),
+                &mut |next, step| push(index(next), step),
+            )
+        },
+        coords,
+        recorder,
+    )?;
+    let goal = goal_slot[*nodes.last()?];
+    Some((goal, nodes.into_iter().map(coords).collect()))
+}
+
+/// The ways a search may move besides stepping to a neighbour, and the
+/// rules restricting those moves.
+struct Moves<'a> {
+    portals: &'a Portals,
+    rules: &'a dyn MoveRules,
+}
+
+impl<'a> Moves<'a> {
+    fn new(portals: &'a Portals) -> Moves<'a> {
+        Moves {
+            portals,
+            rules: &(),
+        }
+    }
+}
+
+/// Pushes every cell reachable in one move from `cell`: its passable
+/// 4-connected neighbours at their entry cost, and passable portal exits at
+/// the portal's cost. Nothing is pushed if `cell` ends movement, unless it
+/// is one of the search's `origins`, and steps the rules block are skipped.
+fn expand_cell<T, G, F>(
+    grid: &G,
+    moves: &Moves<'_>,
+    cost: &F,
+    origins: &[(u16, u16)],
+    cell: (u16, u16),
+    push: &mut dyn FnMut((u16, u16), u32),
+) where
+    G: GridLike<T>,
+    F: Fn(u16, u16, &T) -> Option<u32>,
+{
+    if !origins.contains(&cell) && moves.rules.ends_movement(cell.0, cell.1) {
+        return;
+    }
+    for (next, value) in neighbours(grid, cell) {
+        if let Some(step_cost) = cost(next.0, next.1, value)
+            && !moves.rules.blocks(cell, next)
+        {
+            push(next, step_cost);
+        }
+    }
+    for (exit, portal_cost) in moves.portals.exits(cell) {
+        if let Some(value) = grid.get(exit.0, exit.1)
+            && cost(exit.0, exit.1, value).is_some()
+            && !moves.rules.blocks(cell, exit)
+        {
+            push(exit, portal_cost);
+        }
+    }
+}
+
+/// Yields the in-bounds 4-connected neighbours of a cell with their values.
+fn neighbours<'a, T: 'a, G: GridLike<T>>(
+    grid: &'a G,
+    (x, y): (u16, u16),
+) -> impl Iterator<Item = ((u16, u16), &'a T)> {
+    Direction::CARDINAL
+        .into_iter()
+        .filter_map(move |direction| {
+            let (dx, dy) = direction.offset();
+            let next = grid.offset(x, y, dx, dy)?;
+            grid.get(next.0, next.1).map(|value| (next, value))
+        })
+}
+
+fn distance_to_nearest<T, G: GridLike<T>>(
+    grid: &G,
+    cell: (u16, u16),
+    targets: &[(u16, u16)],
+) -> u32 {
+    targets
+        .iter()
+        .map(|&goal| grid.distance(cell, goal))
+        .min()
+        .unwrap_or(0)
+}
+
+/// Orders the open set: a [`SearchPolicy`] applied to a heuristic over nodes.
+/// Nodes costing more than `budget` to reach are never opened.
+struct Guide<H> {
+    policy: SearchPolicy,
+    heuristic: H,
+    budget: u32,
+}
+
+impl<H: Fn(usize) -> u32> Guide<H> {
+    /// Returns the open-set entry for `node` reached at cost `g`; ties prefer
+    /// nodes closer to the goal.
+    fn key(&self, node: usize, g: u32) -> (u32, u32, usize) {
+        let h = (self.heuristic)(node);
+        (self.policy.priority(g, h), h, node)
+    }
+}
+
+/// A* over abstract nodes `0..count`, shared by every search in this module.
+///
+/// Nodes are expanded in the order given by `guide`.
+/// `expand` calls its `push` argument with each successor and the cost of
+/// moving there; `cell` maps a node to the grid cell reported to `recorder`.
+/// Returns the nodes from whichever of `starts` the path began at to the
+/// first goal expanded.
+fn search_nodes<R: Recorder>(
+    count: usize,
+    starts: &[usize],
+    is_goal: impl Fn(usize) -> bool,
+    guide: Guide<impl Fn(usize) -> u32>,
+    mut expand: impl FnMut(usize, &mut dyn FnMut(usize, u32)),
+    cell: impl Fn(usize) -> (u16, u16),
+    recorder: &mut R,
+) -> Option<Vec<usize>> {
+    let mut g_score = vec![u32::MAX; count];
+    let mut came_from = vec![usize::MAX; count];
+    let mut closed = vec![false; count];
+    let mut open = BinaryHeap::new();
+
+    for &start in starts {
+        g_score[start] = 0;
+        let (x, y) = cell(start);
+        recorder.discovered(x, y, 0);
+        open.push(Reverse(guide.key(start, 0)));
+    }
+
+    while let Some(Reverse((_, _, current))) = open.pop() {
+        if closed[current] {
+            continue;
+        }
+        closed[current] = true;
+        let (x, y) = cell(current);
+        recorder.expanded(x, y);
+        if is_goal(current) {
+            let mut path = vec![current];
+            let mut step = current;
+            while came_from[step] != usize::MAX {
+                step = came_from[step];
+                path.push(step);
+            }
+            path.reverse();
+            return Some(path);
+        }
+
+        expand(current, &mut |next, step_cost| {
+            if closed[next] {
+                return;
+            }
+            let g = g_score[current].saturating_add(step_cost);
+            if g < g_score[next] && g <= guide.budget {
+                g_score[next] = g;
+                came_from[next] = current;
+                let (x, y) = cell(next);
+                recorder.discovered(x, y, g);
+                open.push(Reverse(guide.key(next, g)));
+            }
+        });
+    }
+    None
+}
+
+/// Runs [`astar`] for many start/goal pairs in parallel.
+///
+/// Progress is reported to `progress` as queries finish, and `cancel` is
+/// polled before each query so a caller can abort a large batch.
+///
+/// Returns one result per query in input order, or
+/// [`GridError::Cancelled`] if the batch was cancelled.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{CancellationToken, Grid};
+/// use gridsystem::pathfinding::astar_batch;
+///
+/// let grid: Grid<u8> = Grid::new(16, 16);
+/// let queries = [((0, 0), (15, 15)), ((3, 3), (3, 9))];
+/// let paths = astar_batch(&grid, &queries, |_, _, _| Some(1), &(), &CancellationToken::new()).unwrap();
+/// assert_eq!(paths[0].as_ref().unwrap().len(), 31);
+/// assert_eq!(paths[1].as_ref().unwrap().len(), 7);
+/// ```
+pub fn astar_batch<T, G, F, P>(
+    grid: &G,
+    queries: &[PathQuery],
+    cost: F,
+    progress: &P,
+    cancel: &CancellationToken,
+) -> Result<Vec<Option<Path>>, GridError>
+where
+    G: GridLike<T> + Sync,
+    F: Fn(u16, u16, &T) -> Option<u32> + Sync,
+    P: ProgressSink,
+{
+    use rayon::prelude::*;
+    let done = AtomicUsize::new(0);
+    let total = queries.len();
+    queries
+        .par_iter()
+        .map(|&(start, goal)| {
+            cancel.check()?;
+            let path = search(
+                grid,
+                start,
+                &[goal],
+                Moves::new(&Portals::new()),
+                SearchPolicy::AStar,
+                &cost,
+                &mut (),
+            )
+            .map(|(_, path)| path);
+            progress.report(done.fetch_add(1, Ordering::Relaxed) + 1, total);
+            Ok(path)
+        })
+        .collect()
+}
+
+/// Counts how many of the cheapest paths for `requests` cross each cell.
+///
+/// Every query is solved with [`astar`] in parallel and each cell on a
+/// path, endpoints included, is counted once per path. The result is a
+/// "desire path" density map: high counts mark where roads or corridors
+/// would carry the most traffic. Unreachable queries contribute nothing.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Grid;
+/// use gridsystem::pathfinding::accumulate_paths;
+///
+/// let grid: Grid<u8> = Grid::new(5, 1);
+/// let trips = [((0, 0), (4, 0)), ((1, 0), (2, 0)), ((4, 0), (3, 0))];
+/// let traffic = accumulate_paths(&grid, &trips, |_, _, _| Some(1));
+/// assert_eq!(traffic.as_slice(), &[1, 2, 2, 2, 2]);
+/// ```
+pub fn accumulate_paths<T, G, F>(grid: &G, requests: &[PathQuery], cost: F) -> Grid<u32>
+where
+    G: GridLike<T> + Sync,
+    F: Fn(u16, u16, &T) -> Option<u32> + Sync,
+{
+    use rayon::prelude::*;
+    let (width, height) = (grid.width(), grid.height());
+    let area = width as usize * height as usize;
+    let counts = requests
+        .par_iter()
+        .fold(
+            || vec![0u32; area],
+            |mut counts, &(start, goal)| {
+                let path = search(
+                    grid,
+                    start,
+                    &[goal],
+                    Moves::new(&Portals::new()),
+                    SearchPolicy::AStar,
+                    &cost,
+                    &mut (),
+                );
+                for (x, y) in path.map(|(_, path)| path).unwrap_or_default() {
+                    counts[y as usize * width as usize + x as usize] += 1;
+                }
+                counts
+            },
+        )
+        .reduce(
+            || vec![0u32; area],
+            |mut a, b| {
+                a.iter_mut().zip(b).for_each(|(a, b)| *a += b);
+                a
+            },
+        );
+    Grid::from_raw_parts(width, height, counts).expect("counts match the grid area")
+}
@@ -0,0 +1,112 @@
+use crate::Grid;
+
+/// Summed-area table counting matching cells, one row and column larger
+/// than the grid, so any rectangle's count takes four lookups.
+pub(crate) struct FreeCounts {
+    width: usize,
+    sums: Vec<u32>,
+}
+
+impl FreeCounts {
+    pub(crate) fn new<T, F>(grid: &Grid<T>, free: F) -> FreeCounts
+    where
+        F: Fn(&T) -> bool,
+    {
+        let (width, height) = (grid.width as usize, grid.height as usize);
+        let stride = width + 1;
+        let mut sums = vec![0u32; stride * (height + 1)];
+        for y in 0..height {
+            let mut row = 0;
+            for x in 0..width {
+                row += free(&grid.tiles[y * width + x]) as u32;
+                sums[(y + 1) * stride + x + 1] = sums[y * stride + x + 1] + row;
+            }
+        }
+        FreeCounts { width, sums }
+    }
+
+    /// Number of matching cells in the `w × h` rectangle at (x, y), which
+    /// must lie inside the grid.
+    pub(crate) fn count(&self, x: usize, y: usize, w: usize, h: usize) -> u32 {
+        let at = |x: usize, y: usize| self.sums[y * (self.width + 1) + x];
+        at(x + w, y + h) + at(x, y) - at(x + w, y) - at(x, y + h)
+    }
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Returns the top-left corner of the first `w × h` rectangle, in
+    /// row-major order, whose cells are all free.
+    ///
+    /// Counts free cells once into a summed-area table, so each candidate
+    /// position is checked in constant time however large the footprint.
+    /// Returns `None` if no position fits or either size is 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `w` - Width of the footprint
+    /// * `h` - Height of the footprint
+    /// * `free` - Returns `true` for cells the footprint may cover
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let rows = ["##....", "#..#..", "......"];
+    /// let cells = rows.iter().flat_map(|r| r.chars().map(|c| c == '#')).collect();
+    /// let map = Grid::from_raw_parts(6, 3, cells).unwrap();
+    ///
+    /// assert_eq!(map.find_placement(2, 2, |&wall| !wall), Some((4, 0)));
+    /// assert_eq!(map.find_placement(3, 2, |&wall| !wall), None);
+    /// ```
+    pub fn find_placement<F>(&self, w: u16, h: u16, free: F) -> Option<(u16, u16)>
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.placements(w, h, free).next()
+    }
+
+    /// Returns the top-left corner of every `w × h` rectangle whose cells
+    /// are all free, in row-major order.
+    ///
+    /// Placements may overlap each other. Returns nothing if either size is
+    /// 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut map: Grid<bool> = Grid::new(4, 3);
+    /// let _ = map.set(1, 1, true);
+    /// let spots = map.find_all_placements(2, 2, |&blocked| !blocked);
+    /// assert_eq!(spots, vec![(2, 0), (2, 1)]);
+    /// ```
+    pub fn find_all_placements<F>(&self, w: u16, h: u16, free: F) -> Vec<(u16, u16)>
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.placements(w, h, free).collect()
+    }
+
+    fn placements<F>(&self, w: u16, h: u16, free: F) -> impl Iterator<Item = (u16, u16)>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let fits = w > 0 && h > 0 && w <= self.width && h <= self.height;
+        let counts = fits.then(|| FreeCounts::new(self, free));
+        let (columns, rows) = if fits {
+            (self.width - w + 1, self.height - h + 1)
+        } else {
+            (0, 0)
+        };
+        let area = w as u32 * h as u32;
+        (0..rows)
+            .flat_map(move |y| (0..columns).map(move |x| (x, y)))
+            .filter(move |&(x, y)| {
+                counts.as_ref().is_some_and(|c| {
+                    c.count(x as usize, y as usize, w as usize, h as usize) == area
+                })
+            })
+    }
+}
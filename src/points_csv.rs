@@ -0,0 +1,151 @@
+use std::fmt::Display;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use crate::Grid;
+
+const HEADER: &str = "x,y,value";
+
+/// Writes `x,y,value` lines for each point, preceded by a header line.
+///
+/// Everything after the second comma is the value, so values may contain
+/// commas but must not contain line breaks.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::write_points_csv;
+///
+/// let mut out = Vec::new();
+/// write_points_csv(&mut out, [(3, 4, &"oak"), (7, 1, &"pine, young")]).unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), "x,y,value\n3,4,oak\n7,1,pine, young\n");
+/// ```
+pub fn write_points_csv<'a, W, T, I>(writer: W, points: I) -> io::Result<()>
+where
+    W: Write,
+    T: Display + 'a,
+    I: IntoIterator<Item = (u16, u16, &'a T)>,
+{
+    write_points(writer, points)
+}
+
+/// Reads `x,y,value` lines written by [`write_points_csv`].
+///
+/// The header line is optional, blank lines are skipped, and surrounding
+/// whitespace is trimmed from the coordinates. Returns an `InvalidData`
+/// error naming the line if a coordinate or value does not parse.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::read_points_csv;
+///
+/// let points: Vec<(u16, u16, f32)> = read_points_csv("1,2,0.5\n\n4, 0,-3\n".as_bytes()).unwrap();
+/// assert_eq!(points, vec![(1, 2, 0.5), (4, 0, -3.0)]);
+/// ```
+pub fn read_points_csv<R, T>(reader: R) -> io::Result<Vec<(u16, u16, T)>>
+where
+    R: BufRead,
+    T: FromStr,
+{
+    read_points(reader)
+}
+
+/// [`write_points_csv`] for any coordinate type.
+pub(crate) fn write_points<'a, W, C, T, I>(mut writer: W, points: I) -> io::Result<()>
+where
+    W: Write,
+    C: Display,
+    T: Display + 'a,
+    I: IntoIterator<Item = (C, C, &'a T)>,
+{
+    writeln!(writer, "{HEADER}")?;
+    for (x, y, value) in points {
+        writeln!(writer, "{x},{y},{value}")?;
+    }
+    writer.flush()
+}
+
+/// [`read_points_csv`] for any coordinate type.
+pub(crate) fn read_points<R, C, T>(reader: R) -> io::Result<Vec<(C, C, T)>>
+where
+    R: BufRead,
+    C: FromStr,
+    T: FromStr,
+{
+    let mut points = Vec::new();
+    for (number, line) in reader.lines().enumerate() {
+        let line = line?;
+        let trimmed = line.trim_end_matches('\r');
+        if trimmed.trim().is_empty() || (number == 0 && trimmed.trim() == HEADER) {
+            continue;
+        }
+        let invalid = |what: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("line {}: {what} in {trimmed:?}", number + 1),
+            )
+        };
+        let mut fields = trimmed.splitn(3, ',');
+        let (Some(x), Some(y), Some(value)) = (fields.next(), fields.next(), fields.next()) else {
+            return Err(invalid("expected x,y,value"));
+        };
+        let x = x.trim().parse().map_err(|_| invalid("invalid x"))?;
+        let y = y.trim().parse().map_err(|_| invalid("invalid y"))?;
+        let value = value.parse().map_err(|_| invalid("invalid value"))?;
+        points.push((x, y, value));
+    }
+    Ok(points)
+}
+
+impl<T: Default + Clone + PartialEq + Display> Grid<T> {
+    /// Writes every cell that differs from `T::default()` as an `x,y,value` line.
+    ///
+    /// Cells are written in row-major order; see [`write_points_csv`] for
+    /// the format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut grid: Grid<u32> = Grid::new(100, 100);
+    /// let _ = grid.set(10, 20, 7);
+    ///
+    /// let mut out = Vec::new();
+    /// grid.write_points_csv(&mut out).unwrap();
+    /// assert_eq!(String::from_utf8(out).unwrap(), "x,y,value\n10,20,7\n");
+    /// ```
+    pub fn write_points_csv<W: Write>(&self, writer: W) -> io::Result<()> {
+        let empty = T::default();
+        write_points_csv(writer, self.iter().filter(|(_, _, value)| **value != empty))
+    }
+}
+
+impl<T: Default + Clone + FromStr> Grid<T> {
+    /// Builds a `width × height` grid from `x,y,value` lines.
+    ///
+    /// Cells without a line keep `T::default()`; if a cell is listed more
+    /// than once the last line wins. Returns an `InvalidData` error if a line
+    /// does not parse or a point lies outside the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let csv = "x,y,value\n0,0,5\n2,1,9\n";
+    /// let grid: Grid<u8> = Grid::read_points_csv(3, 2, csv.as_bytes()).unwrap();
+    /// assert_eq!(grid.as_slice(), &[5, 0, 0, 0, 0, 9]);
+    ///
+    /// assert!(Grid::<u8>::read_points_csv(2, 2, csv.as_bytes()).is_err());
+    /// ```
+    pub fn read_points_csv<R: BufRead>(width: u16, height: u16, reader: R) -> io::Result<Grid<T>> {
+        let mut grid = Grid::new(width, height);
+        for (x, y, value) in read_points_csv(reader)? {
+            grid.set(x, y, value)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        }
+        Ok(grid)
+    }
+}
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::GridError;
+
+/// A shared flag that asks long-running operations to stop early.
+///
+/// Clones share the same flag, so a GUI thread can keep one clone and hand
+/// another to a worker. Operations poll the token between units of work and
+/// return an `Err` once it has been cancelled.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let worker = token.clone();
+/// assert!(worker.check().is_ok());
+///
+/// token.cancel();
+/// assert!(worker.is_cancelled());
+/// assert!(worker.check().is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Creates a token that has not been cancelled.
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    /// Requests cancellation; every clone observes it.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` once [`CancellationToken::cancel`] has been called.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Returns [`GridError::Cancelled`] if the token was cancelled.
+    pub fn check(&self) -> Result<(), GridError> {
+        if self.is_cancelled() {
+            Err(GridError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Receives progress updates from long-running operations.
+///
+/// `report(done, total)` is called as work completes, possibly from several
+/// threads at once. Any `Fn(usize, usize) + Sync` closure is a sink, and
+/// `()` discards updates.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::ProgressSink;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// let latest = AtomicUsize::new(0);
+/// let sink = |done: usize, _total: usize| {
+///     latest.fetch_max(done, Ordering::Relaxed);
+/// };
+/// sink.report(3, 10);
+/// assert_eq!(latest.load(Ordering::Relaxed), 3);
+/// ```
+pub trait ProgressSink: Sync {
+    /// Reports that `done` of `total` units of work have completed.
+    fn report(&self, done: usize, total: usize);
+}
+
+impl<F: Fn(usize, usize) + Sync> ProgressSink for F {
+    fn report(&self, done: usize, total: usize) {
+        self(done, total)
+    }
+}
+
+impl ProgressSink for () {
+    fn report(&self, _: usize, _: usize) {}
+}
@@ -0,0 +1,114 @@
+use crate::Grid;
+use crate::placement::FreeCounts;
+
+impl Grid<bool> {
+    /// Fills dead ends of a wall/floor map, where `true` is wall.
+    ///
+    /// Each pass turns every floor cell with at most one floor neighbour
+    /// (4-connected, cells beyond the edge count as wall) into wall, all at
+    /// once, so one pass shortens every dead-end corridor by a cell. Running
+    /// until nothing changes leaves only loops and isolated rooms' cores;
+    /// passes stop early once a pass fills nothing.
+    ///
+    /// Returns the number of cells filled.
+    ///
+    /// # Arguments
+    ///
+    /// * `iterations` - The maximum number of passes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// // A 3-cell spur hanging off a loop around the central pillar.
+    /// let rows = ["#####", "#...#", "#.#.#", "#...#", "##.##", "##.##", "##.##"];
+    /// let cells = rows.iter().flat_map(|r| r.chars().map(|c| c == '#')).collect();
+    /// let mut map = Grid::from_raw_parts(5, 7, cells).unwrap();
+    ///
+    /// assert_eq!(map.fill_dead_ends(1), 1);
+    /// assert_eq!(map.fill_dead_ends(10), 2);
+    /// assert_eq!(map.iter().filter(|&(_, _, &wall)| !wall).count(), 8);
+    /// ```
+    pub fn fill_dead_ends(&mut self, iterations: u32) -> usize {
+        let mut filled = 0;
+        for _ in 0..iterations {
+            let dead_ends: Vec<usize> = (0..self.tiles.len())
+                .filter(|&i| !self.tiles[i])
+                .filter(|&i| {
+                    let (x, y) = self.coords(i);
+                    let open = [(-1, 0), (1, 0), (0, -1), (0, 1)]
+                        .iter()
+                        .filter(|&&(dx, dy)| {
+                            let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+                            nx >= 0 && ny >= 0 && self.get(nx as u16, ny as u16) == Some(&false)
+                        })
+                        .count();
+                    open <= 1
+                })
+                .collect();
+            if dead_ends.is_empty() {
+                break;
+            }
+            for &i in &dead_ends {
+                self.tiles[i] = true;
+            }
+            filled += dead_ends.len();
+        }
+        filled
+    }
+
+    /// Walls up passages narrower than `min_width`, where `true` is wall.
+    ///
+    /// A floor cell survives only if some `min_width × min_width` square of
+    /// floor inside the grid covers it (a morphological opening), so rooms
+    /// and wide caves are kept exactly while thin corridors, one-cell
+    /// notches and stray floor specks are filled. A `min_width` of 0 or 1
+    /// changes nothing.
+    ///
+    /// Returns the number of cells filled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// // Two 2x2 rooms joined by a one-cell corridor.
+    /// let rows = ["#######", "#..#..#", "#.....#", "#######"];
+    /// let cells = rows.iter().flat_map(|r| r.chars().map(|c| c == '#')).collect();
+    /// let mut map = Grid::from_raw_parts(7, 4, cells).unwrap();
+    ///
+    /// assert_eq!(map.prune_corridors(2), 1);
+    /// assert_eq!(map.get(3, 2), Some(&true));
+    /// assert_eq!(map.prune_corridors(3), 8);
+    /// assert!(map.iter().all(|(_, _, &wall)| wall));
+    /// ```
+    pub fn prune_corridors(&mut self, min_width: u16) -> usize {
+        let (width, height) = (self.width as usize, self.height as usize);
+        let size = min_width as usize;
+        if size <= 1 {
+            return 0;
+        }
+        let floor = FreeCounts::new(self, |&wall| !wall);
+        let floor_in = |x: usize, y: usize| floor.count(x, y, size, size);
+
+        let mut covered = vec![false; self.tiles.len()];
+        for y in 0..(height + 1).saturating_sub(size) {
+            for x in 0..(width + 1).saturating_sub(size) {
+                if floor_in(x, y) as usize == size * size {
+                    for row in y..y + size {
+                        covered[row * width + x..row * width + x + size].fill(true);
+                    }
+                }
+            }
+        }
+        let mut filled = 0;
+        for (tile, &keep) in self.tiles.iter_mut().zip(&covered) {
+            if !*tile && !keep {
+                *tile = true;
+                filled += 1;
+            }
+        }
+        filled
+    }
+}
@@ -0,0 +1,194 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rayon::prelude::*;
+
+use crate::{Grid, GridError, GridLike, LineIter, Rect};
+
+/// Which zones of a map can possibly see which others.
+///
+/// Built once from an opacity grid and a zone map with
+/// [`PotentiallyVisibleSet::compute`]; at runtime, field-of-view work for a
+/// viewer can skip every zone that its own zone cannot see.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{Grid, PotentiallyVisibleSet};
+///
+/// // Three rooms in a row; the wall between rooms 2 and 3 has no door.
+/// // Wall cells belong to no zone.
+/// let mut opaque: Grid<bool> = Grid::new(11, 3);
+/// let mut zones: Grid<u32> = Grid::new(11, 3);
+/// zones.map_inplace(|x, _, z| *z = if x % 4 == 3 { 0 } else { 1 + x as u32 / 4 });
+/// for y in 0..3 {
+///     let _ = opaque.set(3, y, y != 1);
+///     let _ = opaque.set(7, y, true);
+/// }
+///
+/// let pvs = PotentiallyVisibleSet::compute(&opaque, &zones, 16).unwrap();
+/// assert!(pvs.can_see(1, 2));
+/// assert!(!pvs.can_see(1, 3));
+/// assert_eq!(pvs.visible_from(2).collect::<Vec<_>>(), vec![1, 2]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PotentiallyVisibleSet {
+    /// The distinct non-zero zone ids, ascending; a zone's position here is
+    /// its row and column in `bits`.
+    ids: Vec<u32>,
+    bits: Vec<u64>,
+}
+
+impl PotentiallyVisibleSet {
+    /// The most distinct zones [`compute`](PotentiallyVisibleSet::compute)
+    /// accepts, which caps the visibility table at 32 MiB.
+    pub const MAX_ZONES: usize = 16_384;
+
+    /// Precomputes zone-to-zone visibility.
+    ///
+    /// Zone 0 in `zones` marks cells that belong to no zone; they are
+    /// neither viewers nor targets. Other ids may be any `u32`; the table
+    /// only grows with the number of distinct ids. From the centre of every
+    /// transparent zoned cell, supercover rays are cast to each cell on the
+    /// border of the square reaching `max_range` cells out (clipped to the
+    /// grid). A ray marks the zone of every cell it touches and stops after
+    /// the first opaque one, so walls are seen but not seen through. The
+    /// relation is made symmetric, and a zone always sees itself if it has
+    /// a transparent cell.
+    ///
+    /// Returns [`GridError::DimensionMismatch`] if the grid sizes differ, or
+    /// [`GridError::InvalidArgument`] if there are more than
+    /// [`MAX_ZONES`](PotentiallyVisibleSet::MAX_ZONES) distinct zones.
+    ///
+    /// # Arguments
+    ///
+    /// * `opacity` - `true` for cells that block sight
+    /// * `zones` - The zone id of every cell
+    /// * `max_range` - How far viewers can see, in cells along either axis
+    pub fn compute<G>(
+        opacity: &G,
+        zones: &Grid<u32>,
+        max_range: u16,
+    ) -> Result<PotentiallyVisibleSet, GridError>
+    where
+        G: GridLike<bool> + Sync,
+    {
+        if (opacity.width(), opacity.height()) != (zones.width, zones.height) {
+            return Err(GridError::DimensionMismatch {
+                expected: (opacity.width(), opacity.height()),
+                found: (zones.width, zones.height),
+            });
+        }
+        let mut ids: Vec<u32> = zones.tiles.iter().copied().filter(|&z| z != 0).collect();
+        ids.par_sort_unstable();
+        ids.dedup();
+        if ids.len() > PotentiallyVisibleSet::MAX_ZONES {
+            return Err(GridError::InvalidArgument(format!(
+                "{} distinct zones exceed the limit of {}",
+                ids.len(),
+                PotentiallyVisibleSet::MAX_ZONES
+            )));
+        }
+        let count = ids.len();
+        // Dense zone per cell: the id's position in `ids`, or None for zone 0.
+        let dense: Vec<Option<usize>> = zones
+            .tiles
+            .par_iter()
+            .map(|&z| ids.binary_search(&z).ok())
+            .collect();
+        let opaque = |x: u16, y: u16| opacity.get(x, y) == Some(&true);
+
+        // One shared table; rows only ever set bits, so relaxed ORs suffice.
+        let table: Vec<AtomicU64> = (0..(count * count).div_ceil(64))
+            .map(|_| AtomicU64::new(0))
+            .collect();
+        let insert = |from: usize, to: usize| {
+            let bit = from * count + to;
+            table[bit / 64].fetch_or(1 << (bit % 64), Ordering::Relaxed);
+        };
+        (0..zones.height).into_par_iter().for_each(|y| {
+            for x in 0..zones.width {
+                let Some(from) = dense[zones.index(x, y)] else {
+                    continue;
+                };
+                if opaque(x, y) {
+                    continue;
+                }
+                for target in perimeter(zones.width, zones.height, x, y, max_range) {
+                    for (cx, cy) in LineIter::supercover((x, y), target) {
+                        if let Some(to) = dense[zones.index(cx, cy)] {
+                            insert(from, to);
+                        }
+                        if opaque(cx, cy) {
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+
+        let mut pvs = PotentiallyVisibleSet {
+            ids,
+            bits: table.into_iter().map(AtomicU64::into_inner).collect(),
+        };
+        for a in 0..count {
+            for b in a + 1..count {
+                if pvs.bit(a, b) || pvs.bit(b, a) {
+                    pvs.set_bit(a, b);
+                    pvs.set_bit(b, a);
+                }
+            }
+        }
+        Ok(pvs)
+    }
+
+    /// Returns `true` if a viewer in zone `from` might see a cell of zone `to`.
+    ///
+    /// Unknown zones and zone 0 are never visible.
+    pub fn can_see(&self, from: u32, to: u32) -> bool {
+        match (self.dense(from), self.dense(to)) {
+            (Some(from), Some(to)) => self.bit(from, to),
+            _ => false,
+        }
+    }
+
+    /// Returns the zones a viewer in zone `from` might see, in ascending order.
+    pub fn visible_from(&self, from: u32) -> impl Iterator<Item = u32> + '_ {
+        let from = self.dense(from);
+        self.ids
+            .iter()
+            .enumerate()
+            .filter(move |&(to, _)| from.is_some_and(|from| self.bit(from, to)))
+            .map(|(_, &id)| id)
+    }
+
+    /// Returns the number of distinct non-zero zones.
+    pub fn zone_count(&self) -> u32 {
+        self.ids.len() as u32
+    }
+
+    fn dense(&self, zone: u32) -> Option<usize> {
+        self.ids.binary_search(&zone).ok()
+    }
+
+    fn bit(&self, from: usize, to: usize) -> bool {
+        let bit = from * self.ids.len() + to;
+        self.bits[bit / 64] & (1 << (bit % 64)) != 0
+    }
+
+    fn set_bit(&mut self, from: usize, to: usize) {
+        let bit = from * self.ids.len() + to;
+        self.bits[bit / 64] |= 1 << (bit % 64);
+    }
+}
+
+/// Returns the cells on the border of the square of radius `r` around
+/// (x, y), clipped to a `width` × `height` grid.
+fn perimeter(width: u16, height: u16, x: u16, y: u16, r: u16) -> impl Iterator<Item = (u16, u16)> {
+    let x0 = x.saturating_sub(r);
+    let y0 = y.saturating_sub(r);
+    let x1 = (x as u32 + r as u32).min(width as u32 - 1) as u16;
+    let y1 = (y as u32 + r as u32).min(height as u32 - 1) as u16;
+    let rect = Rect::new(x0, y0, x1 - x0 + 1, y1 - y0 + 1);
+    rect.cells()
+        .filter(move |&(x, y)| x == x0 || y == y0 || x == x1 || y == y1)
+}
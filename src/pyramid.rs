@@ -0,0 +1,82 @@
+use crate::Grid;
+
+impl<T: Default + Clone> Grid<T> {
+    /// Halves the grid in both dimensions, combining each 2×2 block into one cell.
+    ///
+    /// The aggregator receives the cells of one block in row-major order.
+    /// Blocks on the right and bottom edges of odd-sized grids contain only
+    /// one or two cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut grid: Grid<u32> = Grid::new(3, 2);
+    /// grid.map_inplace(|x, _, v| *v = x as u32 + 1);
+    ///
+    /// let half = grid.downsample(|block| block.iter().copied().sum::<u32>());
+    /// assert_eq!((half.width(), half.height()), (2, 1));
+    /// assert_eq!(half.get(0, 0), Some(&6));
+    /// assert_eq!(half.get(1, 0), Some(&6));
+    /// ```
+    pub fn downsample<F>(&self, aggregator: F) -> Grid<T>
+    where
+        F: Fn(&[&T]) -> T,
+    {
+        let width = self.width.div_ceil(2);
+        let height = self.height.div_ceil(2);
+        let mut tiles = Vec::with_capacity((width as usize) * (height as usize));
+        let mut block: Vec<&T> = Vec::with_capacity(4);
+        for y in 0..height {
+            for x in 0..width {
+                block.clear();
+                for sy in y * 2..(y * 2 + 2).min(self.height) {
+                    for sx in x * 2..(x * 2 + 2).min(self.width) {
+                        block.push(&self.tiles[self.index(sx, sy)]);
+                    }
+                }
+                tiles.push(aggregator(&block));
+            }
+        }
+        Grid {
+            width,
+            height,
+            tiles,
+        }
+    }
+
+    /// Builds an image pyramid of successively halved copies.
+    ///
+    /// Returns up to `levels` grids, starting with the half-resolution level;
+    /// the original grid is not included. Building stops early once a level
+    /// of 1×1 cells has been produced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let grid: Grid<f32> = Grid::with_value(16, 8, 1.0);
+    /// let mean = |block: &[&f32]| block.iter().copied().sum::<f32>() / block.len() as f32;
+    /// let pyramid = grid.build_pyramid(10, mean);
+    ///
+    /// let sizes: Vec<_> = pyramid.iter().map(|g| (g.width(), g.height())).collect();
+    /// assert_eq!(sizes, vec![(8, 4), (4, 2), (2, 1), (1, 1)]);
+    /// ```
+    pub fn build_pyramid<F>(&self, levels: usize, aggregator: F) -> Vec<Grid<T>>
+    where
+        F: Fn(&[&T]) -> T,
+    {
+        let mut pyramid: Vec<Grid<T>> = Vec::with_capacity(levels);
+        while pyramid.len() < levels {
+            let previous = pyramid.last().unwrap_or(self);
+            if previous.width <= 1 && previous.height <= 1 {
+                break;
+            }
+            let next = previous.downsample(&aggregator);
+            pyramid.push(next);
+        }
+        pyramid
+    }
+}
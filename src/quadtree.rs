@@ -0,0 +1,226 @@
+use crate::{CellCodec, Grid, GridError, Rect};
+
+const MAGIC: &[u8; 4] = b"GQT1";
+const HEADER_LEN: usize = 8;
+const LEAF: u8 = 0;
+const SPLIT: u8 = 1;
+
+impl<T: Default + Clone + PartialEq + CellCodec> Grid<T> {
+    /// Encodes the grid as a pruned quadtree.
+    ///
+    /// The grid is covered by a power-of-two square that is split recursively
+    /// into quadrants; any quadrant whose cells are all equal is stored as a
+    /// single leaf. Quadrants lying entirely outside the grid are omitted.
+    /// Large uniform areas therefore cost a few bytes regardless of their size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(256, 256);
+    /// let _ = grid.set(10, 10, 1);
+    ///
+    /// let bytes = grid.to_quadtree_bytes();
+    /// assert!(bytes.len() < 100);
+    ///
+    /// let decoded: Grid<u8> = Grid::from_quadtree_bytes(&bytes).unwrap();
+    /// assert_eq!(decoded.get(10, 10), Some(&1));
+    /// assert_eq!(decoded.get(11, 10), Some(&0));
+    /// ```
+    pub fn to_quadtree_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(HEADER_LEN + T::SIZE + 1);
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&self.width.to_le_bytes());
+        out.extend_from_slice(&self.height.to_le_bytes());
+        if !self.is_empty() {
+            self.encode_node(0, 0, root_size(self.width, self.height), &mut out);
+        }
+        out
+    }
+
+    /// Decodes a grid written by [`Grid::to_quadtree_bytes`].
+    ///
+    /// Returns [`GridError::InvalidData`] if the data is malformed.
+    pub fn from_quadtree_bytes(bytes: &[u8]) -> Result<Grid<T>, GridError> {
+        let (width, height) = read_header(bytes)?;
+        Self::from_quadtree_region(bytes, Rect::new(0, 0, width, height))
+    }
+
+    /// Decodes only the cells inside `region` from quadtree data.
+    ///
+    /// Subtrees that do not touch the region are skipped without decoding
+    /// their values. The result has the size of `region` clipped to the
+    /// encoded grid.
+    ///
+    /// Returns [`GridError::InvalidData`] if the data is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Grid, Rect};
+    ///
+    /// let mut grid: Grid<u16> = Grid::new(64, 64);
+    /// grid.map_inplace(|x, y, v| *v = if x >= 32 && y >= 32 { 7 } else { 0 });
+    /// let bytes = grid.to_quadtree_bytes();
+    ///
+    /// let corner: Grid<u16> = Grid::from_quadtree_region(&bytes, Rect::new(30, 30, 4, 4)).unwrap();
+    /// assert_eq!(corner.get(1, 1), Some(&0));
+    /// assert_eq!(corner.get(2, 2), Some(&7));
+    /// ```
+    pub fn from_quadtree_region(bytes: &[u8], region: Rect) -> Result<Grid<T>, GridError> {
+        let (width, height) = read_header(bytes)?;
+        let region = region
+            .intersection(&Rect::new(0, 0, width, height))
+            .unwrap_or(Rect::new(0, 0, 0, 0));
+        let mut out = Grid::new(region.width, region.height);
+        if width > 0 && height > 0 {
+            let mut decoder = Decoder {
+                bytes,
+                pos: HEADER_LEN,
+                width,
+                height,
+                region,
+            };
+            decoder.node(0, 0, root_size(width, height), &mut out)?;
+        }
+        Ok(out)
+    }
+
+    fn encode_node(&self, x: u32, y: u32, size: u32, out: &mut Vec<u8>) {
+        let Some(cells) = clip(x, y, size, self.width, self.height) else {
+            return;
+        };
+        let first = &self.tiles[self.index(cells.x, cells.y)];
+        let uniform = (cells.y..cells.bottom() as u16).all(|row| {
+            let start = self.index(cells.x, row);
+            self.tiles[start..start + cells.width as usize]
+                .iter()
+                .all(|cell| cell == first)
+        });
+        if uniform {
+            out.push(LEAF);
+            first.encode(out);
+        } else {
+            out.push(SPLIT);
+            let half = size / 2;
+            for (dx, dy) in [(0, 0), (half, 0), (0, half), (half, half)] {
+                self.encode_node(x + dx, y + dy, half, out);
+            }
+        }
+    }
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    width: u16,
+    height: u16,
+    region: Rect,
+}
+
+impl Decoder<'_> {
+    fn node<T>(&mut self, x: u32, y: u32, size: u32, out: &mut Grid<T>) -> Result<(), GridError>
+    where
+        T: Default + Clone + CellCodec,
+    {
+        let Some(cells) = clip(x, y, size, self.width, self.height) else {
+            return Ok(());
+        };
+        let tag = self.take(1)?[0];
+        match tag {
+            LEAF => {
+                let target = cells.intersection(&self.region);
+                let encoded = self.take(T::SIZE)?;
+                let Some(target) = target else {
+                    return Ok(());
+                };
+                let value = T::decode(encoded).ok_or_else(|| {
+                    GridError::InvalidData(format!(
+                        "Invalid cell value at byte {}",
+                        self.pos - T::SIZE
+                    ))
+                })?;
+                for (cx, cy) in target.cells() {
+                    let (ox, oy) = (cx - self.region.x, cy - self.region.y);
+                    let index = out.index(ox, oy);
+                    out.tiles[index] = value.clone();
+                }
+                Ok(())
+            }
+            SPLIT if size > 1 => {
+                let half = size / 2;
+                for (dx, dy) in [(0, 0), (half, 0), (0, half), (half, half)] {
+                    let child = clip(x + dx, y + dy, half, self.width, self.height);
+                    if child.and_then(|c| c.intersection(&self.region)).is_some() {
+                        self.node(x + dx, y + dy, half, out)?;
+                    } else {
+                        self.skip::<T>(x + dx, y + dy, half)?;
+                    }
+                }
+                Ok(())
+            }
+            _ => Err(GridError::InvalidData(format!(
+                "Invalid quadtree node tag {} at byte {}",
+                tag,
+                self.pos - 1
+            ))),
+        }
+    }
+
+    /// Advances past a subtree without decoding any values.
+    fn skip<T: CellCodec>(&mut self, x: u32, y: u32, size: u32) -> Result<(), GridError> {
+        if clip(x, y, size, self.width, self.height).is_none() {
+            return Ok(());
+        }
+        match self.take(1)?[0] {
+            LEAF => self.take(T::SIZE).map(|_| ()),
+            SPLIT if size > 1 => {
+                let half = size / 2;
+                for (dx, dy) in [(0, 0), (half, 0), (0, half), (half, half)] {
+                    self.skip::<T>(x + dx, y + dy, half)?;
+                }
+                Ok(())
+            }
+            tag => Err(GridError::InvalidData(format!(
+                "Invalid quadtree node tag {} at byte {}",
+                tag,
+                self.pos - 1
+            ))),
+        }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&[u8], GridError> {
+        let end = self.pos + len;
+        let slice = self.bytes.get(self.pos..end).ok_or_else(|| {
+            GridError::InvalidData(format!("Quadtree data truncated at byte {}", self.pos))
+        })?;
+        self.pos = end;
+        Ok(slice)
+    }
+}
+
+fn read_header(bytes: &[u8]) -> Result<(u16, u16), GridError> {
+    if bytes.len() < HEADER_LEN || &bytes[..4] != MAGIC {
+        return Err(GridError::InvalidData(
+            "Missing quadtree header".to_string(),
+        ));
+    }
+    let width = u16::from_le_bytes([bytes[4], bytes[5]]);
+    let height = u16::from_le_bytes([bytes[6], bytes[7]]);
+    Ok((width, height))
+}
+
+fn root_size(width: u16, height: u16) -> u32 {
+    (width.max(height) as u32).next_power_of_two()
+}
+
+/// Returns the part of the square node that lies inside the grid.
+fn clip(x: u32, y: u32, size: u32, width: u16, height: u16) -> Option<Rect> {
+    if x >= width as u32 || y >= height as u32 {
+        return None;
+    }
+    let w = size.min(width as u32 - x);
+    let h = size.min(height as u32 - y);
+    Some(Rect::new(x as u16, y as u16, w as u16, h as u16))
+}
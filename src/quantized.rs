@@ -0,0 +1,234 @@
+use crate::{Grid, GridError};
+
+/// How a [`QuantizedGrid`] stores each `f32` in 16 bits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Quantization {
+    /// IEEE 754 half precision: about three significant decimal digits over
+    /// a range of ±65504, with infinities and NaN preserved.
+    F16,
+    /// Fixed point: `min..=max` is divided into 65535 equal steps. Values
+    /// outside the range are clamped and NaN is stored as `min`.
+    Fixed { min: f32, max: f32 },
+}
+
+impl Quantization {
+    /// Encodes a value to its 16-bit representation.
+    pub fn encode(&self, value: f32) -> u16 {
+        match *self {
+            Quantization::F16 => f32_to_f16(value),
+            Quantization::Fixed { min, max } => {
+                if max <= min {
+                    return 0;
+                }
+                let t = (value.clamp(min, max) - min) / (max - min);
+                (t * u16::MAX as f32).round() as u16
+            }
+        }
+    }
+
+    /// Decodes a 16-bit representation back to a value.
+    pub fn decode(&self, bits: u16) -> f32 {
+        match *self {
+            Quantization::F16 => f16_to_f32(bits),
+            Quantization::Fixed { min, max } => {
+                if max <= min {
+                    return min;
+                }
+                min + (bits as f32 / u16::MAX as f32) * (max - min)
+            }
+        }
+    }
+}
+
+/// A 2D grid of `f32` values stored at 16 bits per cell.
+///
+/// Values are encoded on write and decoded on read according to the grid's
+/// [`Quantization`], halving the memory of a `Grid<f32>` at the cost of
+/// precision.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{Grid, Quantization, QuantizedGrid};
+///
+/// let mut heights: Grid<f32> = Grid::new(4, 4);
+/// heights.map_inplace(|x, y, h| *h = (x + y) as f32 * 12.5);
+///
+/// let packed = QuantizedGrid::from_grid(&heights, Quantization::Fixed { min: 0.0, max: 100.0 });
+/// assert!((packed.get(3, 3).unwrap() - 75.0).abs() < 0.001);
+/// assert_eq!(packed.memory_bytes(), 32);
+///
+/// let half = QuantizedGrid::from_grid(&heights, Quantization::F16);
+/// assert_eq!(half.to_grid().as_slice(), heights.as_slice());
+/// ```
+pub struct QuantizedGrid {
+    width: u16,
+    height: u16,
+    quantization: Quantization,
+    cells: Vec<u16>,
+}
+
+impl QuantizedGrid {
+    /// Creates a new grid with every cell set to the quantized value of `0.0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the grid
+    /// * `height` - The height of the grid
+    /// * `quantization` - How cell values are stored
+    pub fn new(width: u16, height: u16, quantization: Quantization) -> QuantizedGrid {
+        QuantizedGrid {
+            width,
+            height,
+            quantization,
+            cells: vec![quantization.encode(0.0); width as usize * height as usize],
+        }
+    }
+
+    /// Quantizes every cell of an `f32` grid.
+    pub fn from_grid(grid: &Grid<f32>, quantization: Quantization) -> QuantizedGrid {
+        QuantizedGrid {
+            width: grid.width(),
+            height: grid.height(),
+            quantization,
+            cells: grid
+                .as_slice()
+                .iter()
+                .map(|&value| quantization.encode(value))
+                .collect(),
+        }
+    }
+
+    /// Decodes every cell into a regular `Grid<f32>`.
+    pub fn to_grid(&self) -> Grid<f32> {
+        let mut grid = Grid::new(self.width, self.height);
+        for (cell, &bits) in grid.as_mut_slice().iter_mut().zip(&self.cells) {
+            *cell = self.quantization.decode(bits);
+        }
+        grid
+    }
+
+    /// Returns the decoded value at (x, y), or `None` if out of bounds.
+    pub fn get(&self, x: u16, y: u16) -> Option<f32> {
+        if x < self.width && y < self.height {
+            Some(self.quantization.decode(self.cells[self.index(x, y)]))
+        } else {
+            None
+        }
+    }
+
+    /// Quantizes and stores a value at (x, y).
+    ///
+    /// Returns `Ok(())` if successful, or [`GridError::OutOfBounds`] if the
+    /// coordinates are out of bounds.
+    pub fn set(&mut self, x: u16, y: u16, value: f32) -> Result<(), GridError> {
+        if x < self.width && y < self.height {
+            let idx = self.index(x, y);
+            self.cells[idx] = self.quantization.encode(value);
+            Ok(())
+        } else {
+            Err(GridError::OutOfBounds {
+                x,
+                y,
+                width: self.width,
+                height: self.height,
+            })
+        }
+    }
+
+    /// Returns an iterator over all cells as `(x, y, value)` in row-major order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (u16, u16, f32)> + '_ {
+        self.cells.iter().enumerate().map(move |(i, &bits)| {
+            let x = (i % self.width as usize) as u16;
+            let y = (i / self.width as usize) as u16;
+            (x, y, self.quantization.decode(bits))
+        })
+    }
+
+    /// Returns the raw 16-bit cell representations in row-major order.
+    pub fn as_raw(&self) -> &[u16] {
+        &self.cells
+    }
+
+    /// Returns the quantization used by this grid.
+    pub fn quantization(&self) -> Quantization {
+        self.quantization
+    }
+
+    /// Returns the width of the grid.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Returns the height of the grid.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Returns the total area (width × height) of the grid.
+    pub fn area(&self) -> u32 {
+        self.width as u32 * self.height as u32
+    }
+
+    /// Returns the number of bytes used by the cell storage.
+    pub fn memory_bytes(&self) -> usize {
+        self.cells.len() * size_of::<u16>()
+    }
+
+    fn index(&self, x: u16, y: u16) -> usize {
+        y as usize * self.width as usize + x as usize
+    }
+}
+
+/// Converts to half precision, rounding to nearest with ties to even.
+fn f32_to_f16(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exp = ((bits >> 23) & 0xff) as i32;
+    let mant = bits & 0x7f_ffff;
+
+    if exp == 0xff {
+        let nan = if mant != 0 { 0x200 } else { 0 };
+        return sign | 0x7c00 | nan;
+    }
+    let half_exp = exp - 127 + 15;
+    if half_exp >= 0x1f {
+        return sign | 0x7c00;
+    }
+    if half_exp <= 0 {
+        // Subnormal or underflow to zero.
+        if half_exp < -10 {
+            return sign;
+        }
+        let full = mant | 0x80_0000;
+        let shift = (14 - half_exp) as u32;
+        let mut half = full >> shift;
+        let rem = full & ((1 << shift) - 1);
+        let halfway = 1 << (shift - 1);
+        if rem > halfway || (rem == halfway && half & 1 == 1) {
+            half += 1;
+        }
+        return sign | half as u16;
+    }
+    let mut half = ((half_exp as u32) << 10) | (mant >> 13);
+    let rem = mant & 0x1fff;
+    if rem > 0x1000 || (rem == 0x1000 && half & 1 == 1) {
+        // A carry out of the mantissa correctly bumps the exponent.
+        half += 1;
+    }
+    sign | half as u16
+}
+
+fn f16_to_f32(half: u16) -> f32 {
+    let sign = ((half & 0x8000) as u32) << 16;
+    let exp = ((half >> 10) & 0x1f) as u32;
+    let mant = (half & 0x3ff) as u32;
+    match exp {
+        0 => {
+            let magnitude = mant as f32 * f32::powi(2.0, -24);
+            if sign != 0 { -magnitude } else { magnitude }
+        }
+        0x1f => f32::from_bits(sign | 0x7f80_0000 | (mant << 13)),
+        _ => f32::from_bits(sign | ((exp + 112) << 23) | (mant << 13)),
+    }
+}
@@ -0,0 +1,106 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::Grid;
+
+/// A lazy random permutation of a grid's cells.
+///
+/// Created by [`Grid::iter_random_order`]. Each step performs one swap of
+/// a Fisher–Yates shuffle over the cell indices, so stopping early costs
+/// only the cells actually visited (plus the index buffer).
+pub struct RandomOrder<'a, T, R: ?Sized> {
+    grid: &'a Grid<T>,
+    rng: &'a mut R,
+    indices: Vec<u32>,
+    next: usize,
+}
+
+impl<'a, T, R> Iterator for RandomOrder<'a, T, R>
+where
+    T: Default + Clone,
+    R: Rng + ?Sized,
+{
+    type Item = (u16, u16, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == self.indices.len() {
+            return None;
+        }
+        let pick = self.rng.random_range(self.next..self.indices.len());
+        self.indices.swap(self.next, pick);
+        let index = self.indices[self.next] as usize;
+        self.next += 1;
+        let (x, y) = self.grid.coords(index);
+        Some((x, y, &self.grid.tiles[index]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.indices.len() - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T, R> ExactSizeIterator for RandomOrder<'a, T, R>
+where
+    T: Default + Clone,
+    R: Rng + ?Sized,
+{
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Randomly permutes the cell values in place.
+    ///
+    /// Every permutation is equally likely (Fisher–Yates). The grid's
+    /// dimensions are unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut grid: Grid<u32> = Grid::new(10, 10);
+    /// grid.map_inplace(|x, y, v| *v = (y * 10 + x) as u32);
+    /// grid.shuffle(&mut StdRng::seed_from_u64(7));
+    ///
+    /// let mut values = grid.into_vec();
+    /// values.sort();
+    /// assert_eq!(values, (0..100).collect::<Vec<_>>());
+    /// ```
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.tiles.shuffle(rng);
+    }
+
+    /// Returns an iterator visiting every cell exactly once in random order.
+    ///
+    /// Yields `(x, y, &value)`. Useful for unbiased cellular automaton update
+    /// orders and random placement passes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let grid: Grid<u8> = Grid::new(4, 4);
+    /// let mut rng = StdRng::seed_from_u64(1);
+    ///
+    /// let mut cells: Vec<_> = grid.iter_random_order(&mut rng).map(|(x, y, _)| (x, y)).collect();
+    /// cells.sort();
+    /// cells.dedup();
+    /// assert_eq!(cells.len(), 16);
+    /// ```
+    pub fn iter_random_order<'a, R: Rng + ?Sized>(
+        &'a self,
+        rng: &'a mut R,
+    ) -> RandomOrder<'a, T, R> {
+        RandomOrder {
+            grid: self,
+            rng,
+            indices: (0..self.tiles.len() as u32).collect(),
+            next: 0,
+        }
+    }
+}
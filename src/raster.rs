@@ -0,0 +1,213 @@
+use crate::Grid;
+
+/// Decides which parts of a self-intersecting polygon count as inside.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FillRule {
+    /// A point is inside if a ray from it crosses the outline an odd number of times.
+    #[default]
+    EvenOdd,
+    /// A point is inside if the outline winds around it a non-zero number of times.
+    NonZero,
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Fills every cell whose centre lies inside a polygon.
+    ///
+    /// Vertices are given in continuous grid coordinates, where cell (x, y)
+    /// covers `[x, x + 1) × [y, y + 1)`; the polygon is closed automatically.
+    /// Rows are filled with a scanline pass that samples cell centres, and
+    /// parts of the polygon outside the grid are clipped.
+    ///
+    /// Returns the number of cells written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{FillRule, Grid};
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(10, 10);
+    /// let square = [(2.0, 2.0), (6.0, 2.0), (6.0, 5.0), (2.0, 5.0)];
+    /// let filled = grid.fill_polygon(&square, FillRule::EvenOdd, 1);
+    /// assert_eq!(filled, 12);
+    /// assert_eq!(grid.get(5, 4), Some(&1));
+    /// assert_eq!(grid.get(6, 4), Some(&0));
+    /// ```
+    pub fn fill_polygon(&mut self, points: &[(f32, f32)], rule: FillRule, value: T) -> usize {
+        if points.len() < 3 {
+            return 0;
+        }
+        let min_y = points.iter().map(|p| p.1).fold(f32::INFINITY, f32::min);
+        let max_y = points.iter().map(|p| p.1).fold(f32::NEG_INFINITY, f32::max);
+        let first_row = (min_y - 0.5).ceil().max(0.0) as u32;
+        let last_row = ((max_y - 0.5).ceil() as i64).min(self.height as i64);
+
+        let mut crossings: Vec<(f32, i32)> = Vec::new();
+        let mut filled = 0;
+        for y in first_row as i64..last_row {
+            let scan_y = y as f32 + 0.5;
+            crossings.clear();
+            for (i, &(x0, y0)) in points.iter().enumerate() {
+                let (x1, y1) = points[(i + 1) % points.len()];
+                let winding = if y0 <= scan_y && scan_y < y1 {
+                    1
+                } else if y1 <= scan_y && scan_y < y0 {
+                    -1
+                } else {
+                    continue;
+                };
+                let x = x0 + (scan_y - y0) / (y1 - y0) * (x1 - x0);
+                crossings.push((x, winding));
+            }
+            crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            let mut winding = 0;
+            for pair in crossings.windows(2) {
+                winding += pair[0].1;
+                let inside = match rule {
+                    FillRule::EvenOdd => winding % 2 != 0,
+                    FillRule::NonZero => winding != 0,
+                };
+                if inside {
+                    filled += self.fill_span(y as u16, pair[0].0, pair[1].0, &value);
+                }
+            }
+        }
+        filled
+    }
+
+    /// Fills every cell whose centre lies inside a triangle.
+    ///
+    /// Returns the number of cells written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut grid: Grid<char> = Grid::with_value(8, 8, '.');
+    /// grid.fill_triangle((0.0, 0.0), (8.0, 0.0), (0.0, 8.0), '#');
+    /// assert_eq!(grid.get(0, 6), Some(&'#'));
+    /// assert_eq!(grid.get(7, 7), Some(&'.'));
+    /// ```
+    pub fn fill_triangle(
+        &mut self,
+        a: (f32, f32),
+        b: (f32, f32),
+        c: (f32, f32),
+        value: T,
+    ) -> usize {
+        self.fill_polygon(&[a, b, c], FillRule::NonZero, value)
+    }
+
+    /// Fills the cells of row `y` whose centres lie in `[from, to)`.
+    fn fill_span(&mut self, y: u16, from: f32, to: f32, value: &T) -> usize {
+        let start = (from - 0.5).ceil().max(0.0) as usize;
+        let end = ((to - 0.5).ceil().max(0.0) as usize).min(self.width as usize);
+        if start >= end {
+            return 0;
+        }
+        let row = self.index(0, y);
+        self.tiles[row + start..row + end].fill(value.clone());
+        end - start
+    }
+}
+
+impl Grid<f32> {
+    /// Accumulates an anti-aliased line into the grid (Xiaolin Wu's algorithm).
+    ///
+    /// Endpoints are in continuous grid coordinates, so `(x + 0.5, y + 0.5)`
+    /// is the centre of cell (x, y). Each touched cell has `intensity` times
+    /// its fractional coverage added to it; coverage along the line sums to
+    /// roughly its length. Parts of the line outside the grid are skipped,
+    /// and a line with a non-finite endpoint draws nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `x0`, `y0` - The start point
+    /// * `x1`, `y1` - The end point
+    /// * `intensity` - The value added per unit of coverage
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut heat: Grid<f32> = Grid::new(8, 4);
+    /// heat.draw_line_aa(0.5, 1.5, 7.5, 1.5, 2.0);
+    /// assert_eq!(heat.get(3, 1), Some(&2.0));
+    /// assert_eq!(heat.get(3, 2), Some(&0.0));
+    ///
+    /// // A line between rows splits its coverage across both.
+    /// let mut heat: Grid<f32> = Grid::new(8, 4);
+    /// heat.draw_line_aa(0.5, 2.0, 7.5, 2.0, 1.0);
+    /// assert_eq!(heat.get(3, 1), Some(&0.5));
+    /// assert_eq!(heat.get(3, 2), Some(&0.5));
+    /// ```
+    pub fn draw_line_aa(&mut self, x0: f32, y0: f32, x1: f32, y1: f32, intensity: f32) {
+        if ![x0, y0, x1, y1].iter().all(|v| v.is_finite()) {
+            return;
+        }
+        // Wu's algorithm places pixel centres on integers.
+        let (mut x0, mut y0, mut x1, mut y1) = (x0 - 0.5, y0 - 0.5, x1 - 0.5, y1 - 0.5);
+        let steep = (y1 - y0).abs() > (x1 - x0).abs();
+        if steep {
+            (x0, y0, x1, y1) = (y0, x0, y1, x1);
+        }
+        if x0 > x1 {
+            (x0, y0, x1, y1) = (x1, y1, x0, y0);
+        }
+        let dx = x1 - x0;
+        let gradient = if dx == 0.0 { 1.0 } else { (y1 - y0) / dx };
+        let (major_len, minor_len) = if steep {
+            (self.height as f32, self.width as f32)
+        } else {
+            (self.width as f32, self.height as f32)
+        };
+
+        let mut plot = |major: f32, minor: f32, coverage: f32| {
+            let (x, y) = if steep {
+                (minor, major)
+            } else {
+                (major, minor)
+            };
+            if x >= 0.0 && y >= 0.0 && x < self.width as f32 && y < self.height as f32 {
+                let idx = self.index(x as u16, y as u16);
+                self.tiles[idx] += intensity * coverage;
+            }
+        };
+        let fpart = |v: f32| v - v.floor();
+
+        let x_start = (x0 + 0.5).floor();
+        let y_start = y0 + gradient * (x_start - x0);
+        let gap = 1.0 - fpart(x0 + 0.5);
+        plot(x_start, y_start.floor(), (1.0 - fpart(y_start)) * gap);
+        plot(x_start, y_start.floor() + 1.0, fpart(y_start) * gap);
+
+        let x_end = (x1 + 0.5).floor();
+        let y_end = y1 + gradient * (x_end - x1);
+        let gap = fpart(x1 + 0.5);
+        plot(x_end, y_end.floor(), (1.0 - fpart(y_end)) * gap);
+        plot(x_end, y_end.floor() + 1.0, fpart(y_end) * gap);
+
+        // Only walk the part of the span that can land inside the grid: the
+        // major axis within its length, and the minor axis within one cell
+        // of the grid on either side.
+        let mut lo = (x_start + 1.0).max(0.0);
+        let mut hi = x_end.min(major_len);
+        if gradient != 0.0 {
+            let a = x_start + (-1.0 - y_start) / gradient;
+            let b = x_start + (minor_len - y_start) / gradient;
+            lo = lo.max(a.min(b).floor());
+            hi = hi.min(a.max(b).ceil() + 1.0);
+        }
+
+        let mut y = y_start + gradient * (lo - x_start);
+        let mut x = lo;
+        while x < hi {
+            plot(x, y.floor(), 1.0 - fpart(y));
+            plot(x, y.floor() + 1.0, fpart(y));
+            y += gradient;
+            x += 1.0;
+        }
+    }
+}
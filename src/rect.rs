@@ -0,0 +1,100 @@
+/// An axis-aligned rectangle of cells.
+///
+/// `x` and `y` are the top-left cell; the rectangle covers the half-open ranges
+/// `x..x + width` and `y..y + height`.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Rect;
+///
+/// let rect = Rect::new(2, 3, 4, 5);
+/// assert!(rect.contains(5, 7));
+/// assert!(!rect.contains(6, 7));
+/// assert_eq!(rect.area(), 20);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rect {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl Rect {
+    /// Creates a new rectangle from its top-left cell and size.
+    pub fn new(x: u16, y: u16, width: u16, height: u16) -> Rect {
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the exclusive right edge (`x + width`).
+    pub fn right(&self) -> u32 {
+        self.x as u32 + self.width as u32
+    }
+
+    /// Returns the exclusive bottom edge (`y + height`).
+    pub fn bottom(&self) -> u32 {
+        self.y as u32 + self.height as u32
+    }
+
+    /// Returns the number of cells covered by the rectangle.
+    pub fn area(&self) -> u32 {
+        (self.width as u32) * (self.height as u32)
+    }
+
+    /// Returns `true` if the rectangle covers no cells.
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Returns `true` if the cell (x, y) lies inside the rectangle.
+    pub fn contains(&self, x: u16, y: u16) -> bool {
+        x >= self.x && y >= self.y && (x as u32) < self.right() && (y as u32) < self.bottom()
+    }
+
+    /// Returns the overlapping part of two rectangles, or `None` if they are disjoint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Rect;
+    ///
+    /// let a = Rect::new(0, 0, 4, 4);
+    /// let b = Rect::new(2, 3, 4, 4);
+    /// assert_eq!(a.intersection(&b), Some(Rect::new(2, 3, 2, 1)));
+    /// assert_eq!(a.intersection(&Rect::new(4, 0, 1, 1)), None);
+    /// ```
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+        if (x as u32) < right && (y as u32) < bottom {
+            Some(Rect::new(
+                x,
+                y,
+                (right - x as u32) as u16,
+                (bottom - y as u32) as u16,
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over every cell in the rectangle in row-major order.
+    pub fn cells(&self) -> impl Iterator<Item = (u16, u16)> + use<> {
+        let Rect {
+            x,
+            y,
+            width,
+            height,
+        } = *self;
+        (y..y.saturating_add(height))
+            .flat_map(move |cy| (x..x.saturating_add(width)).map(move |cx| (cx, cy)))
+    }
+}
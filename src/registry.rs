@@ -0,0 +1,166 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::Grid;
+
+/// Shared metadata for each kind of tile, keyed by the value stored in a
+/// grid cell.
+///
+/// Lets a map store a small id per cell (a `u8`, an enum) while the movement
+/// cost, sprite and flags of each id live once in the registry, instead of
+/// every cell carrying a copy. Look a cell's metadata up with
+/// [`Grid::lookup`].
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{Grid, TileRegistry};
+/// use gridsystem::pathfinding::astar;
+///
+/// struct Terrain {
+///     glyph: char,
+///     cost: Option<u32>,
+/// }
+///
+/// let mut registry = TileRegistry::new();
+/// registry.insert(0u8, Terrain { glyph: '.', cost: Some(1) });
+/// registry.insert(1, Terrain { glyph: '#', cost: None });
+///
+/// let mut map: Grid<u8> = Grid::new(5, 3);
+/// for y in 0..2 {
+///     let _ = map.set(2, y, 1);
+/// }
+/// assert_eq!(map.lookup(2, 0, &registry).map(|t| t.glyph), Some('#'));
+///
+/// let path = astar(&map, (0, 0), (4, 0), |_, _, id| registry.get(id)?.cost).unwrap();
+/// assert!(path.contains(&(2, 2)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TileRegistry<K, M> {
+    entries: HashMap<K, M>,
+}
+
+impl<K, M> Default for TileRegistry<K, M> {
+    fn default() -> Self {
+        TileRegistry {
+            entries: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash, M> TileRegistry<K, M> {
+    /// Creates an empty registry.
+    pub fn new() -> TileRegistry<K, M> {
+        TileRegistry::default()
+    }
+
+    /// Registers `metadata` for `key`, returning the metadata it replaces.
+    pub fn insert(&mut self, key: K, metadata: M) -> Option<M> {
+        self.entries.insert(key, metadata)
+    }
+
+    /// Removes `key`, returning its metadata if it was registered.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<M>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.entries.remove(key)
+    }
+
+    /// Returns the metadata registered for `key`.
+    pub fn get<Q>(&self, key: &Q) -> Option<&M>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.entries.get(key)
+    }
+
+    /// Returns a mutable reference to the metadata registered for `key`.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut M>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.entries.get_mut(key)
+    }
+
+    /// Returns `true` if `key` has metadata.
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.entries.contains_key(key)
+    }
+
+    /// Returns the number of registered keys.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if nothing is registered.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the keys and their metadata, in no
+    /// particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &M)> {
+        self.entries.iter()
+    }
+}
+
+impl<K: Eq + Hash, M> FromIterator<(K, M)> for TileRegistry<K, M> {
+    fn from_iter<I: IntoIterator<Item = (K, M)>>(iter: I) -> Self {
+        TileRegistry {
+            entries: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<K: Default + Clone + Eq + Hash> Grid<K> {
+    /// Returns the registry metadata for the value at (x, y).
+    ///
+    /// Returns `None` if the cell is out of bounds or its value has no entry
+    /// in `registry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Grid, TileRegistry};
+    ///
+    /// let registry: TileRegistry<u8, &str> = [(0, "grass"), (1, "water")].into_iter().collect();
+    /// let map = Grid::from_raw_parts(3, 1, vec![0, 1, 7]).unwrap();
+    ///
+    /// assert_eq!(map.lookup(1, 0, &registry), Some(&"water"));
+    /// assert_eq!(map.lookup(2, 0, &registry), None);
+    /// assert_eq!(map.lookup(3, 0, &registry), None);
+    /// ```
+    pub fn lookup<'r, M>(&self, x: u16, y: u16, registry: &'r TileRegistry<K, M>) -> Option<&'r M> {
+        registry.get(self.get(x, y)?)
+    }
+
+    /// Returns the positions of cells whose value has no entry in
+    /// `registry`, in row-major order.
+    ///
+    /// Useful for checking a loaded map against the tile set before play.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Grid, TileRegistry};
+    ///
+    /// let registry: TileRegistry<u8, ()> = [(0, ()), (1, ())].into_iter().collect();
+    /// let map = Grid::from_raw_parts(2, 2, vec![0, 5, 1, 9]).unwrap();
+    /// assert_eq!(map.unregistered(&registry), vec![(1, 0), (1, 1)]);
+    /// ```
+    pub fn unregistered<M>(&self, registry: &TileRegistry<K, M>) -> Vec<(u16, u16)> {
+        self.iter()
+            .filter(|(_, _, key)| !registry.contains(*key))
+            .map(|(x, y, _)| (x, y))
+            .collect()
+    }
+}
@@ -0,0 +1,230 @@
+use crate::{Grid, GridError};
+
+impl<T: Sync> Grid<T> {
+    /// Renders the grid into `framebuffer` one row at a time across rayon threads.
+    ///
+    /// The framebuffer is split into consecutive rows of `stride` bytes and
+    /// `render` is called as `render(y, row, out)` with the cells of grid
+    /// row `y` and the matching framebuffer row. Rows are independent, so
+    /// a software renderer can scale a tile, look up a palette or blend
+    /// layers per row without sharing any mutable state. Bytes past the
+    /// last row are left untouched.
+    ///
+    /// Returns [`GridError::InvalidArgument`] if `stride` is zero, or
+    /// [`GridError::LengthMismatch`] if the framebuffer is smaller than
+    /// `stride × height` bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `framebuffer` - The caller's pixel buffer
+    /// * `stride` - The number of bytes per framebuffer row
+    /// * `render` - Fills one framebuffer row from one grid row
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let heights = Grid::from_raw_parts(2, 2, vec![0u8, 255, 128, 64]).unwrap();
+    /// let mut rgb = vec![0u8; 2 * 2 * 3];
+    ///
+    /// heights.render_rows_par(&mut rgb, 2 * 3, |_y, row, out| {
+    ///     for (pixel, &h) in out.chunks_exact_mut(3).zip(row) {
+    ///         pixel.copy_from_slice(&[h, h, h]);
+    ///     }
+    /// }).unwrap();
+    /// assert_eq!(&rgb[6..9], &[128, 128, 128]);
+    /// ```
+    pub fn render_rows_par<F>(
+        &self,
+        framebuffer: &mut [u8],
+        stride: usize,
+        render: F,
+    ) -> Result<(), GridError>
+    where
+        F: Fn(u16, &[T], &mut [u8]) + Sync,
+    {
+        use rayon::prelude::*;
+        let needed = stride * self.height as usize;
+        if stride == 0 {
+            return Err(GridError::InvalidArgument(
+                "Framebuffer stride must be non-zero".to_string(),
+            ));
+        }
+        if framebuffer.len() < needed {
+            return Err(GridError::LengthMismatch {
+                expected: needed,
+                found: framebuffer.len(),
+            });
+        }
+        let width = self.width as usize;
+        framebuffer[..needed]
+            .par_chunks_mut(stride)
+            .enumerate()
+            .for_each(|(y, out)| render(y as u16, &self.tiles[y * width..(y + 1) * width], out));
+        Ok(())
+    }
+}
+
+/// Overlays for [`Grid::render_ascii`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AsciiOverlay {
+    /// Print column numbers above the map and row numbers to its left.
+    ///
+    /// Column numbers are written vertically, one digit per line, with the
+    /// higher digits shown only where they change.
+    pub axes: bool,
+    /// Print a `Legend:` line listing every glyph on the map with its label.
+    pub legend: bool,
+    /// Cell sets drawn over the map, later ones on top of earlier ones.
+    pub highlights: Vec<Highlight>,
+}
+
+/// A set of cells drawn with one glyph by [`Grid::render_ascii`], such as a
+/// computed path or a field of view.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Highlight {
+    /// The character drawn on every highlighted cell.
+    pub glyph: char,
+    /// The name shown for `glyph` in the legend.
+    pub label: String,
+    /// The highlighted cells; cells outside the grid are ignored.
+    pub cells: Vec<(u16, u16)>,
+}
+
+impl Highlight {
+    /// Creates a highlight drawing `glyph` over `cells`.
+    pub fn new(
+        glyph: char,
+        label: impl Into<String>,
+        cells: impl IntoIterator<Item = (u16, u16)>,
+    ) -> Highlight {
+        Highlight {
+            glyph,
+            label: label.into(),
+            cells: cells.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Renders the grid as text, one line per row, mapping each cell to a
+    /// character with `glyph`.
+    ///
+    /// Every line, including the last, ends with `\n`, which keeps the
+    /// output easy to compare against test snapshots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut walls: Grid<bool> = Grid::new(3, 2);
+    /// let _ = walls.set(1, 0, true);
+    /// assert_eq!(walls.to_ascii(|&wall| if wall { '#' } else { '.' }), ".#.\n...\n");
+    /// ```
+    pub fn to_ascii<F>(&self, glyph: F) -> String
+    where
+        F: Fn(&T) -> char,
+    {
+        self.render_ascii(|value| (glyph(value), ""), &AsciiOverlay::default())
+    }
+
+    /// Renders the grid as text with axes, highlights and a legend.
+    ///
+    /// `mapper` returns each cell's character and the label to show for it
+    /// in the legend; the first label seen for a character is used. The
+    /// legend lists the map's characters in row-major order of first
+    /// appearance, then each highlight's. Highlights replace the characters
+    /// of the cells they cover.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{AsciiOverlay, Grid, Highlight};
+    ///
+    /// let mut walls: Grid<bool> = Grid::new(4, 2);
+    /// let _ = walls.set(2, 0, true);
+    /// let mapper = |&wall: &bool| if wall { ('#', "wall") } else { ('.', "floor") };
+    /// let overlay = AsciiOverlay {
+    ///     axes: true,
+    ///     legend: true,
+    ///     highlights: vec![Highlight::new('*', "path", [(0, 0), (1, 0), (1, 1), (2, 1)])],
+    /// };
+    ///
+    /// let text = walls.render_ascii(mapper, &overlay);
+    /// assert_eq!(text, "  0123\n0 **#.\n1 .**.\nLegend: . floor, # wall, * path\n");
+    /// ```
+    pub fn render_ascii<F, L>(&self, mapper: F, overlay: &AsciiOverlay) -> String
+    where
+        F: Fn(&T) -> (char, L),
+        L: AsRef<str>,
+    {
+        let (width, height) = (self.width as usize, self.height as usize);
+        let mut legend: Vec<(char, String)> = Vec::new();
+        let mut note = |glyph: char, label: &str| {
+            if !legend.iter().any(|&(g, _)| g == glyph) {
+                legend.push((glyph, label.to_string()));
+            }
+        };
+        let mut canvas: Vec<char> = self
+            .tiles
+            .iter()
+            .map(|value| {
+                let (glyph, label) = mapper(value);
+                note(glyph, label.as_ref());
+                glyph
+            })
+            .collect();
+        for highlight in &overlay.highlights {
+            note(highlight.glyph, &highlight.label);
+            for &(x, y) in &highlight.cells {
+                if (x as usize) < width && (y as usize) < height {
+                    canvas[y as usize * width + x as usize] = highlight.glyph;
+                }
+            }
+        }
+
+        let mut out = String::new();
+        let margin = if overlay.axes {
+            digits(height.saturating_sub(1)) + 1
+        } else {
+            0
+        };
+        if overlay.axes {
+            for place in (0..digits(width.saturating_sub(1)) as u32).rev() {
+                let unit = 10usize.pow(place);
+                out.push_str(&" ".repeat(margin));
+                for x in 0..width {
+                    let shown = place == 0 || x % unit == 0;
+                    out.push(if shown {
+                        char::from(b'0' + (x / unit % 10) as u8)
+                    } else {
+                        ' '
+                    });
+                }
+                out.push('\n');
+            }
+        }
+        for (y, row) in canvas.chunks(width.max(1)).take(height).enumerate() {
+            if overlay.axes {
+                out.push_str(&format!("{:>1$} ", y, margin - 1));
+            }
+            out.extend(row);
+            out.push('\n');
+        }
+        if overlay.legend && !legend.is_empty() {
+            let entries: Vec<String> = legend
+                .iter()
+                .map(|(glyph, label)| format!("{} {}", glyph, label))
+                .collect();
+            out.push_str(&format!("Legend: {}\n", entries.join(", ")));
+        }
+        out
+    }
+}
+
+/// Number of decimal digits needed to print `n`.
+fn digits(n: usize) -> usize {
+    n.checked_ilog10().map_or(1, |d| d as usize + 1)
+}
@@ -0,0 +1,246 @@
+use std::io::{self, Read, Write};
+use std::marker::PhantomData;
+
+use crate::{CellCodec, Grid};
+
+const MAGIC: &[u8; 4] = b"GRW1";
+
+impl<T: Default + Clone + CellCodec> Grid<T> {
+    /// Writes the grid row by row in the raw row format read by [`RowReader`].
+    ///
+    /// The stream is an 8-byte header (magic, width and height) followed by
+    /// every row in order, each cell encoded with [`CellCodec`]. Only one
+    /// row is buffered at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Grid, RowReader};
+    ///
+    /// let mut grid: Grid<u16> = Grid::new(3, 2);
+    /// let _ = grid.set(2, 1, 500);
+    ///
+    /// let mut bytes = Vec::new();
+    /// grid.write_rows_to(&mut bytes).unwrap();
+    /// assert_eq!(bytes.len(), 8 + 6 * 2);
+    ///
+    /// let restored: Grid<u16> = RowReader::new(bytes.as_slice()).unwrap().read_band(2).unwrap();
+    /// assert_eq!(restored.get(2, 1), Some(&500));
+    /// ```
+    pub fn write_rows_to<W: Write>(&self, writer: W) -> io::Result<()> {
+        let mut rows = RowWriter::new(writer, self.width, self.height)?;
+        let width = self.width as usize;
+        for y in 0..self.height as usize {
+            rows.write_row(&self.tiles[y * width..(y + 1) * width])?;
+        }
+        rows.finish().map(|_| ())
+    }
+}
+
+/// Writes a grid in the raw row format one row at a time.
+///
+/// Use this to produce grids that are too large to build in memory; the
+/// output is identical to [`Grid::write_rows_to`].
+pub struct RowWriter<W: Write> {
+    writer: W,
+    width: u16,
+    height: u16,
+    rows_written: u16,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> RowWriter<W> {
+    /// Writes the header for a `width × height` grid.
+    pub fn new(mut writer: W, width: u16, height: u16) -> io::Result<RowWriter<W>> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&width.to_le_bytes())?;
+        writer.write_all(&height.to_le_bytes())?;
+        Ok(RowWriter {
+            writer,
+            width,
+            height,
+            rows_written: 0,
+            buffer: Vec::new(),
+        })
+    }
+
+    /// Writes the next row.
+    ///
+    /// Returns an `InvalidInput` error if the row has the wrong length or all
+    /// rows have already been written.
+    pub fn write_row<T: CellCodec>(&mut self, row: &[T]) -> io::Result<()> {
+        if row.len() != self.width as usize {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("row has {} cells, expected {}", row.len(), self.width),
+            ));
+        }
+        if self.rows_written == self.height {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("all {} rows have already been written", self.height),
+            ));
+        }
+        self.buffer.clear();
+        for cell in row {
+            cell.encode(&mut self.buffer);
+        }
+        self.writer.write_all(&self.buffer)?;
+        self.rows_written += 1;
+        Ok(())
+    }
+
+    /// Flushes the output and returns the underlying writer.
+    ///
+    /// Returns an `InvalidInput` error if fewer rows than the header declared
+    /// were written.
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.rows_written != self.height {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "only {} of {} rows were written",
+                    self.rows_written, self.height
+                ),
+            ));
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Reads a grid in the raw row format one row at a time.
+///
+/// Rows are consumed in order, so a caller can process a huge raster band by
+/// band with [`RowReader::read_band`], or skip to the rows it needs with
+/// [`RowReader::skip_rows`], without holding the whole grid in memory.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{Grid, RowReader};
+///
+/// let mut grid: Grid<u8> = Grid::new(4, 10);
+/// grid.map_inplace(|_, y, v| *v = y as u8);
+/// let mut bytes = Vec::new();
+/// grid.write_rows_to(&mut bytes).unwrap();
+///
+/// let mut reader = RowReader::<_, u8>::new(bytes.as_slice()).unwrap();
+/// reader.skip_rows(6).unwrap();
+/// let band = reader.read_band(3).unwrap();
+/// assert_eq!((band.width(), band.height()), (4, 3));
+/// assert_eq!(band.get(0, 0), Some(&6));
+/// assert_eq!(reader.next_row(), 9);
+/// ```
+pub struct RowReader<R: Read, T> {
+    reader: R,
+    width: u16,
+    height: u16,
+    next_row: u16,
+    buffer: Vec<u8>,
+    marker: PhantomData<T>,
+}
+
+impl<R: Read, T: Default + Clone + CellCodec> RowReader<R, T> {
+    /// Reads the header from `reader`.
+    ///
+    /// Returns an `InvalidData` error if the stream does not start with a
+    /// row-format header.
+    pub fn new(mut reader: R) -> io::Result<RowReader<R, T>> {
+        let mut header = [0u8; 8];
+        reader.read_exact(&mut header)?;
+        if &header[..4] != MAGIC {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "missing row format header",
+            ));
+        }
+        let width = u16::from_le_bytes([header[4], header[5]]);
+        Ok(RowReader {
+            reader,
+            width,
+            height: u16::from_le_bytes([header[6], header[7]]),
+            next_row: 0,
+            buffer: vec![0; width as usize * T::SIZE],
+            marker: PhantomData,
+        })
+    }
+
+    /// Returns the width of the stored grid.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Returns the height of the stored grid.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Returns the index of the row that will be read next.
+    pub fn next_row(&self) -> u16 {
+        self.next_row
+    }
+
+    /// Reads the next row into `row`, which must be `width` cells long.
+    ///
+    /// Returns `Ok(false)` once every row has been read.
+    pub fn read_row_into(&mut self, row: &mut [T]) -> io::Result<bool> {
+        if row.len() != self.width as usize {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "row buffer has {} cells, expected {}",
+                    row.len(),
+                    self.width
+                ),
+            ));
+        }
+        if self.next_row == self.height {
+            return Ok(false);
+        }
+        self.reader.read_exact(&mut self.buffer)?;
+        for (i, (cell, bytes)) in row
+            .iter_mut()
+            .zip(self.buffer.chunks_exact(T::SIZE))
+            .enumerate()
+        {
+            *cell = T::decode(bytes).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid cell value at ({}, {})", i, self.next_row),
+                )
+            })?;
+        }
+        self.next_row += 1;
+        Ok(true)
+    }
+
+    /// Reads the next row, or returns `None` once every row has been read.
+    pub fn read_row(&mut self) -> io::Result<Option<Vec<T>>> {
+        let mut row = vec![T::default(); self.width as usize];
+        Ok(self.read_row_into(&mut row)?.then_some(row))
+    }
+
+    /// Skips up to `count` rows without decoding them.
+    pub fn skip_rows(&mut self, count: u16) -> io::Result<()> {
+        let count = count.min(self.height - self.next_row);
+        for _ in 0..count {
+            self.reader.read_exact(&mut self.buffer)?;
+            self.next_row += 1;
+        }
+        Ok(())
+    }
+
+    /// Reads up to `rows` rows as a horizontal band of the grid.
+    ///
+    /// The band is shorter than requested if the end of the grid is reached.
+    pub fn read_band(&mut self, rows: u16) -> io::Result<Grid<T>> {
+        let rows = rows.min(self.height - self.next_row);
+        let mut band = Grid::new(self.width, rows);
+        for y in 0..rows {
+            let start = y as usize * self.width as usize;
+            self.read_row_into(&mut band.tiles[start..start + self.width as usize])?;
+        }
+        Ok(band)
+    }
+}
@@ -0,0 +1,126 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use crate::Grid;
+
+const FIELDS: &[&str] = &["width", "height", "tiles"];
+
+/// Serializes as a struct with `width`, `height` and the row-major `tiles`.
+impl<T: Serialize> Serialize for Grid<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Grid", 3)?;
+        state.serialize_field("width", &self.width)?;
+        state.serialize_field("height", &self.height)?;
+        state.serialize_field("tiles", &self.tiles)?;
+        state.end()
+    }
+}
+
+/// Deserializes from the form written by `Serialize`, rejecting input whose
+/// `tiles` length is not `width * height`.
+impl<'de, T> Deserialize<'de> for Grid<T>
+where
+    T: Deserialize<'de> + Default + Clone,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("Grid", FIELDS, GridVisitor(PhantomData))
+    }
+}
+
+enum Field {
+    Width,
+    Height,
+    Tiles,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl Visitor<'_> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("`width`, `height` or `tiles`")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                match value {
+                    "width" => Ok(Field::Width),
+                    "height" => Ok(Field::Height),
+                    "tiles" => Ok(Field::Tiles),
+                    other => Err(E::unknown_field(other, FIELDS)),
+                }
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Field, E> {
+                match value {
+                    0 => Ok(Field::Width),
+                    1 => Ok(Field::Height),
+                    2 => Ok(Field::Tiles),
+                    _ => Err(E::invalid_value(de::Unexpected::Unsigned(value), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct GridVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for GridVisitor<T>
+where
+    T: Deserialize<'de> + Default + Clone,
+{
+    type Value = Grid<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a grid with width, height and tiles")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Grid<T>, A::Error> {
+        let width = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let height = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let tiles = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        build(width, height, tiles)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Grid<T>, A::Error> {
+        let (mut width, mut height, mut tiles) = (None, None, None);
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Width if width.is_some() => return Err(de::Error::duplicate_field("width")),
+                Field::Height if height.is_some() => {
+                    return Err(de::Error::duplicate_field("height"));
+                }
+                Field::Tiles if tiles.is_some() => return Err(de::Error::duplicate_field("tiles")),
+                Field::Width => width = Some(map.next_value()?),
+                Field::Height => height = Some(map.next_value()?),
+                Field::Tiles => tiles = Some(map.next_value()?),
+            }
+        }
+        build(
+            width.ok_or_else(|| de::Error::missing_field("width"))?,
+            height.ok_or_else(|| de::Error::missing_field("height"))?,
+            tiles.ok_or_else(|| de::Error::missing_field("tiles"))?,
+        )
+    }
+}
+
+fn build<T: Default + Clone, E: de::Error>(
+    width: u16,
+    height: u16,
+    tiles: Vec<T>,
+) -> Result<Grid<T>, E> {
+    Grid::from_raw_parts(width, height, tiles).map_err(E::custom)
+}
@@ -0,0 +1,132 @@
+use crate::{Direction, Grid, Rect};
+
+impl<T: Default + Clone> Grid<T> {
+    /// Returns an iterator over the in-bounds cells within `r` of (cx, cy).
+    ///
+    /// A cell is inside when its squared Euclidean distance from the centre
+    /// is at most `r²`. Cells are yielded as `(x, y, &value)` in row-major
+    /// order, and only the disk's bounding box is scanned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let grid: Grid<u8> = Grid::new(20, 20);
+    /// assert_eq!(grid.iter_disk(10, 10, 2).count(), 13);
+    /// assert_eq!(grid.iter_disk(0, 0, 2).count(), 6);
+    /// ```
+    pub fn iter_disk(&self, cx: u16, cy: u16, r: u16) -> impl Iterator<Item = (u16, u16, &T)> + '_ {
+        let r2 = r as i64 * r as i64;
+        self.iter_around(cx, cy, r, move |dx, dy| dx * dx + dy * dy <= r2)
+    }
+
+    /// Returns an iterator over the in-bounds cells at distance `r` from (cx, cy).
+    ///
+    /// The ring holds the cells of [`Grid::iter_disk`] with radius `r` that
+    /// are not in the disk of radius `r - 1`, so the rings 0..=r partition
+    /// the disk of radius `r`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let grid: Grid<u8> = Grid::new(20, 20);
+    /// assert_eq!(grid.iter_ring(10, 10, 0).count(), 1);
+    /// assert_eq!(grid.iter_ring(10, 10, 1).count(), 4);
+    /// assert_eq!(grid.iter_ring(10, 10, 2).count(), 8);
+    /// ```
+    pub fn iter_ring(&self, cx: u16, cy: u16, r: u16) -> impl Iterator<Item = (u16, u16, &T)> + '_ {
+        let outer = r as i64 * r as i64;
+        let inner = if r == 0 {
+            -1
+        } else {
+            (r as i64 - 1) * (r as i64 - 1)
+        };
+        self.iter_around(cx, cy, r, move |dx, dy| {
+            let d2 = dx * dx + dy * dy;
+            d2 > inner && d2 <= outer
+        })
+    }
+
+    /// Returns an iterator over the in-bounds cells in a vision cone.
+    ///
+    /// A cell is in the cone when it lies within [`Grid::iter_disk`] of
+    /// `radius` and the angle between `direction` and the line from the
+    /// centre to the cell is at most `half_angle` radians. The centre cell
+    /// is always included.
+    ///
+    /// # Arguments
+    ///
+    /// * `cx`, `cy` - The apex of the cone
+    /// * `direction` - The direction the cone faces
+    /// * `radius` - How far the cone reaches
+    /// * `half_angle` - Half the opening angle, in radians
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Direction, Grid};
+    /// use std::f32::consts::FRAC_PI_4;
+    ///
+    /// let grid: Grid<u8> = Grid::new(20, 20);
+    /// let cone: Vec<_> = grid
+    ///     .iter_cone(10, 10, Direction::East, 3, FRAC_PI_4)
+    ///     .map(|(x, y, _)| (x, y))
+    ///     .collect();
+    /// assert!(cone.contains(&(13, 10)));
+    /// assert!(cone.contains(&(12, 12)));
+    /// assert!(!cone.contains(&(9, 10)));
+    /// assert!(!cone.contains(&(11, 12)));
+    /// ```
+    pub fn iter_cone(
+        &self,
+        cx: u16,
+        cy: u16,
+        direction: Direction,
+        radius: u16,
+        half_angle: f32,
+    ) -> impl Iterator<Item = (u16, u16, &T)> + '_ {
+        let r2 = radius as i64 * radius as i64;
+        let (fx, fy) = direction.offset();
+        let facing_len = ((fx * fx + fy * fy) as f64).sqrt();
+        // A small tolerance keeps cells exactly on the cone's edge inside.
+        let cos_limit = (half_angle as f64).cos() - 1e-9;
+        self.iter_around(cx, cy, radius, move |dx, dy| {
+            let d2 = dx * dx + dy * dy;
+            if d2 == 0 {
+                return true;
+            }
+            let dot = (dx * fx as i64 + dy * fy as i64) as f64;
+            d2 <= r2 && dot >= cos_limit * (d2 as f64).sqrt() * facing_len
+        })
+    }
+
+    /// Scans the clipped square of radius `r` around (cx, cy), keeping cells
+    /// whose offset passes `keep`.
+    fn iter_around<F>(
+        &self,
+        cx: u16,
+        cy: u16,
+        r: u16,
+        keep: F,
+    ) -> impl Iterator<Item = (u16, u16, &T)> + '_
+    where
+        F: Fn(i64, i64) -> bool + 'static,
+    {
+        let x0 = cx.saturating_sub(r);
+        let y0 = cy.saturating_sub(r);
+        let x1 = (cx as u32 + r as u32 + 1).min(self.width as u32);
+        let y1 = (cy as u32 + r as u32 + 1).min(self.height as u32);
+        let area = Rect::new(
+            x0,
+            y0,
+            (x1.saturating_sub(x0 as u32)) as u16,
+            (y1.saturating_sub(y0 as u32)) as u16,
+        );
+        area.cells()
+            .filter(move |&(x, y)| keep(x as i64 - cx as i64, y as i64 - cy as i64))
+            .map(move |(x, y)| (x, y, &self.tiles[self.index(x, y)]))
+    }
+}
@@ -0,0 +1,433 @@
+use crate::stencil::step_stencil;
+use crate::{
+    BitGrid, Boundary, CancellationToken, Grid, GridError, GridLike, GridLikeMut, GridRefMut,
+    Neighborhood, ProgressSink,
+};
+
+/// A cellular automaton running over a grid with a reusable double buffer.
+///
+/// Every step reads the previous generation only, so the update order never
+/// matters, and the two buffers are swapped rather than reallocated, so
+/// long runs do not touch the allocator. Neighbours outside the grid are
+/// resolved by a [`Boundary`] ([`Boundary::Skip`] unless set with
+/// [`Simulation::with_boundary`]). An optional mask restricts which cells
+/// the rule may change; cells outside it keep their value.
+///
+/// For a single step without keeping buffers around, use [`Grid::step`].
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{Boundary, Grid, Simulation};
+///
+/// // Cave smoothing: a cell becomes wall when most of its neighbours are.
+/// let mut noise: Grid<bool> = Grid::new(12, 12);
+/// noise.map_inplace(|x, y, wall| *wall = (x * 7 + y * 3) % 5 < 2);
+///
+/// let mut cave = Simulation::new(noise).with_boundary(Boundary::Constant(true));
+/// cave.run(4, |_, _, &wall, n| {
+///     let walls = n.count(|&w| w);
+///     walls >= 5 || (wall && walls >= 4)
+/// });
+///
+/// assert_eq!(cave.generation(), 4);
+/// assert_eq!(cave.grid().get(0, 0), Some(&true));
+/// ```
+pub struct Simulation<T> {
+    grid: Grid<T>,
+    back: Vec<T>,
+    boundary: Boundary<T>,
+    mask: Option<BitGrid>,
+    generation: u64,
+}
+
+impl<T: Default + Clone> Simulation<T> {
+    /// Creates a simulation whose first generation is `grid`.
+    pub fn new(grid: Grid<T>) -> Simulation<T> {
+        Simulation {
+            back: grid.tiles.clone(),
+            grid,
+            boundary: Boundary::Skip,
+            mask: None,
+            generation: 0,
+        }
+    }
+
+    /// Creates a simulation whose first generation is a copy of any
+    /// [`GridLike`] storage, such as a view, a sparse window or a
+    /// [`TorusGrid`](crate::TorusGrid).
+    ///
+    /// Cells the storage does not return are `T::default()`. Use
+    /// [`Boundary::Wrap`] to keep a torus's topology.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Boundary, Simulation, TorusGrid};
+    ///
+    /// let mut ring: TorusGrid<bool> = TorusGrid::new(5, 1);
+    /// ring.set(0, 0, true).unwrap();
+    ///
+    /// let mut sim = Simulation::from_grid_like(&ring).with_boundary(Boundary::Wrap);
+    /// sim.step(|_, _, _, n| *n.get(-1, 0).unwrap());
+    /// assert_eq!(sim.grid().get(1, 0), Some(&true));
+    /// assert_eq!(sim.grid().get(0, 0), Some(&false));
+    /// ```
+    pub fn from_grid_like<G: GridLike<T>>(grid: &G) -> Simulation<T> {
+        let (width, height) = (grid.width(), grid.height());
+        let tiles = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| grid.get(x, y).cloned().unwrap_or_default())
+            .collect();
+        Simulation::new(Grid {
+            width,
+            height,
+            tiles,
+        })
+    }
+
+    /// Sets how neighbours outside the grid are resolved.
+    pub fn with_boundary(mut self, boundary: Boundary<T>) -> Simulation<T> {
+        self.boundary = boundary;
+        self
+    }
+
+    /// Restricts the rule to cells set in `mask`; the others keep their value.
+    ///
+    /// Returns [`GridError::DimensionMismatch`] if the mask is not the size of the grid.
+    pub fn with_mask(mut self, mask: BitGrid) -> Result<Simulation<T>, GridError> {
+        let (expected, found) = (
+            (self.grid.width, self.grid.height),
+            (mask.width(), mask.height()),
+        );
+        if expected != found {
+            return Err(GridError::DimensionMismatch { expected, found });
+        }
+        self.mask = Some(mask);
+        Ok(self)
+    }
+
+    /// Returns the current generation.
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    /// Returns the current generation mutably, for edits between steps.
+    ///
+    /// The view borrows the cells in place and cannot change the grid's
+    /// size, which must stay in step with the back buffer and the mask.
+    pub fn grid_mut(&mut self) -> GridRefMut<'_, T> {
+        GridRefMut::from(&mut self.grid)
+    }
+
+    /// Consumes the simulation, returning the current generation.
+    pub fn into_grid(self) -> Grid<T> {
+        self.grid
+    }
+
+    /// Returns the number of steps taken so far.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Advances every cell by one generation.
+    ///
+    /// `rule` receives each cell's coordinates, its current value and its
+    /// [`Neighborhood`], and returns the cell's next value.
+    pub fn step<F>(&mut self, rule: F)
+    where
+        F: Fn(u16, u16, &T, Neighborhood<'_, T>) -> T,
+    {
+        let size = (self.grid.width, self.grid.height);
+        let current = &self.grid.tiles;
+        let (boundary, mask) = (&self.boundary, self.mask.as_ref());
+        for (i, next) in self.back.iter_mut().enumerate() {
+            *next = next_value(current, size, i, boundary, mask, &rule);
+        }
+        self.swap();
+    }
+
+    /// Advances every cell by one generation using a stencil function.
+    ///
+    /// Like [`Grid::step_stencil`], but with the simulation's boundary and
+    /// mask, and writing into the reused back buffer instead of allocating
+    /// a new one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Boundary, Grid, Simulation};
+    ///
+    /// // Diffuse heat for many generations without reallocating.
+    /// let mut grid: Grid<f32> = Grid::new(5, 5);
+    /// let _ = grid.set(2, 2, 4.0);
+    /// let mut heat = Simulation::new(grid).with_boundary(Boundary::Constant(0.0));
+    /// heat.step_stencil(|_, _, n| n.von_neumann().sum::<f32>() / 4.0);
+    /// assert_eq!(heat.grid().get(2, 1), Some(&1.0));
+    /// ```
+    pub fn step_stencil<F>(&mut self, f: F)
+    where
+        F: Fn(u16, u16, Neighborhood<'_, T>) -> T,
+    {
+        self.step(|x, y, _, n| f(x, y, n));
+    }
+
+    /// Advances `generations` steps with the same rule.
+    pub fn run<F>(&mut self, generations: u32, rule: F)
+    where
+        F: Fn(u16, u16, &T, Neighborhood<'_, T>) -> T,
+    {
+        for _ in 0..generations {
+            self.step(&rule);
+        }
+    }
+
+    /// Advances `generations` steps with the same rule, reporting progress
+    /// and polling for cancellation between generations.
+    ///
+    /// Progress is reported to `progress` as the number of generations
+    /// completed out of `generations`. Cancellation is checked before each
+    /// step, so the grid always holds a whole generation.
+    ///
+    /// Returns [`GridError::Cancelled`] if the run was cancelled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{CancellationToken, Grid, Simulation};
+    ///
+    /// let mut erosion = Simulation::new(Grid::with_value(16, 16, 100u32));
+    /// let cancel = CancellationToken::new();
+    /// let stop_early = |done: usize, _: usize| {
+    ///     if done == 3 {
+    ///         cancel.cancel();
+    ///     }
+    /// };
+    /// let result = erosion.run_cancellable(50, |_, _, &h, _| h - 1, &stop_early, &cancel);
+    /// assert!(result.is_err());
+    /// assert_eq!(erosion.generation(), 3);
+    /// ```
+    pub fn run_cancellable<F, P>(
+        &mut self,
+        generations: u32,
+        rule: F,
+        progress: &P,
+        cancel: &CancellationToken,
+    ) -> Result<(), GridError>
+    where
+        F: Fn(u16, u16, &T, Neighborhood<'_, T>) -> T,
+        P: ProgressSink,
+    {
+        for done in 0..generations {
+            cancel.check()?;
+            self.step(&rule);
+            progress.report(done as usize + 1, generations as usize);
+        }
+        Ok(())
+    }
+
+    fn swap(&mut self) {
+        std::mem::swap(&mut self.grid.tiles, &mut self.back);
+        self.generation += 1;
+    }
+}
+
+impl<T: Default + Clone + Send + Sync> Simulation<T> {
+    /// Advances every cell by one generation, in parallel.
+    ///
+    /// Behaves exactly like [`Simulation::step`]; since every read goes to
+    /// the previous generation the result does not depend on scheduling.
+    pub fn par_step<F>(&mut self, rule: F)
+    where
+        F: Fn(u16, u16, &T, Neighborhood<'_, T>) -> T + Send + Sync,
+    {
+        use rayon::prelude::*;
+        let size = (self.grid.width, self.grid.height);
+        let current = &self.grid.tiles;
+        let (boundary, mask) = (&self.boundary, self.mask.as_ref());
+        self.back.par_iter_mut().enumerate().for_each(|(i, next)| {
+            *next = next_value(current, size, i, boundary, mask, &rule);
+        });
+        self.swap();
+    }
+
+    /// Advances every cell by one generation using a stencil function, in
+    /// parallel.
+    ///
+    /// Behaves exactly like [`Simulation::step_stencil`].
+    pub fn par_step_stencil<F>(&mut self, f: F)
+    where
+        F: Fn(u16, u16, Neighborhood<'_, T>) -> T + Send + Sync,
+    {
+        self.par_step(|x, y, _, n| f(x, y, n));
+    }
+}
+
+/// Computes the next value of cell `i`, leaving cells outside `mask` unchanged.
+fn next_value<T: Clone>(
+    current: &[T],
+    (width, height): (u16, u16),
+    i: usize,
+    boundary: &Boundary<T>,
+    mask: Option<&BitGrid>,
+    rule: &impl Fn(u16, u16, &T, Neighborhood<'_, T>) -> T,
+) -> T {
+    let (x, y) = ((i % width as usize) as u16, (i / width as usize) as u16);
+    if mask.is_some_and(|mask| mask.get(x, y) != Some(true)) {
+        return current[i].clone();
+    }
+    let neighborhood = Neighborhood::new(current, (width, height), (x, y), boundary);
+    rule(x, y, &current[i], neighborhood)
+}
+
+/// Advances every cell of any writable grid by one cellular-automaton
+/// generation.
+///
+/// The generic counterpart of [`Grid::step`], with neighbours outside the
+/// grid resolved by `boundary`; pass [`Boundary::Wrap`] for a
+/// [`TorusGrid`](crate::TorusGrid). See [`step_stencil`] for how the
+/// generations are buffered and which errors are returned.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::simulation::step;
+/// use gridsystem::{Boundary, Neighborhood, TorusGrid};
+///
+/// // A blinker straddling the seam of a torus.
+/// let mut life: TorusGrid<bool> = TorusGrid::new(5, 5);
+/// for x in -1..2 {
+///     life.set(x, 2, true).unwrap();
+/// }
+/// let conway = |_, _, &alive: &bool, n: Neighborhood<'_, bool>| {
+///     matches!((alive, n.count(|&c| c)), (true, 2) | (_, 3))
+/// };
+/// step(&mut life, &Boundary::Wrap, conway).unwrap();
+///
+/// assert_eq!(life.get(0, 1), Some(&true));
+/// assert_eq!(life.get(4, 2), Some(&false));
+/// ```
+pub fn step<T, G, F>(grid: &mut G, boundary: &Boundary<T>, rule: F) -> Result<(), GridError>
+where
+    T: Clone,
+    G: GridLikeMut<T>,
+    F: Fn(u16, u16, &T, Neighborhood<'_, T>) -> T,
+{
+    step_stencil(grid, boundary, |x, y, n| rule(x, y, n.center(), n))
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Advances every cell by one cellular-automaton generation.
+    ///
+    /// `rule` receives each cell's coordinates, its current value and its
+    /// [`Neighborhood`] in the current generation, and returns the cell's
+    /// next value. Results go to a second buffer, so the whole step sees
+    /// the previous generation. Neighbours outside the grid are absent; use
+    /// [`Simulation`] for other boundaries, masks or many steps without
+    /// reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// // A blinker flips between horizontal and vertical.
+    /// let mut life: Grid<bool> = Grid::new(5, 5);
+    /// for x in 1..4 {
+    ///     let _ = life.set(x, 2, true);
+    /// }
+    /// let conway = |_, _, &alive: &bool, n: gridsystem::Neighborhood<'_, bool>| {
+    ///     matches!((alive, n.count(|&c| c)), (true, 2) | (_, 3))
+    /// };
+    /// life.step(conway);
+    ///
+    /// assert_eq!(life.get(2, 1), Some(&true));
+    /// assert_eq!(life.get(1, 2), Some(&false));
+    /// ```
+    pub fn step<F>(&mut self, rule: F)
+    where
+        F: Fn(u16, u16, &T, Neighborhood<'_, T>) -> T,
+    {
+        self.step_stencil(&Boundary::Skip, |x, y, n| rule(x, y, n.center(), n));
+    }
+}
+
+impl<T: Default + Clone + Send + Sync> Grid<T> {
+    /// Advances every cell by one cellular-automaton generation, in parallel.
+    ///
+    /// Behaves exactly like [`Grid::step`].
+    pub fn par_step<F>(&mut self, rule: F)
+    where
+        F: Fn(u16, u16, &T, Neighborhood<'_, T>) -> T + Send + Sync,
+    {
+        self.par_step_stencil(&Boundary::Skip, |x, y, n| rule(x, y, n.center(), n));
+    }
+
+    /// Updates every cell in two parallel half-steps: first the "red" cells
+    /// where `x + y` is even, then the "black" cells where it is odd.
+    ///
+    /// `f` receives each cell's coordinates and its [`Neighborhood`] and
+    /// returns the cell's new value. Orthogonal neighbours always have the
+    /// opposite colour, so red cells see the black values from before the
+    /// step and black cells see the red values just written, as in red-black
+    /// Gauss-Seidel relaxation. Cells of the colour being updated, such as
+    /// diagonal neighbours, read their values from before the half-step, so
+    /// the result never depends on scheduling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Boundary, Grid};
+    ///
+    /// // Relax towards the solution of Laplace's equation with a hot left edge.
+    /// let mut heat: Grid<f32> = Grid::new(6, 4);
+    /// let boundary = Boundary::Constant(0.0);
+    /// for _ in 0..200 {
+    ///     heat.par_update_checkerboard(&boundary, |x, _, n| {
+    ///         let left = if x == 0 { 100.0 } else { *n.get(-1, 0).unwrap() };
+    ///         (left + n.get(1, 0).unwrap() + n.get(0, -1).unwrap() + n.get(0, 1).unwrap()) / 4.0
+    ///     });
+    /// }
+    /// let (near, far) = (heat[(0, 1)], heat[(5, 1)]);
+    /// assert!(near > 50.0 && far < 5.0);
+    /// ```
+    pub fn par_update_checkerboard<F>(&mut self, boundary: &Boundary<T>, f: F)
+    where
+        F: Fn(u16, u16, Neighborhood<'_, T>) -> T + Send + Sync,
+    {
+        use rayon::prelude::*;
+        let (width, height) = (self.width, self.height);
+        if width == 0 {
+            return;
+        }
+        for colour in 0..2 {
+            let current = &self.tiles;
+            let updates: Vec<Vec<T>> = (0..height)
+                .into_par_iter()
+                .map(|y| {
+                    let first = (y + colour) % 2;
+                    (first..width)
+                        .step_by(2)
+                        .map(|x| {
+                            f(
+                                x,
+                                y,
+                                Neighborhood::new(current, (width, height), (x, y), boundary),
+                            )
+                        })
+                        .collect()
+                })
+                .collect();
+            self.tiles
+                .par_chunks_mut(width as usize)
+                .zip(updates)
+                .enumerate()
+                .for_each(|(y, (row, values))| {
+                    let first = (y + colour as usize) % 2;
+                    for (cell, value) in row.iter_mut().skip(first).step_by(2).zip(values) {
+                        *cell = value;
+                    }
+                });
+        }
+    }
+}
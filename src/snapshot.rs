@@ -0,0 +1,165 @@
+use std::fs;
+use std::path::Path;
+
+use crate::Grid;
+
+#[doc(hidden)]
+pub use insta as __insta;
+
+/// Environment variable that makes [`assert_snapshot`] rewrite stored
+/// snapshots instead of comparing against them.
+pub const UPDATE_VAR: &str = "GRIDSYSTEM_UPDATE_SNAPSHOTS";
+
+impl<T> Grid<T> {
+    /// Renders the grid as a snapshot: a `WIDTHxHEIGHT` header line
+    /// followed by one line per row, mapping each cell to a character with
+    /// `mapper`.
+    ///
+    /// The format is stable: every line, including the last, ends with
+    /// `\n`, nothing else is added or trimmed, and it changes only in a
+    /// breaking release. The header records the size even when the grid
+    /// has no cells. The string works as-is with `insta`'s
+    /// `assert_snapshot!` (see [`assert_grid_snapshot!`](crate::assert_grid_snapshot))
+    /// or with [`assert_snapshot`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut map: Grid<u8> = Grid::new(4, 2);
+    /// let _ = map.set(2, 1, 1);
+    /// let text = map.to_snapshot_string(|&v| if v == 0 { '.' } else { '#' });
+    /// assert_eq!(text, "4x2\n....\n..#.\n");
+    /// ```
+    pub fn to_snapshot_string<F>(&self, mapper: F) -> String
+    where
+        F: Fn(&T) -> char,
+    {
+        let mut out = format!("{}x{}\n", self.width, self.height);
+        out.push_str(&self.to_ascii(mapper));
+        out
+    }
+}
+
+/// Compares `actual` with the snapshot stored at `path`.
+///
+/// When the file does not exist yet, or the [`UPDATE_VAR`] environment
+/// variable is set, `actual` is written to `path` (creating parent
+/// directories) and the assertion passes, so the first run records the
+/// snapshot and later runs guard it. Line endings in the stored file are
+/// normalised, so checking snapshots out on Windows does not break them.
+///
+/// # Panics
+///
+/// Panics with a line-by-line diff if the snapshot differs, or if the file
+/// cannot be read or written.
+///
+/// # Examples
+///
+/// ```no_run
+/// use gridsystem::Grid;
+/// use gridsystem::snapshot::assert_snapshot;
+///
+/// let map: Grid<bool> = Grid::new(8, 8);
+/// let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots/empty_map.snap");
+/// assert_snapshot(path, &map.to_snapshot_string(|&wall| if wall { '#' } else { '.' }));
+/// ```
+pub fn assert_snapshot(path: impl AsRef<Path>, actual: &str) {
+    let path = path.as_ref();
+    if std::env::var_os(UPDATE_VAR).is_some() || !path.exists() {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .unwrap_or_else(|e| panic!("cannot create {}: {e}", parent.display()));
+        }
+        fs::write(path, actual).unwrap_or_else(|e| panic!("cannot write {}: {e}", path.display()));
+        return;
+    }
+    let expected = fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("cannot read {}: {e}", path.display()))
+        .replace("\r\n", "\n");
+    if let Some(diff) = snapshot_diff(&expected, actual) {
+        panic!(
+            "snapshot {} does not match (set {UPDATE_VAR}=1 to accept):\n{diff}",
+            path.display()
+        );
+    }
+}
+
+/// Returns a line-by-line diff of two snapshots, or `None` if they are
+/// equal.
+///
+/// Lines are compared by position, which suits grids: each differing row
+/// is shown as a `-` line from `expected` and a `+` line from `actual`,
+/// prefixed with its line number.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::snapshot::snapshot_diff;
+///
+/// assert_eq!(snapshot_diff("2x1\n..\n", "2x1\n..\n"), None);
+/// let diff = snapshot_diff("2x2\n..\n..\n", "2x2\n..\n.#\n").unwrap();
+/// assert_eq!(diff, "3 - ..\n3 + .#\n");
+/// ```
+pub fn snapshot_diff(expected: &str, actual: &str) -> Option<String> {
+    if expected == actual {
+        return None;
+    }
+    let expected: Vec<&str> = expected.split_inclusive('\n').collect();
+    let actual: Vec<&str> = actual.split_inclusive('\n').collect();
+    let mut diff = String::new();
+    for line in 0..expected.len().max(actual.len()) {
+        let (old, new) = (expected.get(line), actual.get(line));
+        if old == new {
+            continue;
+        }
+        for (sign, text) in [('-', old), ('+', new)] {
+            if let Some(text) = text {
+                match text.strip_suffix('\n') {
+                    Some(text) => diff.push_str(&format!("{} {sign} {text}\n", line + 1)),
+                    None => diff.push_str(&format!("{} {sign} {text} (no newline)\n", line + 1)),
+                }
+            }
+        }
+    }
+    Some(diff)
+}
+
+/// Renders a grid with [`Grid::to_snapshot_string`] and checks it with
+/// `insta::assert_snapshot!`.
+///
+/// Takes the grid and a cell-to-`char` mapper, optionally preceded by a
+/// snapshot name or followed by an inline `@"..."` snapshot. Snapshots
+/// are stored, reviewed and updated with the usual insta workflow
+/// (`cargo insta review`); the crate re-exports insta, so callers do not
+/// need their own dependency on it.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{Grid, assert_grid_snapshot};
+///
+/// let mut map: Grid<bool> = Grid::new(3, 2);
+/// let _ = map.set(1, 1, true);
+/// assert_grid_snapshot!(map, |&wall| if wall { '#' } else { '.' }, @r"
+/// 3x2
+/// ...
+/// .#.
+/// ");
+/// ```
+#[macro_export]
+macro_rules! assert_grid_snapshot {
+    ($grid:expr, $mapper:expr, @$snapshot:literal $(,)?) => {{
+        let grid_snapshot = $crate::Grid::to_snapshot_string(&$grid, $mapper);
+        $crate::snapshot::__insta::assert_snapshot!(grid_snapshot, @$snapshot)
+    }};
+    ($grid:expr, $mapper:expr $(,)?) => {{
+        let grid_snapshot = $crate::Grid::to_snapshot_string(&$grid, $mapper);
+        $crate::snapshot::__insta::assert_snapshot!(grid_snapshot)
+    }};
+    ($name:expr, $grid:expr, $mapper:expr $(,)?) => {{
+        let grid_snapshot = $crate::Grid::to_snapshot_string(&$grid, $mapper);
+        $crate::snapshot::__insta::assert_snapshot!($name, grid_snapshot)
+    }};
+}
@@ -0,0 +1,340 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use crate::points_csv::{read_points, write_points};
+use crate::{Grid, GridLike};
+
+/// An unbounded grid that stores only cells differing from `T::default()`.
+///
+/// Coordinates are `i32` and may be negative, so a world can grow in any
+/// direction from its origin. Memory grows with the number of non-default
+/// cells rather than with the area covered: writing the default value
+/// removes a cell instead of storing it. Regions convert to and from dense
+/// [`Grid`]s with [`to_grid`](SparseGrid::to_grid) and
+/// [`from_grid`](SparseGrid::from_grid).
+///
+/// As a [`GridLike`], a sparse grid exposes the cells with non-negative
+/// coordinates, from the origin up to the furthest cell ever stored there
+/// (until [`clear`](SparseGrid::clear)), so algorithms allocating per-cell
+/// buffers stay bounded. Use [`window`](SparseGrid::window) for any other
+/// region.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::SparseGrid;
+///
+/// let mut world: SparseGrid<u8> = SparseGrid::new();
+/// world.set(-1_000_000, 250, 3);
+/// world.set(40, -7, 1);
+/// assert_eq!(world.get(-1_000_000, 250), &3);
+/// assert_eq!(world.get(0, 0), &0);
+/// assert_eq!(world.len(), 2);
+///
+/// // Writing the default value frees the cell.
+/// world.set(40, -7, 0);
+/// assert_eq!(world.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(i32, i32), T>,
+    default: T,
+    // Width and height of the non-negative region seen through `GridLike`.
+    extent: (u16, u16),
+}
+
+/// A bounded window into a [`SparseGrid`] with `u16` local coordinates.
+///
+/// Created by [`SparseGrid::window`]. (0, 0) is the window's top-left
+/// cell. Implements [`GridLike`], so pathfinding and other read-only
+/// algorithms can run on a region of a sparse world without densifying it.
+pub struct SparseWindow<'a, T> {
+    grid: &'a SparseGrid<T>,
+    x: i32,
+    y: i32,
+    width: u16,
+    height: u16,
+}
+
+impl<T: Default + Clone + PartialEq> SparseGrid<T> {
+    /// Creates a grid in which every cell holds `T::default()`.
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+            default: T::default(),
+            extent: (0, 0),
+        }
+    }
+
+    /// Returns the value at (x, y), which is `T::default()` unless it was
+    /// set to something else.
+    pub fn get(&self, x: i32, y: i32) -> &T {
+        self.cells.get(&(x, y)).unwrap_or(&self.default)
+    }
+
+    /// Sets the value at (x, y), returning the non-default value it
+    /// replaced, if any.
+    ///
+    /// Setting `T::default()` removes the cell from storage.
+    pub fn set(&mut self, x: i32, y: i32, value: T) -> Option<T> {
+        if value == self.default {
+            self.cells.remove(&(x, y))
+        } else {
+            if let (Ok(gx), Ok(gy)) = (u16::try_from(x), u16::try_from(y))
+                && gx < u16::MAX
+                && gy < u16::MAX
+            {
+                self.extent = (self.extent.0.max(gx + 1), self.extent.1.max(gy + 1));
+            }
+            self.cells.insert((x, y), value)
+        }
+    }
+
+    /// Resets (x, y) to `T::default()`, returning the value it held if it
+    /// was stored.
+    pub fn remove(&mut self, x: i32, y: i32) -> Option<T> {
+        self.cells.remove(&(x, y))
+    }
+
+    /// Returns the number of stored (non-default) cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns `true` if every cell holds `T::default()`.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Resets every cell to `T::default()`.
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.extent = (0, 0);
+    }
+
+    /// Returns an iterator over the stored cells with their coordinates, in
+    /// no particular order.
+    ///
+    /// Each item is a tuple of (x, y, &T).
+    pub fn iter(&self) -> impl Iterator<Item = (i32, i32, &T)> + '_ {
+        self.cells.iter().map(|(&(x, y), value)| (x, y, value))
+    }
+
+    /// Returns the smallest rectangle holding every stored cell as
+    /// `(min_x, min_y, max_x, max_y)`, inclusive, or `None` if the grid is
+    /// empty.
+    pub fn bounds(&self) -> Option<(i32, i32, i32, i32)> {
+        self.cells.keys().fold(None, |bounds, &(x, y)| {
+            let (x0, y0, x1, y1) = bounds.unwrap_or((x, y, x, y));
+            Some((x0.min(x), y0.min(y), x1.max(x), y1.max(y)))
+        })
+    }
+
+    /// Copies the `width × height` region whose top-left cell is (x, y)
+    /// into a dense grid.
+    ///
+    /// Visits whichever is smaller, the region or the stored cells, so a
+    /// small region of a busy world and a large region of an empty one are
+    /// both cheap. Cells beyond `i32::MAX` hold `T::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::SparseGrid;
+    ///
+    /// let mut world: SparseGrid<char> = SparseGrid::new();
+    /// world.set(-2, -1, '#');
+    /// world.set(0, 0, '@');
+    ///
+    /// let room = world.to_grid(-2, -1, 3, 2);
+    /// assert_eq!(room.as_slice(), &['#', '\0', '\0', '\0', '\0', '@']);
+    /// ```
+    pub fn to_grid(&self, x: i32, y: i32, width: u16, height: u16) -> Grid<T> {
+        let mut grid = Grid::new(width, height);
+        let area = width as usize * height as usize;
+        if area < self.cells.len() {
+            for (gx, gy, cell) in grid.iter_mut() {
+                if let Some(value) = offset(x, y, gx, gy).and_then(|p| self.cells.get(&p)) {
+                    *cell = value.clone();
+                }
+            }
+        } else {
+            for (&(cx, cy), value) in &self.cells {
+                if let Some((gx, gy)) = local(x, y, cx, cy, width, height) {
+                    let index = grid.index(gx, gy);
+                    grid.tiles[index] = value.clone();
+                }
+            }
+        }
+        grid
+    }
+
+    /// Creates a sparse grid holding the non-default cells of `grid`, with
+    /// its top-left cell placed at (x, y).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Grid, SparseGrid};
+    ///
+    /// let mut room: Grid<u8> = Grid::new(50, 50);
+    /// let _ = room.set(3, 4, 9);
+    ///
+    /// let world = SparseGrid::from_grid(&room, -100, -100);
+    /// assert_eq!(world.len(), 1);
+    /// assert_eq!(world.get(-97, -96), &9);
+    /// ```
+    pub fn from_grid(grid: &Grid<T>, x: i32, y: i32) -> SparseGrid<T> {
+        let mut sparse = SparseGrid::new();
+        sparse.paste(grid, x, y);
+        sparse
+    }
+
+    /// Writes every cell of `grid` into this one with its top-left cell at
+    /// (x, y), default cells included, so the region ends up matching
+    /// `grid` exactly.
+    ///
+    /// Cells that would land beyond `i32::MAX` are skipped.
+    pub fn paste(&mut self, grid: &Grid<T>, x: i32, y: i32) {
+        for (gx, gy, value) in grid.iter() {
+            if let Some((cx, cy)) = offset(x, y, gx, gy) {
+                self.set(cx, cy, value.clone());
+            }
+        }
+    }
+
+    /// Borrows the `width × height` region whose top-left cell is (x, y)
+    /// as a [`GridLike`] window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{GridLike, SparseGrid};
+    ///
+    /// let mut world: SparseGrid<bool> = SparseGrid::new();
+    /// world.set(-5, -5, true);
+    ///
+    /// let window = world.window(-6, -6, 4, 4);
+    /// assert_eq!(window.get(1, 1), Some(&true));
+    /// assert_eq!(window.get(0, 0), Some(&false));
+    /// assert_eq!(window.get(4, 0), None);
+    /// ```
+    pub fn window(&self, x: i32, y: i32, width: u16, height: u16) -> SparseWindow<'_, T> {
+        SparseWindow {
+            grid: self,
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for SparseGrid<T> {
+    fn eq(&self, other: &SparseGrid<T>) -> bool {
+        self.cells == other.cells && self.default == other.default
+    }
+}
+
+impl<T: Default + Clone + PartialEq> GridLike<T> for SparseGrid<T> {
+    fn width(&self) -> u16 {
+        self.extent.0
+    }
+
+    fn height(&self) -> u16 {
+        self.extent.1
+    }
+
+    fn get(&self, x: u16, y: u16) -> Option<&T> {
+        (x < self.extent.0 && y < self.extent.1).then(|| SparseGrid::get(self, x as i32, y as i32))
+    }
+}
+
+impl<T: Default + Clone + PartialEq> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid::new()
+    }
+}
+
+impl<T: Default + Clone + PartialEq + Display> SparseGrid<T> {
+    /// Writes every stored cell as an `x,y,value` line, ordered by row and
+    /// then column.
+    ///
+    /// Uses the same format as [`write_points_csv`](crate::write_points_csv)
+    /// with signed coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::SparseGrid;
+    ///
+    /// let mut world: SparseGrid<u32> = SparseGrid::new();
+    /// world.set(5, 2, 8);
+    /// world.set(-3, -4, 1);
+    ///
+    /// let mut out = Vec::new();
+    /// world.write_points_csv(&mut out).unwrap();
+    /// assert_eq!(String::from_utf8(out).unwrap(), "x,y,value\n-3,-4,1\n5,2,8\n");
+    /// ```
+    pub fn write_points_csv<W: Write>(&self, writer: W) -> io::Result<()> {
+        let mut points: Vec<_> = self.iter().collect();
+        points.sort_unstable_by_key(|&(x, y, _)| (y, x));
+        write_points(writer, points)
+    }
+}
+
+impl<T: Default + Clone + PartialEq + FromStr> SparseGrid<T> {
+    /// Builds a sparse grid from `x,y,value` lines with signed coordinates.
+    ///
+    /// If a cell is listed more than once the last line wins. Returns an
+    /// `InvalidData` error if a line does not parse.
+    pub fn read_points_csv<R: BufRead>(reader: R) -> io::Result<SparseGrid<T>> {
+        let mut sparse = SparseGrid::new();
+        for (x, y, value) in read_points(reader)? {
+            sparse.set(x, y, value);
+        }
+        Ok(sparse)
+    }
+}
+
+impl<T: Default + Clone + PartialEq> SparseWindow<'_, T> {
+    /// Returns the value at local (x, y), or `None` if it is outside the
+    /// window.
+    pub fn get(&self, x: u16, y: u16) -> Option<&T> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let (cx, cy) = offset(self.x, self.y, x, y)?;
+        Some(self.grid.get(cx, cy))
+    }
+}
+
+impl<T: Default + Clone + PartialEq> GridLike<T> for SparseWindow<'_, T> {
+    fn width(&self) -> u16 {
+        self.width
+    }
+
+    fn height(&self) -> u16 {
+        self.height
+    }
+
+    fn get(&self, x: u16, y: u16) -> Option<&T> {
+        SparseWindow::get(self, x, y)
+    }
+}
+
+/// Sparse coordinates of local (x, y) in a region anchored at (ox, oy).
+fn offset(ox: i32, oy: i32, x: u16, y: u16) -> Option<(i32, i32)> {
+    Some((ox.checked_add(x as i32)?, oy.checked_add(y as i32)?))
+}
+
+/// Local coordinates of sparse (x, y) in a `width × height` region anchored
+/// at (ox, oy), if it lies inside.
+fn local(ox: i32, oy: i32, x: i32, y: i32, width: u16, height: u16) -> Option<(u16, u16)> {
+    let dx = x as i64 - ox as i64;
+    let dy = y as i64 - oy as i64;
+    let inside = (0..width as i64).contains(&dx) && (0..height as i64).contains(&dy);
+    inside.then_some((dx as u16, dy as u16))
+}
@@ -0,0 +1,146 @@
+use crate::{Axis, Grid, GridError};
+
+impl<T> Grid<T> {
+    /// Splits the grid along a vertical line into columns `0..at` and `at..width`.
+    ///
+    /// Cells are moved, not cloned. Returns [`GridError::ColumnOutOfBounds`]
+    /// if `at` is greater than the width; `at` equal to 0 or the width
+    /// yields an empty half.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let grid = Grid::from_raw_parts(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// let (left, right) = grid.split_vertically(1).unwrap();
+    /// assert_eq!(left.as_slice(), &[1, 4]);
+    /// assert_eq!(right.as_slice(), &[2, 3, 5, 6]);
+    /// ```
+    pub fn split_vertically(self, at: u16) -> Result<(Grid<T>, Grid<T>), GridError> {
+        if at > self.width {
+            return Err(GridError::ColumnOutOfBounds {
+                x: at,
+                width: self.width,
+                height: self.height,
+            });
+        }
+        let (width, height) = (self.width, self.height);
+        let mut left = Vec::with_capacity(at as usize * height as usize);
+        let mut right = Vec::with_capacity((width - at) as usize * height as usize);
+        let mut cells = self.tiles.into_iter();
+        for _ in 0..height {
+            left.extend(cells.by_ref().take(at as usize));
+            right.extend(cells.by_ref().take((width - at) as usize));
+        }
+        Ok((
+            Grid {
+                width: at,
+                height,
+                tiles: left,
+            },
+            Grid {
+                width: width - at,
+                height,
+                tiles: right,
+            },
+        ))
+    }
+
+    /// Splits the grid along a horizontal line into rows `0..at` and `at..height`.
+    ///
+    /// The bottom rows are moved into a new allocation in one block and the
+    /// top half keeps the original one. Returns
+    /// [`GridError::RowOutOfBounds`] if `at` is greater than the height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let grid = Grid::from_raw_parts(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// let (top, bottom) = grid.split_horizontally(2).unwrap();
+    /// assert_eq!(top.as_slice(), &[1, 2, 3, 4]);
+    /// assert_eq!(bottom.as_slice(), &[5, 6]);
+    /// ```
+    pub fn split_horizontally(mut self, at: u16) -> Result<(Grid<T>, Grid<T>), GridError> {
+        if at > self.height {
+            return Err(GridError::RowOutOfBounds {
+                y: at,
+                width: self.width,
+                height: self.height,
+            });
+        }
+        let bottom = self.tiles.split_off(at as usize * self.width as usize);
+        let (width, height) = (self.width, self.height);
+        self.height = at;
+        Ok((
+            self,
+            Grid {
+                width,
+                height: height - at,
+                tiles: bottom,
+            },
+        ))
+    }
+
+    /// Joins two grids back together, undoing a split along `axis`.
+    ///
+    /// With [`Axis::Vertical`] `second` is placed to the right of `first`
+    /// and their heights must match; with [`Axis::Horizontal`] it is placed
+    /// below and their widths must match. Cells are moved, not cloned.
+    ///
+    /// Returns [`GridError::InvalidArgument`] if the sizes do not line up or
+    /// the joined grid would be wider or taller than `u16::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Axis, Grid};
+    ///
+    /// let grid = Grid::from_raw_parts(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// let (left, right) = grid.split_vertically(2).unwrap();
+    /// let joined = Grid::join(left, right, Axis::Vertical).unwrap();
+    /// assert_eq!(joined.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn join(first: Grid<T>, second: Grid<T>, axis: Axis) -> Result<Grid<T>, GridError> {
+        let mismatch = || {
+            GridError::InvalidArgument(format!(
+                "Cannot join {}x{} and {}x{} grids along {:?} axis",
+                first.width, first.height, second.width, second.height, axis
+            ))
+        };
+        match axis {
+            Axis::Vertical => {
+                let width = first.width.checked_add(second.width);
+                let (Some(width), true) = (width, first.height == second.height) else {
+                    return Err(mismatch());
+                };
+                let mut tiles = Vec::with_capacity(first.tiles.len() + second.tiles.len());
+                let (mut a, mut b) = (first.tiles.into_iter(), second.tiles.into_iter());
+                for _ in 0..first.height {
+                    tiles.extend(a.by_ref().take(first.width as usize));
+                    tiles.extend(b.by_ref().take(second.width as usize));
+                }
+                Ok(Grid {
+                    width,
+                    height: first.height,
+                    tiles,
+                })
+            }
+            Axis::Horizontal => {
+                let height = first.height.checked_add(second.height);
+                let (Some(height), true) = (height, first.width == second.width) else {
+                    return Err(mismatch());
+                };
+                let mut tiles = first.tiles;
+                tiles.extend(second.tiles);
+                Ok(Grid {
+                    width: first.width,
+                    height,
+                    tiles,
+                })
+            }
+        }
+    }
+}
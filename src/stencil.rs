@@ -0,0 +1,356 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{CancellationToken, Direction, Grid, GridError, GridLikeMut, ProgressSink};
+
+/// How neighbours that fall outside the grid are resolved.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Boundary<T> {
+    /// Out-of-bounds neighbours are absent.
+    Skip,
+    /// Out-of-bounds neighbours read the nearest edge cell.
+    Clamp,
+    /// Out-of-bounds neighbours read from the opposite edge (torus topology).
+    Wrap,
+    /// Out-of-bounds neighbours read the given value.
+    Constant(T),
+}
+
+/// A read-only window onto a cell and its surroundings in the previous generation.
+///
+/// Handed to stencil callbacks by [`Grid::step_stencil`] and
+/// [`Grid::par_step_stencil`]. All reads go to the frozen buffer, so the
+/// update order never affects the result.
+pub struct Neighborhood<'a, T> {
+    tiles: &'a [T],
+    width: u16,
+    height: u16,
+    x: u16,
+    y: u16,
+    boundary: &'a Boundary<T>,
+}
+
+impl<'a, T> Neighborhood<'a, T> {
+    /// Creates the neighbourhood of cell (x, y) in a row-major buffer.
+    pub(crate) fn new(
+        tiles: &'a [T],
+        (width, height): (u16, u16),
+        (x, y): (u16, u16),
+        boundary: &'a Boundary<T>,
+    ) -> Neighborhood<'a, T> {
+        Neighborhood {
+            tiles,
+            width,
+            height,
+            x,
+            y,
+            boundary,
+        }
+    }
+
+    /// Returns the x coordinate of the center cell.
+    pub fn x(&self) -> u16 {
+        self.x
+    }
+
+    /// Returns the y coordinate of the center cell.
+    pub fn y(&self) -> u16 {
+        self.y
+    }
+
+    /// Returns the center cell.
+    pub fn center(&self) -> &'a T {
+        &self.tiles[(self.y as usize) * (self.width as usize) + (self.x as usize)]
+    }
+
+    /// Returns the cell at offset (dx, dy) from the center, resolved by the boundary policy.
+    ///
+    /// Returns `None` only for out-of-bounds cells under [`Boundary::Skip`].
+    pub fn get(&self, dx: i32, dy: i32) -> Option<&'a T> {
+        let width = self.width as i64;
+        let height = self.height as i64;
+        let x = self.x as i64 + dx as i64;
+        let y = self.y as i64 + dy as i64;
+        let (x, y) = if x >= 0 && y >= 0 && x < width && y < height {
+            (x, y)
+        } else {
+            match self.boundary {
+                Boundary::Skip => return None,
+                Boundary::Clamp => (x.clamp(0, width - 1), y.clamp(0, height - 1)),
+                Boundary::Wrap => (x.rem_euclid(width), y.rem_euclid(height)),
+                Boundary::Constant(value) => return Some(value),
+            }
+        };
+        Some(&self.tiles[(y as usize) * (self.width as usize) + (x as usize)])
+    }
+
+    /// Returns the neighbour one step away in the given direction.
+    pub fn dir(&self, direction: Direction) -> Option<&'a T> {
+        let (dx, dy) = direction.offset();
+        self.get(dx, dy)
+    }
+
+    /// Returns an iterator over the eight surrounding cells (Moore neighbourhood).
+    pub fn moore(&self) -> impl Iterator<Item = &'a T> + '_ {
+        Direction::ALL.into_iter().filter_map(|d| self.dir(d))
+    }
+
+    /// Returns an iterator over the four orthogonal neighbours (von Neumann neighbourhood).
+    pub fn von_neumann(&self) -> impl Iterator<Item = &'a T> + '_ {
+        Direction::CARDINAL.into_iter().filter_map(|d| self.dir(d))
+    }
+
+    /// Counts the Moore neighbours matching the predicate.
+    pub fn count<F>(&self, predicate: F) -> usize
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.moore().filter(|cell| predicate(cell)).count()
+    }
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Advances every cell by one generation using a stencil function.
+    ///
+    /// The callback receives each cell's coordinates and its [`Neighborhood`]
+    /// in the current generation and returns the cell's next value. Results
+    /// are written to a second buffer that replaces the grid's storage once
+    /// every cell has been computed. That buffer is allocated on every call;
+    /// to step many generations with two buffers that are swapped rather
+    /// than reallocated, use [`Simulation::step_stencil`](crate::Simulation::step_stencil).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Boundary, Grid};
+    ///
+    /// // Diffuse heat: each cell becomes the average of its orthogonal neighbours.
+    /// let mut grid: Grid<f32> = Grid::new(5, 5);
+    /// let _ = grid.set(2, 2, 4.0);
+    /// grid.step_stencil(&Boundary::Constant(0.0), |_, _, n| {
+    ///     n.von_neumann().sum::<f32>() / 4.0
+    /// });
+    /// assert_eq!(grid.get(2, 2), Some(&0.0));
+    /// assert_eq!(grid.get(2, 1), Some(&1.0));
+    /// ```
+    pub fn step_stencil<F>(&mut self, boundary: &Boundary<T>, f: F)
+    where
+        F: Fn(u16, u16, Neighborhood<'_, T>) -> T,
+    {
+        let size = (self.width, self.height);
+        let current = &self.tiles;
+        let next: Vec<T> = (0..current.len())
+            .map(|i| stencil_value(current, size, i, boundary, &f))
+            .collect();
+        self.tiles = next;
+    }
+
+    /// Advances every cell by one generation like [`Grid::step_stencil`],
+    /// reporting progress and polling for cancellation row by row.
+    ///
+    /// Meant for expensive passes such as erosion or large convolution
+    /// kernels on big maps. Progress is reported to `progress` as rows
+    /// finish, and `cancel` is polled before each row; a cancelled pass
+    /// leaves the grid unchanged.
+    ///
+    /// Returns [`GridError::Cancelled`] if the pass was cancelled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Boundary, CancellationToken, Grid};
+    ///
+    /// let mut grid: Grid<f32> = Grid::new(64, 64);
+    /// let _ = grid.set(32, 32, 9.0);
+    /// let blur = |_, _, n: gridsystem::Neighborhood<'_, f32>| n.moore().sum::<f32>() / 8.0;
+    ///
+    /// let cancel = CancellationToken::new();
+    /// grid.step_stencil_cancellable(&Boundary::Clamp, blur, &(), &cancel).unwrap();
+    /// assert_eq!(grid.get(31, 31), Some(&1.125));
+    ///
+    /// cancel.cancel();
+    /// assert!(grid.step_stencil_cancellable(&Boundary::Clamp, blur, &(), &cancel).is_err());
+    /// assert_eq!(grid.get(31, 31), Some(&1.125));
+    /// ```
+    pub fn step_stencil_cancellable<F, P>(
+        &mut self,
+        boundary: &Boundary<T>,
+        f: F,
+        progress: &P,
+        cancel: &CancellationToken,
+    ) -> Result<(), GridError>
+    where
+        F: Fn(u16, u16, Neighborhood<'_, T>) -> T,
+        P: ProgressSink,
+    {
+        let (width, height) = (self.width, self.height);
+        let current = &self.tiles;
+        let mut next = Vec::with_capacity(current.len());
+        for y in 0..height {
+            cancel.check()?;
+            next.extend((0..width).map(|x| {
+                f(
+                    x,
+                    y,
+                    Neighborhood::new(current, (width, height), (x, y), boundary),
+                )
+            }));
+            progress.report(y as usize + 1, height as usize);
+        }
+        self.tiles = next;
+        Ok(())
+    }
+}
+
+impl<T: Default + Clone + Send + Sync> Grid<T> {
+    /// Advances every cell by one generation using a stencil function, in parallel.
+    ///
+    /// Behaves exactly like [`Grid::step_stencil`]; since every read goes to
+    /// the previous generation the result does not depend on scheduling.
+    /// [`Simulation::par_step_stencil`](crate::Simulation::par_step_stencil)
+    /// reuses its buffers across generations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Boundary, Grid};
+    ///
+    /// // Majority smoothing of a noisy cave map.
+    /// let mut grid: Grid<bool> = Grid::new(8, 8);
+    /// let _ = grid.set(4, 4, true);
+    /// grid.par_step_stencil(&Boundary::Constant(true), |_, _, n| n.count(|&wall| wall) >= 5);
+    /// assert_eq!(grid.get(4, 4), Some(&false));
+    /// assert_eq!(grid.get(0, 0), Some(&true));
+    /// ```
+    pub fn par_step_stencil<F>(&mut self, boundary: &Boundary<T>, f: F)
+    where
+        F: Fn(u16, u16, Neighborhood<'_, T>) -> T + Send + Sync,
+    {
+        use rayon::prelude::*;
+        let size = (self.width, self.height);
+        let current = &self.tiles;
+        let next: Vec<T> = (0..current.len())
+            .into_par_iter()
+            .map(|i| stencil_value(current, size, i, boundary, &f))
+            .collect();
+        self.tiles = next;
+    }
+
+    /// Advances every cell by one generation like [`Grid::par_step_stencil`],
+    /// reporting progress and polling for cancellation row by row.
+    ///
+    /// Behaves like [`Grid::step_stencil_cancellable`], with rows computed
+    /// in parallel; `progress` may be called from several threads.
+    ///
+    /// Returns [`GridError::Cancelled`] if the pass was cancelled.
+    pub fn par_step_stencil_cancellable<F, P>(
+        &mut self,
+        boundary: &Boundary<T>,
+        f: F,
+        progress: &P,
+        cancel: &CancellationToken,
+    ) -> Result<(), GridError>
+    where
+        F: Fn(u16, u16, Neighborhood<'_, T>) -> T + Send + Sync,
+        P: ProgressSink,
+    {
+        use rayon::prelude::*;
+        let (width, height) = (self.width, self.height);
+        let current = &self.tiles;
+        let done = AtomicUsize::new(0);
+        let rows: Vec<Vec<T>> = (0..height)
+            .into_par_iter()
+            .map(|y| {
+                cancel.check()?;
+                let row = (0..width)
+                    .map(|x| {
+                        f(
+                            x,
+                            y,
+                            Neighborhood::new(current, (width, height), (x, y), boundary),
+                        )
+                    })
+                    .collect();
+                progress.report(done.fetch_add(1, Ordering::Relaxed) + 1, height as usize);
+                Ok(row)
+            })
+            .collect::<Result<_, GridError>>()?;
+        self.tiles = rows.into_iter().flatten().collect();
+        Ok(())
+    }
+}
+
+/// Advances every cell of any writable grid by one generation using a
+/// stencil function.
+///
+/// The generic counterpart of [`Grid::step_stencil`]: the current cells are
+/// copied into a frozen buffer first, so every callback sees the previous
+/// generation, and the results are written back through
+/// [`GridLikeMut::set`]. Use [`Boundary::Wrap`] on a
+/// [`TorusGrid`](crate::TorusGrid).
+///
+/// Returns [`GridError::LengthMismatch`] if the grid does not return a cell
+/// for every coordinate inside its bounds, leaving it unchanged, or the
+/// first error from [`GridLikeMut::set`].
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::stencil::step_stencil;
+/// use gridsystem::{Boundary, Grid, TorusGrid};
+///
+/// // Heat spreads across the seam of a torus.
+/// let mut ring: TorusGrid<u32> = TorusGrid::new(6, 1);
+/// ring.set(0, 0, 8).unwrap();
+/// step_stencil(&mut ring, &Boundary::Wrap, |_, _, n| n.get(-1, 0).unwrap() / 2 + n.get(1, 0).unwrap() / 2)
+///     .unwrap();
+/// assert_eq!(ring.get(5, 0), Some(&4));
+/// assert_eq!(ring.get(1, 0), Some(&4));
+///
+/// // Only the viewed region of a larger grid changes.
+/// let mut grid: Grid<u32> = Grid::with_value(6, 6, 1);
+/// let mut view = grid.view_mut(1, 1, 2, 2).unwrap();
+/// step_stencil(&mut view, &Boundary::Skip, |_, _, n| n.von_neumann().sum()).unwrap();
+/// assert_eq!(grid.get(1, 1), Some(&2));
+/// assert_eq!(grid.get(0, 0), Some(&1));
+/// ```
+pub fn step_stencil<T, G, F>(grid: &mut G, boundary: &Boundary<T>, f: F) -> Result<(), GridError>
+where
+    T: Clone,
+    G: GridLikeMut<T>,
+    F: Fn(u16, u16, Neighborhood<'_, T>) -> T,
+{
+    let (width, height) = (grid.width(), grid.height());
+    let current: Vec<T> = (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .filter_map(|(x, y)| grid.get(x, y).cloned())
+        .collect();
+    let expected = width as usize * height as usize;
+    if current.len() != expected {
+        return Err(GridError::LengthMismatch {
+            expected,
+            found: current.len(),
+        });
+    }
+    for i in 0..expected {
+        let value = stencil_value(&current, (width, height), i, boundary, &f);
+        let (x, y) = ((i % width as usize) as u16, (i / width as usize) as u16);
+        grid.set(x, y, value)?;
+    }
+    Ok(())
+}
+
+/// Computes the next value of cell `i` of a row-major `width × height` buffer.
+fn stencil_value<T>(
+    current: &[T],
+    (width, height): (u16, u16),
+    i: usize,
+    boundary: &Boundary<T>,
+    f: &impl Fn(u16, u16, Neighborhood<'_, T>) -> T,
+) -> T {
+    let (x, y) = ((i % width as usize) as u16, (i / width as usize) as u16);
+    f(
+        x,
+        y,
+        Neighborhood::new(current, (width, height), (x, y), boundary),
+    )
+}
@@ -0,0 +1,474 @@
+use std::collections::{HashMap, HashSet};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::sync::mpsc::{Receiver, Sender, channel};
+
+use crate::{Axis, Grid, GridError};
+
+/// Coordinates of a chunk in chunk space (not cell space).
+pub type ChunkCoord = (i32, i32);
+
+type Loader<T> = Arc<dyn Fn(ChunkCoord, u16) -> Grid<T> + Send + Sync>;
+type LoadHook<T> = Box<dyn FnMut(ChunkCoord, &Grid<T>)>;
+type UnloadHook<T> = Box<dyn FnMut(ChunkCoord, Grid<T>)>;
+type LoadResult<T> = (ChunkCoord, Result<Grid<T>, GridError>);
+
+/// Keeps the chunks around a moving viewer resident, loading new chunks on
+/// background threads and evicting chunks that fall out of range.
+///
+/// Chunks are square `Grid<T>`s of `chunk_size × chunk_size` cells. The loader
+/// closure is the hook for the generation and persistence layers: it runs on
+/// the rayon thread pool and may read a chunk from disk or generate it from
+/// scratch. Evicted chunks are handed to the `on_unload` hook by value so they
+/// can be written back. A loader that panics or returns a chunk of the wrong
+/// size does not take the streamer down: the chunk stays unloaded and the
+/// error is kept for [`take_failures`](ChunkStreamer::take_failures).
+///
+/// # Type Parameters
+///
+/// * `T` - The cell type of each chunk. Must be `Send` so chunks can be built off-thread.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{ChunkStreamer, Grid};
+///
+/// let mut streamer = ChunkStreamer::new(16, 1, |(cx, cy), size| {
+///     Grid::with_value(size, size, cx + cy)
+/// });
+/// streamer.set_viewer(0, 0);
+/// streamer.wait_all();
+///
+/// assert_eq!(streamer.loaded_count(), 9);
+/// assert_eq!(streamer.get(20, -1), Some(&0));
+/// ```
+pub struct ChunkStreamer<T> {
+    chunk_size: u16,
+    radius: u32,
+    viewer: Option<ChunkCoord>,
+    chunks: HashMap<ChunkCoord, Grid<T>>,
+    pending: HashSet<ChunkCoord>,
+    failures: Vec<(ChunkCoord, GridError)>,
+    /// Seams already passed to `stitch_borders`, keyed by the west or north chunk.
+    stitched: HashSet<(ChunkCoord, Axis)>,
+    loader: Loader<T>,
+    on_load: Option<LoadHook<T>>,
+    on_unload: Option<UnloadHook<T>>,
+    sender: Sender<LoadResult<T>>,
+    receiver: Receiver<LoadResult<T>>,
+}
+
+impl<T: Default + Clone + Send + 'static> ChunkStreamer<T> {
+    /// Creates a new streamer with no viewer and no resident chunks.
+    ///
+    /// # Arguments
+    ///
+    /// * `chunk_size` - The width and height of every chunk in cells
+    /// * `radius` - How many chunks around the viewer's chunk stay resident
+    /// * `loader` - Produces the chunk at the given chunk coordinates; runs on a background thread
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn new<F>(chunk_size: u16, radius: u32, loader: F) -> ChunkStreamer<T>
+    where
+        F: Fn(ChunkCoord, u16) -> Grid<T> + Send + Sync + 'static,
+    {
+        assert!(chunk_size > 0, "chunk size must be non-zero");
+        let (sender, receiver) = channel();
+        ChunkStreamer {
+            chunk_size,
+            radius,
+            viewer: None,
+            chunks: HashMap::new(),
+            pending: HashSet::new(),
+            failures: Vec::new(),
+            stitched: HashSet::new(),
+            loader: Arc::new(loader),
+            on_load: None,
+            on_unload: None,
+            sender,
+            receiver,
+        }
+    }
+
+    /// Registers a hook invoked on the owning thread whenever a chunk becomes resident.
+    pub fn on_load<F>(mut self, hook: F) -> Self
+    where
+        F: FnMut(ChunkCoord, &Grid<T>) + 'static,
+    {
+        self.on_load = Some(Box::new(hook));
+        self
+    }
+
+    /// Registers a hook that receives every evicted chunk by value, e.g. to persist it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    /// use gridsystem::{ChunkStreamer, Grid};
+    ///
+    /// let saved = Rc::new(RefCell::new(Vec::new()));
+    /// let sink = Rc::clone(&saved);
+    /// let mut streamer = ChunkStreamer::new(8, 0, |_, size| Grid::<u8>::new(size, size))
+    ///     .on_unload(move |coord, _chunk| sink.borrow_mut().push(coord));
+    ///
+    /// streamer.set_viewer(0, 0);
+    /// streamer.wait_all();
+    /// streamer.set_viewer(100, 0);
+    /// assert_eq!(*saved.borrow(), vec![(0, 0)]);
+    /// ```
+    pub fn on_unload<F>(mut self, hook: F) -> Self
+    where
+        F: FnMut(ChunkCoord, Grid<T>) + 'static,
+    {
+        self.on_unload = Some(Box::new(hook));
+        self
+    }
+
+    /// Returns the width and height of every chunk in cells.
+    pub fn chunk_size(&self) -> u16 {
+        self.chunk_size
+    }
+
+    /// Returns the residency radius in chunks.
+    pub fn radius(&self) -> u32 {
+        self.radius
+    }
+
+    /// Converts a cell position into the coordinates of the chunk containing it.
+    pub fn chunk_of(&self, x: i32, y: i32) -> ChunkCoord {
+        let size = self.chunk_size as i32;
+        (x.div_euclid(size), y.div_euclid(size))
+    }
+
+    /// Moves the viewer to the given cell position.
+    ///
+    /// Chunks that left the residency square are evicted immediately, and loads
+    /// are requested for every chunk in range that is neither resident nor
+    /// already in flight. Completed loads are collected by [`poll`](Self::poll).
+    pub fn set_viewer(&mut self, x: i32, y: i32) {
+        let center = self.chunk_of(x, y);
+        if self.viewer == Some(center) {
+            return;
+        }
+        self.viewer = Some(center);
+
+        let evicted: Vec<ChunkCoord> = self
+            .chunks
+            .keys()
+            .copied()
+            .filter(|&coord| !self.in_range(coord))
+            .collect();
+        for coord in evicted {
+            forget_seams(&mut self.stitched, coord);
+            if let Some(chunk) = self.chunks.remove(&coord)
+                && let Some(hook) = self.on_unload.as_mut()
+            {
+                hook(coord, chunk);
+            }
+        }
+
+        // Widen to i64 so the square can be clipped at the ends of i32.
+        let r = self.radius as i64;
+        let span = |c: i32| {
+            let lo = (c as i64 - r).max(i32::MIN as i64) as i32;
+            let hi = (c as i64 + r).min(i32::MAX as i64) as i32;
+            lo..=hi
+        };
+        for cy in span(center.1) {
+            for cx in span(center.0) {
+                self.request((cx, cy));
+            }
+        }
+    }
+
+    /// Collects every chunk that finished loading since the last call without blocking.
+    ///
+    /// Returns the number of chunks that became resident. Chunks that finished
+    /// after the viewer moved away are dropped, and failed loads are recorded
+    /// for [`take_failures`](Self::take_failures).
+    pub fn poll(&mut self) -> usize {
+        let mut loaded = 0;
+        while let Ok((coord, result)) = self.receiver.try_recv() {
+            if self.accept(coord, result) {
+                loaded += 1;
+            }
+        }
+        loaded
+    }
+
+    /// Blocks until every requested chunk has finished loading.
+    ///
+    /// Returns the number of chunks that became resident. Every request
+    /// settles, even when its loader panics, so this always returns.
+    pub fn wait_all(&mut self) -> usize {
+        let mut loaded = 0;
+        while !self.pending.is_empty() {
+            let Ok((coord, result)) = self.receiver.recv() else {
+                break;
+            };
+            if self.accept(coord, result) {
+                loaded += 1;
+            }
+        }
+        loaded
+    }
+
+    /// Returns and clears the loads that failed since the last call.
+    ///
+    /// A load fails with [`GridError::Panicked`] if the loader panicked, or
+    /// [`GridError::DimensionMismatch`] if it returned a chunk that is not
+    /// `chunk_size × chunk_size`. A failed chunk is requested again once the
+    /// viewer moves to another chunk while it is still in range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{ChunkStreamer, Grid, GridError};
+    ///
+    /// let mut streamer = ChunkStreamer::new(4, 0, |_, _| Grid::<u8>::new(2, 2));
+    /// streamer.set_viewer(0, 0);
+    /// assert_eq!(streamer.wait_all(), 0);
+    /// assert!(matches!(
+    ///     streamer.take_failures().as_slice(),
+    ///     [((0, 0), GridError::DimensionMismatch { .. })]
+    /// ));
+    /// ```
+    pub fn take_failures(&mut self) -> Vec<(ChunkCoord, GridError)> {
+        std::mem::take(&mut self.failures)
+    }
+
+    /// Returns the resident chunk at the given chunk coordinates.
+    pub fn chunk(&self, coord: ChunkCoord) -> Option<&Grid<T>> {
+        self.chunks.get(&coord)
+    }
+
+    /// Returns a mutable reference to the resident chunk at the given chunk coordinates.
+    pub fn chunk_mut(&mut self, coord: ChunkCoord) -> Option<&mut Grid<T>> {
+        self.chunks.get_mut(&coord)
+    }
+
+    /// Gets the cell at the given world position if its chunk is resident.
+    pub fn get(&self, x: i32, y: i32) -> Option<&T> {
+        let size = self.chunk_size as i32;
+        self.chunks
+            .get(&self.chunk_of(x, y))?
+            .get(x.rem_euclid(size) as u16, y.rem_euclid(size) as u16)
+    }
+
+    /// Returns `true` if the chunk is resident.
+    pub fn is_loaded(&self, coord: ChunkCoord) -> bool {
+        self.chunks.contains_key(&coord)
+    }
+
+    /// Returns `true` if a load for the chunk is in flight.
+    pub fn is_pending(&self, coord: ChunkCoord) -> bool {
+        self.pending.contains(&coord)
+    }
+
+    /// Returns the number of resident chunks.
+    pub fn loaded_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Returns the number of loads in flight.
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns an iterator over all resident chunks and their coordinates.
+    pub fn loaded_chunks(&self) -> impl Iterator<Item = (ChunkCoord, &Grid<T>)> {
+        self.chunks.iter().map(|(&coord, chunk)| (coord, chunk))
+    }
+
+    /// Evicts every resident chunk through the `on_unload` hook, e.g. before shutdown.
+    pub fn unload_all(&mut self) {
+        self.stitched.clear();
+        for (coord, chunk) in self.chunks.drain() {
+            if let Some(hook) = self.on_unload.as_mut() {
+                hook(coord, chunk);
+            }
+        }
+    }
+
+    /// Reconciles the cells facing each other across chunk seams.
+    ///
+    /// Chunks are usually generated independently, so terrain rarely lines
+    /// up where two of them meet. For every seam between two resident,
+    /// edge-adjacent chunks that has not been stitched yet, `resolver` is
+    /// called once per facing cell pair with the west (or north) cell first
+    /// and the east (or south) cell second, and may rewrite either to hide
+    /// the seam. Seams are resolved in chunk coordinate order, so a cell at
+    /// a chunk corner always ends up with the same value. Each seam is
+    /// stitched once while both chunks stay resident; evicting either chunk
+    /// makes the seam eligible again after it reloads. Call this after
+    /// [`poll`](Self::poll) or [`wait_all`](Self::wait_all) to stitch newly
+    /// arrived chunks.
+    ///
+    /// Returns the number of seams stitched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{ChunkStreamer, Grid};
+    ///
+    /// let mut streamer = ChunkStreamer::new(4, 1, |(cx, cy), size| {
+    ///     Grid::with_value(size, size, (cx + cy * 3) as f32)
+    /// });
+    /// streamer.set_viewer(0, 0);
+    /// streamer.wait_all();
+    ///
+    /// // Blend the two heights facing each other across every seam.
+    /// let blend = |a: &mut f32, b: &mut f32| {
+    ///     let mean = (*a + *b) / 2.0;
+    ///     (*a, *b) = (mean, mean);
+    /// };
+    /// assert_eq!(streamer.stitch_borders(blend), 12);
+    /// assert_eq!(streamer.get(3, 1), streamer.get(4, 1));
+    /// assert_eq!(streamer.stitch_borders(blend), 0);
+    /// ```
+    pub fn stitch_borders<F>(&mut self, mut resolver: F) -> usize
+    where
+        F: FnMut(&mut T, &mut T),
+    {
+        stitch_seams(
+            &mut self.chunks,
+            &mut self.stitched,
+            self.chunk_size,
+            &mut resolver,
+        )
+    }
+
+    fn in_range(&self, (cx, cy): ChunkCoord) -> bool {
+        match self.viewer {
+            Some((vx, vy)) => {
+                let r = self.radius as i64;
+                (cx as i64 - vx as i64).abs() <= r && (cy as i64 - vy as i64).abs() <= r
+            }
+            None => false,
+        }
+    }
+
+    fn request(&mut self, coord: ChunkCoord) {
+        if self.chunks.contains_key(&coord) || !self.pending.insert(coord) {
+            return;
+        }
+        let loader = Arc::clone(&self.loader);
+        let sender = self.sender.clone();
+        let size = self.chunk_size;
+        rayon::spawn(move || {
+            let result = match panic::catch_unwind(AssertUnwindSafe(|| loader(coord, size))) {
+                Ok(chunk) if (chunk.width, chunk.height) == (size, size) => Ok(chunk),
+                Ok(chunk) => Err(GridError::DimensionMismatch {
+                    expected: (size, size),
+                    found: (chunk.width, chunk.height),
+                }),
+                Err(payload) => Err(GridError::Panicked(panic_message(payload.as_ref()))),
+            };
+            // The receiver only disappears when the streamer is dropped.
+            let _ = sender.send((coord, result));
+        });
+    }
+
+    fn accept(&mut self, coord: ChunkCoord, result: Result<Grid<T>, GridError>) -> bool {
+        self.pending.remove(&coord);
+        let chunk = match result {
+            Ok(chunk) => chunk,
+            Err(error) => {
+                self.failures.push((coord, error));
+                return false;
+            }
+        };
+        if !self.in_range(coord) {
+            return false;
+        }
+        if let Some(hook) = self.on_load.as_mut() {
+            hook(coord, &chunk);
+        }
+        self.chunks.insert(coord, chunk);
+        true
+    }
+}
+
+/// Extracts the message of a panic payload, as the default panic hook prints it.
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+/// Runs `resolver` over every seam between two edge-adjacent chunks that is
+/// not yet in `stitched`, then records it there. Seams are visited in chunk
+/// coordinate order, vertical before horizontal. Returns the number of seams
+/// stitched.
+pub(crate) fn stitch_seams<T: Default + Clone, F>(
+    chunks: &mut HashMap<ChunkCoord, Grid<T>>,
+    stitched: &mut HashSet<(ChunkCoord, Axis)>,
+    chunk_size: u16,
+    resolver: &mut F,
+) -> usize
+where
+    F: FnMut(&mut T, &mut T),
+{
+    let mut seams: Vec<(ChunkCoord, Axis)> = Vec::new();
+    for &coord in chunks.keys() {
+        for axis in [Axis::Vertical, Axis::Horizontal] {
+            let has_other = neighbour(coord, axis).is_some_and(|other| chunks.contains_key(&other));
+            if has_other && !stitched.contains(&(coord, axis)) {
+                seams.push((coord, axis));
+            }
+        }
+    }
+    // Seams meeting at a chunk corner share a cell, so resolve them in a
+    // fixed order rather than the map's iteration order.
+    seams.sort_by_key(|&(coord, axis)| (coord, axis == Axis::Horizontal));
+    let last = chunk_size - 1;
+    for &(coord, axis) in &seams {
+        let Some(other) = neighbour(coord, axis) else {
+            continue;
+        };
+        let [Some(first), Some(second)] = chunks.get_disjoint_mut([&coord, &other]) else {
+            continue;
+        };
+        for i in 0..chunk_size {
+            let (a, b) = match axis {
+                Axis::Vertical => ((last, i), (0, i)),
+                Axis::Horizontal => ((i, last), (i, 0)),
+            };
+            if let (Some(a), Some(b)) = (first.get_mut(a.0, a.1), second.get_mut(b.0, b.1)) {
+                resolver(a, b);
+            }
+        }
+        stitched.insert((coord, axis));
+    }
+    seams.len()
+}
+
+/// Returns the chunk east of (`Vertical`) or south of (`Horizontal`)
+/// `coord`, or `None` past the end of chunk space.
+fn neighbour((cx, cy): ChunkCoord, axis: Axis) -> Option<ChunkCoord> {
+    match axis {
+        Axis::Vertical => Some((cx.checked_add(1)?, cy)),
+        Axis::Horizontal => Some((cx, cy.checked_add(1)?)),
+    }
+}
+
+/// Makes the four seams touching `coord` eligible for stitching again.
+pub(crate) fn forget_seams(stitched: &mut HashSet<(ChunkCoord, Axis)>, (cx, cy): ChunkCoord) {
+    for key in [
+        ((cx, cy), Axis::Vertical),
+        ((cx, cy), Axis::Horizontal),
+        // Wrapping past the end names a seam that can never be stitched.
+        ((cx.wrapping_sub(1), cy), Axis::Vertical),
+        ((cx, cy.wrapping_sub(1)), Axis::Horizontal),
+    ] {
+        stitched.remove(&key);
+    }
+}
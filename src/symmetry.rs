@@ -0,0 +1,113 @@
+use crate::{Grid, GridError};
+
+/// A mirror line through the centre of a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// A vertical line: the left half is reflected onto the right half.
+    Vertical,
+    /// A horizontal line: the top half is reflected onto the bottom half.
+    Horizontal,
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Makes the grid mirror-symmetric by reflecting one half onto the other.
+    ///
+    /// With [`Axis::Vertical`] the left half overwrites the right half, and
+    /// with [`Axis::Horizontal`] the top half overwrites the bottom half. On
+    /// an odd-sized grid the middle column or row lies on the axis and is
+    /// left as it is. Apply both axes for four-way symmetry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Axis, Grid};
+    ///
+    /// let mut map = Grid::from_raw_parts(5, 1, vec!['a', 'b', 'c', 'x', 'y']).unwrap();
+    /// map.mirror_into(Axis::Vertical);
+    /// assert_eq!(map.as_slice(), &['a', 'b', 'c', 'b', 'a']);
+    /// ```
+    pub fn mirror_into(&mut self, axis: Axis) {
+        let (width, height) = (self.width, self.height);
+        for y in 0..height {
+            for x in 0..width {
+                let (tx, ty) = match axis {
+                    Axis::Vertical if x < width / 2 => (width - 1 - x, y),
+                    Axis::Horizontal if y < height / 2 => (x, height - 1 - y),
+                    _ => continue,
+                };
+                let (target, source) = (self.index(tx, ty), self.index(x, y));
+                self.tiles[target] = self.tiles[source].clone();
+            }
+        }
+    }
+
+    /// Gives the grid `n`-fold rotational symmetry about its centre.
+    ///
+    /// One `1/n` sector of the grid is copied onto the others. For `n = 2`
+    /// the first half in row-major order is turned 180° onto the second
+    /// half, which works for any size. For `n = 4` the top-left quarter
+    /// (a pinwheel quarter on odd sizes) is turned through 90°, 180° and
+    /// 270°; this needs a square grid. `n = 1` leaves the grid unchanged.
+    /// The centre cell of an odd-sized grid is kept as is.
+    ///
+    /// Returns [`GridError::InvalidArgument`] if `n` is not 1, 2 or 4,
+    /// the only symmetries that map cells exactly onto cells, or if `n` is 4
+    /// and the grid is not square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let mut map: Grid<u8> = Grid::new(4, 4);
+    /// let _ = map.set(0, 0, 1); // player base
+    /// let _ = map.set(1, 0, 7); // gold mine
+    /// map.rotational_symmetrize(4).unwrap();
+    ///
+    /// let corners = [(0, 0), (3, 0), (3, 3), (0, 3)];
+    /// assert!(corners.iter().all(|&(x, y)| map.get(x, y) == Some(&1)));
+    /// assert_eq!(map.iter().filter(|&(_, _, &v)| v == 7).count(), 4);
+    ///
+    /// let mut wide: Grid<u8> = Grid::new(5, 2);
+    /// assert!(wide.rotational_symmetrize(4).is_err());
+    /// assert!(wide.rotational_symmetrize(2).is_ok());
+    /// ```
+    pub fn rotational_symmetrize(&mut self, n: u32) -> Result<(), GridError> {
+        let (width, height) = (self.width, self.height);
+        match n {
+            1 => Ok(()),
+            2 => {
+                let area = self.tiles.len();
+                for i in 0..area / 2 {
+                    self.tiles[area - 1 - i] = self.tiles[i].clone();
+                }
+                Ok(())
+            }
+            4 if width == height => {
+                let size = width;
+                // Clockwise quarter turn about the centre.
+                let turn = |(x, y): (u16, u16)| (size - 1 - y, x);
+                for y in 0..size / 2 {
+                    for x in 0..size.div_ceil(2) {
+                        let value = self.tiles[self.index(x, y)].clone();
+                        let mut cell = (x, y);
+                        for _ in 0..3 {
+                            cell = turn(cell);
+                            let target = self.index(cell.0, cell.1);
+                            self.tiles[target] = value.clone();
+                        }
+                    }
+                }
+                Ok(())
+            }
+            4 => Err(GridError::InvalidArgument(format!(
+                "4-fold rotational symmetry needs a square grid (got {}x{})",
+                width, height
+            ))),
+            _ => Err(GridError::InvalidArgument(format!(
+                "Unsupported rotational symmetry order {} (expected 1, 2 or 4)",
+                n
+            ))),
+        }
+    }
+}
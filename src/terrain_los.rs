@@ -0,0 +1,61 @@
+use crate::{Grid, LineIter};
+
+impl Grid<f32> {
+    /// Returns `true` if an observer at `from` can see a target at `to` over this heightmap.
+    ///
+    /// The eye sits `observer_height` above the terrain at `from` and the
+    /// target point `target_height` above the terrain at `to`. Every cell
+    /// the sight line crosses between them (a supercover line, so a thin
+    /// ridge cannot be slipped through diagonally) is tested against the
+    /// height of the line above that cell's centre; terrain strictly higher
+    /// than the line blocks it, terrain exactly level with it does not. The
+    /// endpoint cells never block.
+    ///
+    /// Returns `false` if either endpoint is out of bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The observer's cell
+    /// * `observer_height` - Eye height above the terrain at `from`
+    /// * `to` - The target's cell
+    /// * `target_height` - Height of the point to see above the terrain at `to`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// // A 3-high ridge halfway between two valleys.
+    /// let terrain = Grid::from_raw_parts(7, 1, vec![0.0, 0.0, 1.0, 3.0, 1.0, 0.0, 0.0]).unwrap();
+    ///
+    /// assert!(!terrain.terrain_line_of_sight((0, 0), 2.0, (6, 0), 0.0));
+    /// // A watchtower looks over the ridge at a tall target.
+    /// assert!(terrain.terrain_line_of_sight((0, 0), 4.0, (6, 0), 2.0));
+    /// ```
+    pub fn terrain_line_of_sight(
+        &self,
+        from: (u16, u16),
+        observer_height: f32,
+        to: (u16, u16),
+        target_height: f32,
+    ) -> bool {
+        let (Some(&ground_from), Some(&ground_to)) =
+            (self.get(from.0, from.1), self.get(to.0, to.1))
+        else {
+            return false;
+        };
+        let (eye, target) = (ground_from + observer_height, ground_to + target_height);
+        let (dx, dy) = (to.0 as f32 - from.0 as f32, to.1 as f32 - from.1 as f32);
+        let length_sq = dx * dx + dy * dy;
+
+        LineIter::supercover(from, to)
+            .filter(|&cell| cell != from && cell != to)
+            .all(|(x, y)| {
+                // Position of the cell centre projected onto the sight line, 0 at `from`.
+                let t =
+                    ((x as f32 - from.0 as f32) * dx + (y as f32 - from.1 as f32) * dy) / length_sq;
+                let sight = eye + (target - eye) * t.clamp(0.0, 1.0);
+                self.get(x, y).is_none_or(|&ground| ground <= sight)
+            })
+    }
+}
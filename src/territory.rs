@@ -0,0 +1,91 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::{Direction, Grid};
+
+impl<T: Default + Clone> Grid<T> {
+    /// Grows faction territories outward from `seeds` until they meet.
+    ///
+    /// Every seed spends `strength(i)` worth of `cost` on claiming land: a
+    /// cell can only be claimed if the cheapest 4-connected route to it from
+    /// the seed costs at most the seed's strength. All seeds grow at once,
+    /// ordered by the fraction of their strength they have spent, so a
+    /// strong faction pushes the border further towards a weak one than
+    /// plain Voronoi would. Territory is not crossed: once a cell is
+    /// claimed, other factions have to go around it.
+    ///
+    /// Returns a grid holding `i + 1` for cells claimed by `seeds[i]` and 0
+    /// for unclaimed cells. Seeds outside the grid or on a cell already
+    /// seeded are ignored; the seed cell itself is always claimed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seeds` - The capital of each faction
+    /// * `strength` - Called with a seed's index, returns its total budget
+    /// * `cost` - Called with `(x, y, &value)`, returns the cost of claiming a cell, or `None` if it cannot be claimed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let plains: Grid<u8> = Grid::with_value(11, 1, 1);
+    /// let strengths = [6, 2];
+    /// let map = plains.expand_territories(&[(0, 0), (10, 0)], |i| strengths[i], |_, _, &c| Some(c as u32));
+    ///
+    /// // The stronger faction claims more land but neither covers the whole map.
+    /// assert_eq!(map.as_slice(), &[1, 1, 1, 1, 1, 1, 1, 0, 2, 2, 2]);
+    /// ```
+    pub fn expand_territories<S, C>(&self, seeds: &[(u16, u16)], strength: S, cost: C) -> Grid<u32>
+    where
+        S: Fn(usize) -> u32,
+        C: Fn(u16, u16, &T) -> Option<u32>,
+    {
+        let mut owners: Grid<u32> = Grid::new(self.width, self.height);
+        // The lowest pressure (share of a budget spent) any faction has reached each cell with.
+        let mut best = vec![f64::INFINITY; self.tiles.len()];
+        let budgets: Vec<u32> = (0..seeds.len()).map(&strength).collect();
+        let mut queue = BinaryHeap::new();
+        let mut order = 0u64;
+
+        for (faction, &(x, y)) in seeds.iter().enumerate() {
+            if self.get(x, y).is_some() && best[self.index(x, y)] > 0.0 {
+                best[self.index(x, y)] = 0.0;
+                queue.push(Reverse((0u64, order, self.index(x, y), 0u32, faction)));
+                order += 1;
+            }
+        }
+        // Pressures are non-negative, so their bit patterns sort like the values.
+        while let Some(Reverse((pressure, _, index, g, faction))) = queue.pop() {
+            if owners.tiles[index] != 0 || pressure != best[index].to_bits() {
+                continue;
+            }
+            owners.tiles[index] = faction as u32 + 1;
+            let (x, y) = self.coords(index);
+            for direction in Direction::CARDINAL {
+                let (dx, dy) = direction.offset();
+                let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+                if nx < 0 || ny < 0 {
+                    continue;
+                }
+                let (nx, ny) = (nx as u16, ny as u16);
+                let Some(value) = self.get(nx, ny) else {
+                    continue;
+                };
+                let next = self.index(nx, ny);
+                let Some(step) = cost(nx, ny, value) else {
+                    continue;
+                };
+                let total = g.saturating_add(step);
+                let pressure = total as f64 / budgets[faction].max(1) as f64;
+                if owners.tiles[next] != 0 || total > budgets[faction] || pressure >= best[next] {
+                    continue;
+                }
+                best[next] = pressure;
+                queue.push(Reverse((pressure.to_bits(), order, next, total, faction)));
+                order += 1;
+            }
+        }
+        owners
+    }
+}
@@ -0,0 +1,278 @@
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+use proptest::arbitrary::{Arbitrary as PropArbitrary, any_with};
+use proptest::collection::vec;
+use proptest::strategy::{BoxedStrategy, Strategy};
+use quickcheck::{Arbitrary as QuickArbitrary, Gen};
+use rand::Rng;
+use rand::distr::{Distribution, StandardUniform};
+
+use crate::Grid;
+
+/// Generates random grids for property-based tests and shrinks failing
+/// ones.
+///
+/// Width and height are drawn from configurable ranges and every cell from
+/// a caller-supplied strategy, so the same configuration drives a plain
+/// loop over a seeded RNG, a proptest [`Strategy`] through
+/// [`GridStrategy::proptest_strategy`], or a quickcheck [`Gen`] through
+/// [`GridStrategy::generate_quickcheck`]. `Grid<T>` also implements both
+/// crates' `Arbitrary` traits.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::testing::GridStrategy;
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// let strategy = GridStrategy::new(1..=16, 1..=16);
+/// let mut rng = StdRng::seed_from_u64(3);
+/// for _ in 0..100 {
+///     let grid = strategy.generate(&mut rng, |rng| rand::Rng::random_range(rng, 0..4u8));
+///     assert!((1..=16).contains(&grid.width()));
+///     assert!(grid.as_slice().iter().all(|&v| v < 4));
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridStrategy {
+    width: RangeInclusive<u16>,
+    height: RangeInclusive<u16>,
+}
+
+impl GridStrategy {
+    /// Creates a strategy drawing widths and heights from the given ranges.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range is empty.
+    pub fn new(width: RangeInclusive<u16>, height: RangeInclusive<u16>) -> GridStrategy {
+        assert!(!width.is_empty(), "width range must not be empty");
+        assert!(!height.is_empty(), "height range must not be empty");
+        GridStrategy { width, height }
+    }
+
+    /// Returns the range widths are drawn from.
+    pub fn width(&self) -> &RangeInclusive<u16> {
+        &self.width
+    }
+
+    /// Returns the range heights are drawn from.
+    pub fn height(&self) -> &RangeInclusive<u16> {
+        &self.height
+    }
+
+    /// Generates a grid of random size, filling it in row-major order with
+    /// values from `cell`.
+    pub fn generate<T, R, F>(&self, rng: &mut R, mut cell: F) -> Grid<T>
+    where
+        T: Default + Clone,
+        R: Rng + ?Sized,
+        F: FnMut(&mut R) -> T,
+    {
+        let width = rng.random_range(self.width.clone());
+        let height = rng.random_range(self.height.clone());
+        let tiles = (0..width as usize * height as usize)
+            .map(|_| cell(rng))
+            .collect();
+        Grid {
+            width,
+            height,
+            tiles,
+        }
+    }
+
+    /// Generates a grid whose cells come from rand's standard
+    /// distribution for `T`.
+    pub fn generate_standard<T, R>(&self, rng: &mut R) -> Grid<T>
+    where
+        T: Default + Clone,
+        R: Rng + ?Sized,
+        StandardUniform: Distribution<T>,
+    {
+        self.generate(rng, |rng| rng.random())
+    }
+
+    /// Returns a proptest strategy for grids of this strategy's sizes with
+    /// cells drawn from `cell`.
+    ///
+    /// Failing cases shrink towards smaller grids and simpler cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::testing::GridStrategy;
+    /// use proptest::prelude::*;
+    ///
+    /// proptest!(|(grid in GridStrategy::new(1..=8, 1..=8).proptest_strategy(0..4u8))| {
+    ///     prop_assert!(grid.width() <= 8);
+    ///     prop_assert!(grid.as_slice().iter().all(|&v| v < 4));
+    /// });
+    /// ```
+    pub fn proptest_strategy<S>(&self, cell: S) -> BoxedStrategy<Grid<S::Value>>
+    where
+        S: Strategy + 'static,
+        S::Value: Default + Clone,
+    {
+        let cell = Arc::new(cell);
+        (self.width.clone(), self.height.clone())
+            .prop_flat_map(move |(width, height)| {
+                vec(Arc::clone(&cell), width as usize * height as usize).prop_map(move |tiles| {
+                    Grid {
+                        width,
+                        height,
+                        tiles,
+                    }
+                })
+            })
+            .boxed()
+    }
+
+    /// Generates a grid of random size from a quickcheck [`Gen`], filling it
+    /// in row-major order with values from `cell`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    /// use gridsystem::testing::GridStrategy;
+    /// use quickcheck::{Arbitrary, Gen};
+    ///
+    /// let mut g = Gen::new(100);
+    /// let grid: Grid<bool> = GridStrategy::new(2..=4, 2..=4).generate_quickcheck(&mut g, bool::arbitrary);
+    /// assert!((2..=4).contains(&grid.height()));
+    /// ```
+    pub fn generate_quickcheck<T, F>(&self, g: &mut Gen, mut cell: F) -> Grid<T>
+    where
+        T: Default + Clone,
+        F: FnMut(&mut Gen) -> T,
+    {
+        let width = pick(g, &self.width);
+        let height = pick(g, &self.height);
+        let tiles = (0..width as usize * height as usize)
+            .map(|_| cell(g))
+            .collect();
+        Grid {
+            width,
+            height,
+            tiles,
+        }
+    }
+
+    /// Returns smaller variants of `grid` that still satisfy the strategy,
+    /// simplest first.
+    ///
+    /// Candidates halve the width or height, drop the last column or row,
+    /// and reset single non-default cells to `T::default()`. Retrying a
+    /// failing property with each candidate, and recursing on the first
+    /// that still fails, narrows a counterexample down to a small map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    /// use gridsystem::testing::GridStrategy;
+    ///
+    /// // Property under test: "no cell holds 7" — shrink the counterexample.
+    /// let mut grid: Grid<u8> = Grid::new(12, 9);
+    /// let _ = grid.set(2, 1, 7);
+    /// let _ = grid.set(10, 8, 3);
+    ///
+    /// let strategy = GridStrategy::new(1..=16, 1..=16);
+    /// let fails = |g: &Grid<u8>| g.as_slice().contains(&7);
+    /// while let Some(smaller) = strategy.shrink(&grid).into_iter().find(|g| fails(g)) {
+    ///     grid = smaller;
+    /// }
+    /// assert_eq!((grid.width(), grid.height()), (3, 2));
+    /// assert_eq!(grid.as_slice().iter().filter(|&&v| v != 0).count(), 1);
+    /// ```
+    pub fn shrink<T>(&self, grid: &Grid<T>) -> Vec<Grid<T>>
+    where
+        T: Default + Clone + PartialEq,
+    {
+        let (w, h) = (grid.width, grid.height);
+        // Ordered so that equal sizes (e.g. halving and trimming a width of 2)
+        // are adjacent for `dedup`.
+        let mut sizes = vec![
+            (w / 2, h),
+            (w.saturating_sub(1), h),
+            (w, h / 2),
+            (w, h.saturating_sub(1)),
+        ];
+        sizes.retain(|&(sw, sh)| {
+            (sw, sh) != (w, h) && self.width.contains(&sw) && self.height.contains(&sh)
+        });
+        sizes.dedup();
+        let mut candidates: Vec<Grid<T>> = sizes
+            .into_iter()
+            .map(|(sw, sh)| {
+                let tiles = (0..sh)
+                    .flat_map(|y| (0..sw).map(move |x| (x, y)))
+                    .map(|(x, y)| grid.tiles[grid.index(x, y)].clone())
+                    .collect();
+                Grid {
+                    width: sw,
+                    height: sh,
+                    tiles,
+                }
+            })
+            .collect();
+        let empty = T::default();
+        for (i, value) in grid.tiles.iter().enumerate() {
+            if *value != empty {
+                let mut simpler = Grid {
+                    width: w,
+                    height: h,
+                    tiles: grid.tiles.clone(),
+                };
+                simpler.tiles[i] = T::default();
+                candidates.push(simpler);
+            }
+        }
+        candidates
+    }
+}
+
+impl Default for GridStrategy {
+    /// Grids from 1×1 up to 32×32.
+    fn default() -> GridStrategy {
+        GridStrategy::new(1..=32, 1..=32)
+    }
+}
+
+/// Draws a value from `range` using a quickcheck generator.
+fn pick(g: &mut Gen, range: &RangeInclusive<u16>) -> u16 {
+    let span = (*range.end() - *range.start()) as u32 + 1;
+    *range.start() + (<u32 as QuickArbitrary>::arbitrary(g) % span) as u16
+}
+
+impl<T> PropArbitrary for Grid<T>
+where
+    T: PropArbitrary + Default + Clone + 'static,
+{
+    /// The grid sizes and the parameters of each cell.
+    type Parameters = (GridStrategy, T::Parameters);
+    type Strategy = BoxedStrategy<Grid<T>>;
+
+    fn arbitrary_with((sizes, cell): Self::Parameters) -> Self::Strategy {
+        sizes.proptest_strategy(any_with::<T>(cell))
+    }
+}
+
+impl<T> QuickArbitrary for Grid<T>
+where
+    T: QuickArbitrary + Default + PartialEq,
+{
+    /// Generates grids from 1×1 up to 32×32, further capped by the
+    /// generator's size.
+    fn arbitrary(g: &mut Gen) -> Grid<T> {
+        let max = g.size().clamp(1, 32) as u16;
+        GridStrategy::new(1..=max, 1..=max).generate_quickcheck(g, <T as QuickArbitrary>::arbitrary)
+    }
+
+    /// Shrinks with [`GridStrategy::shrink`] over the default size ranges.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Grid<T>>> {
+        Box::new(GridStrategy::default().shrink(self).into_iter())
+    }
+}
@@ -0,0 +1,127 @@
+use crate::Grid;
+
+/// A resumable position in a row-major traversal of a grid.
+///
+/// Hand the same state to [`Grid::for_each_budgeted`] or
+/// [`Grid::for_each_budgeted_mut`] every frame to spread a whole-grid pass
+/// over many calls. When a pass finishes the state wraps around, so the
+/// next call starts a fresh pass.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{Grid, IterState};
+///
+/// let grid: Grid<u32> = Grid::with_value(100, 100, 1);
+/// let mut state = IterState::new();
+/// let mut total = 0;
+///
+/// let mut frames = 0;
+/// while !grid.for_each_budgeted(&mut state, 3000, |_, _, v| total += v) {
+///     frames += 1;
+/// }
+/// assert_eq!(frames, 3);
+/// assert_eq!(total, 10_000);
+/// assert_eq!(state.passes(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IterState {
+    next: usize,
+    passes: u64,
+}
+
+impl IterState {
+    /// Creates a state positioned at the start of the first pass.
+    pub fn new() -> IterState {
+        IterState::default()
+    }
+
+    /// Returns the number of cells already visited in the current pass.
+    pub fn cells_done(&self) -> usize {
+        self.next
+    }
+
+    /// Returns the number of passes completed so far.
+    pub fn passes(&self) -> u64 {
+        self.passes
+    }
+
+    /// Restarts the current pass from the first cell.
+    pub fn reset(&mut self) {
+        self.next = 0;
+    }
+
+    /// Returns the index range to visit this call and advances the state.
+    fn advance(&mut self, area: usize, budget: usize) -> (std::ops::Range<usize>, bool) {
+        // A grid that shrank since the last call ends the pass early.
+        let start = self.next.min(area);
+        let end = start.saturating_add(budget).min(area);
+        if end == area {
+            self.next = 0;
+            self.passes += 1;
+            (start..end, true)
+        } else {
+            self.next = end;
+            (start..end, false)
+        }
+    }
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Visits up to `budget` cells in row-major order, resuming from `state`.
+    ///
+    /// Returns `true` if this call reached the end of the grid, completing a
+    /// pass; the following call starts again from the first cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The traversal position, updated in place
+    /// * `budget` - The maximum number of cells to visit in this call
+    /// * `f` - Called with `(x, y, &value)` for each visited cell
+    pub fn for_each_budgeted<F>(&self, state: &mut IterState, budget: usize, mut f: F) -> bool
+    where
+        F: FnMut(u16, u16, &T),
+    {
+        let (range, finished) = state.advance(self.tiles.len(), budget);
+        for i in range {
+            let (x, y) = self.coords(i);
+            f(x, y, &self.tiles[i]);
+        }
+        finished
+    }
+
+    /// Updates up to `budget` cells in row-major order, resuming from `state`.
+    ///
+    /// Behaves like [`Grid::for_each_budgeted`] but hands out mutable cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Grid, IterState};
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(4, 4);
+    /// let mut state = IterState::new();
+    ///
+    /// assert!(!grid.for_each_budgeted_mut(&mut state, 10, |_, _, v| *v += 1));
+    /// assert_eq!(grid.get(1, 2), Some(&1));
+    /// assert_eq!(grid.get(2, 2), Some(&0));
+    /// assert!(grid.for_each_budgeted_mut(&mut state, 10, |_, _, v| *v += 1));
+    /// assert!(grid.iter().all(|(_, _, &v)| v == 1));
+    /// ```
+    pub fn for_each_budgeted_mut<F>(
+        &mut self,
+        state: &mut IterState,
+        budget: usize,
+        mut f: F,
+    ) -> bool
+    where
+        F: FnMut(u16, u16, &mut T),
+    {
+        let (range, finished) = state.advance(self.tiles.len(), budget);
+        for i in range {
+            let (x, y) = self.coords(i);
+            f(x, y, &mut self.tiles[i]);
+        }
+        finished
+    }
+}
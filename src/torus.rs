@@ -0,0 +1,135 @@
+use crate::{Grid, GridError, GridLike, GridLikeMut};
+
+/// A grid whose opposite edges are joined, so it wraps around like a torus.
+///
+/// Stepping off the east edge lands on the west edge and stepping off the
+/// south edge lands on the north edge. Pathfinding and field of view see
+/// that topology through [`GridLike::offset`] and [`GridLike::distance`],
+/// so paths and sight lines cross the seams. For cellular automata and
+/// stencils, pass [`Boundary::Wrap`](crate::Boundary::Wrap) to
+/// [`step_stencil`](crate::stencil::step_stencil).
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::TorusGrid;
+/// use gridsystem::pathfinding::astar;
+///
+/// let mut world: TorusGrid<u8> = TorusGrid::new(10, 4);
+/// world.set(-1, 0, 7).unwrap();
+/// assert_eq!(world.get(9, 0), Some(&7));
+/// assert_eq!(world.get(19, 4), Some(&7));
+///
+/// // The short way round crosses the west edge.
+/// let path = astar(&world, (1, 1), (8, 1), |_, _, _| Some(1)).unwrap();
+/// assert_eq!(path, vec![(1, 1), (0, 1), (9, 1), (8, 1)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TorusGrid<T> {
+    grid: Grid<T>,
+}
+
+impl<T: Default + Clone> TorusGrid<T> {
+    /// Creates a torus of the given size, filled with default values.
+    pub fn new(width: u16, height: u16) -> TorusGrid<T> {
+        TorusGrid::from_grid(Grid::new(width, height))
+    }
+
+    /// Joins the opposite edges of an existing grid.
+    pub fn from_grid(grid: Grid<T>) -> TorusGrid<T> {
+        TorusGrid { grid }
+    }
+
+    /// Returns the width of the torus.
+    pub fn width(&self) -> u16 {
+        self.grid.width
+    }
+
+    /// Returns the height of the torus.
+    pub fn height(&self) -> u16 {
+        self.grid.height
+    }
+
+    /// Maps any coordinates onto the cell they wrap to, or `None` if the
+    /// torus has no cells.
+    pub fn wrap(&self, x: i32, y: i32) -> Option<(u16, u16)> {
+        let (width, height) = (self.grid.width as i32, self.grid.height as i32);
+        if width == 0 || height == 0 {
+            return None;
+        }
+        Some((x.rem_euclid(width) as u16, y.rem_euclid(height) as u16))
+    }
+
+    /// Gets a reference to the cell that (x, y) wraps to.
+    ///
+    /// Returns `None` only if the torus has no cells.
+    pub fn get(&self, x: i32, y: i32) -> Option<&T> {
+        let (x, y) = self.wrap(x, y)?;
+        self.grid.get(x, y)
+    }
+
+    /// Gets a mutable reference to the cell that (x, y) wraps to.
+    pub fn get_mut(&mut self, x: i32, y: i32) -> Option<&mut T> {
+        let (x, y) = self.wrap(x, y)?;
+        self.grid.get_mut(x, y)
+    }
+
+    /// Sets the cell that (x, y) wraps to.
+    ///
+    /// Returns [`GridError::EmptyGrid`] if the torus has no cells.
+    pub fn set(&mut self, x: i32, y: i32, value: T) -> Result<(), GridError> {
+        let cell = self.get_mut(x, y).ok_or(GridError::EmptyGrid)?;
+        *cell = value;
+        Ok(())
+    }
+
+    /// Returns the underlying grid.
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    /// Returns the underlying grid mutably.
+    pub fn grid_mut(&mut self) -> &mut Grid<T> {
+        &mut self.grid
+    }
+
+    /// Consumes the torus, returning the underlying grid.
+    pub fn into_grid(self) -> Grid<T> {
+        self.grid
+    }
+}
+
+impl<T: Default + Clone> GridLike<T> for TorusGrid<T> {
+    fn width(&self) -> u16 {
+        self.grid.width
+    }
+
+    fn height(&self) -> u16 {
+        self.grid.height
+    }
+
+    fn get(&self, x: u16, y: u16) -> Option<&T> {
+        self.grid.get(x, y)
+    }
+
+    fn offset(&self, x: u16, y: u16, dx: i32, dy: i32) -> Option<(u16, u16)> {
+        if !self.in_bounds(x, y) {
+            return None;
+        }
+        self.wrap(x as i32 + dx, y as i32 + dy)
+    }
+
+    fn distance(&self, a: (u16, u16), b: (u16, u16)) -> u32 {
+        let wrapped = |from: u16, to: u16, size: u16| {
+            let direct = from.abs_diff(to);
+            direct.min(size.saturating_sub(direct)) as u32
+        };
+        wrapped(a.0, b.0, self.grid.width) + wrapped(a.1, b.1, self.grid.height)
+    }
+}
+
+impl<T: Default + Clone> GridLikeMut<T> for TorusGrid<T> {
+    fn get_mut(&mut self, x: u16, y: u16) -> Option<&mut T> {
+        self.grid.get_mut(x, y)
+    }
+}
@@ -0,0 +1,264 @@
+use crate::{Grid, GridError};
+
+/// How cell values are sampled at fractional source positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interpolation {
+    /// Take the value of the cell containing the sample point.
+    #[default]
+    Nearest,
+    /// Blend the four surrounding cell centres linearly.
+    Bilinear,
+}
+
+/// Values that can be blended linearly, used by [`Interpolation::Bilinear`].
+pub trait Lerp: Copy {
+    /// Returns `a + (b - a) * t`.
+    fn lerp(a: Self, b: Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(a: f32, b: f32, t: f32) -> f32 {
+        a + (b - a) * t
+    }
+}
+
+impl Lerp for f64 {
+    fn lerp(a: f64, b: f64, t: f32) -> f64 {
+        a + (b - a) * t as f64
+    }
+}
+
+macro_rules! impl_lerp_for_int {
+    ($($t:ty),*) => {
+        $(
+            impl Lerp for $t {
+                fn lerp(a: $t, b: $t, t: f32) -> $t {
+                    (a as f64 + (b as f64 - a as f64) * t as f64).round() as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_lerp_for_int!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+/// A 2D affine transform in continuous grid coordinates.
+///
+/// Maps a point `(x, y)` to `(a·x + b·y + c, d·x + e·y + f)`. Cell (x, y)
+/// covers `[x, x + 1) × [y, y + 1)`, so cell centres sit at half-integers.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Affine;
+///
+/// let t = Affine::translation(2.0, 0.0).then(&Affine::scale(3.0, 3.0));
+/// assert_eq!(t.apply(1.0, 1.0), (9.0, 3.0));
+/// assert_eq!(t.inverse().unwrap().apply(9.0, 3.0), (1.0, 1.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Affine {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+    pub d: f32,
+    pub e: f32,
+    pub f: f32,
+}
+
+impl Affine {
+    /// The transform that leaves every point in place.
+    pub fn identity() -> Affine {
+        Affine::new(1.0, 0.0, 0.0, 0.0, 1.0, 0.0)
+    }
+
+    /// Creates a transform from its six coefficients.
+    pub fn new(a: f32, b: f32, c: f32, d: f32, e: f32, f: f32) -> Affine {
+        Affine { a, b, c, d, e, f }
+    }
+
+    /// A translation by (tx, ty).
+    pub fn translation(tx: f32, ty: f32) -> Affine {
+        Affine::new(1.0, 0.0, tx, 0.0, 1.0, ty)
+    }
+
+    /// A scaling about the origin.
+    pub fn scale(sx: f32, sy: f32) -> Affine {
+        Affine::new(sx, 0.0, 0.0, 0.0, sy, 0.0)
+    }
+
+    /// A rotation about the origin by `angle` radians.
+    ///
+    /// Because the y axis grows downwards, positive angles appear clockwise.
+    pub fn rotation(angle: f32) -> Affine {
+        let (sin, cos) = angle.sin_cos();
+        Affine::new(cos, -sin, 0.0, sin, cos, 0.0)
+    }
+
+    /// Returns the transform that applies `self` first and then `next`.
+    pub fn then(&self, next: &Affine) -> Affine {
+        Affine::new(
+            next.a * self.a + next.b * self.d,
+            next.a * self.b + next.b * self.e,
+            next.a * self.c + next.b * self.f + next.c,
+            next.d * self.a + next.e * self.d,
+            next.d * self.b + next.e * self.e,
+            next.d * self.c + next.e * self.f + next.f,
+        )
+    }
+
+    /// Returns the inverse transform, or `None` if the transform is singular.
+    ///
+    /// The determinant is compared against a tolerance scaled by the square
+    /// of the largest linear coefficient, so uniformly tiny or huge scales
+    /// still invert while transforms that are singular up to `f32` rounding
+    /// do not.
+    pub fn inverse(&self) -> Option<Affine> {
+        let det = self.a * self.e - self.b * self.d;
+        let magnitude = self
+            .a
+            .abs()
+            .max(self.b.abs())
+            .max(self.d.abs())
+            .max(self.e.abs());
+        if !det.is_finite() || det.abs() <= f32::EPSILON * magnitude * magnitude {
+            return None;
+        }
+        let a = self.e / det;
+        let b = -self.b / det;
+        let d = -self.d / det;
+        let e = self.a / det;
+        Some(Affine::new(
+            a,
+            b,
+            -(a * self.c + b * self.f),
+            d,
+            e,
+            -(d * self.c + e * self.f),
+        ))
+    }
+
+    /// Applies the transform to a point.
+    pub fn apply(&self, x: f32, y: f32) -> (f32, f32) {
+        (
+            self.a * x + self.b * y + self.c,
+            self.d * x + self.e * y + self.f,
+        )
+    }
+}
+
+impl<T: Default + Lerp> Grid<T> {
+    /// Resamples the grid through an affine transform.
+    ///
+    /// `transform` maps source positions to destination positions. Each cell
+    /// of the new `width × height` grid samples the source at the inverse
+    /// image of its centre; samples outside the source read `fill`.
+    ///
+    /// Returns [`GridError::InvalidArgument`] if the transform is not invertible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Affine, Grid, Interpolation};
+    ///
+    /// let mut grid: Grid<f32> = Grid::new(4, 4);
+    /// let _ = grid.set(1, 1, 8.0);
+    ///
+    /// let shifted = grid
+    ///     .warp_affine(&Affine::translation(2.0, 1.0), 4, 4, Interpolation::Nearest, 0.0)
+    ///     .unwrap();
+    /// assert_eq!(shifted.get(3, 2), Some(&8.0));
+    ///
+    /// let doubled = grid
+    ///     .warp_affine(&Affine::scale(2.0, 2.0), 8, 8, Interpolation::Bilinear, 0.0)
+    ///     .unwrap();
+    /// assert_eq!(doubled.get(2, 2), Some(&4.5));
+    /// ```
+    pub fn warp_affine(
+        &self,
+        transform: &Affine,
+        width: u16,
+        height: u16,
+        interpolation: Interpolation,
+        fill: T,
+    ) -> Result<Grid<T>, GridError> {
+        let inverse = transform.inverse().ok_or_else(|| {
+            GridError::InvalidArgument(format!(
+                "Affine transform {:?} is not invertible",
+                transform
+            ))
+        })?;
+        let mut out = Grid::with_value(width, height, fill);
+        out.map_inplace(|x, y, cell| {
+            let (sx, sy) = inverse.apply(x as f32 + 0.5, y as f32 + 0.5);
+            *cell = match interpolation {
+                Interpolation::Nearest => self.sample_or(sx.floor(), sy.floor(), fill),
+                Interpolation::Bilinear => self.sample_bilinear(sx - 0.5, sy - 0.5, fill),
+            };
+        });
+        Ok(out)
+    }
+
+    /// Rotates the grid about its centre by `angle` radians.
+    ///
+    /// The result is enlarged to fit the whole rotated grid, and the exposed
+    /// corners are set to `fill`. Positive angles rotate clockwise as
+    /// displayed (y grows downwards); multiples of a quarter turn with
+    /// [`Interpolation::Nearest`] reproduce the cells exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Grid, Interpolation};
+    /// use std::f32::consts::FRAC_PI_2;
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(3, 2);
+    /// let _ = grid.set(0, 0, 1);
+    ///
+    /// let rotated = grid.rotate_by(FRAC_PI_2, Interpolation::Nearest, 0);
+    /// assert_eq!((rotated.width(), rotated.height()), (2, 3));
+    /// assert_eq!(rotated.get(1, 0), Some(&1));
+    /// ```
+    pub fn rotate_by(&self, angle: f32, interpolation: Interpolation, fill: T) -> Grid<T> {
+        let (sin, cos) = angle.sin_cos();
+        let (w, h) = (self.width as f32, self.height as f32);
+        // Trim float noise so quarter turns don't grow by a cell.
+        let fit = |size: f32| ((size - 1e-3).ceil().max(0.0) as u32).min(u16::MAX as u32) as u16;
+        let new_width = fit(w * cos.abs() + h * sin.abs());
+        let new_height = fit(w * sin.abs() + h * cos.abs());
+
+        let transform = Affine::translation(-w / 2.0, -h / 2.0)
+            .then(&Affine::rotation(angle))
+            .then(&Affine::translation(
+                new_width as f32 / 2.0,
+                new_height as f32 / 2.0,
+            ));
+        // A rotation is always invertible.
+        self.warp_affine(&transform, new_width, new_height, interpolation, fill)
+            .unwrap_or_else(|_| Grid::with_value(new_width, new_height, fill))
+    }
+
+    fn sample_or(&self, x: f32, y: f32, fill: T) -> T {
+        if x >= 0.0 && y >= 0.0 && x < self.width as f32 && y < self.height as f32 {
+            self.tiles[self.index(x as u16, y as u16)]
+        } else {
+            fill
+        }
+    }
+
+    fn sample_bilinear(&self, x: f32, y: f32, fill: T) -> T {
+        let (x0, y0) = (x.floor(), y.floor());
+        let (tx, ty) = (x - x0, y - y0);
+        let top = T::lerp(
+            self.sample_or(x0, y0, fill),
+            self.sample_or(x0 + 1.0, y0, fill),
+            tx,
+        );
+        let bottom = T::lerp(
+            self.sample_or(x0, y0 + 1.0, fill),
+            self.sample_or(x0 + 1.0, y0 + 1.0, fill),
+            tx,
+        );
+        T::lerp(top, bottom, ty)
+    }
+}
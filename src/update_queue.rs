@@ -0,0 +1,247 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::{Grid, GridError};
+
+/// Per-cell timers that fire in tick order.
+///
+/// Each cell has at most one pending update. Scheduling a cell again
+/// replaces its earlier update, and [`UpdateQueue::overwrite`] cancels it
+/// when the cell's value is replaced, so a crop that burns down never
+/// finishes growing on the ashes. Superseded entries are skipped rather
+/// than searched for, so rescheduling is `O(log n)`.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{Grid, UpdateQueue};
+///
+/// #[derive(Clone, Default, Debug, PartialEq)]
+/// enum Field {
+///     #[default]
+///     Empty,
+///     Seedling,
+///     Wheat,
+///     Ash,
+/// }
+///
+/// let mut farm: Grid<Field> = Grid::new(3, 1);
+/// let mut timers = UpdateQueue::new(3, 1);
+/// for x in 0..3 {
+///     timers.overwrite(&mut farm, x, 0, Field::Seedling, Some(2)).unwrap();
+/// }
+/// // Fire reaches the middle field before the crop is ripe.
+/// timers.step(&mut farm, |_, _, _| None).unwrap();
+/// timers.overwrite(&mut farm, 1, 0, Field::Ash, None).unwrap();
+///
+/// let grown = timers.step(&mut farm, |_, _, field| {
+///     *field = Field::Wheat;
+///     None
+/// });
+/// assert_eq!(grown, Ok(2));
+/// assert_eq!(farm.as_slice(), &[Field::Wheat, Field::Ash, Field::Wheat]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct UpdateQueue {
+    width: u16,
+    height: u16,
+    now: u64,
+    // (due tick, sequence, cell); stale unless it matches `pending[cell]`.
+    heap: BinaryHeap<Reverse<(u64, u64, usize)>>,
+    pending: Vec<Option<(u64, u64)>>,
+    sequence: u64,
+    len: usize,
+}
+
+impl UpdateQueue {
+    /// Creates an empty queue for a `width × height` grid, at tick 0.
+    pub fn new(width: u16, height: u16) -> UpdateQueue {
+        UpdateQueue {
+            width,
+            height,
+            now: 0,
+            heap: BinaryHeap::new(),
+            pending: vec![None; width as usize * height as usize],
+            sequence: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the width of the grid the queue covers.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Returns the height of the grid the queue covers.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Returns the current tick.
+    pub fn now(&self) -> u64 {
+        self.now
+    }
+
+    /// Returns the number of cells with a pending update.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no updates are pending.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Schedules an update of (x, y) `delay` ticks from now, replacing any
+    /// update already pending for the cell.
+    ///
+    /// A delay of 0 is treated as 1, so the update runs on the next
+    /// [`advance`](UpdateQueue::advance).
+    ///
+    /// Returns [`GridError::OutOfBounds`] if the cell is outside the queue.
+    pub fn schedule(&mut self, x: u16, y: u16, delay: u64) -> Result<(), GridError> {
+        let cell = self.cell(x, y)?;
+        let due = self.now.saturating_add(delay.max(1));
+        if self.pending[cell].is_none() {
+            self.len += 1;
+        }
+        self.pending[cell] = Some((due, self.sequence));
+        self.heap.push(Reverse((due, self.sequence, cell)));
+        self.sequence += 1;
+        Ok(())
+    }
+
+    /// Cancels the pending update of (x, y), returning `true` if there was
+    /// one.
+    ///
+    /// Returns [`GridError::OutOfBounds`] if the cell is outside the queue.
+    pub fn cancel(&mut self, x: u16, y: u16) -> Result<bool, GridError> {
+        let cell = self.cell(x, y)?;
+        let cancelled = self.pending[cell].take().is_some();
+        if cancelled {
+            self.len -= 1;
+        }
+        Ok(cancelled)
+    }
+
+    /// Returns the tick at which (x, y) is due, if an update is pending.
+    pub fn due(&self, x: u16, y: u16) -> Option<u64> {
+        let cell = self.cell(x, y).ok()?;
+        self.pending[cell].map(|(due, _)| due)
+    }
+
+    /// Moves to the next tick and removes the updates due by then.
+    ///
+    /// Returns their cells ordered by due tick, then by the order they were
+    /// scheduled in.
+    pub fn advance(&mut self) -> Vec<(u16, u16)> {
+        self.now += 1;
+        let mut due = Vec::new();
+        while let Some(&Reverse((tick, sequence, cell))) = self.heap.peek() {
+            if tick > self.now {
+                break;
+            }
+            self.heap.pop();
+            if self.pending[cell] == Some((tick, sequence)) {
+                self.pending[cell] = None;
+                self.len -= 1;
+                due.push((
+                    (cell % self.width as usize) as u16,
+                    (cell / self.width as usize) as u16,
+                ));
+            }
+        }
+        due
+    }
+
+    /// Advances one tick and runs `update` on every cell that became due.
+    ///
+    /// `update` receives the cell's coordinates and value and returns the
+    /// delay until its next update, or `None` to leave it unscheduled.
+    /// Returns the number of cells updated.
+    ///
+    /// Returns [`GridError::DimensionMismatch`] if `grid` is not the size of
+    /// the queue; the tick does not advance in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Grid, UpdateQueue};
+    ///
+    /// // A fire that burns down one fuel unit every other tick.
+    /// let mut fuel: Grid<u8> = Grid::with_value(1, 1, 2);
+    /// let mut timers = UpdateQueue::new(1, 1);
+    /// timers.schedule(0, 0, 2).unwrap();
+    ///
+    /// let burn = |_: u16, _: u16, f: &mut u8| {
+    ///     *f -= 1;
+    ///     (*f > 0).then_some(2)
+    /// };
+    /// let ran: Vec<usize> = (0..5).map(|_| timers.step(&mut fuel, burn).unwrap()).collect();
+    /// assert_eq!(ran, vec![0, 1, 0, 1, 0]);
+    /// assert_eq!(fuel.get(0, 0), Some(&0));
+    /// assert!(timers.is_empty());
+    /// ```
+    pub fn step<T, F>(&mut self, grid: &mut Grid<T>, mut update: F) -> Result<usize, GridError>
+    where
+        T: Default + Clone,
+        F: FnMut(u16, u16, &mut T) -> Option<u64>,
+    {
+        self.check(grid)?;
+        let due = self.advance();
+        for &(x, y) in &due {
+            let cell = grid.index(x, y);
+            if let Some(delay) = update(x, y, &mut grid.tiles[cell]) {
+                self.schedule(x, y, delay)?;
+            }
+        }
+        Ok(due.len())
+    }
+
+    /// Replaces the value at (x, y) and its pending update, returning the
+    /// old value.
+    ///
+    /// Whatever was scheduled for the old value is cancelled; with
+    /// `Some(delay)` a fresh update is scheduled for the new one.
+    ///
+    /// Returns [`GridError::DimensionMismatch`] if `grid` is not the size of
+    /// the queue, or [`GridError::OutOfBounds`] if the cell is outside it.
+    pub fn overwrite<T: Default + Clone>(
+        &mut self,
+        grid: &mut Grid<T>,
+        x: u16,
+        y: u16,
+        value: T,
+        delay: Option<u64>,
+    ) -> Result<T, GridError> {
+        self.check(grid)?;
+        self.cancel(x, y)?;
+        if let Some(delay) = delay {
+            self.schedule(x, y, delay)?;
+        }
+        let cell = grid.index(x, y);
+        Ok(std::mem::replace(&mut grid.tiles[cell], value))
+    }
+
+    fn cell(&self, x: u16, y: u16) -> Result<usize, GridError> {
+        if x >= self.width || y >= self.height {
+            return Err(GridError::OutOfBounds {
+                x,
+                y,
+                width: self.width,
+                height: self.height,
+            });
+        }
+        Ok(y as usize * self.width as usize + x as usize)
+    }
+
+    fn check<T>(&self, grid: &Grid<T>) -> Result<(), GridError> {
+        if (grid.width, grid.height) != (self.width, self.height) {
+            return Err(GridError::DimensionMismatch {
+                expected: (self.width, self.height),
+                found: (grid.width, grid.height),
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,209 @@
+use crate::{Grid, GridError, Lerp};
+
+/// Data stored on the corners of a grid's cells.
+///
+/// A grid of `cell_width × cell_height` cells has
+/// `(cell_width + 1) × (cell_height + 1)` corners. Vertex (x, y) is the
+/// top-left corner of cell (x, y), so cell (x, y) spans vertices (x, y) to
+/// (x + 1, y + 1). This is the layout marching squares, dual-grid
+/// autotiling and staggered fluid grids work on.
+///
+/// Values convert between the two layouts with [`Grid::to_vertices`] and
+/// [`VertexGrid::to_cells`].
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::VertexGrid;
+///
+/// let mut corners: VertexGrid<bool> = VertexGrid::new(2, 1).unwrap();
+/// assert_eq!((corners.width(), corners.height()), (3, 2));
+///
+/// let _ = corners.set(1, 1, true);
+/// // Autotiling picks a tile for each cell from its four corners.
+/// assert_eq!(corners.corners(0, 0), Some([&false, &false, &true, &false]));
+/// assert_eq!(corners.corners(1, 0), Some([&false, &false, &false, &true]));
+/// ```
+pub struct VertexGrid<T> {
+    vertices: Grid<T>,
+}
+
+impl<T: Default + Clone> VertexGrid<T> {
+    /// Creates the corners of a `cell_width × cell_height` grid, all set to `T::default()`.
+    ///
+    /// Returns [`GridError::InvalidArgument`] if a dimension is `u16::MAX`,
+    /// since the extra row or column of corners would not fit.
+    pub fn new(cell_width: u16, cell_height: u16) -> Result<VertexGrid<T>, GridError> {
+        match (cell_width.checked_add(1), cell_height.checked_add(1)) {
+            (Some(width), Some(height)) => Ok(VertexGrid {
+                vertices: Grid::new(width, height),
+            }),
+            _ => Err(GridError::InvalidArgument(format!(
+                "A {cell_width}x{cell_height} grid has too many corners to store"
+            ))),
+        }
+    }
+
+    /// Wraps a grid of corner values.
+    ///
+    /// Returns [`GridError::EmptyGrid`] if `vertices` has no cells, since
+    /// every vertex grid has at least one corner.
+    pub fn from_grid(vertices: Grid<T>) -> Result<VertexGrid<T>, GridError> {
+        if vertices.width == 0 || vertices.height == 0 {
+            return Err(GridError::EmptyGrid);
+        }
+        Ok(VertexGrid { vertices })
+    }
+
+    /// Returns the number of corners along x, `cell_width + 1`.
+    pub fn width(&self) -> u16 {
+        self.vertices.width
+    }
+
+    /// Returns the number of corners along y, `cell_height + 1`.
+    pub fn height(&self) -> u16 {
+        self.vertices.height
+    }
+
+    /// Returns the number of cells along x.
+    pub fn cell_width(&self) -> u16 {
+        self.vertices.width - 1
+    }
+
+    /// Returns the number of cells along y.
+    pub fn cell_height(&self) -> u16 {
+        self.vertices.height - 1
+    }
+
+    /// Gets a reference to the value at vertex (x, y).
+    pub fn get(&self, x: u16, y: u16) -> Option<&T> {
+        self.vertices.get(x, y)
+    }
+
+    /// Sets the value at vertex (x, y).
+    ///
+    /// Returns [`GridError::OutOfBounds`] if the vertex is out of bounds.
+    pub fn set(&mut self, x: u16, y: u16, value: T) -> Result<(), GridError> {
+        self.vertices.set(x, y, value)
+    }
+
+    /// Returns the four corners of cell (x, y) clockwise from the top-left:
+    /// `[top-left, top-right, bottom-right, bottom-left]`.
+    ///
+    /// Returns `None` if the cell is out of bounds.
+    pub fn corners(&self, x: u16, y: u16) -> Option<[&T; 4]> {
+        if x >= self.cell_width() || y >= self.cell_height() {
+            return None;
+        }
+        let v = &self.vertices;
+        Some([
+            &v.tiles[v.index(x, y)],
+            &v.tiles[v.index(x + 1, y)],
+            &v.tiles[v.index(x + 1, y + 1)],
+            &v.tiles[v.index(x, y + 1)],
+        ])
+    }
+
+    /// Returns the underlying grid of corner values.
+    pub fn as_grid(&self) -> &Grid<T> {
+        &self.vertices
+    }
+
+    /// Consumes the vertex grid, returning the underlying grid of corner values.
+    pub fn into_grid(self) -> Grid<T> {
+        self.vertices
+    }
+}
+
+impl<T: Lerp + Default> VertexGrid<T> {
+    /// Converts to cell-centred values, each the average of the cell's four corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Grid, VertexGrid};
+    ///
+    /// let corners = VertexGrid::from_grid(Grid::from_raw_parts(2, 2, vec![0.0, 4.0, 2.0, 6.0]).unwrap()).unwrap();
+    /// assert_eq!(corners.to_cells().as_slice(), &[3.0]);
+    /// ```
+    pub fn to_cells(&self) -> Grid<T> {
+        let mut cells = Grid::new(self.cell_width(), self.cell_height());
+        cells.map_inplace(|x, y, cell| {
+            if let Some([&nw, &ne, &se, &sw]) = self.corners(x, y) {
+                *cell = T::lerp(T::lerp(nw, ne, 0.5), T::lerp(sw, se, 0.5), 0.5);
+            }
+        });
+        cells
+    }
+
+    /// Bilinearly interpolates the corner values at a point in cell units.
+    ///
+    /// Vertex (x, y) sits at point (x, y), so cell (x, y) covers the square
+    /// from (x, y) to (x + 1, y + 1).
+    ///
+    /// Returns `None` if the point lies outside the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Grid, VertexGrid};
+    ///
+    /// let corners = VertexGrid::from_grid(Grid::from_raw_parts(2, 2, vec![0.0, 4.0, 2.0, 6.0]).unwrap()).unwrap();
+    /// assert_eq!(corners.interpolate(0.5, 0.0), Some(2.0));
+    /// assert_eq!(corners.interpolate(1.0, 1.0), Some(6.0));
+    /// assert_eq!(corners.interpolate(1.5, 0.0), None);
+    /// ```
+    pub fn interpolate(&self, x: f32, y: f32) -> Option<T> {
+        let (max_x, max_y) = (self.cell_width() as f32, self.cell_height() as f32);
+        if !(0.0..=max_x).contains(&x) || !(0.0..=max_y).contains(&y) {
+            return None;
+        }
+        // Points on the far edge belong to the last cell.
+        let cx = (x as u16).min(self.cell_width().saturating_sub(1));
+        let cy = (y as u16).min(self.cell_height().saturating_sub(1));
+        let v = &self.vertices;
+        let at = |vx: u16, vy: u16| v.tiles[v.index(vx.min(v.width - 1), vy.min(v.height - 1))];
+        let (tx, ty) = (x - cx as f32, y - cy as f32);
+        let top = T::lerp(at(cx, cy), at(cx + 1, cy), tx);
+        let bottom = T::lerp(at(cx, cy + 1), at(cx + 1, cy + 1), tx);
+        Some(T::lerp(top, bottom, ty))
+    }
+}
+
+impl<T: Lerp + Default> Grid<T> {
+    /// Converts cell-centred values to corner values.
+    ///
+    /// Each vertex becomes the average of the cells that touch it: four in
+    /// the interior, two along the border and one at the outer corners.
+    ///
+    /// Returns [`GridError::EmptyGrid`] if the grid has no cells, or
+    /// [`GridError::InvalidArgument`] if a dimension is `u16::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// let cells = Grid::from_raw_parts(2, 1, vec![2.0_f32, 6.0]).unwrap();
+    /// let corners = cells.to_vertices().unwrap();
+    ///
+    /// assert_eq!(corners.as_grid().as_slice(), &[2.0, 4.0, 6.0, 2.0, 4.0, 6.0]);
+    /// // Averaging back smooths the values towards their neighbours.
+    /// assert_eq!(corners.to_cells().as_slice(), &[3.0, 5.0]);
+    /// ```
+    pub fn to_vertices(&self) -> Result<VertexGrid<T>, GridError> {
+        if self.tiles.is_empty() {
+            return Err(GridError::EmptyGrid);
+        }
+        let mut vertices = VertexGrid::new(self.width, self.height)?;
+        let (last_x, last_y) = (self.width - 1, self.height - 1);
+        let cell = |x: u16, y: u16| self.tiles[self.index(x.min(last_x), y.min(last_y))];
+        vertices.vertices.map_inplace(|x, y, vertex| {
+            let (left, top) = (x.saturating_sub(1), y.saturating_sub(1));
+            let upper = T::lerp(cell(left, top), cell(x, top), 0.5);
+            let lower = T::lerp(cell(left, y), cell(x, y), 0.5);
+            *vertex = T::lerp(upper, lower, 0.5);
+        });
+        Ok(vertices)
+    }
+}
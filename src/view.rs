@@ -0,0 +1,607 @@
+use crate::{Grid, GridError, GridLike, GridLikeMut, Rect};
+
+/// A borrowed rectangular window into a [`Grid`].
+///
+/// Created by [`Grid::view`]. Coordinates are local to the window, so
+/// (0, 0) is its top-left cell; nothing is copied. Implements
+/// [`GridLike`], so pathfinding and other read-only algorithms run on a
+/// region of a large map directly.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Grid;
+///
+/// let mut map: Grid<u8> = Grid::new(100, 100);
+/// let _ = map.set(42, 17, 5);
+///
+/// let room = map.view(40, 15, 8, 6).unwrap();
+/// assert_eq!((room.width(), room.height()), (8, 6));
+/// assert_eq!(room.get(2, 2), Some(&5));
+/// assert_eq!(room.get(8, 0), None);
+/// ```
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    rect: Rect,
+}
+
+/// A mutably borrowed rectangular window into a [`Grid`].
+///
+/// Created by [`Grid::view_mut`]. Like [`GridView`] but also allows writes,
+/// which can never reach cells outside the window. Implements
+/// [`GridLikeMut`].
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Grid;
+///
+/// let mut map: Grid<char> = Grid::with_value(6, 4, '#');
+/// map.view_mut(1, 1, 4, 2).unwrap().fill('.');
+///
+/// assert_eq!(map.row(0).unwrap(), &['#'; 6]);
+/// assert_eq!(map.row(1).unwrap(), &['#', '.', '.', '.', '.', '#']);
+/// ```
+pub struct GridViewMut<'a, T> {
+    grid: &'a mut Grid<T>,
+    rect: Rect,
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Borrows the `width × height` window whose top-left cell is (x, y).
+    ///
+    /// Returns [`GridError::InvalidArgument`] if the window does not fit
+    /// inside the grid.
+    pub fn view(
+        &self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+    ) -> Result<GridView<'_, T>, GridError> {
+        let rect = self.window(Rect::new(x, y, width, height))?;
+        Ok(GridView { grid: self, rect })
+    }
+
+    /// Mutably borrows the `width × height` window whose top-left cell is (x, y).
+    ///
+    /// Returns [`GridError::InvalidArgument`] if the window does not fit
+    /// inside the grid.
+    pub fn view_mut(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+    ) -> Result<GridViewMut<'_, T>, GridError> {
+        let rect = self.window(Rect::new(x, y, width, height))?;
+        Ok(GridViewMut { grid: self, rect })
+    }
+
+    fn window(&self, rect: Rect) -> Result<Rect, GridError> {
+        if rect.right() <= self.width as u32 && rect.bottom() <= self.height as u32 {
+            Ok(rect)
+        } else {
+            Err(GridError::InvalidArgument(format!(
+                "A {}x{} view at ({}, {}) does not fit in a {}x{} grid",
+                rect.width, rect.height, rect.x, rect.y, self.width, self.height
+            )))
+        }
+    }
+}
+
+impl<'a, T> GridView<'a, T> {
+    /// Returns the width of the window.
+    pub fn width(&self) -> u16 {
+        self.rect.width
+    }
+
+    /// Returns the height of the window.
+    pub fn height(&self) -> u16 {
+        self.rect.height
+    }
+
+    /// Returns the window's position and size in the underlying grid.
+    pub fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    /// Gets a reference to the element at window coordinates (x, y).
+    ///
+    /// Returns `None` if the coordinates lie outside the window.
+    pub fn get(&self, x: u16, y: u16) -> Option<&'a T> {
+        offset(self.rect, self.grid.width, x, y).map(|i| &self.grid.tiles[i])
+    }
+
+    /// Returns window row `y` as a contiguous slice.
+    pub fn row(&self, y: u16) -> Option<&'a [T]> {
+        let start = offset(self.rect, self.grid.width, 0, y)?;
+        Some(&self.grid.tiles[start..start + self.rect.width as usize])
+    }
+
+    /// Returns an iterator over `(x, y, &value)` in row-major order, in window coordinates.
+    pub fn iter(&self) -> impl Iterator<Item = (u16, u16, &'a T)> + use<'a, T> {
+        let view = GridView {
+            grid: self.grid,
+            rect: self.rect,
+        };
+        (0..self.rect.height).flat_map(move |y| {
+            let row = view.row(y).unwrap_or_default();
+            row.iter()
+                .enumerate()
+                .map(move |(x, value)| (x as u16, y, value))
+        })
+    }
+
+    /// Borrows a window of this window; coordinates are local to `self`.
+    ///
+    /// Returns [`GridError::InvalidArgument`] if it does not fit inside `self`.
+    pub fn view(
+        &self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+    ) -> Result<GridView<'a, T>, GridError> {
+        let rect = sub_window(self.rect, Rect::new(x, y, width, height))?;
+        Ok(GridView {
+            grid: self.grid,
+            rect,
+        })
+    }
+}
+
+impl<T: Default + Clone> GridView<'_, T> {
+    /// Copies the window into a new, owned grid.
+    pub fn to_grid(&self) -> Grid<T> {
+        let tiles = (0..self.rect.height)
+            .flat_map(|y| self.row(y).unwrap_or_default().iter().cloned())
+            .collect();
+        Grid {
+            width: self.rect.width,
+            height: self.rect.height,
+            tiles,
+        }
+    }
+}
+
+impl<'a, T> GridViewMut<'a, T> {
+    /// Returns the width of the window.
+    pub fn width(&self) -> u16 {
+        self.rect.width
+    }
+
+    /// Returns the height of the window.
+    pub fn height(&self) -> u16 {
+        self.rect.height
+    }
+
+    /// Returns the window's position and size in the underlying grid.
+    pub fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    /// Gets a reference to the element at window coordinates (x, y).
+    pub fn get(&self, x: u16, y: u16) -> Option<&T> {
+        offset(self.rect, self.grid.width, x, y).map(|i| &self.grid.tiles[i])
+    }
+
+    /// Gets a mutable reference to the element at window coordinates (x, y).
+    pub fn get_mut(&mut self, x: u16, y: u16) -> Option<&mut T> {
+        offset(self.rect, self.grid.width, x, y).map(|i| &mut self.grid.tiles[i])
+    }
+
+    /// Sets the element at window coordinates (x, y).
+    ///
+    /// Returns [`GridError::OutOfBounds`] if the coordinates lie outside the window.
+    pub fn set(&mut self, x: u16, y: u16, value: T) -> Result<(), GridError> {
+        let (width, height) = (self.rect.width, self.rect.height);
+        match self.get_mut(x, y) {
+            Some(cell) => {
+                *cell = value;
+                Ok(())
+            }
+            None => Err(GridError::OutOfBounds {
+                x,
+                y,
+                width,
+                height,
+            }),
+        }
+    }
+
+    /// Returns window row `y` as a contiguous mutable slice.
+    pub fn row_mut(&mut self, y: u16) -> Option<&mut [T]> {
+        let start = offset(self.rect, self.grid.width, 0, y)?;
+        Some(&mut self.grid.tiles[start..start + self.rect.width as usize])
+    }
+
+    /// Returns an iterator over `(x, y, &mut value)` in row-major order, in window coordinates.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (u16, u16, &mut T)> {
+        let Rect {
+            x,
+            y,
+            width,
+            height,
+        } = self.rect;
+        let stride = (self.grid.width as usize).max(1);
+        self.grid
+            .tiles
+            .chunks_mut(stride)
+            .skip(y as usize)
+            .take(height as usize)
+            .zip(0..)
+            .flat_map(move |(row, wy)| {
+                row[x as usize..x as usize + width as usize]
+                    .iter_mut()
+                    .enumerate()
+                    .map(move |(wx, value)| (wx as u16, wy, value))
+            })
+    }
+
+    /// Borrows this window read-only.
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView {
+            grid: self.grid,
+            rect: self.rect,
+        }
+    }
+}
+
+impl<T: Clone> GridViewMut<'_, T> {
+    /// Sets every cell in the window to `value`.
+    pub fn fill(&mut self, value: T) {
+        for y in 0..self.rect.height {
+            if let Some(row) = self.row_mut(y) {
+                row.fill(value.clone());
+            }
+        }
+    }
+}
+
+impl<T> GridLike<T> for GridView<'_, T> {
+    fn width(&self) -> u16 {
+        self.rect.width
+    }
+
+    fn height(&self) -> u16 {
+        self.rect.height
+    }
+
+    fn get(&self, x: u16, y: u16) -> Option<&T> {
+        GridView::get(self, x, y)
+    }
+}
+
+impl<T> GridLike<T> for GridViewMut<'_, T> {
+    fn width(&self) -> u16 {
+        self.rect.width
+    }
+
+    fn height(&self) -> u16 {
+        self.rect.height
+    }
+
+    fn get(&self, x: u16, y: u16) -> Option<&T> {
+        GridViewMut::get(self, x, y)
+    }
+}
+
+impl<T> GridLikeMut<T> for GridViewMut<'_, T> {
+    fn get_mut(&mut self, x: u16, y: u16) -> Option<&mut T> {
+        GridViewMut::get_mut(self, x, y)
+    }
+}
+
+/// A borrowed view of every `step_x`-th column and `step_y`-th row of a [`Grid`].
+///
+/// Created by [`Grid::view_strided`]. View cell (x, y) is grid cell
+/// `(offset_x + x * step_x, offset_y + y * step_y)`, so the view is a
+/// sub-lattice of the grid, as used by multigrid solvers. Implements
+/// [`GridLike`].
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Grid;
+///
+/// let grid = Grid::from_raw_parts(5, 3, (0..15).collect()).unwrap();
+/// let coarse = grid.view_strided(0, 0, 2, 2).unwrap();
+///
+/// assert_eq!((coarse.width(), coarse.height()), (3, 2));
+/// assert_eq!(coarse.get(1, 1), Some(&12));
+/// assert_eq!(coarse.to_parent(2, 1), Some((4, 2)));
+/// assert_eq!(coarse.to_parent(3, 0), None);
+/// ```
+pub struct StridedView<'a, T> {
+    grid: &'a Grid<T>,
+    lattice: Lattice,
+}
+
+/// A mutably borrowed view of a sub-lattice of a [`Grid`].
+///
+/// Created by [`Grid::view_strided_mut`]. Like [`StridedView`] but also
+/// allows writes, which never reach cells off the lattice. Red-black
+/// Gauss-Seidel and checkerboard cellular automata update one colour of
+/// cells at a time through two such views.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Grid;
+///
+/// let mut grid: Grid<u8> = Grid::new(4, 2);
+/// for (_, _, cell) in grid.view_strided_mut(1, 0, 2, 1).unwrap().iter_mut() {
+///     *cell = 1;
+/// }
+/// assert_eq!(grid.as_slice(), &[0, 1, 0, 1, 0, 1, 0, 1]);
+/// ```
+pub struct StridedViewMut<'a, T> {
+    grid: &'a mut Grid<T>,
+    lattice: Lattice,
+}
+
+/// Where a strided view's cells sit in the underlying grid.
+#[derive(Debug, Clone, Copy)]
+struct Lattice {
+    offset: (u16, u16),
+    step: (u16, u16),
+    width: u16,
+    height: u16,
+}
+
+impl Lattice {
+    fn new(
+        grid_size: (u16, u16),
+        offset: (u16, u16),
+        step: (u16, u16),
+    ) -> Result<Lattice, GridError> {
+        if step.0 == 0 || step.1 == 0 {
+            return Err(GridError::InvalidArgument(
+                "Strided view steps must be at least 1".to_string(),
+            ));
+        }
+        let count = |size: u16, offset: u16, step: u16| size.saturating_sub(offset).div_ceil(step);
+        Ok(Lattice {
+            offset,
+            step,
+            width: count(grid_size.0, offset.0, step.0),
+            height: count(grid_size.1, offset.1, step.1),
+        })
+    }
+
+    /// In-range view cells always map inside the grid, so the arithmetic
+    /// below cannot overflow once the bounds check passes.
+    fn to_parent(self, x: u16, y: u16) -> Option<(u16, u16)> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        Some((
+            self.offset.0 + x * self.step.0,
+            self.offset.1 + y * self.step.1,
+        ))
+    }
+
+    fn index(&self, grid_width: u16, x: u16, y: u16) -> Option<usize> {
+        let (px, py) = self.to_parent(x, y)?;
+        Some(py as usize * grid_width as usize + px as usize)
+    }
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Borrows the sub-lattice of cells `(offset_x + i * step_x, offset_y + j * step_y)`.
+    ///
+    /// The view is empty along an axis whose offset lies past the grid.
+    ///
+    /// Returns [`GridError::InvalidArgument`] if either step is 0.
+    pub fn view_strided(
+        &self,
+        offset_x: u16,
+        offset_y: u16,
+        step_x: u16,
+        step_y: u16,
+    ) -> Result<StridedView<'_, T>, GridError> {
+        let lattice = Lattice::new(
+            (self.width, self.height),
+            (offset_x, offset_y),
+            (step_x, step_y),
+        )?;
+        Ok(StridedView {
+            grid: self,
+            lattice,
+        })
+    }
+
+    /// Mutably borrows a sub-lattice of cells; see [`Grid::view_strided`].
+    ///
+    /// Returns [`GridError::InvalidArgument`] if either step is 0.
+    pub fn view_strided_mut(
+        &mut self,
+        offset_x: u16,
+        offset_y: u16,
+        step_x: u16,
+        step_y: u16,
+    ) -> Result<StridedViewMut<'_, T>, GridError> {
+        let lattice = Lattice::new(
+            (self.width, self.height),
+            (offset_x, offset_y),
+            (step_x, step_y),
+        )?;
+        Ok(StridedViewMut {
+            grid: self,
+            lattice,
+        })
+    }
+}
+
+impl<'a, T> StridedView<'a, T> {
+    /// Returns the number of lattice columns.
+    pub fn width(&self) -> u16 {
+        self.lattice.width
+    }
+
+    /// Returns the number of lattice rows.
+    pub fn height(&self) -> u16 {
+        self.lattice.height
+    }
+
+    /// Returns the grid coordinates of view cell (x, y), or `None` if the
+    /// cell lies outside the view.
+    pub fn to_parent(&self, x: u16, y: u16) -> Option<(u16, u16)> {
+        self.lattice.to_parent(x, y)
+    }
+
+    /// Gets a reference to the element at view coordinates (x, y).
+    pub fn get(&self, x: u16, y: u16) -> Option<&'a T> {
+        let grid = self.grid;
+        self.lattice.index(grid.width, x, y).map(|i| &grid.tiles[i])
+    }
+
+    /// Returns an iterator over `(x, y, &value)` in row-major order, in view coordinates.
+    pub fn iter(&self) -> impl Iterator<Item = (u16, u16, &'a T)> + use<'a, T> {
+        let (grid, lattice) = (self.grid, self.lattice);
+        (0..lattice.height).flat_map(move |y| {
+            (0..lattice.width).filter_map(move |x| {
+                lattice
+                    .index(grid.width, x, y)
+                    .map(|i| (x, y, &grid.tiles[i]))
+            })
+        })
+    }
+}
+
+impl<T> StridedViewMut<'_, T> {
+    /// Returns the number of lattice columns.
+    pub fn width(&self) -> u16 {
+        self.lattice.width
+    }
+
+    /// Returns the number of lattice rows.
+    pub fn height(&self) -> u16 {
+        self.lattice.height
+    }
+
+    /// Returns the grid coordinates of view cell (x, y), or `None` if the
+    /// cell lies outside the view.
+    pub fn to_parent(&self, x: u16, y: u16) -> Option<(u16, u16)> {
+        self.lattice.to_parent(x, y)
+    }
+
+    /// Gets a reference to the element at view coordinates (x, y).
+    pub fn get(&self, x: u16, y: u16) -> Option<&T> {
+        self.lattice
+            .index(self.grid.width, x, y)
+            .map(|i| &self.grid.tiles[i])
+    }
+
+    /// Gets a mutable reference to the element at view coordinates (x, y).
+    pub fn get_mut(&mut self, x: u16, y: u16) -> Option<&mut T> {
+        self.lattice
+            .index(self.grid.width, x, y)
+            .map(|i| &mut self.grid.tiles[i])
+    }
+
+    /// Sets the element at view coordinates (x, y).
+    ///
+    /// Returns [`GridError::OutOfBounds`] if the coordinates lie outside the view.
+    pub fn set(&mut self, x: u16, y: u16, value: T) -> Result<(), GridError> {
+        let (width, height) = (self.lattice.width, self.lattice.height);
+        match self.get_mut(x, y) {
+            Some(cell) => {
+                *cell = value;
+                Ok(())
+            }
+            None => Err(GridError::OutOfBounds {
+                x,
+                y,
+                width,
+                height,
+            }),
+        }
+    }
+
+    /// Returns an iterator over `(x, y, &mut value)` in row-major order, in view coordinates.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (u16, u16, &mut T)> {
+        let lattice = self.lattice;
+        let stride = (self.grid.width as usize).max(1);
+        self.grid
+            .tiles
+            .chunks_mut(stride)
+            .skip(lattice.offset.1 as usize)
+            .step_by(lattice.step.1 as usize)
+            .take(lattice.height as usize)
+            .zip(0..)
+            .flat_map(move |(row, y)| {
+                row.iter_mut()
+                    .skip(lattice.offset.0 as usize)
+                    .step_by(lattice.step.0 as usize)
+                    .zip(0..)
+                    .map(move |(value, x)| (x, y, value))
+            })
+    }
+
+    /// Borrows this view read-only.
+    pub fn as_view(&self) -> StridedView<'_, T> {
+        StridedView {
+            grid: self.grid,
+            lattice: self.lattice,
+        }
+    }
+}
+
+impl<T> GridLike<T> for StridedView<'_, T> {
+    fn width(&self) -> u16 {
+        self.lattice.width
+    }
+
+    fn height(&self) -> u16 {
+        self.lattice.height
+    }
+
+    fn get(&self, x: u16, y: u16) -> Option<&T> {
+        StridedView::get(self, x, y)
+    }
+}
+
+impl<T> GridLike<T> for StridedViewMut<'_, T> {
+    fn width(&self) -> u16 {
+        self.lattice.width
+    }
+
+    fn height(&self) -> u16 {
+        self.lattice.height
+    }
+
+    fn get(&self, x: u16, y: u16) -> Option<&T> {
+        StridedViewMut::get(self, x, y)
+    }
+}
+
+impl<T> GridLikeMut<T> for StridedViewMut<'_, T> {
+    fn get_mut(&mut self, x: u16, y: u16) -> Option<&mut T> {
+        StridedViewMut::get_mut(self, x, y)
+    }
+}
+
+/// Returns the index in the underlying grid of window cell (x, y).
+fn offset(rect: Rect, grid_width: u16, x: u16, y: u16) -> Option<usize> {
+    (x < rect.width && y < rect.height)
+        .then(|| (rect.y + y) as usize * grid_width as usize + (rect.x + x) as usize)
+}
+
+/// Places `inner`, given relative to `outer`, into the underlying grid.
+fn sub_window(outer: Rect, inner: Rect) -> Result<Rect, GridError> {
+    if inner.right() <= outer.width as u32 && inner.bottom() <= outer.height as u32 {
+        Ok(Rect::new(
+            outer.x + inner.x,
+            outer.y + inner.y,
+            inner.width,
+            inner.height,
+        ))
+    } else {
+        Err(GridError::InvalidArgument(format!(
+            "A {}x{} view at ({}, {}) does not fit in a {}x{} view",
+            inner.width, inner.height, inner.x, inner.y, outer.width, outer.height
+        )))
+    }
+}
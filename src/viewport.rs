@@ -0,0 +1,134 @@
+use crate::{Grid, Rect};
+
+/// An axis-aligned rectangle in world (render) space.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct WorldRect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl WorldRect {
+    /// Creates a new rectangle from its top-left corner and size.
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> WorldRect {
+        WorldRect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+/// Places grid cells in world space: where cell (0, 0) starts and how large each cell is.
+///
+/// Cell (x, y) covers `origin + (x, y) * cell_size` up to the next cell.
+/// Cell sizes must be positive.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridTransform {
+    pub origin_x: f32,
+    pub origin_y: f32,
+    pub cell_width: f32,
+    pub cell_height: f32,
+}
+
+impl GridTransform {
+    /// Creates a transform with the given origin and cell size.
+    pub fn new(origin_x: f32, origin_y: f32, cell_width: f32, cell_height: f32) -> GridTransform {
+        GridTransform {
+            origin_x,
+            origin_y,
+            cell_width,
+            cell_height,
+        }
+    }
+
+    /// Returns the world position of the top-left corner of cell (x, y).
+    pub fn cell_to_world(&self, x: u16, y: u16) -> (f32, f32) {
+        (
+            self.origin_x + x as f32 * self.cell_width,
+            self.origin_y + y as f32 * self.cell_height,
+        )
+    }
+
+    /// Returns the (possibly out-of-bounds) cell containing a world position.
+    pub fn world_to_cell(&self, wx: f32, wy: f32) -> (i32, i32) {
+        (
+            ((wx - self.origin_x) / self.cell_width).floor() as i32,
+            ((wy - self.origin_y) / self.cell_height).floor() as i32,
+        )
+    }
+
+    /// Returns the cells of a `width × height` grid overlapping `view`.
+    ///
+    /// Returns `None` if no cell overlaps. Cells that only touch the edge of
+    /// the view are excluded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{GridTransform, Rect, WorldRect};
+    ///
+    /// let transform = GridTransform::new(0.0, 0.0, 16.0, 16.0);
+    /// let view = WorldRect::new(20.0, 0.0, 32.0, 16.0);
+    /// assert_eq!(transform.visible_cells(&view, 100, 100), Some(Rect::new(1, 0, 3, 1)));
+    /// ```
+    pub fn visible_cells(&self, view: &WorldRect, width: u16, height: u16) -> Option<Rect> {
+        let span = |start: f32, len: f32, origin: f32, size: f32, max: u16| {
+            let first = ((start - origin) / size).floor().max(0.0);
+            let end = ((start + len - origin) / size).ceil().min(max as f32);
+            (end > first).then_some((first as u16, (end - first) as u16))
+        };
+        let (x, w) = span(view.x, view.width, self.origin_x, self.cell_width, width)?;
+        let (y, h) = span(view.y, view.height, self.origin_y, self.cell_height, height)?;
+        Some(Rect::new(x, y, w, h))
+    }
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Returns an iterator over the cells overlapping a camera rectangle.
+    ///
+    /// Yields `(x, y, (world_x, world_y), &value)` in draw order (row by row,
+    /// top to bottom), where the world position is the cell's top-left corner.
+    /// Only the visible cells are visited, so the cost is independent of the
+    /// grid's size.
+    ///
+    /// # Arguments
+    ///
+    /// * `view` - The camera rectangle in world space
+    /// * `transform` - Where the grid's cells lie in world space
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::{Grid, GridTransform, WorldRect};
+    ///
+    /// let grid: Grid<u8> = Grid::new(1000, 1000);
+    /// let transform = GridTransform::new(-8.0, -8.0, 16.0, 16.0);
+    /// let camera = WorldRect::new(0.0, 0.0, 320.0, 240.0);
+    ///
+    /// let visible: Vec<_> = grid.iter_viewport(&camera, &transform).collect();
+    /// assert_eq!(visible.len(), 21 * 16);
+    /// assert_eq!(visible[0].2, (-8.0, -8.0));
+    /// ```
+    pub fn iter_viewport<'a>(
+        &'a self,
+        view: &WorldRect,
+        transform: &GridTransform,
+    ) -> impl Iterator<Item = (u16, u16, (f32, f32), &'a T)> + 'a {
+        let transform = *transform;
+        transform
+            .visible_cells(view, self.width, self.height)
+            .into_iter()
+            .flat_map(|rect| rect.cells())
+            .map(move |(x, y)| {
+                (
+                    x,
+                    y,
+                    transform.cell_to_world(x, y),
+                    &self.tiles[self.index(x, y)],
+                )
+            })
+    }
+}
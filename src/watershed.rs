@@ -0,0 +1,94 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::{Direction, Grid, GridError};
+
+impl Grid<f32> {
+    /// Segments a heightmap into basins grown from labelled markers.
+    ///
+    /// `markers` has the same size as the heightmap; non-zero cells seed the
+    /// basin with that label and zero cells are unlabelled. Basins flood
+    /// outwards in order of increasing height (priority-flood watershed), so
+    /// each unlabelled cell joins the basin that reaches it over the lowest
+    /// pass. Cells are 4-connected, ties are resolved first-come first-served,
+    /// and NaN heights are flooded last. Cells that no marker can reach keep
+    /// label 0.
+    ///
+    /// Returns [`GridError::DimensionMismatch`] if the grid sizes differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridsystem::Grid;
+    ///
+    /// // Two valleys separated by a ridge at x = 3.
+    /// let heights = Grid::from_raw_parts(7, 1, vec![0.0, 1.0, 2.0, 5.0, 2.0, 1.0, 0.0]).unwrap();
+    /// let mut markers: Grid<u32> = Grid::new(7, 1);
+    /// let _ = markers.set(0, 0, 1);
+    /// let _ = markers.set(6, 0, 2);
+    ///
+    /// let basins = heights.watershed(&markers).unwrap();
+    /// assert_eq!(basins.as_slice()[..3], [1, 1, 1]);
+    /// assert_eq!(basins.as_slice()[4..], [2, 2, 2]);
+    /// ```
+    pub fn watershed(&self, markers: &Grid<u32>) -> Result<Grid<u32>, GridError> {
+        if (self.width, self.height) != (markers.width, markers.height) {
+            return Err(GridError::DimensionMismatch {
+                expected: (self.width, self.height),
+                found: (markers.width, markers.height),
+            });
+        }
+        let mut labels = markers.map(|_, _, &label| label);
+        let mut queue = BinaryHeap::new();
+        let mut order = 0u64;
+
+        for (i, &label) in markers.tiles.iter().enumerate() {
+            if label != 0 {
+                let (x, y) = self.coords(i);
+                self.enqueue_neighbours(x, y, label, &labels, &mut queue, &mut order);
+            }
+        }
+        while let Some(Reverse((_, _, index, label))) = queue.pop() {
+            if labels.tiles[index] != 0 {
+                continue;
+            }
+            labels.tiles[index] = label;
+            let (x, y) = self.coords(index);
+            self.enqueue_neighbours(x, y, label, &labels, &mut queue, &mut order);
+        }
+        Ok(labels)
+    }
+
+    fn enqueue_neighbours(
+        &self,
+        x: u16,
+        y: u16,
+        label: u32,
+        labels: &Grid<u32>,
+        queue: &mut BinaryHeap<Reverse<(u32, u64, usize, u32)>>,
+        order: &mut u64,
+    ) {
+        for direction in Direction::CARDINAL {
+            let (dx, dy) = direction.offset();
+            let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+            if nx < 0 || ny < 0 || nx >= self.width as i32 || ny >= self.height as i32 {
+                continue;
+            }
+            let index = self.index(nx as u16, ny as u16);
+            if labels.tiles[index] == 0 {
+                queue.push(Reverse((sortable(self.tiles[index]), *order, index, label)));
+                *order += 1;
+            }
+        }
+    }
+}
+
+/// Maps an `f32` to a `u32` with the same total order (NaN sorts last).
+fn sortable(value: f32) -> u32 {
+    let bits = value.to_bits();
+    if bits & 0x8000_0000 != 0 {
+        !bits
+    } else {
+        bits | 0x8000_0000
+    }
+}
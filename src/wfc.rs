@@ -0,0 +1,567 @@
+use rand::Rng;
+
+use crate::{BitGrid, CancellationToken, Direction, Grid, GridError, ProgressSink};
+
+/// Which tiles may sit next to which, and how often each tile is chosen,
+/// for [`generate`] and [`generate_masked`].
+///
+/// Build the rules by hand with [`allow`](AdjacencyRules::allow), or learn
+/// them from an example map with [`from_sample`](AdjacencyRules::from_sample).
+/// Adjacency is symmetric: allowing `b` east of `a` also allows `a` west of
+/// `b`.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Direction;
+/// use gridsystem::wfc::AdjacencyRules;
+///
+/// let mut rules = AdjacencyRules::new();
+/// rules.allow('~', Direction::East, '.').unwrap();
+/// assert!(rules.is_allowed(&'.', Direction::West, &'~'));
+/// assert!(!rules.is_allowed(&'~', Direction::West, &'.'));
+/// assert_eq!(rules.tiles(), &['~', '.']);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AdjacencyRules<T> {
+    tiles: Vec<T>,
+    weights: Vec<f32>,
+    // adjacency[a][b][side]: tile `b` may sit on `side` of tile `a`.
+    adjacency: Vec<Vec<[bool; 4]>>,
+}
+
+impl<T> Default for AdjacencyRules<T> {
+    fn default() -> Self {
+        AdjacencyRules {
+            tiles: Vec::new(),
+            weights: Vec::new(),
+            adjacency: Vec::new(),
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> AdjacencyRules<T> {
+    /// Creates rules with no tiles.
+    pub fn new() -> AdjacencyRules<T> {
+        AdjacencyRules::default()
+    }
+
+    /// Learns the rules from an example map.
+    ///
+    /// Every pair of 4-connected neighbours in `sample` becomes an allowed
+    /// adjacency, and each tile's weight is the number of cells holding it,
+    /// so generated maps keep roughly the sample's proportions.
+    pub fn from_sample(sample: &Grid<T>) -> AdjacencyRules<T>
+    where
+        T: Default,
+    {
+        let mut rules = AdjacencyRules::new();
+        let mut counts: Vec<f32> = Vec::new();
+        for (x, y, tile) in sample.iter() {
+            let index = rules.intern(tile);
+            counts.resize(rules.tiles.len(), 0.0);
+            counts[index] += 1.0;
+            for direction in [Direction::East, Direction::South] {
+                let (dx, dy) = direction.offset();
+                if let Some(next) = sample.get(x + dx as u16, y + dy as u16) {
+                    let next = rules.intern(next);
+                    rules.link(index, side(direction), next);
+                }
+            }
+        }
+        counts.resize(rules.tiles.len(), 0.0);
+        rules.weights = counts;
+        rules
+    }
+
+    /// Adds `tile` with the given weight, or changes the weight of a tile
+    /// already present, and returns its index in [`tiles`](AdjacencyRules::tiles).
+    ///
+    /// Tiles are chosen in proportion to their weights among the options
+    /// left for a cell. Tiles added through [`allow`](AdjacencyRules::allow)
+    /// start with a weight of 1.
+    pub fn add_tile(&mut self, tile: T, weight: f32) -> usize {
+        let index = self.intern(&tile);
+        self.weights[index] = weight;
+        index
+    }
+
+    /// Allows `b` to sit next to `a` in `direction`, adding either tile if
+    /// it is new.
+    ///
+    /// Returns [`GridError::InvalidArgument`] for a diagonal direction.
+    pub fn allow(&mut self, a: T, direction: Direction, b: T) -> Result<(), GridError> {
+        if direction.is_diagonal() {
+            return Err(GridError::InvalidArgument(format!(
+                "Adjacency rules only cover orthogonal neighbours, got {:?}",
+                direction
+            )));
+        }
+        let (a, b) = (self.intern(&a), self.intern(&b));
+        self.link(a, side(direction), b);
+        Ok(())
+    }
+
+    /// Returns `true` if `b` may sit next to `a` in `direction`.
+    ///
+    /// Always `false` for diagonal directions and unknown tiles.
+    pub fn is_allowed(&self, a: &T, direction: Direction, b: &T) -> bool {
+        match (self.index_of(a), self.index_of(b)) {
+            (Some(a), Some(b)) if !direction.is_diagonal() => self.adjacency[a][b][side(direction)],
+            _ => false,
+        }
+    }
+
+    /// Returns the tiles, in the order they were first added.
+    pub fn tiles(&self) -> &[T] {
+        &self.tiles
+    }
+
+    /// Returns the number of distinct tiles.
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    /// Returns `true` if no tiles have been added.
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    fn index_of(&self, tile: &T) -> Option<usize> {
+        self.tiles.iter().position(|t| t == tile)
+    }
+
+    fn intern(&mut self, tile: &T) -> usize {
+        if let Some(index) = self.index_of(tile) {
+            return index;
+        }
+        self.tiles.push(tile.clone());
+        self.weights.push(1.0);
+        for row in &mut self.adjacency {
+            row.push([false; 4]);
+        }
+        self.adjacency.push(vec![[false; 4]; self.tiles.len()]);
+        self.tiles.len() - 1
+    }
+
+    fn link(&mut self, a: usize, side: usize, b: usize) {
+        self.adjacency[a][b][side] = true;
+        self.adjacency[b][a][(side + 2) % 4] = true;
+    }
+}
+
+/// Index of an orthogonal direction, clockwise from north.
+fn side(direction: Direction) -> usize {
+    match direction {
+        Direction::North => 0,
+        Direction::East => 1,
+        Direction::South => 2,
+        _ => 3,
+    }
+}
+
+/// Generates a `width × height` grid by wave function collapse.
+///
+/// Every cell starts out able to hold any tile. The solver repeatedly picks
+/// the cell with the fewest options left (ties broken at random), collapses
+/// it to a tile chosen by weight, and removes tiles from the surrounding
+/// cells that no longer have a compatible neighbour. When that leaves a
+/// cell with no options it undoes its most recent choice and rules that
+/// tile out instead. The map edges are unconstrained.
+///
+/// Returns `None` if the rules cannot fill the grid or the solver
+/// backtracked more than `max_backtracks` times. The same rules and RNG
+/// state always give the same result.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::Grid;
+/// use gridsystem::wfc::{AdjacencyRules, generate};
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// // Sea next to beach next to grass, never sea next to grass.
+/// let sample = Grid::from_raw_parts(5, 2, "~~.##~..##".chars().collect()).unwrap();
+/// let rules = AdjacencyRules::from_sample(&sample);
+/// let map = generate(16, 8, &rules, 100, &mut StdRng::seed_from_u64(3)).unwrap();
+///
+/// for (x, y, &tile) in map.iter() {
+///     if let Some(&right) = map.get(x + 1, y) {
+///         assert!(!matches!((tile, right), ('~', '#') | ('#', '~')));
+///     }
+/// }
+/// ```
+pub fn generate<T, R>(
+    width: u16,
+    height: u16,
+    rules: &AdjacencyRules<T>,
+    max_backtracks: usize,
+    rng: &mut R,
+) -> Option<Grid<T>>
+where
+    T: Default + Clone + PartialEq,
+    R: Rng + ?Sized,
+{
+    // A fresh token is never cancelled, so the solve cannot fail.
+    let cancel = CancellationToken::new();
+    generate_cancellable(width, height, rules, max_backtracks, rng, &(), &cancel)
+        .ok()
+        .flatten()
+}
+
+/// Like [`generate`], but reports progress and can be cancelled.
+///
+/// Progress is reported to `progress` as the number of settled cells out
+/// of the cells to generate, and `cancel` is polled before every choice so
+/// a caller can abort a large map.
+///
+/// Returns `Ok(None)` where [`generate`] returns `None`, or
+/// [`GridError::Cancelled`] if the solve was cancelled.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{CancellationToken, Grid, GridError};
+/// use gridsystem::wfc::{AdjacencyRules, generate_cancellable};
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// let sample = Grid::from_raw_parts(5, 2, "~~.##~..##".chars().collect()).unwrap();
+/// let rules = AdjacencyRules::from_sample(&sample);
+/// let mut rng = StdRng::seed_from_u64(3);
+/// let cancel = CancellationToken::new();
+/// let map = generate_cancellable(16, 8, &rules, 100, &mut rng, &|done, total| {
+///     assert!(done <= total);
+/// }, &cancel);
+/// assert!(map.unwrap().is_some());
+///
+/// cancel.cancel();
+/// let map = generate_cancellable(16, 8, &rules, 100, &mut rng, &(), &cancel);
+/// assert!(matches!(map, Err(GridError::Cancelled)));
+/// ```
+pub fn generate_cancellable<T, R, P>(
+    width: u16,
+    height: u16,
+    rules: &AdjacencyRules<T>,
+    max_backtracks: usize,
+    rng: &mut R,
+    progress: &P,
+    cancel: &CancellationToken,
+) -> Result<Option<Grid<T>>, GridError>
+where
+    T: Default + Clone + PartialEq,
+    R: Rng + ?Sized,
+    P: ProgressSink,
+{
+    let mut grid: Grid<T> = Grid::new(width, height);
+    let cells = vec![Cell::Free; grid.tiles.len()];
+    let mut solver = Solver::new(rules, width, height, &cells);
+    if !solver.run(max_backtracks, rng, progress, cancel)? {
+        return Ok(None);
+    }
+    solver.write(&mut grid);
+    Ok(Some(grid))
+}
+
+/// Regenerates the cells of `grid` where `mask` is set, keeping the rest.
+///
+/// Works like [`generate`], but cells outside the mask keep their values
+/// and constrain their masked neighbours, so a patch can be rebuilt to fit
+/// seamlessly into an existing map. Unmasked cells holding a value that is
+/// not in `rules` do not constrain anything.
+///
+/// Returns the new grid, or `Ok(None)` if the masked cells cannot be
+/// filled within `max_backtracks`.
+///
+/// Returns [`GridError::DimensionMismatch`] if the mask is not the size of
+/// the grid.
+///
+/// # Examples
+///
+/// ```
+/// use gridsystem::{BitGrid, Direction, Grid};
+/// use gridsystem::wfc::{AdjacencyRules, generate_masked};
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// let mut rules = AdjacencyRules::new();
+/// for direction in Direction::CARDINAL {
+///     rules.allow(0u8, direction, 1).unwrap();
+/// }
+/// // A checkerboard with a hole punched in it.
+/// let mut board: Grid<u8> = Grid::new(6, 6);
+/// board.map_inplace(|x, y, v| *v = ((x + y) % 2) as u8);
+/// let mut hole = BitGrid::new(6, 6);
+/// let _ = hole.set(2, 3, true);
+/// let _ = board.set(2, 3, 7);
+///
+/// let repaired = generate_masked(&board, &hole, &rules, 10, &mut StdRng::seed_from_u64(0))
+///     .unwrap()
+///     .unwrap();
+/// assert_eq!(repaired.get(2, 3), Some(&1));
+/// ```
+pub fn generate_masked<T, R>(
+    grid: &Grid<T>,
+    mask: &BitGrid,
+    rules: &AdjacencyRules<T>,
+    max_backtracks: usize,
+    rng: &mut R,
+) -> Result<Option<Grid<T>>, GridError>
+where
+    T: Default + Clone + PartialEq,
+    R: Rng + ?Sized,
+{
+    let cancel = CancellationToken::new();
+    generate_masked_cancellable(grid, mask, rules, max_backtracks, rng, &(), &cancel)
+}
+
+/// Like [`generate_masked`], but reports progress and can be cancelled.
+///
+/// Progress and cancellation work as in [`generate_cancellable`]; a
+/// cancelled solve returns [`GridError::Cancelled`].
+pub fn generate_masked_cancellable<T, R, P>(
+    grid: &Grid<T>,
+    mask: &BitGrid,
+    rules: &AdjacencyRules<T>,
+    max_backtracks: usize,
+    rng: &mut R,
+    progress: &P,
+    cancel: &CancellationToken,
+) -> Result<Option<Grid<T>>, GridError>
+where
+    T: Default + Clone + PartialEq,
+    R: Rng + ?Sized,
+    P: ProgressSink,
+{
+    if (mask.width(), mask.height()) != (grid.width, grid.height) {
+        return Err(GridError::DimensionMismatch {
+            expected: (grid.width, grid.height),
+            found: (mask.width(), mask.height()),
+        });
+    }
+    let cells: Vec<Cell> = grid
+        .iter()
+        .map(
+            |(x, y, value)| match (mask.get(x, y), rules.index_of(value)) {
+                (Some(true), _) => Cell::Free,
+                (_, Some(tile)) => Cell::Fixed(tile),
+                (_, None) => Cell::Unknown,
+            },
+        )
+        .collect();
+    let mut solver = Solver::new(rules, grid.width, grid.height, &cells);
+    if !solver.run(max_backtracks, rng, progress, cancel)? {
+        return Ok(None);
+    }
+    let mut result = Grid::from_raw_parts(grid.width, grid.height, grid.tiles.clone())?;
+    solver.write(&mut result);
+    Ok(Some(result))
+}
+
+/// How a cell takes part in a solve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cell {
+    /// Still to be generated.
+    Free,
+    /// Kept as this tile, constraining its neighbours.
+    Fixed(usize),
+    /// Kept, but holds no known tile, so it constrains nothing.
+    Unknown,
+}
+
+struct Solver<'r, T> {
+    rules: &'r AdjacencyRules<T>,
+    width: usize,
+    height: usize,
+    free: Vec<bool>,
+    // wave[cell * n + tile]: `tile` is still possible at `cell`.
+    wave: Vec<bool>,
+    options: Vec<usize>,
+    // Every removal, so a failed choice can be undone.
+    trail: Vec<(usize, usize)>,
+    pending: Vec<usize>,
+}
+
+impl<'r, T: Clone + PartialEq> Solver<'r, T> {
+    fn new(rules: &'r AdjacencyRules<T>, width: u16, height: u16, cells: &[Cell]) -> Self {
+        let n = rules.len();
+        let mut solver = Solver {
+            rules,
+            width: width as usize,
+            height: height as usize,
+            free: vec![false; cells.len()],
+            wave: vec![false; cells.len() * n],
+            options: vec![0; cells.len()],
+            trail: Vec::new(),
+            pending: Vec::new(),
+        };
+        for (cell, &kind) in cells.iter().enumerate() {
+            let possible = &mut solver.wave[cell * n..(cell + 1) * n];
+            match kind {
+                Cell::Free => {
+                    possible.fill(true);
+                    solver.free[cell] = true;
+                    solver.options[cell] = n;
+                }
+                Cell::Fixed(tile) => {
+                    possible[tile] = true;
+                    solver.options[cell] = 1;
+                }
+                Cell::Unknown => continue,
+            }
+            solver.pending.push(cell);
+        }
+        solver
+    }
+
+    fn run<R, P>(
+        &mut self,
+        max_backtracks: usize,
+        rng: &mut R,
+        progress: &P,
+        cancel: &CancellationToken,
+    ) -> Result<bool, GridError>
+    where
+        R: Rng + ?Sized,
+        P: ProgressSink,
+    {
+        if self.free.contains(&true) && self.rules.is_empty() {
+            return Ok(false);
+        }
+        if !self.propagate() {
+            return Ok(false);
+        }
+        let total = self.free.iter().filter(|&&free| free).count();
+        // (cell, trail length before the choice, tile chosen)
+        let mut choices: Vec<(usize, usize, usize)> = Vec::new();
+        let mut backtracks = 0;
+        loop {
+            cancel.check()?;
+            let (next, settled) = self.most_constrained(rng);
+            progress.report(settled, total);
+            let Some(cell) = next else {
+                break;
+            };
+            let tile = self.pick_tile(cell, rng);
+            choices.push((cell, self.trail.len(), tile));
+            for other in 0..self.rules.len() {
+                if other != tile && self.wave[cell * self.rules.len() + other] {
+                    self.ban(cell, other);
+                }
+            }
+            let mut consistent = self.propagate();
+            while !consistent {
+                let Some((cell, mark, tile)) = choices.pop() else {
+                    return Ok(false);
+                };
+                backtracks += 1;
+                if backtracks > max_backtracks {
+                    return Ok(false);
+                }
+                self.undo(mark);
+                consistent = self.ban(cell, tile) && self.propagate();
+            }
+        }
+        Ok(true)
+    }
+
+    /// The free cell with the fewest options above one, ties broken at
+    /// random, and the number of free cells already settled to one option.
+    fn most_constrained<R: Rng + ?Sized>(&self, rng: &mut R) -> (Option<usize>, usize) {
+        let (mut best, mut fewest, mut ties, mut settled) = (None, usize::MAX, 0, 0);
+        for cell in (0..self.free.len()).filter(|&c| self.free[c]) {
+            if self.options[cell] <= 1 {
+                settled += 1;
+            } else if self.options[cell] < fewest {
+                (best, fewest, ties) = (Some(cell), self.options[cell], 1);
+            } else if self.options[cell] == fewest {
+                ties += 1;
+                if rng.random_range(0..ties) == 0 {
+                    best = Some(cell);
+                }
+            }
+        }
+        (best, settled)
+    }
+
+    /// A tile still possible at `cell`, chosen in proportion to its weight.
+    fn pick_tile<R: Rng + ?Sized>(&self, cell: usize, rng: &mut R) -> usize {
+        let n = self.rules.len();
+        let possible: Vec<usize> = (0..n).filter(|&t| self.wave[cell * n + t]).collect();
+        let weight = |t: usize| self.rules.weights[t].max(0.0);
+        let total: f32 = possible.iter().map(|&t| weight(t)).sum();
+        if total <= 0.0 {
+            return possible[rng.random_range(0..possible.len())];
+        }
+        let mut remaining = rng.random::<f32>() * total;
+        for &tile in &possible {
+            remaining -= weight(tile);
+            if remaining < 0.0 {
+                return tile;
+            }
+        }
+        possible[possible.len() - 1]
+    }
+
+    /// Rules `tile` out at `cell`; returns `false` if no options are left.
+    fn ban(&mut self, cell: usize, tile: usize) -> bool {
+        self.wave[cell * self.rules.len() + tile] = false;
+        self.options[cell] -= 1;
+        self.trail.push((cell, tile));
+        self.pending.push(cell);
+        self.options[cell] > 0
+    }
+
+    fn undo(&mut self, mark: usize) {
+        for (cell, tile) in self.trail.drain(mark..) {
+            self.wave[cell * self.rules.len() + tile] = true;
+            self.options[cell] += 1;
+        }
+        self.pending.clear();
+    }
+
+    /// Removes tiles left without a compatible neighbour until nothing
+    /// changes; returns `false` on a contradiction.
+    fn propagate(&mut self) -> bool {
+        let n = self.rules.len();
+        while let Some(cell) = self.pending.pop() {
+            let (x, y) = (cell % self.width, cell / self.width);
+            for direction in Direction::CARDINAL {
+                let (dx, dy) = direction.offset();
+                let (nx, ny) = (x as isize + dx as isize, y as isize + dy as isize);
+                if nx < 0 || ny < 0 || nx as usize >= self.width || ny as usize >= self.height {
+                    continue;
+                }
+                let next = ny as usize * self.width + nx as usize;
+                if !self.free[next] {
+                    continue;
+                }
+                let side = side(direction);
+                for tile in 0..n {
+                    if !self.wave[next * n + tile] {
+                        continue;
+                    }
+                    let supported = (0..n).any(|from| {
+                        self.wave[cell * n + from] && self.rules.adjacency[from][tile][side]
+                    });
+                    if !supported && !self.ban(next, tile) {
+                        self.pending.clear();
+                        return false;
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// Writes the collapsed tile of every free cell into `grid`.
+    fn write(&self, grid: &mut Grid<T>) {
+        let n = self.rules.len();
+        for cell in (0..self.free.len()).filter(|&c| self.free[c]) {
+            if let Some(tile) = (0..n).find(|&t| self.wave[cell * n + t]) {
+                grid.tiles[cell] = self.rules.tiles[tile].clone();
+            }
+        }
+    }
+}
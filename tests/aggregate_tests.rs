@@ -0,0 +1,64 @@
+use gridsystem::{Grid, GridError};
+
+fn checkerboard_labels(width: u16, height: u16) -> Grid<u32> {
+    let mut labels: Grid<u32> = Grid::new(width, height);
+    labels.map_inplace(|x, y, label| *label = 1 + (x / 8 + y / 8) as u32 % 3);
+    labels
+}
+
+#[test]
+fn test_sum_matches_serial_on_large_grid() {
+    let mut values: Grid<u64> = Grid::new(200, 150);
+    values.map_inplace(|x, y, v| *v = (x as u64 * 31 + y as u64 * 17) % 101);
+    let labels = checkerboard_labels(200, 150);
+
+    let sums = values
+        .aggregate_by(&labels, |acc: &mut u64, &v| *acc += v, |a, b| *a += b)
+        .unwrap();
+
+    let mut expected = [0u64; 4];
+    for (v, l) in values.as_slice().iter().zip(labels.as_slice()) {
+        expected[*l as usize] += v;
+    }
+    assert_eq!(sums.len(), 3);
+    for label in 1..4 {
+        assert_eq!(sums[&label], expected[label as usize]);
+    }
+}
+
+#[test]
+fn test_max_per_region_skips_unlabelled_cells() {
+    let values = Grid::from_raw_parts(3, 2, vec![4, 9, 1, 7, 3, 100]).unwrap();
+    let labels = Grid::from_raw_parts(3, 2, vec![1, 1, 2, 2, 1, 0]).unwrap();
+
+    let max = values
+        .aggregate_by(
+            &labels,
+            |acc: &mut Option<i32>, &v| *acc = (*acc).max(Some(v)),
+            |a, b| *a = (*a).max(b),
+        )
+        .unwrap();
+    assert_eq!(max[&1], Some(9));
+    assert_eq!(max[&2], Some(7));
+    assert!(!max.contains_key(&0));
+}
+
+#[test]
+fn test_mismatched_labels_are_rejected() {
+    let values: Grid<u8> = Grid::new(4, 4);
+    let labels: Grid<u32> = Grid::new(4, 3);
+    let Err(e) = values.aggregate_by(
+        &labels,
+        |acc: &mut u32, &v| *acc += v as u32,
+        |a, b| *a += b,
+    ) else {
+        panic!("expected an error");
+    };
+    assert!(matches!(
+        e,
+        GridError::DimensionMismatch {
+            expected: (4, 4),
+            found: (4, 3)
+        }
+    ));
+}
@@ -0,0 +1,40 @@
+use gridsystem::pathfinding::astar;
+use gridsystem::{ArcGrid, Grid, GridInterner};
+
+#[test]
+fn test_interner_shares_repeated_templates() {
+    let mut interner = GridInterner::new();
+    let templates: Vec<ArcGrid<u8>> = (0..300)
+        .map(|i| interner.intern(Grid::with_value(8, 8, (i % 3) as u8)))
+        .collect();
+    assert_eq!(interner.len(), 3);
+    assert!(ArcGrid::ptr_eq(&templates[0], &templates[3]));
+    assert_eq!(templates[0].share_count(), 101);
+
+    // Same cells but different shape are distinct.
+    let wide = interner.intern(Grid::with_value(16, 4, 0u8));
+    assert!(!ArcGrid::ptr_eq(&wide, &templates[0]));
+    assert_eq!(interner.len(), 4);
+
+    drop(wide);
+    drop(templates);
+    assert_eq!(interner.prune(), 4);
+    assert!(interner.is_empty());
+}
+
+#[test]
+fn test_arc_grid_reads_and_into_grid() {
+    let mut grid: Grid<bool> = Grid::new(4, 1);
+    grid.set(2, 0, true).unwrap();
+    let shared = ArcGrid::new(grid);
+    let other = shared.clone();
+
+    // Works wherever a GridLike is accepted.
+    assert!(astar(&other, (0, 0), (3, 0), |_, _, &wall| (!wall).then_some(1)).is_none());
+
+    let mut owned = shared.into_grid();
+    owned.set(2, 0, false).unwrap();
+    assert_eq!(other.get(2, 0), Some(&true));
+    assert_eq!(other.share_count(), 1);
+    assert_eq!(other.into_grid().as_slice(), &[false, false, true, false]);
+}
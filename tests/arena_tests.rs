@@ -0,0 +1,50 @@
+use gridsystem::{Grid, GridArena, GridError};
+
+#[test]
+fn test_grids_do_not_overlap() {
+    let mut arena: GridArena<u32> = GridArena::new();
+    let a = arena.alloc(3, 2);
+    let b = arena.alloc_from(&Grid::from_raw_parts(2, 2, vec![1, 2, 3, 4]).unwrap());
+    let empty = arena.alloc(0, 5);
+
+    for (i, cell) in arena.cells_mut(a).unwrap().iter_mut().enumerate() {
+        *cell = 10 + i as u32;
+    }
+    assert_eq!(arena.cells(b).unwrap(), &[1, 2, 3, 4]);
+    assert_eq!(arena.get(a, 2, 1), Some(&15));
+    assert_eq!(arena.get(a, 3, 0), None);
+    assert_eq!(arena.dimensions(empty), Some((0, 5)));
+    assert!(arena.cells(empty).unwrap().is_empty());
+    assert!(matches!(
+        arena.set(b, 2, 0, 0),
+        Err(GridError::OutOfBounds { x: 2, .. })
+    ));
+    assert_eq!(arena.to_grid(b).unwrap().get(1, 1), Some(&4));
+    assert_eq!((arena.len(), arena.cells_used()), (3, 10));
+}
+
+#[test]
+fn test_reset_reuses_memory_and_invalidates_handles() {
+    let mut arena: GridArena<u8> = GridArena::new();
+    for _ in 0..100 {
+        arena.alloc(8, 8);
+    }
+    let stale = arena.alloc(2, 2);
+    let capacity = arena.capacity();
+    arena.reset();
+    assert!(arena.is_empty());
+
+    let fresh = arena.alloc_with(2, 2, 7);
+    assert_ne!(fresh, stale);
+    assert_eq!(arena.cells(stale), None);
+    assert_eq!(arena.to_grid(stale).map(|g| g.width()), None);
+    assert!(matches!(
+        arena.set(stale, 0, 0, 1),
+        Err(GridError::InvalidArgument(_))
+    ));
+    assert_eq!(arena.get(fresh, 1, 1), Some(&7));
+    for _ in 0..100 {
+        arena.alloc(8, 8);
+    }
+    assert_eq!(arena.capacity(), capacity);
+}
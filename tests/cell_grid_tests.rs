@@ -0,0 +1,29 @@
+use gridsystem::{CellGrid, Grid};
+
+#[test]
+fn test_visitor_reads_and_writes_through_shared_reference() {
+    // Propagate a signal rightwards along each row in a single visitor pass.
+    let grid = CellGrid::from(Grid::from_raw_parts(4, 2, vec![5, 0, 0, 0, 0, 0, 3, 0]).unwrap());
+    let visit = |x: u16, y: u16| {
+        if x > 0 {
+            let left = grid.get(x - 1, y).unwrap();
+            grid.update(x, y, |v: u32| v.max(left.saturating_sub(1)));
+        }
+    };
+    for (x, y, _) in grid.iter() {
+        visit(x, y);
+    }
+    assert_eq!(grid.into_grid().as_slice(), &[5, 4, 3, 2, 0, 0, 3, 2]);
+}
+
+#[test]
+fn test_out_of_bounds_access() {
+    let grid: CellGrid<u8> = CellGrid::new(2, 3);
+    assert_eq!((grid.width(), grid.height()), (2, 3));
+    assert!(grid.set(2, 0, 1).is_err());
+    assert_eq!(grid.get(0, 3), None);
+    assert!(grid.cell(1, 2).is_some());
+    assert!(grid.set(1, 2, 7).is_ok());
+    assert_eq!(grid.cell(1, 2).map(|c| c.replace(8)), Some(7));
+    assert_eq!(grid.get(1, 2), Some(8));
+}
@@ -0,0 +1,80 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use gridsystem::{ChunkedGrid, Grid};
+
+#[test]
+fn test_chunks_generate_once_on_demand() {
+    let calls = Rc::new(Cell::new(0));
+    let counter = Rc::clone(&calls);
+    let mut world = ChunkedGrid::new(8, move |(cx, cy), size| {
+        counter.set(counter.get() + 1);
+        Grid::with_value(size, size, (cx, cy))
+    });
+
+    assert_eq!(world.get(0, 0), None);
+    assert_eq!(calls.get(), 0);
+
+    assert_eq!(world.set(-1, -1, (9, 9)), (-1, -1));
+    *world.get_mut(-8, -8) = (7, 7);
+    assert_eq!(calls.get(), 1);
+    assert_eq!(world.get(-1, -1), Some(&(9, 9)));
+    assert_eq!(world.chunk((-1, -1)).unwrap().get(0, 0), Some(&(7, 7)));
+
+    world.set(8, 0, (1, 1));
+    assert_eq!(calls.get(), 2);
+    let mut loaded: Vec<_> = world.loaded_chunks().map(|(coord, _)| coord).collect();
+    loaded.sort();
+    assert_eq!(loaded, vec![(-1, -1), (1, 0)]);
+
+    // Unloading discards edits; the chunk is regenerated next time.
+    let chunk = world.unload((-1, -1)).unwrap();
+    assert_eq!(chunk.get(7, 7), Some(&(9, 9)));
+    assert_eq!(*world.get_mut(-1, -1), (-1, -1));
+    assert_eq!(calls.get(), 3);
+}
+
+#[test]
+fn test_region_and_paste_cross_chunk_boundaries() {
+    let mut world = ChunkedGrid::new(3, |_, size| Grid::<u32>::new(size, size));
+    let cells = (0..35).collect();
+    let stamp = Grid::from_raw_parts(7, 5, cells).unwrap();
+
+    world.paste(&stamp, -4, -2);
+    assert_eq!(world.loaded_count(), 6);
+    assert_eq!(world.get(-4, -2), Some(&0));
+    assert_eq!(world.get(2, 2), Some(&34));
+    assert_eq!(world.get(0, 0), Some(&18));
+
+    let copy = world.region(-4, -2, 7, 5);
+    assert_eq!(copy.as_slice(), stamp.as_slice());
+
+    let wider = world.region(-5, -2, 2, 1);
+    assert_eq!(wider.as_slice(), &[0, 0]);
+}
+
+#[test]
+fn test_unload_distant_and_restitch() {
+    let mut world = ChunkedGrid::new(2, |(cx, cy), size| Grid::with_value(size, size, cx + cy));
+    for cy in -2..=2 {
+        for cx in -2..=2 {
+            world.load((cx, cy));
+        }
+    }
+    let take_max = |a: &mut i32, b: &mut i32| {
+        let max = (*a).max(*b);
+        (*a, *b) = (max, max);
+    };
+    assert_eq!(world.stitch_borders(take_max), 40);
+
+    let evicted = world.unload_distant(1, 1, 1);
+    assert_eq!(evicted.len(), 16);
+    assert!(world.is_loaded((-1, -1)));
+    assert!(!world.is_loaded((2, 0)));
+
+    // Reloading a chunk makes its seams eligible again.
+    world.load((2, 0));
+    assert_eq!(world.stitch_borders(take_max), 1);
+    assert_eq!(world.unload_all().len(), 10);
+    assert_eq!(world.loaded_count(), 0);
+}
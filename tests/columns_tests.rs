@@ -0,0 +1,101 @@
+use gridsystem::{Grid, GridColumns};
+
+#[test]
+fn test_columns_roundtrip() {
+    let mut grid: Grid<u16> = Grid::new(5, 4);
+    grid.map_inplace(|x, y, v| *v = x * 10 + y);
+
+    let columns = grid.to_columns();
+    assert_eq!(columns.len(), 20);
+    assert_eq!((columns.x[7], columns.y[7], columns.values[7]), (2, 1, 21));
+    let restored = Grid::from_columns(5, 4, &columns).unwrap();
+    assert_eq!(restored.as_slice(), grid.as_slice());
+}
+
+#[test]
+fn test_columns_filtered_and_errors() {
+    let grid: Grid<u8> = Grid::new(3, 3);
+    assert!(grid.to_columns_where(|_, _, &v| v > 0).is_empty());
+
+    let mismatched = GridColumns {
+        x: vec![0, 1],
+        y: vec![0],
+        values: vec![1u8],
+    };
+    assert!(Grid::from_columns(3, 3, &mismatched).is_err());
+
+    let outside = GridColumns {
+        x: vec![3],
+        y: vec![0],
+        values: vec![1u8],
+    };
+    assert!(Grid::from_columns(3, 3, &outside).is_err());
+}
+
+#[cfg(feature = "arrow")]
+#[test]
+fn test_record_batch_columns() {
+    use arrow_array::{Array, BooleanArray, UInt16Array};
+
+    let mut grid: Grid<bool> = Grid::new(3, 2);
+    let _ = grid.set(2, 1, true);
+    let batch = grid.to_record_batch();
+    assert_eq!((batch.num_rows(), batch.num_columns()), (6, 3));
+
+    let x = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<UInt16Array>()
+        .unwrap();
+    let y = batch
+        .column(1)
+        .as_any()
+        .downcast_ref::<UInt16Array>()
+        .unwrap();
+    let values = batch
+        .column(2)
+        .as_any()
+        .downcast_ref::<BooleanArray>()
+        .unwrap();
+    assert_eq!((x.value(5), y.value(5), values.value(5)), (2, 1, true));
+    assert_eq!(values.true_count(), 1);
+
+    let mismatched = GridColumns {
+        x: vec![0, 1],
+        y: vec![0],
+        values: vec![1u8],
+    };
+    assert!(mismatched.to_record_batch().is_err());
+}
+
+#[cfg(feature = "parquet")]
+#[test]
+fn test_parquet_roundtrip() {
+    use arrow_array::{Array, Float32Array};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    let mut grid: Grid<f32> = Grid::new(40, 30);
+    grid.map_inplace(|x, y, v| *v = x as f32 * 0.5 - y as f32);
+
+    let path =
+        std::env::temp_dir().join(format!("gridsystem-columns-{}.parquet", std::process::id()));
+    grid.write_parquet(std::fs::File::create(&path).unwrap())
+        .unwrap();
+    let reader = ParquetRecordBatchReaderBuilder::try_new(std::fs::File::open(&path).unwrap())
+        .unwrap()
+        .build()
+        .unwrap();
+    let mut values = Vec::new();
+    for batch in reader {
+        let batch = batch.unwrap();
+        assert_eq!(batch.schema().as_ref(), &GridColumns::<f32>::arrow_schema());
+        let column = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<Float32Array>()
+            .unwrap();
+        values.extend(column.values().iter().copied());
+    }
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(values, grid.as_slice());
+}
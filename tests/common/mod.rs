@@ -0,0 +1,41 @@
+//! Fixtures shared by the integration tests.
+#![allow(dead_code)]
+
+use gridsystem::Grid;
+
+/// Parses an ASCII map, one string per row, mapping each character with `cell`.
+pub fn parse_with<T, F>(rows: &[&str], cell: F) -> Grid<T>
+where
+    T: Default + Clone,
+    F: Fn(char) -> T,
+{
+    let width = rows.first().map_or(0, |row| row.chars().count());
+    let cells = rows.iter().flat_map(|row| row.chars().map(&cell)).collect();
+    Grid::from_raw_parts(width as u16, rows.len() as u16, cells).unwrap()
+}
+
+/// Parses an ASCII map into its characters.
+pub fn parse_char(rows: &[&str]) -> Grid<char> {
+    parse_with(rows, |c| c)
+}
+
+/// Parses an ASCII map where `#` marks the set cells.
+pub fn parse_bool(rows: &[&str]) -> Grid<bool> {
+    parse_with(rows, |c| c == '#')
+}
+
+/// Renders a grid as one string per row, mapping each cell with `glyph`.
+pub fn render<T, F>(grid: &Grid<T>, glyph: F) -> Vec<String>
+where
+    T: Default + Clone,
+    F: Fn(&T) -> char,
+{
+    (0..grid.height())
+        .map(|y| (0..grid.width()).map(|x| glyph(&grid[(x, y)])).collect())
+        .collect()
+}
+
+/// A grid whose cells hold their row-major index.
+pub fn numbered(width: u16, height: u16) -> Grid<u32> {
+    Grid::from_raw_parts(width, height, (0..width as u32 * height as u32).collect()).unwrap()
+}
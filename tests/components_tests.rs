@@ -0,0 +1,41 @@
+use gridsystem::{Grid, Rect};
+
+mod common;
+
+use common::parse_char;
+
+#[test]
+fn test_components_merge_u_shapes_and_ignore_diagonals() {
+    // The U only joins up on the bottom row, after both arms got their own
+    // provisional roots; the '.' regions touch each other diagonally only.
+    let map = parse_char(&["#.#.#", "#.#..", "###.#", "...#."]);
+    let (labels, summary) = map.connected_components(|a, b| a == b);
+
+    assert_eq!(summary.count(), 8);
+    assert_eq!(labels.as_slice()[..5], [1, 2, 1, 3, 4]);
+    assert_eq!(summary.size(1), Some(7));
+    assert_eq!(summary.bounds(1), Some(Rect::new(0, 0, 3, 3)));
+    assert_eq!(summary.size(3), Some(4));
+    assert_eq!(summary.bounds(3), Some(Rect::new(3, 0, 2, 3)));
+    assert_eq!(labels.get(3, 3), Some(&7));
+    assert_eq!(summary.largest(), Some(1));
+    assert_eq!(summary.sizes.iter().sum::<u32>(), 20);
+    assert_eq!(summary.size(0), None);
+    assert_eq!(summary.bounds(9), None);
+}
+
+#[test]
+fn test_components_with_custom_equivalence() {
+    // Land heights 1..=9 count as one landmass; 0 is water.
+    let heights = Grid::from_raw_parts(4, 2, vec![3, 5, 0, 2, 0, 9, 0, 7]).unwrap();
+    let (labels, summary) = heights.connected_components(|&a: &u8, &b| (a > 0) == (b > 0));
+    assert_eq!(labels.as_slice(), &[1, 1, 2, 3, 4, 1, 2, 3]);
+    assert_eq!(summary.sizes, vec![3, 2, 2, 1]);
+
+    let empty: Grid<u8> = Grid::new(0, 4);
+    let (labels, summary) = empty.connected_components(|a, b| a == b);
+    assert_eq!(
+        (labels.width(), summary.count(), summary.largest()),
+        (0, 0, None)
+    );
+}
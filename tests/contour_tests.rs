@@ -0,0 +1,85 @@
+use gridsystem::{Grid, simplify_polyline};
+
+mod common;
+
+use common::parse_bool;
+
+fn area(ring: &[(f32, f32)]) -> f32 {
+    let n = ring.len();
+    (0..n)
+        .map(|k| ring[k].0 * ring[(k + 1) % n].1 - ring[(k + 1) % n].0 * ring[k].1)
+        .sum::<f32>()
+        / 2.0
+}
+
+#[test]
+fn test_contours_winding_and_diagonal_regions() {
+    let grid = parse_bool(&[
+        "#....", //
+        ".#...", //
+        "...##", //
+        "...##",
+    ]);
+    let rings = grid.contours(|&s| s);
+    // Diagonal neighbours stay separate, and the grid edge closes the block.
+    assert_eq!(rings.len(), 3);
+    assert!(rings.iter().all(|ring| area(ring) > 0.0));
+    let block = rings
+        .iter()
+        .find(|ring| ring.contains(&(5.0, 2.5)))
+        .unwrap();
+    assert_eq!(area(block), 3.5);
+}
+
+#[test]
+fn test_colliders_assign_holes_to_enclosing_region() {
+    let grid = parse_bool(&[
+        "#########", //
+        "#.......#", //
+        "#.###...#", //
+        "#.#.#...#", //
+        "#.###...#", //
+        "#.......#", //
+        "#########",
+    ]);
+    let colliders = grid.colliders(|&s| s, 0.0);
+    assert_eq!(colliders.len(), 2);
+
+    let outer = colliders
+        .iter()
+        .find(|p| p.exterior.iter().any(|&(x, _)| x == 0.0))
+        .unwrap();
+    let inner = colliders
+        .iter()
+        .find(|p| p.exterior.iter().all(|&(x, _)| x > 1.0))
+        .unwrap();
+    assert_eq!(outer.holes.len(), 1);
+    assert_eq!(inner.holes.len(), 1);
+    assert!(area(&outer.holes[0]) < 0.0);
+    // A straight wall simplifies to a single edge: the frame is an octagon.
+    assert_eq!(outer.exterior.len(), 8);
+}
+
+#[test]
+fn test_colliders_tolerance_reduces_points() {
+    let mut grid: Grid<bool> = Grid::new(30, 30);
+    grid.map_inplace(|x, y, s| {
+        *s = (x as f32 - 14.5).hypot(y as f32 - 14.5) < 12.0;
+    });
+    let exact = grid.colliders(|&s| s, 0.0);
+    let rough = grid.colliders(|&s| s, 1.0);
+    assert_eq!(exact.len(), 1);
+    assert!(rough[0].exterior.len() < exact[0].exterior.len());
+    assert!((area(&rough[0].exterior) - area(&exact[0].exterior)).abs() < 30.0);
+}
+
+#[test]
+fn test_simplify_polyline_keeps_short_lines() {
+    let line = [(0.0, 0.0), (5.0, 0.0)];
+    assert_eq!(simplify_polyline(&line, 1.0), line.to_vec());
+    let straight = [(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0)];
+    assert_eq!(
+        simplify_polyline(&straight, 0.0),
+        vec![(0.0, 0.0), (3.0, 3.0)]
+    );
+}
@@ -0,0 +1,60 @@
+use gridsystem::{Direction, EdgePolicy, Grid, GridCursor};
+
+#[test]
+fn test_cursor_rejects_out_of_bounds_start() {
+    let mut grid: Grid<u8> = Grid::new(3, 3);
+    assert!(GridCursor::new(&mut grid, 3, 0).is_none());
+
+    let mut empty: Grid<u8> = Grid::new(0, 0);
+    assert!(GridCursor::new(&mut empty, 0, 0).is_none());
+}
+
+#[test]
+fn test_cursor_edge_policies() {
+    let mut grid: Grid<u8> = Grid::new(4, 3);
+
+    let mut cursor = GridCursor::new(&mut grid, 0, 0).unwrap();
+    assert!(!cursor.move_dir(Direction::West));
+    assert_eq!(cursor.position(), (0, 0));
+    assert!(cursor.peek(Direction::North).is_none());
+
+    let mut cursor = cursor.with_policy(EdgePolicy::Wrap);
+    assert!(cursor.move_dir(Direction::NorthWest));
+    assert_eq!(cursor.position(), (3, 2));
+
+    let mut cursor = cursor.with_policy(EdgePolicy::Clamp);
+    assert!(!cursor.move_by(10, 10));
+    assert!(cursor.move_by(-10, 0));
+    assert_eq!(cursor.position(), (0, 2));
+}
+
+#[test]
+fn test_cursor_reads_and_writes() {
+    let mut grid: Grid<i32> = Grid::new(3, 3);
+    {
+        let mut cursor = GridCursor::new(&mut grid, 1, 1).unwrap();
+        assert_eq!(cursor.set(4), 0);
+        *cursor.get_mut() += 1;
+        assert_eq!(*cursor.get(), 5);
+        for direction in Direction::CARDINAL {
+            cursor.move_dir(direction);
+            cursor.set(1);
+        }
+    }
+    assert_eq!(grid.get(1, 1), Some(&1));
+    assert_eq!(grid.get(1, 0), Some(&1));
+    assert_eq!(grid.get(2, 0), Some(&1));
+    assert_eq!(grid.get(2, 1), Some(&1));
+}
+
+#[test]
+fn test_direction_rotation() {
+    for direction in Direction::ALL {
+        assert_eq!(direction.rotate_cw().rotate_ccw(), direction);
+        assert_eq!(direction.opposite().opposite(), direction);
+        let (dx, dy) = direction.offset();
+        assert_eq!(direction.opposite().offset(), (-dx, -dy));
+    }
+    assert!(Direction::SouthWest.is_diagonal());
+    assert!(!Direction::South.is_diagonal());
+}
@@ -0,0 +1,34 @@
+use gridsystem::{Grid, assert_grids_eq};
+
+#[test]
+fn test_diff_report_truncates_long_lists_and_large_maps() {
+    let a: Grid<u16> = Grid::new(200, 2);
+    let mut b: Grid<u16> = Grid::new(200, 2);
+    b.map_inplace(|x, _, v| *v = x % 3);
+    let report = a.diff_report(&b).unwrap();
+
+    assert!(report.starts_with("266 of 400 cells differ\n"));
+    assert!(!report.contains(".X"), "no map for a 200-wide grid");
+    assert!(report.contains("(1, 0): 0 != 1"));
+    assert!(report.ends_with("... and 246 more\n"));
+
+    let small: Grid<u16> = Grid::new(3, 3);
+    assert_eq!(
+        small.diff_report(&a).unwrap(),
+        "Grid sizes differ: 3x3 vs 200x2"
+    );
+}
+
+#[test]
+fn test_assert_grids_eq_panics_with_report() {
+    let a: Grid<bool> = Grid::new(2, 2);
+    let mut b: Grid<bool> = Grid::new(2, 2);
+    assert_grids_eq!(a, b);
+
+    let _ = b.set(0, 1, true);
+    let panic = std::panic::catch_unwind(|| assert_grids_eq!(a, b, "level {}", 3)).unwrap_err();
+    let message = panic.downcast_ref::<String>().unwrap();
+    assert!(message.contains("grids differ: level 3"));
+    assert!(message.contains("..\nX.\n"));
+    assert!(message.contains("(0, 1): false != true"));
+}
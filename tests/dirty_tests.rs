@@ -0,0 +1,43 @@
+use gridsystem::{DirtyGrid, GridError, Rect};
+
+#[test]
+fn test_marks_aggregate_into_partial_edge_blocks() {
+    let mut dirty = DirtyGrid::new(10, 7, 4).unwrap();
+    assert_eq!(dirty.block_dimensions(), (3, 2));
+    assert_eq!(dirty.block_rect(2, 1), Some(Rect::new(8, 4, 2, 3)));
+    assert_eq!(dirty.block_rect(3, 0), None);
+
+    dirty.mark(9, 6).unwrap();
+    dirty.mark_rect(&Rect::new(3, 3, 2, 20));
+    assert!(matches!(
+        dirty.mark(10, 0),
+        Err(GridError::OutOfBounds { .. })
+    ));
+
+    let blocks: Vec<_> = dirty.dirty_blocks().collect();
+    assert_eq!(blocks, vec![(0, 0), (1, 0), (0, 1), (1, 1), (2, 1)]);
+    let mut cells: Vec<_> = dirty.dirty_cells().collect();
+    cells.sort();
+    assert_eq!(cells.len(), 9);
+    assert!(cells.contains(&(4, 6)) && cells.contains(&(9, 6)));
+    assert!(!dirty.is_dirty(5, 3));
+}
+
+#[test]
+fn test_any_dirty_in_checks_cells_inside_dirty_blocks() {
+    let mut dirty = DirtyGrid::new(32, 32, 8).unwrap();
+    dirty.mark(12, 12).unwrap();
+    assert!(dirty.any_dirty_in(&Rect::new(12, 12, 1, 1)));
+    assert!(dirty.any_dirty_in(&Rect::new(0, 0, 32, 32)));
+    assert!(!dirty.any_dirty_in(&Rect::new(8, 8, 4, 8)));
+    assert!(!dirty.any_dirty_in(&Rect::new(40, 40, 5, 5)));
+
+    dirty.mark(30, 1).unwrap();
+    dirty.clear_block(1, 1);
+    assert!(!dirty.is_dirty(12, 12));
+    assert!(!dirty.is_clean());
+    assert_eq!(dirty.dirty_blocks().collect::<Vec<_>>(), vec![(3, 0)]);
+    dirty.clear();
+    assert!(dirty.is_clean());
+    assert!(DirtyGrid::new(4, 4, 0).is_err());
+}
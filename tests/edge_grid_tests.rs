@@ -0,0 +1,63 @@
+use gridsystem::pathfinding::{astar, astar_with_rules, movement_range_with_rules};
+use gridsystem::{Direction, EdgeGrid, Grid, GridError};
+
+#[test]
+fn test_edges_are_shared_by_both_cells() {
+    let mut rivers: EdgeGrid<u8> = EdgeGrid::new(3, 2);
+    rivers.set_between((2, 1), (2, 0), 4).unwrap();
+    *rivers.edge_between_mut((0, 1), (1, 1)).unwrap() = 7;
+
+    assert_eq!(rivers.edge(2, 0, Direction::South), Some(&4));
+    assert_eq!(rivers.edge(2, 1, Direction::North), Some(&4));
+    assert_eq!(rivers.edge(1, 1, Direction::West), Some(&7));
+    assert_eq!(rivers.edge(2, 0, Direction::East), None);
+    assert_eq!(rivers.edge(0, 0, Direction::North), None);
+    assert_eq!(rivers.edge(0, 0, Direction::SouthEast), None);
+    assert_eq!(rivers.edge_between((1, 0), (1, 0)), None);
+
+    assert!(matches!(
+        rivers.set_between((2, 1), (3, 1), 1),
+        Err(GridError::OutOfBounds { x: 3, y: 1, .. })
+    ));
+    assert!(matches!(
+        rivers.set_between((0, 0), (2, 0), 1),
+        Err(GridError::InvalidArgument(_))
+    ));
+    let empty: EdgeGrid<u8> = EdgeGrid::new(1, 0);
+    assert_eq!((empty.width(), empty.height()), (1, 0));
+}
+
+#[test]
+fn test_thin_walls_steer_pathfinding() {
+    // A wall along the east side of column 1, open only at the bottom row.
+    let open: Grid<u8> = Grid::new(4, 4);
+    let mut walls: EdgeGrid<bool> = EdgeGrid::new(4, 4);
+    for y in 0..3 {
+        walls.set_between((1, y), (2, y), true).unwrap();
+    }
+    let step = |_: u16, _: u16, _: &u8| Some(1);
+
+    assert_eq!(astar(&open, (1, 0), (2, 0), step).unwrap().len(), 2);
+    let path = astar_with_rules(&open, (1, 0), (2, 0), &walls, step).unwrap();
+    assert_eq!(path.len(), 8);
+    assert!(path.contains(&(1, 3)) && path.contains(&(2, 3)));
+
+    let range = movement_range_with_rules(&open, (0, 0), 5, &walls, step);
+    assert_eq!(range.get(2, 3), Some(&Some(5)));
+    assert_eq!(range.get(2, 2), Some(&None));
+}
+
+#[test]
+fn test_line_blocked_by_thin_walls() {
+    let mut walls: EdgeGrid<bool> = EdgeGrid::new(6, 6);
+    walls.set_between((3, 2), (3, 3), true).unwrap();
+    let wall = |&w: &bool| w;
+
+    assert!(walls.line_blocked((3, 0), (3, 5), wall));
+    assert!(walls.line_blocked((3, 5), (3, 0), wall));
+    assert!(!walls.line_blocked((2, 0), (2, 5), wall));
+    assert!(!walls.line_blocked((3, 3), (3, 5), wall));
+    // The diagonal passes exactly through the corner the wall touches.
+    assert!(walls.line_blocked((2, 1), (4, 3), wall));
+    assert!(!walls.line_blocked((0, 0), (0, 0), wall));
+}
@@ -0,0 +1,58 @@
+use gridsystem::Grid;
+
+mod common;
+
+use common::parse_char;
+
+#[test]
+fn test_iter_flood_visits_region_in_bfs_order() {
+    let grid = parse_char(&[
+        "..#...", //
+        "..#.#.", //
+        "....#.", //
+        "####..",
+    ]);
+    let cells: Vec<_> = grid
+        .iter_flood(0, 0, |&c| c == '.')
+        .map(|(x, y, _)| (x, y))
+        .collect();
+    assert_eq!(cells.len(), 16);
+    assert_eq!(cells[0], (0, 0));
+    assert!(cells.contains(&(5, 0)) && cells.contains(&(4, 3)));
+
+    let mut sorted = cells.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(sorted.len(), cells.len());
+
+    // Breadth-first: Manhattan distance never drops on this open cave.
+    let open: Grid<char> = Grid::with_value(9, 9, '.');
+    let distances: Vec<_> = open
+        .iter_flood(4, 4, |_| true)
+        .map(|(x, y, _)| x.abs_diff(4) + y.abs_diff(4))
+        .collect();
+    assert!(distances.windows(2).all(|w| w[0] <= w[1]));
+    assert_eq!(distances.len(), 81);
+}
+
+#[test]
+fn test_iter_flood_empty_cases() {
+    let grid = parse_char(&["#.", ".#"]);
+    assert_eq!(grid.iter_flood(0, 0, |&c| c == '.').count(), 0);
+    assert_eq!(grid.iter_flood(5, 0, |_| true).count(), 0);
+    assert_eq!(grid.iter_flood(1, 0, |&c| c == '.').count(), 1);
+}
+
+#[test]
+fn test_flood_fill_paints_only_connected_region() {
+    // Two caves separated by rock; diagonal contact does not connect them.
+    let mut map = parse_char(&["..#..", ".#.#.", "#..#."]);
+    let filled = map.flood_fill(0, 0, |&c| c == '.', 'a');
+    assert_eq!(filled, 3);
+    assert_eq!(map.flood_fill(2, 1, |&c| c == '.', '.'), 3);
+    assert_eq!(map.flood_region(3, 0, |&c| c == '.').len(), 4);
+    assert_eq!(map.flood_fill(0, 2, |&c| c == '.', 'x'), 0);
+    assert_eq!(map.flood_fill(9, 9, |&c| c == '.', 'x'), 0);
+    let painted: String = map.iter().map(|(_, _, &c)| c).collect();
+    assert_eq!(painted, "aa#..a#.#.#..#.");
+}
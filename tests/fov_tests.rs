@@ -0,0 +1,80 @@
+use gridsystem::fov::field_of_view;
+use gridsystem::{EdgeGrid, Grid};
+
+mod common;
+
+use common::{parse_bool, render};
+
+#[test]
+fn test_room_and_doorway() {
+    let map = parse_bool(&[
+        "#########",
+        "#.......#",
+        "#.......#",
+        "####.####",
+        "#.......#",
+        "#.......#",
+    ]);
+    // Only cells whose centres can be seen through the doorway's opening
+    // are visible beyond it.
+    let seen = map.field_of_view((4, 1), 20, |&wall| wall);
+    assert_eq!(
+        render(&seen, |&seen| if seen { 'o' } else { ' ' }),
+        [
+            "ooooooooo",
+            "ooooooooo",
+            "ooooooooo",
+            "ooooooooo",
+            "    o    ",
+            "   ooo   ",
+        ]
+    );
+}
+
+#[test]
+fn test_visibility_is_symmetric_between_floor_cells() {
+    let mut map: Grid<bool> = Grid::new(14, 11);
+    map.map_inplace(|x, y, wall| *wall = (x * 7 + y * 13) % 11 == 0 || (x * 3 + y * 5) % 17 == 0);
+    let floors: Vec<_> = map
+        .iter()
+        .filter(|(_, _, w)| !**w)
+        .map(|(x, y, _)| (x, y))
+        .collect();
+    let fields: Vec<_> = floors
+        .iter()
+        .map(|&cell| field_of_view(&map, cell, 30, |&w| w))
+        .collect();
+    for (i, &a) in floors.iter().enumerate() {
+        for (j, &b) in floors.iter().enumerate() {
+            assert_eq!(fields[i][b], fields[j][a], "{a:?} and {b:?}");
+        }
+    }
+}
+
+#[test]
+fn test_radius_bounds_and_views() {
+    let open: Grid<bool> = Grid::new(11, 11);
+    let seen = open.field_of_view((5, 5), 3, |&w| w);
+    let count = seen.iter().filter(|(_, _, s)| **s).count();
+    // Lattice points within a circle of radius 3.
+    assert_eq!(count, 29);
+    assert!(seen[(8, 5)]);
+    assert!(!seen[(8, 6)]);
+    assert!(
+        open.field_of_view((11, 0), 5, |&w| w)
+            .iter()
+            .all(|(_, _, s)| !*s)
+    );
+
+    let view = open.view(5, 5, 6, 6).unwrap();
+    let local = field_of_view(&view, (0, 0), 3, |&w| w);
+    assert_eq!(
+        (local.width(), local[(3, 0)], local[(2, 2)]),
+        (6, true, true)
+    );
+
+    let mut walls: EdgeGrid<bool> = EdgeGrid::new(11, 11);
+    walls.set_between((5, 5), (6, 5), true).unwrap();
+    let edged = gridsystem::fov::field_of_view_with_edges(&open, (5, 5), 3, &walls, |&w| w);
+    assert_eq!((edged[(7, 5)], edged[(5, 7)]), (false, true));
+}
@@ -0,0 +1,236 @@
+use gridsystem::Grid;
+use gridsystem::generate::{IslandConfig, NoiseConfig, islands};
+
+fn regions(land: &gridsystem::Grid<bool>, target: bool) -> Vec<Vec<(u16, u16)>> {
+    let mut seen = vec![false; land.area() as usize];
+    let mut regions = Vec::new();
+    for (x, y, &cell) in land.iter() {
+        let i = y as usize * land.width() as usize + x as usize;
+        if cell != target || seen[i] {
+            continue;
+        }
+        let region: Vec<_> = land
+            .iter_flood(x, y, |&c| c == target)
+            .map(|(x, y, _)| (x, y))
+            .collect();
+        for &(x, y) in &region {
+            seen[y as usize * land.width() as usize + x as usize] = true;
+        }
+        regions.push(region);
+    }
+    regions
+}
+
+#[test]
+fn test_islands_cleanup_removes_small_regions() {
+    let config = IslandConfig {
+        width: 96,
+        height: 96,
+        seed: 42,
+        min_island_cells: 30,
+        min_lake_cells: 12,
+        ..IslandConfig::default()
+    };
+    let map = islands(&config).unwrap();
+    assert!(regions(&map.land, true).iter().all(|r| r.len() >= 30));
+    let (w, h) = (map.land.width(), map.land.height());
+    for lake in regions(&map.land, false) {
+        let inland = lake
+            .iter()
+            .all(|&(x, y)| x > 0 && y > 0 && x < w - 1 && y < h - 1);
+        assert!(!inland || lake.len() >= 12);
+    }
+    assert!(
+        map.heights
+            .iter()
+            .all(|(_, _, &v)| (0.0..=1.0).contains(&v))
+    );
+}
+
+#[test]
+fn test_islands_seed_and_sea_level_change_the_map() {
+    let base = IslandConfig::default();
+    let a = islands(&base).unwrap();
+    let b = islands(&IslandConfig {
+        seed: 1,
+        ..base.clone()
+    })
+    .unwrap();
+    assert_ne!(a.heights.as_slice(), b.heights.as_slice());
+
+    let land_count = |config: &IslandConfig| {
+        islands(config)
+            .unwrap()
+            .land
+            .iter()
+            .filter(|&(_, _, &l)| l)
+            .count()
+    };
+    let low = land_count(&IslandConfig {
+        sea_level: 0.2,
+        ..base.clone()
+    });
+    let high = land_count(&IslandConfig {
+        sea_level: 0.5,
+        ..base
+    });
+    assert!(low > high);
+}
+
+#[test]
+fn test_islands_mask_restricts_land_and_heights() {
+    use gridsystem::BitGrid;
+
+    let mut mask = BitGrid::new(64, 64);
+    for y in 0..64 {
+        for x in 0..32 {
+            let _ = mask.set(x, y, true);
+        }
+    }
+    let config = IslandConfig {
+        width: 64,
+        height: 64,
+        seed: 3,
+        falloff: 0.0,
+        sea_level: 0.2,
+        mask: Some(mask),
+        ..IslandConfig::default()
+    };
+    let map = islands(&config).unwrap();
+    assert!(map.land.iter().any(|(x, _, &l)| l && x < 32));
+    assert!(map.land.iter().all(|(x, _, &l)| !l || x < 32));
+    assert!(map.heights.iter().all(|(x, _, &h)| x < 32 || h == 0.0));
+
+    let wrong = IslandConfig {
+        mask: Some(BitGrid::new(10, 10)),
+        ..config
+    };
+    assert!(islands(&wrong).is_err());
+}
+
+#[test]
+fn test_dungeon_rooms_are_separate_and_all_reachable() {
+    use gridsystem::generate::{DungeonConfig, DungeonTile, dungeon};
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    for seed in 0..5 {
+        let config = DungeonConfig {
+            loop_chance: 0.5,
+            ..DungeonConfig::default()
+        };
+        let map = dungeon(&config, &mut StdRng::seed_from_u64(seed));
+        assert!(map.rooms.len() > 3);
+        for (i, a) in map.rooms.iter().enumerate() {
+            assert!(a.x >= 1 && a.y >= 1);
+            assert!(a.right() < config.width as u32 && a.bottom() < config.height as u32);
+            for b in &map.rooms[i + 1..] {
+                let grown = gridsystem::Rect::new(a.x - 1, a.y - 1, a.width + 2, a.height + 2);
+                assert!(grown.intersection(b).is_none());
+            }
+        }
+
+        // Every room is reachable over floor from room 0.
+        let (sx, sy) = (map.rooms[0].x, map.rooms[0].y);
+        let reached: Vec<_> = map
+            .tiles
+            .iter_flood(sx, sy, |&t| t != DungeonTile::Wall)
+            .map(|(x, y, _)| (x, y))
+            .collect();
+        assert!(map.rooms.iter().all(|r| reached.contains(&(r.x, r.y))));
+
+        // The graph is connected and has no duplicate edges.
+        let mut edges = map.connections.clone();
+        edges.sort();
+        edges.dedup();
+        assert_eq!(edges.len(), map.connections.len());
+        let mut seen = vec![false; map.rooms.len()];
+        let mut stack = vec![0];
+        while let Some(room) = stack.pop() {
+            if !std::mem::replace(&mut seen[room], true) {
+                stack.extend(map.neighbours(room));
+            }
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+}
+
+#[test]
+fn test_wang_tiling_matches_all_edges_or_reports_failure() {
+    use gridsystem::generate::{WangTile, wang_tiling};
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    // Road pieces: 'r' edges must meet 'r' edges.
+    let tiles = [
+        WangTile::new('g', 'g', 'g', 'g'),
+        WangTile::new('g', 'r', 'g', 'r'),
+        WangTile::new('r', 'g', 'r', 'g'),
+        WangTile::new('r', 'r', 'r', 'r'),
+        WangTile::new('g', 'r', 'r', 'g'),
+    ];
+    let tiling = wang_tiling(10, 10, &tiles, 10_000, &mut StdRng::seed_from_u64(9)).unwrap();
+    for (x, y, &i) in tiling.iter() {
+        if let Some(&east) = tiling.get(x + 1, y) {
+            assert_eq!(tiles[i].east, tiles[east].west);
+        }
+        if let Some(&south) = tiling.get(x, y + 1) {
+            assert_eq!(tiles[i].south, tiles[south].north);
+        }
+    }
+
+    // A tile whose east never matches any west can only fill a single column.
+    let stuck = [WangTile::new(0, 1, 0, 2)];
+    assert!(wang_tiling(1, 4, &stuck, 100, &mut StdRng::seed_from_u64(0)).is_some());
+    assert!(wang_tiling(2, 4, &stuck, 100, &mut StdRng::seed_from_u64(0)).is_none());
+    assert!(wang_tiling::<u8, _>(0, 0, &[], 0, &mut StdRng::seed_from_u64(0)).is_some());
+}
+
+#[test]
+fn test_noise_octaves_and_seed_shape_the_field() {
+    let base = NoiseConfig {
+        seed: 9,
+        octaves: 1,
+        ..NoiseConfig::default()
+    };
+    let smooth = Grid::from_noise(96, 96, base.clone()).unwrap();
+    let rough = Grid::from_noise(
+        96,
+        96,
+        NoiseConfig {
+            octaves: 6,
+            persistence: 0.8,
+            ..base.clone()
+        },
+    )
+    .unwrap();
+    let other = Grid::from_noise(96, 96, NoiseConfig { seed: 10, ..base }).unwrap();
+
+    let roughness = |field: &Grid<f32>| -> f32 {
+        field
+            .iter()
+            .filter(|&(x, _, _)| x > 0)
+            .map(|(x, y, &h)| (h - field[(x - 1, y)]).abs())
+            .sum()
+    };
+    assert!(roughness(&rough) > roughness(&smooth));
+    assert_ne!(other.as_slice(), smooth.as_slice());
+    assert!(smooth.iter().any(|(_, _, &h)| h > 0.6) && smooth.iter().any(|(_, _, &h)| h < 0.4));
+}
+
+#[test]
+fn test_noise_mask_leaves_other_cells_zero() {
+    use gridsystem::BitGrid;
+
+    let mut mask = BitGrid::new(20, 10);
+    let _ = mask.set(3, 4, true);
+    let config = NoiseConfig {
+        seed: 1,
+        mask: Some(mask),
+        ..NoiseConfig::default()
+    };
+    let field = Grid::from_noise(20, 10, config.clone()).unwrap();
+    assert!(field.iter().all(|(x, y, &h)| (x, y) == (3, 4) || h == 0.0));
+    assert!(field[(3, 4)] > 0.0);
+    assert!(Grid::from_noise(10, 10, config).is_err());
+}
@@ -0,0 +1,74 @@
+#[cfg(feature = "geotiff")]
+use gridsystem::CrsModel;
+use gridsystem::{GeoGrid, GeoTransform, Grid};
+
+#[test]
+fn test_geotransform_roundtrip_with_rotation() {
+    let t = GeoTransform::from_gdal([500_000.0, 30.0, 2.0, 4_100_000.0, 1.5, -30.0]);
+    assert_eq!(GeoTransform::from_gdal(t.to_gdal()), t);
+    let (x, y) = t.apply(12.25, 7.5);
+    let (column, row) = t.invert(x, y).unwrap();
+    assert!((column - 12.25).abs() < 1e-9 && (row - 7.5).abs() < 1e-9);
+
+    let singular = GeoTransform::from_gdal([0.0, 1.0, 1.0, 0.0, 1.0, 1.0]);
+    assert_eq!(singular.invert(1.0, 1.0), None);
+}
+
+#[test]
+fn test_geogrid_cell_lookup() {
+    let grid: Grid<u8> = Grid::new(10, 5);
+    let geo = GeoGrid::new(
+        grid,
+        GeoTransform::north_up(-180.0, 90.0, 36.0, 36.0),
+        "EPSG:4326",
+    );
+    assert_eq!(geo.crs(), "EPSG:4326");
+
+    for (x, y, _) in geo.grid().iter() {
+        let (lon, lat) = geo.cell_to_lonlat(x, y);
+        assert_eq!(geo.lonlat_to_cell(lon, lat), Some((x, y)));
+    }
+    assert_eq!(geo.lonlat_to_cell(-180.0, 90.0), Some((0, 0)));
+    assert_eq!(geo.lonlat_to_cell(180.0, 0.0), None);
+    assert_eq!(geo.lonlat_to_cell(0.0, -90.5), None);
+}
+
+#[cfg(feature = "geotiff")]
+#[test]
+fn test_geotiff_roundtrip() {
+    let mut grid: Grid<f32> = Grid::new(5, 3);
+    grid.map_inplace(|x, y, v| *v = x as f32 - y as f32 * 0.25);
+    let transform = GeoTransform::north_up(600_000.0, 5_000_000.0, 10.0, 10.0);
+    let geo = GeoGrid::new(grid, transform, "EPSG:32633");
+
+    let mut bytes = Vec::new();
+    geo.write_geotiff(&mut bytes, CrsModel::Projected).unwrap();
+    let read = GeoGrid::read_geotiff(bytes.as_slice()).unwrap();
+    assert_eq!(read.grid().as_slice(), geo.grid().as_slice());
+    assert_eq!(read.transform(), geo.transform());
+    assert_eq!(read.crs(), "EPSG:32633");
+
+    assert!(GeoGrid::read_geotiff(&bytes[..bytes.len() / 2]).is_err());
+}
+
+#[cfg(feature = "geotiff")]
+#[test]
+fn test_geotiff_geographic_crs() {
+    let transform = GeoTransform::north_up(-100.0, 50.0, 0.5, 0.5);
+    let geo = GeoGrid::new(Grid::with_value(4, 2, 1.5f32), transform, "EPSG:4269");
+    let mut bytes = Vec::new();
+    geo.write_geotiff(&mut bytes, CrsModel::Geographic).unwrap();
+    // GTModelTypeGeoKey = geographic, then GeographicTypeGeoKey = 4269.
+    let key = |id: u16, value: u16| {
+        [id, 0, 1, value]
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect::<Vec<u8>>()
+    };
+    let contains = |needle: &[u8]| bytes.windows(needle.len()).any(|w| w == needle);
+    assert!(contains(&key(1024, 2)));
+    assert!(contains(&key(2048, 4269)));
+    let read = GeoGrid::read_geotiff(bytes.as_slice()).unwrap();
+    assert_eq!(read.crs(), "EPSG:4269");
+    assert_eq!(read.grid().get(3, 1), Some(&1.5));
+}
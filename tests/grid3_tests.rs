@@ -0,0 +1,66 @@
+use gridsystem::{Grid, Grid3, GridError};
+
+#[test]
+fn test_layout_is_x_then_y_then_z() {
+    let tiles: Vec<u32> = (0..24).collect();
+    let grid = Grid3::from_raw_parts(4, 3, 2, tiles).unwrap();
+    assert_eq!(grid.get(1, 0, 0), Some(&1));
+    assert_eq!(grid.get(0, 1, 0), Some(&4));
+    assert_eq!(grid.get(0, 0, 1), Some(&12));
+    assert_eq!(grid.get(3, 2, 1), Some(&23));
+    assert_eq!(grid.get(4, 0, 0), None);
+    for (x, y, z, &v) in grid.iter() {
+        assert_eq!(v, x as u32 + y as u32 * 4 + z as u32 * 12);
+    }
+
+    let Err(e) = Grid3::from_raw_parts(4, 3, 2, vec![0u8; 23]) else {
+        panic!("expected an error");
+    };
+    assert_eq!(
+        e,
+        GridError::LengthMismatch {
+            expected: 24,
+            found: 23
+        }
+    );
+}
+
+#[test]
+fn test_parallel_map_matches_serial_map() {
+    let mut grid: Grid3<i64> = Grid3::new(17, 9, 5);
+    for (x, y, z, v) in grid.iter_mut() {
+        *v = x as i64 * 3 - y as i64 * 7 + z as i64 * 11;
+    }
+    let f = |x: u16, _: u16, z: u16, &v: &i64| v * v - x as i64 + z as i64;
+    assert_eq!(grid.par_map(f).as_slice(), grid.map(f).as_slice());
+}
+
+#[test]
+fn test_layers_view_and_copy_without_touching_neighbours() {
+    let mut grid: Grid3<u8> = Grid3::new(3, 2, 3);
+    {
+        let mut middle = grid.layer_mut(1).unwrap();
+        middle.set(2, 1, 5).unwrap();
+    }
+    assert_eq!(
+        grid.layers()
+            .map(|l| l.iter().filter(|(_, _, v)| **v == 5).count())
+            .collect::<Vec<_>>(),
+        vec![0, 1, 0]
+    );
+    assert!(grid.layer(3).is_none());
+
+    let copy: Grid<u8> = grid.layer_to_grid(1).unwrap();
+    assert_eq!(copy.get(2, 1), Some(&5));
+
+    let Err(e) = Grid3::from_layers(vec![Grid::<u8>::new(2, 2), Grid::new(2, 3)]) else {
+        panic!("expected an error");
+    };
+    assert!(matches!(
+        e,
+        GridError::DimensionMismatch {
+            expected: (2, 2),
+            found: (2, 3)
+        }
+    ));
+}
@@ -0,0 +1,28 @@
+use gridsystem::{Grid, GridLike, GridLikeMut};
+
+fn sum<G: GridLike<i32>>(grid: &G) -> i32 {
+    let mut total = 0;
+    for y in 0..grid.height() {
+        for x in 0..grid.width() {
+            total += grid.get(x, y).copied().unwrap_or_default();
+        }
+    }
+    total
+}
+
+#[test]
+fn test_grid_implements_grid_like() {
+    let grid: Grid<i32> = Grid::with_value(4, 5, 2);
+    assert_eq!(sum(&grid), 40);
+    assert!(GridLike::in_bounds(&grid, 3, 4));
+    assert!(!GridLike::in_bounds(&grid, 4, 0));
+}
+
+#[test]
+fn test_grid_like_mut_set_reports_out_of_bounds() {
+    let mut grid: Grid<i32> = Grid::new(3, 3);
+    assert!(GridLikeMut::set(&mut grid, 1, 1, 5).is_ok());
+    let err = GridLikeMut::set(&mut grid, 3, 0, 5).unwrap_err();
+    assert!(err.to_string().contains("3x3"));
+    assert_eq!(sum(&grid), 5);
+}
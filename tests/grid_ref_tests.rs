@@ -0,0 +1,56 @@
+use gridsystem::pathfinding::astar;
+use gridsystem::{Grid, GridArena, GridError, GridLikeMut, GridRef, GridRefMut};
+
+#[test]
+fn test_grid_ref_reads_external_memory() {
+    let buffer: Vec<u16> = (0..12).collect();
+    let grid = GridRef::new(4, 3, &buffer).unwrap();
+    assert_eq!((grid.width(), grid.height()), (4, 3));
+    assert_eq!(grid.get(3, 2), Some(&11));
+    assert_eq!(grid.get(4, 0), None);
+    assert_eq!(grid.row(3), None);
+    assert_eq!(grid.iter().nth(5), Some((1, 1, &5)));
+    assert_eq!(grid.to_grid().as_slice(), buffer.as_slice());
+    assert!(matches!(
+        GridRef::new(5, 3, &buffer),
+        Err(GridError::LengthMismatch {
+            expected: 15,
+            found: 12
+        })
+    ));
+
+    let owned = Grid::from_raw_parts(3, 1, vec![false, true, false]).unwrap();
+    let borrowed = GridRef::from(&owned);
+    let step = |_: u16, _: u16, &wall: &bool| (!wall).then_some(1);
+    assert!(astar(&borrowed, (0, 0), (2, 0), step).is_none());
+}
+
+#[test]
+fn test_grid_ref_mut_writes_through() {
+    let mut buffer = [0u8; 6];
+    {
+        let mut grid = GridRefMut::new(3, 2, &mut buffer).unwrap();
+        for (x, y, cell) in grid.iter_mut() {
+            *cell = (x + 10 * y) as u8;
+        }
+        assert!(grid.set(3, 0, 1).is_err());
+        GridLikeMut::set(&mut grid, 0, 0, 99).unwrap();
+        assert_eq!(grid.as_grid_ref().row(1), Some(&[10u8, 11, 12][..]));
+        assert_eq!(grid.iter().count(), 6);
+    }
+    assert_eq!(buffer, [99, 1, 2, 10, 11, 12]);
+
+    let mut owned: Grid<u8> = Grid::new(2, 2);
+    GridRefMut::from(&mut owned).fill(4);
+    assert_eq!(owned.as_slice(), &[4; 4]);
+}
+
+#[test]
+fn test_arena_grids_as_grid_refs() {
+    let mut arena: GridArena<u8> = GridArena::new();
+    let handle = arena.alloc(2, 2);
+    arena.grid_mut(handle).unwrap().set(1, 1, 5).unwrap();
+    assert_eq!(arena.grid(handle).unwrap().get(1, 1), Some(&5));
+    arena.reset();
+    assert!(arena.grid(handle).is_none());
+}
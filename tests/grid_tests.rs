@@ -1,4 +1,4 @@
-use gridsystem::Grid;
+use gridsystem::{Grid, Rect};
 
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
 struct Tile {
@@ -70,8 +70,8 @@ fn test_error_message() {
     let Err(err) = result else {
         panic!("expected error, got: {result:?}");
     };
-    assert!(err.contains("10, 10"));
-    assert!(err.contains("5x5"));
+    assert!(err.to_string().contains("10, 10"));
+    assert!(err.to_string().contains("5x5"));
 }
 
 #[test]
@@ -140,3 +140,315 @@ fn test_large_map() {
     let map: Grid<u8> = Grid::new(255, 255);
     assert_eq!(map.area(), 65025);
 }
+
+#[test]
+fn test_get_many_mut() {
+    let mut map: Grid<i32> = Grid::new(4, 4);
+    let _ = map.set(1, 2, 7);
+
+    if let Some([a, b, c]) = map.get_many_mut([(0, 0), (1, 2), (3, 3)]) {
+        *a = *b + 1;
+        *c = *b * 2;
+        *b = 0;
+    }
+    assert_eq!(map.get(0, 0), Some(&8));
+    assert_eq!(map.get(1, 2), Some(&0));
+    assert_eq!(map.get(3, 3), Some(&14));
+
+    // Overlapping or out-of-bounds requests are rejected
+    assert!(map.get_many_mut([(2, 2), (0, 0), (2, 2)]).is_none());
+    assert!(map.get_many_mut([(0, 0), (4, 0)]).is_none());
+    assert!(map.get_many_mut::<0>([]).is_some());
+}
+
+#[test]
+fn test_row_access() {
+    let mut map: Grid<i32> = Grid::new(3, 3);
+    map.row_mut(1).unwrap().copy_from_slice(&[4, 5, 6]);
+
+    assert_eq!(map.row(0), Some(&[0, 0, 0][..]));
+    assert_eq!(map.row(1), Some(&[4, 5, 6][..]));
+    assert_eq!(map.get(2, 1), Some(&6));
+    assert!(map.row(3).is_none());
+    assert!(map.row_mut(3).is_none());
+
+    let empty: Grid<i32> = Grid::new(0, 2);
+    assert_eq!(empty.row(1), Some(&[][..]));
+}
+
+#[test]
+fn test_copy_region() {
+    let mut src: Grid<i32> = Grid::new(4, 4);
+    src.map_inplace(|x, y, v| *v = (y as i32) * 10 + x as i32);
+    let mut dst: Grid<i32> = Grid::with_value(5, 5, -1);
+
+    let written = dst.copy_region(&src, Rect::new(1, 1, 2, 3), (0, 0));
+    assert_eq!(written, Some(Rect::new(0, 0, 2, 3)));
+    assert_eq!(dst.row(0), Some(&[11, 12, -1, -1, -1][..]));
+    assert_eq!(dst.row(2), Some(&[31, 32, -1, -1, -1][..]));
+    assert_eq!(dst.row(3), Some(&[-1; 5][..]));
+
+    // Source rect is clipped to the source grid, destination to the target grid
+    let written = dst.copy_region(&src, Rect::new(2, 2, 10, 10), (4, 4));
+    assert_eq!(written, Some(Rect::new(4, 4, 1, 1)));
+    assert_eq!(dst.get(4, 4), Some(&22));
+
+    assert_eq!(dst.copy_region(&src, Rect::new(0, 0, 2, 2), (5, 0)), None);
+    assert_eq!(dst.copy_region(&src, Rect::new(4, 0, 2, 2), (0, 0)), None);
+}
+
+#[test]
+fn test_swap_and_rotate_rows_cols() {
+    let mut map: Grid<i32> = Grid::new(3, 3);
+    map.map_inplace(|x, y, v| *v = (y as i32) * 3 + x as i32);
+
+    assert!(map.swap_rows(2, 0).is_ok());
+    assert_eq!(map.row(0), Some(&[6, 7, 8][..]));
+    assert_eq!(map.row(2), Some(&[0, 1, 2][..]));
+
+    assert!(map.swap_cols(0, 1).is_ok());
+    assert_eq!(map.row(0), Some(&[7, 6, 8][..]));
+
+    assert!(map.rotate_row(1, 4).is_ok());
+    assert_eq!(map.row(1), Some(&[5, 4, 3][..]));
+
+    assert!(map.rotate_col(2, -1).is_ok());
+    assert_eq!(map.get(2, 0), Some(&3));
+    assert_eq!(map.get(2, 1), Some(&2));
+    assert_eq!(map.get(2, 2), Some(&8));
+
+    assert!(map.swap_rows(1, 3).is_err());
+    assert!(map.swap_cols(3, 1).is_err());
+    assert!(map.rotate_row(3, 1).is_err());
+    assert!(map.rotate_col(3, 1).is_err());
+}
+
+#[test]
+fn test_raw_parts_roundtrip() {
+    let mut map: Grid<i32> = Grid::new(3, 2);
+    let _ = map.set(2, 1, 9);
+    let ptr = map.as_slice().as_ptr();
+
+    let (width, height, tiles) = map.into_raw_parts();
+    assert_eq!((width, height), (3, 2));
+    assert_eq!(tiles.as_ptr(), ptr);
+
+    let map = Grid::from_raw_parts(width, height, tiles).unwrap();
+    assert_eq!(map.get(2, 1), Some(&9));
+    assert_eq!(map.into_vec(), vec![0, 0, 0, 0, 0, 9]);
+
+    let Err(err) = Grid::from_raw_parts(2, 2, vec![1, 2, 3]) else {
+        panic!("expected a length mismatch error");
+    };
+    assert_eq!(
+        err,
+        gridsystem::GridError::LengthMismatch {
+            expected: 4,
+            found: 3
+        }
+    );
+}
+
+#[test]
+fn test_try_new() {
+    let map: Grid<i32> = Grid::try_new(4, 3).unwrap();
+    assert_eq!(map.area(), 12);
+    assert_eq!(map.get(3, 2), Some(&0));
+
+    let Err(err) = Grid::<i32>::try_new_with_budget(64, 64, 1000) else {
+        panic!("expected the cell budget to be exceeded");
+    };
+    assert!(err.to_string().contains("64x64"));
+}
+
+#[test]
+fn test_empty_grids() {
+    for (width, height) in [(0, 0), (0, 7), (7, 0)] {
+        let mut map: Grid<i32> = Grid::new(width, height);
+        assert!(map.is_empty());
+        assert_eq!(map.area(), 0);
+        assert!(map.get(0, 0).is_none());
+        assert_eq!(map.iter().count(), 0);
+        assert_eq!(map.iter_mut().count(), 0);
+        assert_eq!(map.map(|_, _, v| *v).area(), 0);
+        map.map_inplace(|_, _, v| *v += 1);
+        map.par_map_inplace(|_, _, v| *v += 1);
+        assert_eq!(map.par_map(|_, _, v| *v).area(), 0);
+    }
+}
+
+#[test]
+fn test_iterators_are_exact_and_double_ended() {
+    use rayon::prelude::*;
+
+    let mut map: Grid<i32> = Grid::new(4, 3);
+    map.map_inplace(|x, y, v| *v = (y as i32) * 4 + x as i32);
+
+    {
+        let mut iter = map.iter();
+        assert_eq!(iter.len(), 12);
+        assert_eq!(iter.next_back(), Some((3, 2, &11)));
+        assert_eq!(iter.next(), Some((0, 0, &0)));
+        assert_eq!(iter.len(), 10);
+    }
+
+    let reversed: Vec<i32> = map.iter_mut().rev().map(|(_, _, v)| *v).collect();
+    assert_eq!(reversed.first(), Some(&11));
+    assert_eq!(map.iter_mut().len(), 12);
+
+    let other: Grid<i32> = Grid::with_value(4, 3, 100);
+    let sums: Vec<i32> = map
+        .par_iter()
+        .zip(other.par_iter())
+        .map(|((_, _, a), (_, _, b))| a + b)
+        .collect();
+    assert_eq!(sums[5], 105);
+    assert_eq!(map.par_iter_mut().len(), 12);
+}
+
+#[test]
+fn test_par_zip_map() {
+    let mut a: Grid<i32> = Grid::new(5, 4);
+    a.map_inplace(|x, y, v| *v = x as i32 + y as i32);
+    let b: Grid<u8> = Grid::with_value(5, 4, 3);
+
+    let combined = a
+        .par_zip_map(&b, |x, y, &va, &vb| (x, y, va * vb as i32))
+        .unwrap();
+    for (x, y, &(cx, cy, v)) in combined.iter() {
+        assert_eq!((cx, cy), (x, y));
+        assert_eq!(v, (x as i32 + y as i32) * 3);
+    }
+
+    let mismatched: Grid<u8> = Grid::new(4, 5);
+    let Err(err) = a.par_zip_map(&mismatched, |_, _, _, _| 0) else {
+        panic!("expected a dimension mismatch");
+    };
+    assert!(err.to_string().contains("5x4 vs 4x5"));
+}
+
+#[test]
+fn test_par_fold_and_min_max() {
+    let mut map: Grid<i64> = Grid::new(300, 200);
+    map.par_map_inplace(|x, y, v| *v = x as i64 * y as i64 - 1000);
+
+    let sequential: i64 = map.iter().map(|(_, _, &v)| v).sum();
+    let parallel = map.par_fold(|| 0i64, |acc, _, _, &v| acc + v, |a, b| a + b);
+    assert_eq!(parallel, sequential);
+
+    assert_eq!(map.par_min_max(), Some((-1000, 299 * 199 - 1000)));
+
+    let mut floats: Grid<f32> = Grid::with_value(3, 3, 1.0);
+    let _ = floats.set(0, 0, f32::NAN);
+    let _ = floats.set(2, 2, -4.0);
+    assert_eq!(floats.par_min_max(), Some((-4.0, 1.0)));
+
+    let empty: Grid<i32> = Grid::new(0, 0);
+    assert_eq!(empty.par_min_max(), None);
+}
+
+#[test]
+fn test_set_many_applies_valid_writes() {
+    use gridsystem::GridError;
+
+    let mut grid: Grid<i32> = Grid::new(3, 2);
+    assert!(grid.set_many([(0, 0, 1), (2, 1, 2)]).is_ok());
+    assert_eq!(grid.get(2, 1), Some(&2));
+
+    let failures = grid
+        .set_many(vec![(3, 0, 7), (1, 1, 8), (0, 2, 9)])
+        .unwrap_err();
+    assert_eq!(
+        failures,
+        vec![
+            (
+                0,
+                GridError::OutOfBounds {
+                    x: 3,
+                    y: 0,
+                    width: 3,
+                    height: 2
+                }
+            ),
+            (
+                2,
+                GridError::OutOfBounds {
+                    x: 0,
+                    y: 2,
+                    width: 3,
+                    height: 2
+                }
+            ),
+        ]
+    );
+    assert_eq!(grid.get(1, 1), Some(&8));
+    assert_eq!(
+        failures[0].1.to_string(),
+        "Coordinates (3, 0) out of bounds (grid is 3x2)"
+    );
+}
+
+#[test]
+fn test_index_and_index_mut() {
+    let mut grid: Grid<Tile> = Grid::new(3, 2);
+    grid[(2, 1)].walkable = true;
+    assert!(grid[(2, 1)].walkable);
+    assert_eq!(grid.get(2, 1), Some(&grid[(2, 1)]));
+}
+
+#[test]
+#[should_panic(expected = "Coordinates (3, 0) out of bounds (grid is 3x2)")]
+fn test_index_out_of_bounds_panics_like_set_error() {
+    let mut grid: Grid<u8> = Grid::new(3, 2);
+    grid[(3, 0)] = 1;
+}
+
+#[test]
+fn test_fallible_apis_return_typed_errors() {
+    use gridsystem::GridError;
+
+    let mut grid: Grid<u8> = Grid::new(3, 2);
+    assert_eq!(
+        grid.set(3, 0, 1),
+        Err(GridError::OutOfBounds {
+            x: 3,
+            y: 0,
+            width: 3,
+            height: 2
+        })
+    );
+    assert_eq!(
+        grid.swap_rows(0, 2),
+        Err(GridError::RowOutOfBounds {
+            y: 2,
+            width: 3,
+            height: 2
+        })
+    );
+    assert_eq!(
+        Grid::from_raw_parts(3, 2, vec![0u8; 5]).err(),
+        Some(GridError::LengthMismatch {
+            expected: 6,
+            found: 5
+        })
+    );
+    let other: Grid<u8> = Grid::new(2, 3);
+    let Err(err) = grid.par_zip_map(&other, |_, _, &a, &b| a + b) else {
+        panic!("expected a dimension mismatch");
+    };
+    assert_eq!(
+        err,
+        GridError::DimensionMismatch {
+            expected: (3, 2),
+            found: (2, 3)
+        }
+    );
+    assert_eq!(err.to_string(), "Grid dimensions differ (3x2 vs 2x3)");
+    assert!(matches!(
+        Grid::<u8>::try_new_with_budget(100, 100, 10),
+        Err(GridError::TooLarge { max_cells: 10, .. })
+    ));
+
+    let boxed: Box<dyn std::error::Error> = Box::new(GridError::Cancelled);
+    assert_eq!(boxed.to_string(), "Operation was cancelled");
+}
@@ -0,0 +1,68 @@
+use gridsystem::{Grid, GridError, Hex, HexGrid};
+
+#[test]
+fn test_coordinate_conversions_round_trip() {
+    for row in -5..5 {
+        for col in -5..5 {
+            let hex = Hex::from_offset(col, row);
+            assert_eq!(hex.to_offset(), (col, row));
+            let (q, r, s) = hex.cube();
+            assert_eq!(Hex::from_cube(q, r, s), Some(hex));
+        }
+    }
+    assert_eq!(Hex::from_cube(1, 1, 1), None);
+    // Odd rows are shifted right: offset (0, 1) sits between (0, 0) and (1, 0).
+    let odd = Hex::from_offset(0, 1);
+    assert_eq!(odd.distance(Hex::from_offset(0, 0)), 1);
+    assert_eq!(odd.distance(Hex::from_offset(1, 0)), 1);
+}
+
+#[test]
+fn test_rings_and_spirals_cover_each_distance_once() {
+    let centre = Hex::new(-2, 7);
+    assert_eq!(centre.ring(0).collect::<Vec<_>>(), vec![centre]);
+    for radius in 1..5 {
+        let ring: Vec<Hex> = centre.ring(radius).collect();
+        assert_eq!(ring.len(), 6 * radius as usize);
+        assert!(ring.iter().all(|h| h.distance(centre) == radius));
+        // Consecutive ring hexes are adjacent, including the wrap-around.
+        for (i, h) in ring.iter().enumerate() {
+            assert_eq!(h.distance(ring[(i + 1) % ring.len()]), 1);
+        }
+    }
+    let spiral: Vec<Hex> = centre.spiral(3).collect();
+    assert_eq!(spiral.len(), 37);
+    let mut unique = spiral.clone();
+    unique.sort_by_key(|h| (h.q, h.r));
+    unique.dedup();
+    assert_eq!(unique.len(), 37);
+    assert!(
+        spiral
+            .windows(2)
+            .all(|w| w[0].distance(centre) <= w[1].distance(centre))
+    );
+}
+
+#[test]
+fn test_hex_grid_bounds_and_neighbors() {
+    let mut map: HexGrid<u32> = HexGrid::new(4, 3);
+    for (hex, value) in map.iter_mut() {
+        let (col, row) = hex.to_offset();
+        *value = (row * 10 + col) as u32;
+    }
+    // A corner hex on an even row has three neighbours on the map.
+    let corner = Hex::from_offset(0, 0);
+    let mut around: Vec<u32> = map.neighbors(corner).map(|(_, &v)| v).collect();
+    around.sort();
+    assert_eq!(around, vec![1, 10]);
+    assert_eq!(map.neighbors(Hex::from_offset(1, 1)).count(), 6);
+
+    let Err(e) = map.set(Hex::from_offset(4, 0), 1) else {
+        panic!("expected an error");
+    };
+    assert!(matches!(e, GridError::InvalidArgument(_)));
+    assert!(!map.contains(Hex::new(0, -1)));
+
+    let grid: Grid<u32> = map.into_grid();
+    assert_eq!(grid.get(3, 2), Some(&23));
+}
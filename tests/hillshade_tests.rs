@@ -0,0 +1,41 @@
+use gridsystem::Grid;
+
+fn approx(a: f32, b: f32) -> bool {
+    (a - b).abs() < 1e-5
+}
+
+#[test]
+fn test_gradient_of_plane() {
+    let mut heights: Grid<f32> = Grid::new(6, 4);
+    heights.map_inplace(|x, y, h| *h = x as f32 * 0.5 - y as f32 * 3.0);
+
+    let gradient = heights.gradient(0.5);
+    for (_, _, &(dx, dy)) in gradient
+        .iter()
+        .filter(|(x, y, _)| (1..5).contains(x) && (1..3).contains(y))
+    {
+        assert!(approx(dx, 1.0));
+        assert!(approx(dy, -6.0));
+    }
+}
+
+#[test]
+fn test_flat_terrain_shading() {
+    let heights: Grid<f32> = Grid::with_value(4, 4, 10.0);
+    let shade = heights.hillshade(315.0, 30.0);
+    assert!(shade.iter().all(|(_, _, &s)| approx(s, 0.5)));
+}
+
+#[test]
+fn test_slopes_facing_the_light_are_brighter() {
+    // Terrain rising towards the east (+x): its slope faces west.
+    let mut heights: Grid<f32> = Grid::new(5, 5);
+    heights.map_inplace(|x, _, h| *h = x as f32);
+
+    let from_west = *heights.hillshade(270.0, 45.0).get(2, 2).unwrap();
+    let from_east = *heights.hillshade(90.0, 45.0).get(2, 2).unwrap();
+    let from_north = *heights.hillshade(0.0, 45.0).get(2, 2).unwrap();
+    assert!(approx(from_west, 1.0));
+    assert!(approx(from_east, 0.0));
+    assert!(from_north > from_east && from_north < from_west);
+}
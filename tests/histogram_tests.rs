@@ -0,0 +1,33 @@
+use gridsystem::Grid;
+
+#[test]
+fn test_equalize_histogram_preserves_order_and_ties() {
+    let mut field = Grid::from_raw_parts(3, 2, vec![3.0, f32::NAN, 3.0, -1.0, 2.0, 7.0]).unwrap();
+    field.equalize_histogram();
+    let v = field.as_slice();
+    assert!(v[1].is_nan());
+    assert_eq!(v[0], v[2]);
+    assert_eq!((v[3], v[5]), (0.0, 1.0));
+    assert!(v[3] < v[4] && v[4] < v[0] && v[0] < v[5]);
+
+    let mut flat: Grid<f32> = Grid::with_value(2, 2, 4.0);
+    flat.equalize_histogram();
+    assert!(flat.iter().all(|(_, _, &v)| v == 0.0));
+}
+
+#[test]
+fn test_stretch_contrast_clamps_outliers() {
+    let mut field: Grid<f32> = Grid::new(101, 1);
+    field.map_inplace(|x, _, v| *v = x as f32 * 2.0);
+    field.stretch_contrast(0.0, 100.0).unwrap();
+    assert_eq!(field.get(50, 0), Some(&0.5));
+
+    field.stretch_contrast(25.0, 75.0).unwrap();
+    assert_eq!(field.get(10, 0), Some(&0.0));
+    assert_eq!(field.get(50, 0), Some(&0.5));
+    assert_eq!(field.get(90, 0), Some(&1.0));
+
+    assert!(field.stretch_contrast(-1.0, 50.0).is_err());
+    assert!(field.stretch_contrast(60.0, 40.0).is_err());
+    assert!(field.stretch_contrast(10.0, 101.0).is_err());
+}
@@ -0,0 +1,26 @@
+use gridsystem::Grid;
+
+mod common;
+
+use common::parse_char;
+
+#[test]
+fn test_fill_holes_ignores_diagonal_leaks_and_border_regions() {
+    // The bottom-right pocket only touches the outside diagonally, so it is a hole.
+    let mut map = parse_char(&[".....", ".###.", ".#.#.", ".##.#", "...#."]);
+    assert_eq!(map.fill_holes(|&c| c == '.', '#'), 2);
+    assert_eq!(map.get(2, 2), Some(&'#'));
+    assert_eq!(map.get(3, 3), Some(&'#'));
+    assert_eq!(map.get(4, 4), Some(&'.'));
+    assert_eq!(map.fill_holes(|&c| c == '.', '#'), 0);
+}
+
+#[test]
+fn test_fill_holes_on_solid_and_empty_grids() {
+    let mut solid: Grid<u8> = Grid::with_value(4, 4, 1);
+    assert_eq!(solid.fill_holes(|&c| c == 0, 1), 0);
+    assert_eq!(solid.fill_holes(|&c| c == 1, 2), 0);
+
+    let mut empty: Grid<u8> = Grid::new(0, 0);
+    assert_eq!(empty.fill_holes(|_| true, 1), 0);
+}
@@ -0,0 +1,54 @@
+use gridsystem::{Grid, GridError, Layout};
+
+#[test]
+fn test_layouts_round_trip_on_odd_sizes() {
+    let grid = Grid::from_raw_parts(5, 3, (0..15).collect::<Vec<u32>>()).unwrap();
+    for layout in [Layout::RowMajor, Layout::ColumnMajor, Layout::Morton] {
+        let cells = grid.to_layout(layout);
+        let mut sorted = cells.clone();
+        sorted.sort();
+        assert_eq!(
+            sorted,
+            (0..15).collect::<Vec<_>>(),
+            "{layout:?} is a permutation"
+        );
+        let back = Grid::from_layout(5, 3, cells, layout).unwrap();
+        assert_eq!(back.as_slice(), grid.as_slice());
+    }
+    assert!(Grid::from_layout(5, 3, vec![0u32; 14], Layout::Morton).is_err());
+}
+
+#[test]
+fn test_morton_layout_matches_z_curve_on_power_of_two() {
+    let grid: Grid<u32> = Grid::from_raw_parts(4, 4, (0..16).collect()).unwrap();
+    let cells = grid.to_layout(Layout::Morton);
+    for (rank, &i) in cells.iter().enumerate() {
+        let (x, y) = ((i % 4) as u16, (i / 4) as u16);
+        assert_eq!(Layout::morton_code(x, y), rank as u32);
+    }
+    assert_eq!(Layout::morton_code(u16::MAX, u16::MAX), u32::MAX);
+}
+
+#[test]
+fn test_reorder_into_reuses_grid_and_checks_size() {
+    let grid = Grid::from_raw_parts(3, 2, (0..6).collect::<Vec<u32>>()).unwrap();
+    let mut out = Grid::new(3, 2);
+    grid.reorder_into(&mut out, Layout::ColumnMajor).unwrap();
+    assert_eq!(
+        out.as_slice(),
+        grid.to_layout(Layout::ColumnMajor).as_slice()
+    );
+    grid.reorder_into(&mut out, Layout::RowMajor).unwrap();
+    assert_eq!(out.as_slice(), grid.as_slice());
+
+    let err = grid
+        .reorder_into(&mut Grid::new(2, 3), Layout::Morton)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        GridError::DimensionMismatch {
+            expected: (3, 2),
+            found: (2, 3)
+        }
+    );
+}
@@ -0,0 +1,51 @@
+use gridsystem::{Grid, GridError};
+
+fn ramp(width: u16, height: u16) -> Grid<i32> {
+    let mut grid: Grid<i32> = Grid::new(width, height);
+    grid.map_inplace(|x, y, v| *v = x as i32 - y as i32 * 3);
+    grid
+}
+
+#[test]
+fn test_fused_chain_matches_eager_maps() {
+    let a = ramp(40, 30);
+    let b: Grid<i32> = Grid::with_value(40, 30, 7);
+
+    let eager = a.par_zip_map(&b, |_, _, &x, &y| (x * 2 + 1) * y).unwrap();
+    let lazy = a.lazy().map(|&x| x * 2 + 1).zip(&b).map(|(x, &y)| x * y);
+    let sequential = a
+        .lazy()
+        .map(|&x| x * 2 + 1)
+        .zip(&b)
+        .map(|(x, &y)| x * y)
+        .eval()
+        .unwrap();
+
+    assert_eq!((lazy.width(), lazy.height()), (40, 30));
+    assert_eq!(lazy.par_eval().unwrap().as_slice(), eager.as_slice());
+    assert_eq!(sequential.as_slice(), eager.as_slice());
+}
+
+#[test]
+fn test_zip_with_another_lazy_expression() {
+    let a = ramp(5, 4);
+    let doubled = a.lazy().map(|&v| v * 2);
+    let summed = a.lazy().zip(doubled).map(|(&v, d)| v + d).eval().unwrap();
+    assert_eq!(summed.get(4, 3), Some(&((4 - 9) * 3)));
+}
+
+#[test]
+fn test_mismatched_zip_fails_on_eval() {
+    let a = ramp(5, 4);
+    let b = ramp(4, 5);
+    let Err(e) = a.lazy().zip(&b).map(|(&x, &y)| x + y).eval() else {
+        panic!("expected an error");
+    };
+    assert!(matches!(
+        e,
+        GridError::DimensionMismatch {
+            expected: (5, 4),
+            found: (4, 5)
+        }
+    ));
+}
@@ -0,0 +1,98 @@
+use gridsystem::{Grid, LineIter};
+
+fn assert_connected(cells: &[(u16, u16)], diagonal: bool) {
+    for pair in cells.windows(2) {
+        let dx = pair[0].0.abs_diff(pair[1].0);
+        let dy = pair[0].1.abs_diff(pair[1].1);
+        if diagonal {
+            assert!(dx <= 1 && dy <= 1 && dx + dy > 0, "{:?}", pair);
+        } else {
+            assert_eq!(dx + dy, 1, "{:?}", pair);
+        }
+    }
+}
+
+#[test]
+fn test_bresenham_all_octants() {
+    let center = (20, 20);
+    for (tx, ty) in [
+        (30, 24),
+        (24, 30),
+        (10, 24),
+        (16, 30),
+        (10, 16),
+        (16, 10),
+        (30, 16),
+        (24, 10),
+        (20, 20),
+        (20, 5),
+    ] {
+        let cells: Vec<_> = LineIter::new(center, (tx, ty)).collect();
+        assert_eq!(cells.first(), Some(&center));
+        assert_eq!(cells.last(), Some(&(tx, ty)));
+        let major = 20u16.abs_diff(tx).max(20u16.abs_diff(ty)) as usize;
+        assert_eq!(cells.len(), major + 1);
+        assert_connected(&cells, true);
+    }
+}
+
+#[test]
+fn test_supercover_is_edge_connected() {
+    let shares_edge = |a: (u16, u16), b: (u16, u16)| a.0.abs_diff(b.0) + a.1.abs_diff(b.1) == 1;
+    for target in [(9, 3), (3, 9), (0, 0), (9, 9), (5, 0), (8, 7)] {
+        let cells: Vec<_> = LineIter::supercover((5, 5), target).collect();
+        assert_eq!(cells.first(), Some(&(5, 5)));
+        assert_eq!(cells.last(), Some(&target));
+        for i in 1..cells.len() {
+            let previous = &cells[i.saturating_sub(2)..i];
+            assert!(
+                previous.iter().any(|&p| shares_edge(p, cells[i])),
+                "{:?}",
+                cells
+            );
+        }
+    }
+    // Lines without corner crossings are plain 4-connected walks.
+    let cells: Vec<_> = LineIter::supercover((0, 0), (5, 2)).collect();
+    assert_eq!(cells.len(), 8);
+    assert_connected(&cells, false);
+    // Exact corner crossings include both side cells.
+    let cells: Vec<_> = LineIter::supercover((3, 3), (1, 1)).collect();
+    assert_eq!(
+        cells,
+        vec![(3, 3), (2, 3), (3, 2), (2, 2), (1, 2), (2, 1), (1, 1)]
+    );
+}
+
+#[test]
+fn test_grid_iter_line_skips_out_of_bounds() {
+    let mut grid: Grid<u8> = Grid::new(5, 5);
+    grid.map_inplace(|x, y, v| *v = (x + y * 5) as u8);
+    let values: Vec<_> = grid.iter_line((0, 2), (9, 2)).map(|(_, _, &v)| v).collect();
+    assert_eq!(values, vec![10, 11, 12, 13, 14]);
+
+    let cover = grid.iter_line_supercover((0, 0), (4, 4)).count();
+    assert_eq!(cover, 13);
+}
+
+#[test]
+fn test_line_is_clipped_and_los_ignores_endpoints() {
+    let mut walls: Grid<bool> = Grid::new(10, 10);
+    assert_eq!(
+        walls.line(8, 8, 12, 8).collect::<Vec<_>>(),
+        [(8, 8), (9, 8)]
+    );
+    assert_eq!(walls.line(20, 20, 30, 25).count(), 0);
+    assert!(walls.line(0, 9, 9, 0).eq(LineIter::new((0, 9), (9, 0))));
+
+    let _ = walls.set(4, 4, true);
+    let _ = walls.set(0, 0, true);
+    let wall = |&w: &bool| w;
+    assert!(!walls.has_line_of_sight((1, 1), (7, 7), wall));
+    assert!(!walls.has_line_of_sight((7, 7), (1, 1), wall));
+    assert!(walls.has_line_of_sight((0, 0), (3, 3), wall));
+    assert!(walls.has_line_of_sight((4, 4), (4, 4), wall));
+    assert!(walls.has_line_of_sight((0, 4), (3, 4), wall));
+    assert!(!walls.has_line_of_sight((0, 4), (9, 4), wall));
+    assert!(!walls.has_line_of_sight((0, 5), (10, 5), wall));
+}
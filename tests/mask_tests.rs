@@ -0,0 +1,29 @@
+use gridsystem::{BitGrid, Grid};
+
+#[test]
+fn test_update_masked_only_touches_set_cells() {
+    let mut grid: Grid<u8> = Grid::with_value(3, 3, 1);
+    let mut mask = BitGrid::new(3, 3);
+    let _ = mask.set(0, 0, true);
+    let _ = mask.set(2, 2, true);
+
+    let mut visited = Vec::new();
+    grid.update_masked(Some(&mask), |x, y, cell| {
+        visited.push((x, y));
+        *cell = 5;
+    })
+    .unwrap();
+    assert_eq!(visited, vec![(0, 0), (2, 2)]);
+    assert_eq!(grid.as_slice(), &[5, 1, 1, 1, 1, 1, 1, 1, 5]);
+}
+
+#[test]
+fn test_update_masked_rejects_mismatched_mask() {
+    let mut grid: Grid<u8> = Grid::new(3, 3);
+    let mask = BitGrid::with_value(3, 2, true);
+    assert!(
+        grid.update_masked(Some(&mask), |_, _, cell| *cell = 1)
+            .is_err()
+    );
+    assert!(grid.iter().all(|(_, _, &v)| v == 0));
+}
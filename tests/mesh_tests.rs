@@ -0,0 +1,65 @@
+use gridsystem::{Grid, MeshFormat, MeshOptions, export_mesh};
+
+fn export(heights: &Grid<f32>, options: MeshOptions) -> String {
+    let mut out = Vec::new();
+    export_mesh(heights, 1.0, &options, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+fn count(text: &str, prefix: &str) -> usize {
+    text.lines().filter(|l| l.starts_with(prefix)).count()
+}
+
+#[test]
+fn test_obj_export() {
+    let mut heights: Grid<f32> = Grid::new(4, 3);
+    heights.map_inplace(|x, y, h| *h = (x * y) as f32);
+
+    let obj = export(&heights, MeshOptions::default());
+    assert_eq!(count(&obj, "v "), 12);
+    assert_eq!(count(&obj, "f "), 12);
+    assert!(obj.contains("v 3 6 2"));
+    assert!(obj.contains("f 1 5 2"));
+}
+
+#[test]
+fn test_simplified_export_merges_flat_areas() {
+    let mut heights: Grid<f32> = Grid::new(10, 10);
+    let _ = heights.set(9, 9, 5.0);
+
+    let options = MeshOptions {
+        format: MeshFormat::Obj,
+        simplify: true,
+    };
+    let obj = export(&heights, options);
+    let full = export(&heights, MeshOptions::default());
+    assert_eq!(count(&full, "f "), 162);
+    assert!(count(&obj, "f ") < 10);
+    assert!(count(&obj, "v ") < 15);
+}
+
+#[test]
+fn test_gltf_export() {
+    let heights: Grid<f32> = Grid::with_value(2, 2, 0.5);
+    let gltf = export(
+        &heights,
+        MeshOptions {
+            format: MeshFormat::Gltf,
+            simplify: false,
+        },
+    );
+    assert!(gltf.starts_with("{\"asset\":{\"version\":\"2.0\""));
+    assert!(gltf.contains("\"count\":4,\"type\":\"VEC3\""));
+    assert!(gltf.contains("\"count\":6,\"type\":\"SCALAR\""));
+    assert!(gltf.contains("\"min\":[0,0.5,0],\"max\":[1,0.5,1]"));
+    // 4 vertices * 12 bytes + 6 indices * 4 bytes
+    assert!(gltf.contains("\"byteLength\":72,\"uri\":\"data:application/octet-stream;base64,"));
+}
+
+#[test]
+fn test_export_rejects_degenerate_heightmaps() {
+    let heights: Grid<f32> = Grid::new(1, 5);
+    let mut out = Vec::new();
+    let err = export_mesh(&heights, 1.0, &MeshOptions::default(), &mut out).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+}
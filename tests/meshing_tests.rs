@@ -0,0 +1,40 @@
+use gridsystem::{Grid, Rect};
+
+#[test]
+fn test_greedy_rects_cover_every_cell_once() {
+    let mut grid: Grid<u8> = Grid::new(17, 11);
+    grid.map_inplace(|x, y, v| *v = ((x / 3 + y / 4) % 3) as u8);
+
+    let rects = grid.greedy_rects(|a, b| a == b);
+    let mut covered: Grid<u8> = Grid::new(17, 11);
+    for (rect, value) in &rects {
+        for (x, y) in rect.cells() {
+            assert_eq!(grid.get(x, y), Some(value));
+            *covered.get_mut(x, y).unwrap() += 1;
+        }
+    }
+    assert!(covered.iter().all(|(_, _, &c)| c == 1));
+    assert!(rects.len() < 40);
+}
+
+#[test]
+fn test_greedy_rects_uniform_and_empty() {
+    let grid: Grid<char> = Grid::with_value(6, 4, '#');
+    assert_eq!(
+        grid.greedy_rects(|a, b| a == b),
+        vec![(Rect::new(0, 0, 6, 4), '#')]
+    );
+
+    let empty: Grid<char> = Grid::new(0, 4);
+    assert!(empty.greedy_rects(|a, b| a == b).is_empty());
+}
+
+#[test]
+fn test_greedy_rects_custom_equality() {
+    let mut grid: Grid<i32> = Grid::new(3, 1);
+    grid.map_inplace(|x, _, v| *v = x as i32 * 10);
+
+    // Treat all non-negative values as the same material
+    let rects = grid.greedy_rects(|a, b| (*a >= 0) == (*b >= 0));
+    assert_eq!(rects, vec![(Rect::new(0, 0, 3, 1), 0)]);
+}
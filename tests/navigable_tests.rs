@@ -0,0 +1,51 @@
+use gridsystem::pathfinding::{astar, astar_to_any};
+use gridsystem::{Grid, Navigable, navigable_cost};
+
+#[derive(Clone, Default)]
+enum Terrain {
+    #[default]
+    Grass,
+    Swamp,
+    Wall,
+}
+
+impl Navigable for Terrain {
+    fn cost(&self) -> Option<u32> {
+        match self {
+            Terrain::Grass => Some(1),
+            Terrain::Swamp => Some(5),
+            Terrain::Wall => None,
+        }
+    }
+}
+
+#[test]
+fn test_navigable_cost_matches_closure_adapter() {
+    let mut map: Grid<Terrain> = Grid::new(6, 3);
+    for y in 0..2 {
+        let _ = map.set(2, y, Terrain::Wall);
+    }
+    let _ = map.set(3, 2, Terrain::Swamp);
+
+    let direct = astar(&map, (0, 0), (5, 0), navigable_cost);
+    let adapted = astar(&map, (0, 0), (5, 0), |_, _, t: &Terrain| t.cost());
+    assert_eq!(direct, adapted);
+    assert!(
+        direct
+            .unwrap()
+            .iter()
+            .all(|&(x, y)| map.get(x, y).unwrap().cost().is_some())
+    );
+}
+
+#[test]
+fn test_navigable_works_across_search_apis() {
+    let mut map: Grid<Terrain> = Grid::new(5, 1);
+    let _ = map.set(1, 0, Terrain::Wall);
+
+    let (goal, path) = astar_to_any(&map, (2, 0), &[(0, 0), (4, 0)], navigable_cost).unwrap();
+    assert_eq!((goal, path.len()), (1, 3));
+
+    let owners = map.expand_territories(&[(0, 0), (4, 0)], |_| 10, navigable_cost);
+    assert_eq!(owners.as_slice(), &[1, 0, 2, 2, 2]);
+}
@@ -0,0 +1,60 @@
+use gridsystem::{GridError, OccupancyGrid, Rect};
+
+#[test]
+fn test_failed_claims_leave_the_grid_unchanged() {
+    let mut map: OccupancyGrid<u32> = OccupancyGrid::new(6, 6);
+    map.claim(7, Rect::new(0, 0, 2, 2)).unwrap();
+
+    for bad in [
+        Rect::new(1, 1, 3, 3),
+        Rect::new(5, 5, 2, 1),
+        Rect::new(3, 3, 0, 2),
+    ] {
+        let Err(e) = map.claim(8, bad) else {
+            panic!("expected {bad:?} to be rejected");
+        };
+        assert!(matches!(e, GridError::InvalidArgument(_)));
+    }
+    assert!(map.claim(7, Rect::new(4, 4, 1, 1)).is_err());
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.footprint(8), None);
+    let covered = map.as_grid().iter().filter(|(_, _, o)| o.is_some()).count();
+    assert_eq!(covered, 4);
+}
+
+#[test]
+fn test_relocate_may_overlap_own_footprint_only() {
+    let mut map: OccupancyGrid<char> = OccupancyGrid::new(8, 4);
+    map.claim('a', Rect::new(0, 0, 2, 2)).unwrap();
+    map.claim('b', Rect::new(4, 0, 2, 2)).unwrap();
+
+    assert_eq!(
+        map.relocate('a', Rect::new(1, 0, 2, 2)),
+        Ok(Rect::new(0, 0, 2, 2))
+    );
+    assert_eq!(map.occupant(0, 0), None);
+    assert_eq!(map.occupant(2, 1), Some('a'));
+
+    assert!(map.relocate('a', Rect::new(3, 0, 2, 2)).is_err());
+    assert_eq!(map.footprint('a'), Some(Rect::new(1, 0, 2, 2)));
+    assert!(map.relocate('z', Rect::new(0, 2, 1, 1)).is_err());
+}
+
+#[test]
+fn test_overlap_queries_and_release() {
+    let mut map: OccupancyGrid<u8> = OccupancyGrid::new(10, 10);
+    map.claim(3, Rect::new(6, 1, 2, 2)).unwrap();
+    map.claim(1, Rect::new(1, 1, 3, 1)).unwrap();
+    map.claim(2, Rect::new(2, 4, 1, 1)).unwrap();
+
+    assert_eq!(map.occupants_in(&Rect::new(0, 0, 10, 10)), vec![1, 3, 2]);
+    assert_eq!(map.occupants_in(&Rect::new(7, 2, 20, 20)), vec![3]);
+    assert!(!map.is_free(&Rect::new(9, 9, 2, 2)));
+    assert!(map.is_free(&Rect::new(0, 5, 10, 5)));
+
+    assert_eq!(map.release(1), Some(Rect::new(1, 1, 3, 1)));
+    assert_eq!(map.release(1), None);
+    assert!(map.is_free(&Rect::new(0, 0, 5, 2)));
+    map.clear();
+    assert!(map.is_empty() && map.occupant(6, 1).is_none());
+}
@@ -0,0 +1,89 @@
+use gridsystem::{Grid, PackedCell, PackedGrid};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Nibble(u8);
+
+impl PackedCell for Nibble {
+    const BITS: u32 = 4;
+
+    fn to_bits(&self) -> u32 {
+        self.0 as u32
+    }
+
+    fn from_bits(bits: u32) -> Self {
+        Nibble(bits as u8)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Tri(u8);
+
+impl PackedCell for Tri {
+    const BITS: u32 = 3;
+
+    fn to_bits(&self) -> u32 {
+        self.0 as u32
+    }
+
+    fn from_bits(bits: u32) -> Self {
+        Tri(bits as u8)
+    }
+}
+
+#[test]
+fn test_packed_set_get_does_not_disturb_neighbours() {
+    let mut grid: PackedGrid<Nibble> = PackedGrid::new(7, 5);
+    for (x, y) in [(0, 0), (6, 0), (3, 2), (6, 4)] {
+        grid.set(x, y, Nibble(15)).unwrap();
+    }
+    grid.set(3, 2, Nibble(9)).unwrap();
+
+    assert_eq!(grid.get(0, 0), Some(Nibble(15)));
+    assert_eq!(grid.get(1, 0), Some(Nibble(0)));
+    assert_eq!(grid.get(3, 2), Some(Nibble(9)));
+    assert_eq!(grid.get(6, 4), Some(Nibble(15)));
+    assert_eq!(grid.get(7, 0), None);
+    assert!(grid.set(0, 5, Nibble(1)).is_err());
+    assert_eq!(grid.iter().filter(|(_, _, v)| v.0 != 0).count(), 4);
+}
+
+#[test]
+fn test_packed_roundtrip_with_grid() {
+    let mut grid: Grid<Tri> = Grid::new(33, 9);
+    grid.map_inplace(|x, y, v| *v = Tri(((x * 3 + y) % 8) as u8));
+
+    let packed = PackedGrid::from_grid(&grid);
+    assert_eq!((packed.width(), packed.height()), (33, 9));
+    // 21 three-bit cells fit in each word.
+    assert_eq!(packed.memory_bytes(), (33 * 9usize).div_ceil(21) * 8);
+    assert_eq!(packed.to_grid().as_slice(), grid.as_slice());
+    for (x, y, value) in packed.iter() {
+        assert_eq!(grid.get(x, y), Some(&value));
+    }
+}
+
+#[test]
+fn test_packed_with_value_and_empty() {
+    let grid: PackedGrid<Tri> = PackedGrid::with_value(10, 10, Tri(5));
+    assert!(grid.iter().all(|(_, _, v)| v == Tri(5)));
+
+    let empty: PackedGrid<bool> = PackedGrid::new(0, 10);
+    assert_eq!(empty.area(), 0);
+    assert_eq!(empty.memory_bytes(), 0);
+    assert_eq!(empty.iter().count(), 0);
+}
+
+#[test]
+fn test_bit_grid_counts_and_grid_like() {
+    use gridsystem::{BitGrid, GridLike};
+
+    let mut mask = BitGrid::with_value(10, 7, true);
+    assert_eq!(mask.count_ones(), 70);
+    mask.set(9, 6, false).unwrap();
+    assert_eq!(mask.count_ones(), 69);
+    assert_eq!(mask.iter_ones().last(), Some((8, 6)));
+
+    assert_eq!(GridLike::get(&mask, 9, 6), Some(&false));
+    assert_eq!(GridLike::get(&mask, 0, 0), Some(&true));
+    assert_eq!(GridLike::get(&mask, 10, 0), None);
+}
@@ -0,0 +1,75 @@
+use gridsystem::{DirtyGrid, Grid, PathCache};
+
+fn passable(_: u16, _: u16, wall: &bool) -> Option<u32> {
+    (!*wall).then_some(1)
+}
+
+#[test]
+fn test_invalidate_drops_only_routes_through_dirty_cells() {
+    let mut walls: Grid<bool> = Grid::new(16, 16);
+    let mut cache = PathCache::new(8);
+    let north = cache
+        .find(&walls, (0, 0), (15, 0), passable)
+        .unwrap()
+        .to_vec();
+    cache.find(&walls, (0, 15), (15, 15), passable);
+    for y in 0..16 {
+        let _ = walls.set(8, y, y != 15);
+    }
+    // The cached north route still crosses the new wall until invalidated.
+    assert_eq!(
+        cache.find(&walls, (0, 0), (15, 0), passable).unwrap(),
+        north
+    );
+
+    let mut dirty = DirtyGrid::new(16, 16, 4).unwrap();
+    dirty.mark_rect(&gridsystem::Rect::new(8, 0, 1, 15));
+    assert_eq!(cache.invalidate(&dirty), 1);
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.invalidate(&DirtyGrid::new(16, 16, 4).unwrap()), 0);
+
+    let detour = cache.find(&walls, (0, 0), (15, 0), passable).unwrap();
+    assert!(detour.contains(&(8, 15)));
+    assert_eq!((cache.hits(), cache.misses()), (1, 3));
+}
+
+#[test]
+fn test_failures_are_cached_until_anything_changes() {
+    let mut walls: Grid<bool> = Grid::new(4, 1);
+    let _ = walls.set(2, 0, true);
+    let mut cache = PathCache::new(4);
+    let mut plans = 0;
+    for _ in 0..3 {
+        let found = cache.get_or_insert_with((0, 0), (3, 0), || {
+            plans += 1;
+            None
+        });
+        assert!(found.is_none());
+    }
+    assert_eq!(plans, 1);
+
+    let mut dirty = DirtyGrid::new(4, 1, 2).unwrap();
+    dirty.mark(0, 0).unwrap();
+    assert_eq!(cache.invalidate(&dirty), 1);
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn test_least_recently_used_is_evicted() {
+    let open: Grid<bool> = Grid::new(6, 6);
+    let mut cache = PathCache::new(2);
+    cache.find(&open, (0, 0), (1, 0), passable);
+    cache.find(&open, (0, 0), (2, 0), passable);
+    cache.find(&open, (0, 0), (1, 0), passable);
+    cache.find(&open, (0, 0), (3, 0), passable);
+    assert_eq!(cache.len(), 2);
+    cache.find(&open, (0, 0), (1, 0), passable);
+    assert_eq!(cache.hits(), 2);
+    cache.find(&open, (0, 0), (2, 0), passable);
+    assert_eq!(cache.misses(), 4);
+
+    let mut single = PathCache::new(0);
+    assert!(single.find(&open, (0, 0), (5, 5), passable).is_some());
+    cache.clear();
+    assert!(cache.is_empty());
+}
@@ -0,0 +1,459 @@
+use gridsystem::Grid;
+use gridsystem::pathfinding::{astar, astar_debug, astar_to_any};
+
+mod common;
+
+use common::parse_with;
+
+/// Parses a map where `#` is a wall and digits are entry costs ('.' costs 1).
+fn parse(rows: &[&str]) -> Grid<u8> {
+    parse_with(rows, |c| match c {
+        '#' => 0,
+        '.' => 1,
+        digit => digit as u8 - b'0',
+    })
+}
+
+fn cost(_: u16, _: u16, &cell: &u8) -> Option<u32> {
+    (cell > 0).then_some(cell as u32)
+}
+
+fn path_cost(grid: &Grid<u8>, path: &[(u16, u16)]) -> u32 {
+    path[1..]
+        .iter()
+        .map(|&(x, y)| *grid.get(x, y).unwrap() as u32)
+        .sum()
+}
+
+#[test]
+fn test_astar_prefers_cheaper_detour() {
+    let grid = parse(&[
+        ".....", //
+        ".999.", //
+        ".....",
+    ]);
+    let path = astar(&grid, (0, 1), (4, 1), cost).unwrap();
+    assert_eq!(path.first(), Some(&(0, 1)));
+    assert_eq!(path.last(), Some(&(4, 1)));
+    assert_eq!(path_cost(&grid, &path), 6);
+    for pair in path.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        assert_eq!(a.0.abs_diff(b.0) + a.1.abs_diff(b.1), 1);
+    }
+}
+
+#[test]
+fn test_astar_unreachable_and_trivial() {
+    let grid = parse(&[
+        "..#..", //
+        "..#..", //
+        "..#..",
+    ]);
+    assert_eq!(astar(&grid, (0, 0), (4, 2), cost), None);
+    assert_eq!(astar(&grid, (0, 0), (2, 0), cost), None);
+    assert_eq!(astar(&grid, (0, 0), (9, 0), cost), None);
+    assert_eq!(astar(&grid, (1, 1), (1, 1), cost), Some(vec![(1, 1)]));
+}
+
+#[test]
+fn test_astar_debug_matches_plain_search() {
+    let grid = parse(&[
+        "......", //
+        ".####.", //
+        "...3#.", //
+        "#.#...",
+    ]);
+    let plain = astar(&grid, (0, 3), (5, 0), cost);
+    let (path, debug) = astar_debug(&grid, (0, 3), (5, 0), cost);
+    assert_eq!(path, plain);
+    let path = path.unwrap();
+
+    assert_eq!(
+        debug.expansion_order.len() as u32,
+        debug.visited.count_ones()
+    );
+    assert_eq!(debug.expansion_order.first(), Some(&(0, 3)));
+    assert_eq!(debug.expansion_order.last(), Some(&(5, 0)));
+    assert_eq!(
+        debug.g_scores.get(5, 0),
+        Some(&Some(path_cost(&grid, &path)))
+    );
+    for &(x, y) in &debug.expansion_order {
+        assert!(debug.g_scores.get(x, y).unwrap().is_some());
+    }
+    assert_eq!(debug.g_scores.get(0, 3), Some(&Some(0)));
+    assert_eq!(debug.g_scores.get(1, 1), Some(&None));
+}
+
+#[test]
+fn test_astar_batch_progress_and_cancellation() {
+    use gridsystem::CancellationToken;
+    use gridsystem::pathfinding::astar_batch;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let grid = parse(&[
+        "......", //
+        ".####.", //
+        "......",
+    ]);
+    let queries: Vec<_> = (0..6).map(|x| ((0, 0), (x, 2))).collect();
+    let reports = AtomicUsize::new(0);
+    let highest = AtomicUsize::new(0);
+    let sink = |done: usize, total: usize| {
+        assert_eq!(total, 6);
+        reports.fetch_add(1, Ordering::Relaxed);
+        highest.fetch_max(done, Ordering::Relaxed);
+    };
+
+    let paths = astar_batch(&grid, &queries, cost, &sink, &CancellationToken::new()).unwrap();
+    assert_eq!(reports.load(Ordering::Relaxed), 6);
+    assert_eq!(highest.load(Ordering::Relaxed), 6);
+    for (path, &(start, goal)) in paths.iter().zip(&queries) {
+        assert_eq!(path, &astar(&grid, start, goal, cost));
+    }
+
+    let cancelled = CancellationToken::new();
+    cancelled.cancel();
+    assert!(astar_batch(&grid, &queries, cost, &(), &cancelled).is_err());
+}
+
+#[test]
+fn test_astar_to_any_picks_cheapest_goal() {
+    let grid = parse(&[
+        ".....#....", //
+        ".....#....", //
+        ".99999....", //
+        "..........",
+    ]);
+    let goals = [(9, 0), (20, 20), (0, 3), (0, 3)];
+    let (chosen, path) = astar_to_any(&grid, (4, 0), &goals, cost).unwrap();
+    assert_eq!(chosen, 2);
+    assert_eq!(path.first(), Some(&(4, 0)));
+    assert_eq!(path.last(), Some(&(0, 3)));
+    assert_eq!(path_cost(&grid, &path), 7);
+
+    let best_single = goals
+        .iter()
+        .filter_map(|&goal| astar(&grid, (4, 0), goal, cost))
+        .map(|p| path_cost(&grid, &p))
+        .min();
+    assert_eq!(best_single, Some(7));
+    assert!(astar_to_any(&grid, (4, 0), &[(30, 0)], cost).is_none());
+}
+
+#[test]
+fn test_astar_directional_one_way_cells_and_turns() {
+    use gridsystem::Direction;
+    use gridsystem::pathfinding::astar_directional;
+
+    // '>' cells can only be entered heading east.
+    let rows = [
+        "......", //
+        ".>>>>.", //
+        "......",
+    ];
+    let grid: Grid<u8> = {
+        let mut g = Grid::new(6, 3);
+        g.map_inplace(|x, y, c| *c = rows[y as usize].as_bytes()[x as usize]);
+        g
+    };
+    let one_way = |_: u16, _: u16, &c: &u8, heading: Direction| {
+        (c != b'>' || heading == Direction::East).then_some(1)
+    };
+    let east = astar_directional(&grid, (0, 1), None, (5, 1), 0, one_way).unwrap();
+    assert_eq!(east.len(), 6);
+    let west = astar_directional(&grid, (5, 1), None, (0, 1), 0, one_way).unwrap();
+    assert_eq!(west.len(), 8);
+    assert!(west.iter().all(|&(x, y)| y != 1 || x == 0 || x == 5));
+
+    // Facing west, turning around costs two turns; going straight costs none.
+    let open: Grid<u8> = Grid::new(5, 1);
+    let facing_west = astar_directional(
+        &open,
+        (2, 0),
+        Some(Direction::West),
+        (4, 0),
+        5,
+        |_, _, _, _| Some(1),
+    );
+    assert_eq!(facing_west.unwrap().len(), 3);
+    assert!(astar_directional(&open, (0, 0), None, (9, 0), 0, |_, _, _, _| Some(1)).is_none());
+}
+
+#[test]
+fn test_astar_with_portals_takes_teleporter_only_when_cheaper() {
+    use gridsystem::pathfinding::{Portals, astar_with_portals};
+
+    let grid = parse(&["...................."]);
+    let mut portals = Portals::new();
+    portals.add((1, 0), (18, 0), 5);
+    let path = astar_with_portals(&grid, (0, 0), (19, 0), &portals, cost).unwrap();
+    assert_eq!(path, vec![(0, 0), (1, 0), (18, 0), (19, 0)]);
+
+    // One-way: walking back ignores the teleporter.
+    let back = astar_with_portals(&grid, (19, 0), (0, 0), &portals, cost).unwrap();
+    assert_eq!(back.len(), 20);
+
+    // Too expensive to be worth it.
+    let mut pricey = Portals::new();
+    pricey.add((1, 0), (18, 0), 50);
+    let walked = astar_with_portals(&grid, (0, 0), (19, 0), &pricey, cost).unwrap();
+    assert_eq!(walked.len(), 20);
+    pricey.remove_from((1, 0));
+    assert!(pricey.is_empty());
+}
+
+#[test]
+fn test_astar_with_portals_reaches_disconnected_area() {
+    use gridsystem::pathfinding::{Portals, astar_with_portals};
+
+    let grid = parse(&[
+        "...#...", //
+        "...#...", //
+        "...#...",
+    ]);
+    assert!(astar(&grid, (0, 0), (6, 2), cost).is_none());
+    let mut portals = Portals::new();
+    portals.add((2, 2), (4, 0), 1);
+    portals.add((0, 0), (3, 1), 1);
+    let path = astar_with_portals(&grid, (0, 0), (6, 2), &portals, cost).unwrap();
+    assert_eq!(path[..5], [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]);
+    assert_eq!(path[5], (4, 0));
+    assert_eq!(path.len(), 10);
+}
+
+#[test]
+fn test_search_policies_trade_optimality() {
+    use gridsystem::pathfinding::{SearchPolicy, astar_with_policy};
+
+    let grid = parse(&[
+        "..........", //
+        ".########.", //
+        ".#......#.", //
+        ".#.####.#.", //
+        ".#.#..#.#.", //
+        "...#..#...", //
+    ]);
+    // The pocket at (4..6, 4..6) is sealed off under every policy.
+    assert!(astar_with_policy(&grid, (0, 5), (5, 4), SearchPolicy::Greedy, cost).is_none());
+
+    let (start, goal) = ((0, 5), (9, 5));
+    let best = path_cost(&grid, &astar(&grid, start, goal, cost).unwrap());
+    for policy in [
+        SearchPolicy::AStar,
+        SearchPolicy::Weighted(1.5),
+        SearchPolicy::Weighted(0.5),
+        SearchPolicy::Greedy,
+    ] {
+        let path = astar_with_policy(&grid, start, goal, policy, cost).unwrap();
+        assert_eq!((path[0], *path.last().unwrap()), (start, goal));
+        let total = path_cost(&grid, &path);
+        match policy {
+            SearchPolicy::Weighted(w) => assert!(total as f32 <= best as f32 * w.max(1.0)),
+            SearchPolicy::AStar => assert_eq!(total, best),
+            SearchPolicy::Greedy => assert!(total >= best),
+        }
+    }
+}
+
+#[test]
+fn test_astar_bidirectional_matches_astar_cost() {
+    use gridsystem::pathfinding::astar_bidirectional;
+
+    let grid = parse(&[
+        "..........#.....", //
+        ".######.#.#.###.", //
+        ".#....#.#...#...", //
+        ".#.##.#.#####.#.", //
+        "...#..5.....9.#.", //
+        "####.########.#.", //
+        ".......3........",
+    ]);
+    let pairs = [
+        ((0, 0), (15, 6)),
+        ((0, 6), (15, 0)),
+        ((5, 2), (2, 2)),
+        ((4, 4), (4, 4)),
+    ];
+    for (start, goal) in pairs {
+        let reference = astar(&grid, start, goal, cost).map(|p| path_cost(&grid, &p));
+        let path = astar_bidirectional(&grid, start, goal, cost);
+        assert_eq!(
+            path.as_ref().map(|p| path_cost(&grid, p)),
+            reference,
+            "{start:?} -> {goal:?}"
+        );
+        if let Some(path) = path {
+            assert_eq!((path[0], *path.last().unwrap()), (start, goal));
+            assert!(
+                path.windows(2)
+                    .all(|w| w[0].0.abs_diff(w[1].0) + w[0].1.abs_diff(w[1].1) == 1)
+            );
+        }
+    }
+    assert!(astar_bidirectional(&grid, (0, 0), (0, 7), cost).is_none());
+    assert!(astar_bidirectional(&grid, (0, 0), (10, 0), cost).is_none());
+}
+
+#[test]
+fn test_accumulate_paths_concentrates_on_shared_route() {
+    use gridsystem::pathfinding::accumulate_paths;
+
+    // A wall with one gap forces every crossing through (5, 4).
+    let mut walls: Grid<bool> = Grid::new(11, 9);
+    for y in 0..9 {
+        if y != 4 {
+            let _ = walls.set(5, y, true);
+        }
+    }
+    let requests: Vec<_> = (0..9).map(|y| ((0, y), (10, 8 - y))).collect();
+    let cost = |_: u16, _: u16, &wall: &bool| (!wall).then_some(1);
+    let traffic = accumulate_paths(&walls, &requests, cost);
+
+    assert_eq!(traffic.get(5, 4), Some(&9));
+    assert!(traffic.iter().all(|(_, _, &n)| n <= 9));
+    assert!(
+        walls
+            .iter()
+            .all(|(x, y, &w)| !w || traffic.get(x, y) == Some(&0))
+    );
+
+    let serial: u32 = requests
+        .iter()
+        .map(|&(s, g)| astar(&walls, s, g, cost).unwrap().len() as u32)
+        .sum();
+    assert_eq!(traffic.iter().map(|(_, _, &n)| n).sum::<u32>(), serial);
+}
+
+#[test]
+fn test_movement_range_matches_astar_costs() {
+    use gridsystem::pathfinding::movement_range;
+
+    let mut terrain: Grid<u32> = Grid::new(12, 9);
+    terrain.map_inplace(|x, y, c| {
+        *c = if (x * 7 + y * 3) % 5 == 0 {
+            0
+        } else {
+            1 + (x as u32 + y as u32) % 3
+        }
+    });
+    let cost = |_: u16, _: u16, &c: &u32| (c != 0).then_some(c);
+    let origin = (5, 4);
+    let _ = terrain.set(origin.0, origin.1, 1);
+    let range = movement_range(&terrain, origin, 7, cost);
+
+    for (x, y, &reach) in range.iter() {
+        let path_cost = astar(&terrain, origin, (x, y), cost).map(|path| {
+            path[1..]
+                .iter()
+                .map(|&(px, py)| terrain[(px, py)])
+                .sum::<u32>()
+        });
+        assert_eq!(reach, path_cost.filter(|&c| c <= 7), "cell ({x}, {y})");
+    }
+    assert!(
+        movement_range(&terrain, (20, 0), 5, cost)
+            .iter()
+            .all(|(_, _, r)| r.is_none())
+    );
+}
+
+#[test]
+fn test_zone_of_control_stops_movement_but_not_at_origin() {
+    use gridsystem::BitGrid;
+    use gridsystem::pathfinding::{astar_with_rules, movement_range, movement_range_with_rules};
+
+    let grid = parse(&[".....", ".....", "....."]);
+    let cost = |_: u16, _: u16, &c: &u8| (c != 0).then_some(c as u32);
+    let mut zoc = BitGrid::new(5, 3);
+    for y in 0..3 {
+        let _ = zoc.set(2, y, true);
+    }
+
+    let range = movement_range_with_rules(&grid, (0, 1), 10, &zoc, cost);
+    assert_eq!(range.get(2, 1), Some(&Some(2)));
+    assert!((3..5).all(|x| (0..3).all(|y| range.get(x, y) == Some(&None))));
+    // Starting inside the zone is no obstacle.
+    let from_inside = movement_range_with_rules(&grid, (2, 1), 10, &zoc, cost);
+    assert_eq!(from_inside.get(4, 1), Some(&Some(2)));
+    // With no rules the result matches the plain range.
+    let plain = movement_range(&grid, (0, 1), 10, cost);
+    assert_eq!(
+        movement_range_with_rules(&grid, (0, 1), 10, &(), cost).as_slice(),
+        plain.as_slice()
+    );
+
+    assert_eq!(
+        astar_with_rules(&grid, (0, 0), (2, 2), &zoc, cost)
+            .unwrap()
+            .len(),
+        5
+    );
+    assert!(astar_with_rules(&grid, (0, 0), (4, 0), &zoc, cost).is_none());
+}
+
+#[test]
+fn test_blocked_edges_cannot_be_crossed() {
+    use gridsystem::pathfinding::{MoveRules, astar_with_rules, movement_range_with_rules};
+
+    // A door-less wall segment on the edge between (1, 1) and (2, 1).
+    struct Wall;
+    impl MoveRules for Wall {
+        fn blocks(&self, from: (u16, u16), to: (u16, u16)) -> bool {
+            let (a, b) = if from < to { (from, to) } else { (to, from) };
+            a == (1, 1) && b == (2, 1)
+        }
+    }
+
+    let grid = parse(&["....", "...."]);
+    let cost = |_: u16, _: u16, &c: &u8| (c != 0).then_some(c as u32);
+    let path = astar_with_rules(&grid, (1, 1), (2, 1), &Wall, cost).unwrap();
+    assert_eq!(path, vec![(1, 1), (1, 0), (2, 0), (2, 1)]);
+    let back = astar_with_rules(&grid, (2, 1), (1, 1), &Wall, cost).unwrap();
+    assert_eq!(back.len(), 4);
+
+    let range = movement_range_with_rules(&grid, (1, 1), 2, &Wall, cost);
+    assert_eq!(range.get(2, 1), Some(&None));
+    assert_eq!(range.get(2, 0), Some(&Some(2)));
+}
+
+#[test]
+fn test_path_cost_matches_search_cost() {
+    use gridsystem::pathfinding::{movement_range, path_cost};
+
+    let grid = parse(&["1.3#.", ".#9..", "..2.5"]);
+    let cost = |_: u16, _: u16, &c: &u8| (c != 0).then_some(c as u32);
+    let range = movement_range(&grid, (0, 0), u32::MAX, cost);
+    for (x, y, &reach) in range.iter() {
+        let path = astar(&grid, (0, 0), (x, y), cost);
+        assert_eq!(path.and_then(|p| path_cost(&grid, &p, cost)), reach);
+    }
+    assert_eq!(path_cost(&grid, &[], cost), Some(0));
+    assert_eq!(path_cost(&grid, &[(3, 0)], cost), Some(0));
+    assert_eq!(path_cost(&grid, &[(2, 0), (3, 0)], cost), None);
+    assert_eq!(path_cost(&grid, &[(4, 2), (5, 2)], cost), None);
+}
+
+#[test]
+fn test_distance_field_is_min_over_sources() {
+    use gridsystem::pathfinding::movement_range;
+
+    let grid = parse(&["..#....", ".3#.9..", "...#.2.", "#..#..."]);
+    let cost = |_: u16, _: u16, &c: &u8| (c != 0).then_some(c as u32);
+    let sources = [(0, 0), (6, 3), (20, 20)];
+    let field = grid.distance_field(&sources, cost);
+
+    let singles: Vec<_> = sources[..2]
+        .iter()
+        .map(|&s| movement_range(&grid, s, u32::MAX, cost))
+        .collect();
+    for (x, y, &distance) in field.iter() {
+        let nearest = singles.iter().filter_map(|r| *r.get(x, y).unwrap()).min();
+        assert_eq!(distance, nearest, "cell ({x}, {y})");
+    }
+    assert!(
+        grid.distance_field(&[], cost)
+            .iter()
+            .all(|(_, _, d)| d.is_none())
+    );
+}
@@ -0,0 +1,41 @@
+use gridsystem::Grid;
+
+fn brute_force(grid: &Grid<u8>, w: u16, h: u16) -> Vec<(u16, u16)> {
+    let mut found = Vec::new();
+    for y in 0..grid.height().saturating_sub(h - 1) {
+        for x in 0..grid.width().saturating_sub(w - 1) {
+            let fits = (y..y + h).all(|cy| (x..x + w).all(|cx| grid.get(cx, cy) == Some(&0)));
+            if fits {
+                found.push((x, y));
+            }
+        }
+    }
+    found
+}
+
+#[test]
+fn test_matches_brute_force_on_scattered_obstacles() {
+    let mut grid: Grid<u8> = Grid::new(23, 17);
+    grid.map_inplace(|x, y, v| *v = (x as u32 * 7 + y as u32 * 13).is_multiple_of(11) as u8);
+    for (w, h) in [(1, 1), (2, 3), (3, 2), (4, 4), (23, 1), (1, 17)] {
+        let expected = brute_force(&grid, w, h);
+        assert_eq!(
+            grid.find_all_placements(w, h, |&v| v == 0),
+            expected,
+            "{w}x{h}"
+        );
+        assert_eq!(
+            grid.find_placement(w, h, |&v| v == 0),
+            expected.first().copied()
+        );
+    }
+}
+
+#[test]
+fn test_degenerate_footprints_find_nothing() {
+    let grid: Grid<u8> = Grid::new(5, 4);
+    assert_eq!(grid.find_placement(0, 2, |_| true), None);
+    assert_eq!(grid.find_placement(6, 1, |_| true), None);
+    assert!(grid.find_all_placements(2, 5, |_| true).is_empty());
+    assert_eq!(grid.find_all_placements(5, 4, |_| true), vec![(0, 0)]);
+}
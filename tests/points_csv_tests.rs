@@ -0,0 +1,32 @@
+use gridsystem::{Grid, read_points_csv};
+
+#[test]
+fn test_points_csv_round_trip_sparse() {
+    let mut grid: Grid<i32> = Grid::new(50, 40);
+    grid.set(0, 0, -1).unwrap();
+    grid.set(49, 39, 12).unwrap();
+    grid.set(7, 3, 300).unwrap();
+
+    let mut out = Vec::new();
+    grid.write_points_csv(&mut out).unwrap();
+    let text = String::from_utf8(out).unwrap();
+    assert_eq!(text.lines().count(), 4);
+
+    let restored: Grid<i32> = Grid::read_points_csv(50, 40, text.as_bytes()).unwrap();
+    assert_eq!(restored.as_slice(), grid.as_slice());
+}
+
+#[test]
+fn test_points_csv_errors_name_the_line() {
+    let err = read_points_csv::<_, u8>("x,y,value\n1,2,3\n1,oops,3\n".as_bytes()).unwrap_err();
+    assert!(err.to_string().starts_with("line 3: invalid y"));
+
+    let err = read_points_csv::<_, u8>("1,2\n".as_bytes()).unwrap_err();
+    assert!(err.to_string().contains("expected x,y,value"));
+
+    let err = read_points_csv::<_, u8>("1,2,300\r\n".as_bytes()).unwrap_err();
+    assert!(err.to_string().contains("invalid value"));
+
+    let ok: Vec<(u16, u16, u8)> = read_points_csv("1,2,3\r\n".as_bytes()).unwrap();
+    assert_eq!(ok, vec![(1, 2, 3)]);
+}
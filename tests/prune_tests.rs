@@ -0,0 +1,36 @@
+mod common;
+
+use common::{parse_bool, render};
+
+#[test]
+fn test_fill_dead_ends_keeps_loops_and_handles_edges() {
+    // The spur runs off the bottom edge; out-of-bounds counts as wall.
+    let mut map = parse_bool(&["#...#", "#.#.#", "#...#", "##.##", "##.##"]);
+    let filled = map.fill_dead_ends(u32::MAX);
+    assert_eq!(filled, 2);
+    assert_eq!(
+        render(&map, |&wall| if wall { '#' } else { '.' }),
+        ["#...#", "#.#.#", "#...#", "#####", "#####"]
+    );
+    assert_eq!(map.fill_dead_ends(5), 0);
+}
+
+#[test]
+fn test_fill_dead_ends_removes_trees_entirely() {
+    let mut map = parse_bool(&[".....", "#.#.#", "#.#.."]);
+    assert_eq!(map.fill_dead_ends(100), 10);
+    assert!(map.iter().all(|(_, _, &wall)| wall));
+    assert_eq!(map.fill_dead_ends(0), 0);
+}
+
+#[test]
+fn test_prune_corridors_keeps_wide_areas() {
+    let mut map = parse_bool(&["...#....", "...#....", "........", "###....."]);
+    assert_eq!(map.prune_corridors(3), 2);
+    assert_eq!(
+        render(&map, |&wall| if wall { '#' } else { '.' }),
+        ["...#....", "...#....", "...#....", "####...."]
+    );
+    assert_eq!(map.prune_corridors(1), 0);
+    assert_eq!(map.prune_corridors(9), 25);
+}
@@ -0,0 +1,80 @@
+use gridsystem::{BitGrid, Grid, PotentiallyVisibleSet};
+
+#[test]
+fn test_pvs_respects_range_and_is_symmetric() {
+    // One long open corridor split into four zones of 10 cells each.
+    let opaque: Grid<bool> = Grid::new(40, 1);
+    let mut zones: Grid<u32> = Grid::new(40, 1);
+    zones.map_inplace(|x, _, z| *z = 1 + x as u32 / 10);
+
+    let pvs = PotentiallyVisibleSet::compute(&opaque, &zones, 10).unwrap();
+    assert_eq!(pvs.zone_count(), 4);
+    assert!(pvs.can_see(1, 2) && pvs.can_see(2, 1));
+    assert!(!pvs.can_see(1, 3));
+    assert!(pvs.can_see(2, 3));
+    for a in 0..6 {
+        for b in 0..6 {
+            assert_eq!(pvs.can_see(a, b), pvs.can_see(b, a));
+        }
+    }
+    assert!(!pvs.can_see(0, 1) && !pvs.can_see(7, 1));
+}
+
+#[test]
+fn test_pvs_sees_through_doorway_around_corner() {
+    // Zone 1 is a room; zone 2 is behind a wall with a one-cell doorway.
+    let rows = [
+        "....#....", //
+        "....#....", //
+        ".........", //
+        "....#....", //
+        "....#....",
+    ];
+    let mut opaque = BitGrid::new(9, 5);
+    let mut zones: Grid<u32> = Grid::new(9, 5);
+    for (y, row) in rows.iter().enumerate() {
+        for (x, c) in row.bytes().enumerate() {
+            opaque.set(x as u16, y as u16, c == b'#').unwrap();
+            let zone = if c == b'#' {
+                0
+            } else if x < 4 {
+                1
+            } else {
+                2
+            };
+            zones.set(x as u16, y as u16, zone).unwrap();
+        }
+    }
+    let pvs = PotentiallyVisibleSet::compute(&opaque, &zones, 20).unwrap();
+    assert!(pvs.can_see(1, 2));
+    assert_eq!(pvs.visible_from(1).collect::<Vec<_>>(), vec![1, 2]);
+}
+
+#[test]
+fn test_pvs_rejects_mismatched_grids() {
+    let opaque: Grid<bool> = Grid::new(4, 4);
+    let zones: Grid<u32> = Grid::new(4, 5);
+    assert!(PotentiallyVisibleSet::compute(&opaque, &zones, 4).is_err());
+}
+
+#[test]
+fn test_pvs_handles_sparse_ids_and_rejects_too_many_zones() {
+    // Two huge ids share a corridor; the table only holds two zones.
+    let opaque: Grid<bool> = Grid::new(8, 1);
+    let mut zones: Grid<u32> = Grid::new(8, 1);
+    zones.map_inplace(|x, _, z| *z = if x < 4 { u32::MAX } else { 3_000_000_000 });
+    let pvs = PotentiallyVisibleSet::compute(&opaque, &zones, 8).unwrap();
+    assert_eq!(pvs.zone_count(), 2);
+    assert!(pvs.can_see(u32::MAX, 3_000_000_000));
+    assert_eq!(
+        pvs.visible_from(u32::MAX).collect::<Vec<_>>(),
+        vec![3_000_000_000, u32::MAX]
+    );
+    assert!(!pvs.can_see(u32::MAX, 5));
+
+    let opaque: Grid<bool> = Grid::new(200, 100);
+    let mut zones: Grid<u32> = Grid::new(200, 100);
+    zones.map_inplace(|x, y, z| *z = 1 + y as u32 * 200 + x as u32);
+    let err = PotentiallyVisibleSet::compute(&opaque, &zones, 1).unwrap_err();
+    assert!(err.to_string().contains("16384"), "{err}");
+}
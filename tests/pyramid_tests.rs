@@ -0,0 +1,40 @@
+use gridsystem::Grid;
+
+#[test]
+fn test_pyramid_max_aggregation() {
+    let mut grid: Grid<u8> = Grid::new(8, 8);
+    let _ = grid.set(5, 6, 9);
+
+    let max = |block: &[&u8]| block.iter().copied().copied().max().unwrap_or(0);
+    let pyramid = grid.build_pyramid(3, max);
+    assert_eq!(pyramid.len(), 3);
+    assert_eq!(pyramid[0].get(2, 3), Some(&9));
+    assert_eq!(pyramid[1].get(1, 1), Some(&9));
+    assert_eq!(pyramid[2].get(0, 0), Some(&9));
+    assert_eq!(pyramid[1].get(0, 0), Some(&0));
+}
+
+#[test]
+fn test_odd_sizes_use_partial_blocks() {
+    let grid: Grid<u32> = Grid::with_value(5, 3, 1);
+    let count = |block: &[&u32]| block.len() as u32;
+    let half = grid.downsample(count);
+
+    assert_eq!((half.width(), half.height()), (3, 2));
+    assert_eq!(half.row(0), Some(&[4, 4, 2][..]));
+    assert_eq!(half.row(1), Some(&[2, 2, 1][..]));
+}
+
+#[test]
+fn test_pyramid_of_tiny_grids() {
+    let single: Grid<u8> = Grid::new(1, 1);
+    assert!(single.build_pyramid(4, |b| *b[0]).is_empty());
+
+    let strip: Grid<u8> = Grid::new(1, 5);
+    let sizes: Vec<_> = strip
+        .build_pyramid(8, |b| *b[0])
+        .iter()
+        .map(|g| (g.width(), g.height()))
+        .collect();
+    assert_eq!(sizes, vec![(1, 3), (1, 2), (1, 1)]);
+}
@@ -0,0 +1,69 @@
+use gridsystem::{Grid, Rect};
+
+fn blobby(width: u16, height: u16) -> Grid<u8> {
+    let mut grid = Grid::new(width, height);
+    grid.map_inplace(|x, y, v| {
+        let dx = x as i32 - 20;
+        let dy = y as i32 - 12;
+        *v = if dx * dx + dy * dy < 100 {
+            2
+        } else if x < 5 {
+            1
+        } else {
+            0
+        };
+    });
+    grid
+}
+
+#[test]
+fn test_quadtree_roundtrip_non_square() {
+    let grid = blobby(45, 27);
+    let bytes = grid.to_quadtree_bytes();
+    assert!(bytes.len() < grid.area() as usize / 2);
+
+    let decoded: Grid<u8> = Grid::from_quadtree_bytes(&bytes).unwrap();
+    assert_eq!((decoded.width(), decoded.height()), (45, 27));
+    assert_eq!(decoded.as_slice(), grid.as_slice());
+}
+
+#[test]
+fn test_quadtree_region_decoding() {
+    let grid = blobby(45, 27);
+    let bytes = grid.to_quadtree_bytes();
+
+    let region = Rect::new(12, 5, 20, 30);
+    let part: Grid<u8> = Grid::from_quadtree_region(&bytes, region).unwrap();
+    assert_eq!((part.width(), part.height()), (20, 22));
+    for (x, y, value) in part.iter() {
+        assert_eq!(grid.get(x + 12, y + 5), Some(value));
+    }
+
+    let outside: Grid<u8> = Grid::from_quadtree_region(&bytes, Rect::new(100, 0, 5, 5)).unwrap();
+    assert!(outside.is_empty());
+}
+
+#[test]
+fn test_quadtree_rejects_bad_data() {
+    let grid = blobby(16, 16);
+    let bytes = grid.to_quadtree_bytes();
+
+    assert!(Grid::<u8>::from_quadtree_bytes(&bytes[..bytes.len() - 1]).is_err());
+    assert!(Grid::<u8>::from_quadtree_bytes(b"nope").is_err());
+
+    let mut corrupt = bytes.clone();
+    corrupt[8] = 9;
+    assert!(Grid::<u8>::from_quadtree_bytes(&corrupt).is_err());
+
+    let flags: Grid<bool> = Grid::with_value(3, 3, true);
+    let mut bytes = flags.to_quadtree_bytes();
+    *bytes.last_mut().unwrap() = 5;
+    assert!(Grid::<bool>::from_quadtree_bytes(&bytes).is_err());
+}
+
+#[test]
+fn test_quadtree_empty_grid() {
+    let grid: Grid<f32> = Grid::new(0, 9);
+    let decoded: Grid<f32> = Grid::from_quadtree_bytes(&grid.to_quadtree_bytes()).unwrap();
+    assert_eq!((decoded.width(), decoded.height()), (0, 0));
+}
@@ -0,0 +1,72 @@
+use gridsystem::{Grid, Quantization, QuantizedGrid};
+
+#[test]
+fn test_f16_known_values() {
+    let q = Quantization::F16;
+    assert_eq!(q.encode(1.0), 0x3c00);
+    assert_eq!(q.encode(-2.0), 0xc000);
+    assert_eq!(q.encode(65504.0), 0x7bff);
+    assert_eq!(q.encode(1e6), 0x7c00);
+    assert_eq!(q.encode(f32::NEG_INFINITY), 0xfc00);
+    assert_eq!(q.encode(2f32.powi(-24)), 0x0001);
+    assert_eq!(q.encode(1e-10), 0);
+    // 1 + 2^-11 is halfway between 1.0 and the next half; ties go to even.
+    assert_eq!(q.encode(1.0 + 2f32.powi(-11)), 0x3c00);
+    assert_eq!(q.encode(1.0 + 3.0 * 2f32.powi(-11)), 0x3c02);
+
+    assert_eq!(q.decode(0x3555), 0.333_251_95);
+    assert_eq!(q.decode(0x0001), 2f32.powi(-24));
+    assert_eq!(q.decode(0x7c00), f32::INFINITY);
+    assert!(q.decode(q.encode(f32::NAN)).is_nan());
+}
+
+#[test]
+fn test_f16_roundtrip_relative_error() {
+    let q = Quantization::F16;
+    for i in 1..2000 {
+        let value = i as f32 * 0.731 - 500.0;
+        let back = q.decode(q.encode(value));
+        assert!(
+            (back - value).abs() <= value.abs() * 0.0005 + 1e-4,
+            "{} -> {}",
+            value,
+            back
+        );
+    }
+}
+
+#[test]
+fn test_fixed_point_clamps_and_bounds_error() {
+    let q = Quantization::Fixed {
+        min: -10.0,
+        max: 10.0,
+    };
+    assert_eq!(q.encode(-50.0), 0);
+    assert_eq!(q.encode(50.0), u16::MAX);
+    assert_eq!(q.decode(0), -10.0);
+    assert_eq!(q.decode(u16::MAX), 10.0);
+    let step = 20.0 / u16::MAX as f32;
+    for i in 0..=200 {
+        let value = i as f32 * 0.1 - 10.0;
+        assert!((q.decode(q.encode(value)) - value).abs() <= step / 2.0 + 1e-5);
+    }
+}
+
+#[test]
+fn test_quantized_grid_access() {
+    let mut grid = QuantizedGrid::new(3, 2, Quantization::Fixed { min: 5.0, max: 6.0 });
+    assert_eq!(grid.get(0, 0), Some(5.0));
+    grid.set(2, 1, 5.5).unwrap();
+    assert!((grid.get(2, 1).unwrap() - 5.5).abs() < 1e-4);
+    assert!(grid.set(3, 0, 1.0).is_err());
+    assert_eq!(grid.get(0, 2), None);
+    assert_eq!(grid.as_raw().len(), 6);
+    assert_eq!(grid.iter().count(), 6);
+
+    let mut source: Grid<f32> = Grid::new(2, 2);
+    let _ = source.set(1, 1, 0.1);
+    let half = QuantizedGrid::from_grid(&source, Quantization::F16);
+    let restored = half.to_grid();
+    assert_eq!(restored.get(0, 0), Some(&0.0));
+    assert!((restored.get(1, 1).unwrap() - 0.1).abs() < 1e-4);
+}
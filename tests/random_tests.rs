@@ -0,0 +1,43 @@
+use gridsystem::Grid;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+#[test]
+fn test_shuffle_is_a_permutation_and_seeded() {
+    let mut a: Grid<u16> = Grid::new(13, 7);
+    a.map_inplace(|x, y, v| *v = y * 13 + x);
+    let original = a.as_slice().to_vec();
+    let mut b: Grid<u16> = Grid::from_raw_parts(13, 7, original.clone()).unwrap();
+
+    a.shuffle(&mut StdRng::seed_from_u64(42));
+    b.shuffle(&mut StdRng::seed_from_u64(42));
+    assert_eq!(a.as_slice(), b.as_slice());
+    assert_ne!(a.as_slice(), original.as_slice());
+
+    let mut sorted = a.into_vec();
+    sorted.sort();
+    assert_eq!(sorted, original);
+}
+
+#[test]
+fn test_random_order_is_roughly_uniform() {
+    let grid: Grid<u8> = Grid::new(3, 3);
+    let mut rng = StdRng::seed_from_u64(3);
+    let mut first_counts = [0u32; 9];
+    for _ in 0..9000 {
+        let mut order = grid.iter_random_order(&mut rng);
+        assert_eq!(order.len(), 9);
+        let (x, y, _) = order.next().unwrap();
+        first_counts[(y * 3 + x) as usize] += 1;
+        assert_eq!(order.len(), 8);
+        assert_eq!(order.count(), 8);
+    }
+    assert!(
+        first_counts.iter().all(|&c| (800..1200).contains(&c)),
+        "{:?}",
+        first_counts
+    );
+
+    let empty: Grid<u8> = Grid::new(0, 5);
+    assert_eq!(empty.iter_random_order(&mut rng).count(), 0);
+}
@@ -0,0 +1,119 @@
+use gridsystem::{FillRule, Grid};
+
+mod common;
+
+use common::render;
+
+#[test]
+fn test_fill_triangle() {
+    let mut grid: Grid<u8> = Grid::new(6, 4);
+    let filled = grid.fill_triangle((0.0, 0.0), (6.0, 0.0), (3.0, 4.0), 1);
+    assert_eq!(
+        render(&grid, |&c| if c > 0 { '#' } else { '.' }),
+        vec!["######", ".####.", "..##..", "......"]
+    );
+    assert_eq!(filled, 12);
+}
+
+#[test]
+fn test_fill_rules_differ_on_overlapping_loops() {
+    // Two overlapping squares traced in the same direction as one outline.
+    let outline = [
+        (0.0, 0.0),
+        (4.0, 0.0),
+        (4.0, 4.0),
+        (0.0, 4.0),
+        (0.0, 0.0),
+        (2.0, 2.0),
+        (6.0, 2.0),
+        (6.0, 6.0),
+        (2.0, 6.0),
+        (2.0, 2.0),
+    ];
+    let mut even_odd: Grid<u8> = Grid::new(6, 6);
+    even_odd.fill_polygon(&outline, FillRule::EvenOdd, 1);
+    let mut non_zero: Grid<u8> = Grid::new(6, 6);
+    non_zero.fill_polygon(&outline, FillRule::NonZero, 1);
+
+    assert_eq!(even_odd.get(3, 3), Some(&0));
+    assert_eq!(non_zero.get(3, 3), Some(&1));
+    assert_eq!(even_odd.get(1, 1), Some(&1));
+    assert_eq!(non_zero.get(5, 5), Some(&1));
+}
+
+#[test]
+fn test_fill_polygon_clips_to_grid() {
+    let mut grid: Grid<u8> = Grid::new(4, 4);
+    let huge = [(-10.0, -10.0), (20.0, -10.0), (20.0, 20.0), (-10.0, 20.0)];
+    assert_eq!(grid.fill_polygon(&huge, FillRule::NonZero, 1), 16);
+
+    let outside = [(10.0, 10.0), (12.0, 10.0), (11.0, 12.0)];
+    assert_eq!(grid.fill_polygon(&outside, FillRule::NonZero, 2), 0);
+    assert_eq!(
+        grid.fill_polygon(&[(0.0, 0.0), (3.0, 3.0)], FillRule::NonZero, 2),
+        0
+    );
+}
+
+#[test]
+fn test_draw_line_aa_coverage() {
+    let mut grid: Grid<f32> = Grid::new(12, 12);
+    grid.draw_line_aa(1.5, 1.5, 9.5, 5.5, 1.0);
+
+    // Coverage along a shallow line sums to its horizontal extent.
+    let total: f32 = grid.iter().map(|(_, _, &v)| v).sum();
+    assert!((total - 8.0).abs() < 1e-4, "total {}", total);
+    // Each column gets exactly one unit split over at most two rows.
+    for x in 2..9 {
+        let column: f32 = (0..12).map(|y| *grid.get(x, y).unwrap()).sum();
+        assert!((column - 1.0).abs() < 1e-4);
+        assert!((0..12).filter(|&y| *grid.get(x, y).unwrap() > 0.0).count() <= 2);
+    }
+    assert!(grid.get(5, 3).unwrap() > &0.4);
+
+    // Steep lines behave symmetrically.
+    let mut steep: Grid<f32> = Grid::new(12, 12);
+    steep.draw_line_aa(1.5, 1.5, 5.5, 9.5, 1.0);
+    for (x, y, &v) in steep.iter() {
+        assert_eq!(v, *grid.get(y, x).unwrap());
+    }
+}
+
+#[test]
+fn test_draw_line_aa_clips_and_accumulates() {
+    let mut grid: Grid<f32> = Grid::new(4, 4);
+    grid.draw_line_aa(-10.5, 0.5, 20.5, 0.5, 1.0);
+    grid.draw_line_aa(-10.5, 0.5, 20.5, 0.5, 1.0);
+    assert!(grid.row(0).unwrap().iter().all(|&v| v == 2.0));
+    assert!(grid.row(1).unwrap().iter().all(|&v| v == 0.0));
+
+    grid.draw_line_aa(2.5, 2.5, 2.5, 2.5, 1.0);
+    assert!(*grid.get(2, 2).unwrap() > 0.0);
+}
+
+#[test]
+fn test_draw_line_aa_far_and_non_finite_endpoints() {
+    let mut grid: Grid<f32> = Grid::new(8, 8);
+    grid.draw_line_aa(-0.5, 0.5, 1e8, 0.5, 1.0);
+    assert!(grid.row(0).unwrap().iter().all(|&v| (v - 1.0).abs() < 1e-4));
+
+    // A steep line far off the side of the grid never touches it.
+    grid.draw_line_aa(-1e8, 0.5, -1e8 + 1.0, 1e9, 1.0);
+    grid.draw_line_aa(0.5, 0.5, f32::INFINITY, 4.5, 1.0);
+    grid.draw_line_aa(f32::NAN, 0.5, 4.5, 4.5, 1.0);
+    let total: f32 = grid.iter().map(|(_, _, &v)| v).sum();
+    assert!((total - 8.0).abs() < 1e-3, "total {}", total);
+
+    // A long diagonal is clipped to the part crossing the grid.
+    let mut diagonal: Grid<f32> = Grid::new(8, 8);
+    diagonal.draw_line_aa(-1e6, -1e6, 1e6, 1e6, 1.0);
+    for x in 0..8 {
+        let column: f32 = (0..8).map(|y| *diagonal.get(x, y).unwrap()).sum();
+        assert!(
+            (column - 1.0).abs() < 1e-3,
+            "column {} sums to {}",
+            x,
+            column
+        );
+    }
+}
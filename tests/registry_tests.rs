@@ -0,0 +1,87 @@
+use gridsystem::pathfinding::{astar, path_cost};
+use gridsystem::{Grid, Navigable, TileRegistry};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+enum Kind {
+    #[default]
+    Grass,
+    Swamp,
+    Wall,
+}
+
+struct Terrain {
+    cost: Option<u32>,
+    sprite: &'static str,
+}
+
+impl Navigable for Terrain {
+    fn cost(&self) -> Option<u32> {
+        self.cost
+    }
+}
+
+fn registry() -> TileRegistry<Kind, Terrain> {
+    [
+        (
+            Kind::Grass,
+            Terrain {
+                cost: Some(1),
+                sprite: "grass.png",
+            },
+        ),
+        (
+            Kind::Swamp,
+            Terrain {
+                cost: Some(5),
+                sprite: "swamp.png",
+            },
+        ),
+        (
+            Kind::Wall,
+            Terrain {
+                cost: None,
+                sprite: "wall.png",
+            },
+        ),
+    ]
+    .into_iter()
+    .collect()
+}
+
+#[test]
+fn test_costs_come_from_shared_metadata() {
+    let mut map: Grid<Kind> = Grid::new(5, 3);
+    for y in 0..3 {
+        let _ = map.set(2, y, Kind::Swamp);
+    }
+    let _ = map.set(2, 1, Kind::Wall);
+    let mut registry = registry();
+    let cost = |_: u16, _: u16, kind: &Kind| registry.get(kind).and_then(Navigable::cost);
+
+    let path = astar(&map, (0, 1), (4, 1), cost).unwrap();
+    assert_eq!(path_cost(&map, &path, cost), Some(5 + 5));
+    assert_eq!(
+        map.lookup(2, 0, &registry).map(|t| t.sprite),
+        Some("swamp.png")
+    );
+
+    // Draining the swamp changes every swamp cell at once.
+    registry.get_mut(&Kind::Swamp).unwrap().cost = Some(1);
+    let cost = |_: u16, _: u16, kind: &Kind| registry.get(kind).and_then(Navigable::cost);
+    let path = astar(&map, (0, 1), (4, 1), cost).unwrap();
+    assert_eq!(path_cost(&map, &path, cost), Some(6));
+}
+
+#[test]
+fn test_unregistered_cells_are_reported() {
+    let mut registry = registry();
+    let mut map: Grid<Kind> = Grid::new(3, 2);
+    let _ = map.set(1, 1, Kind::Wall);
+    assert!(map.unregistered(&registry).is_empty());
+
+    assert!(registry.remove(&Kind::Wall).is_some());
+    assert_eq!(registry.len(), 2);
+    assert!(!registry.contains(&Kind::Wall));
+    assert_eq!(map.unregistered(&registry), vec![(1, 1)]);
+    assert!(map.lookup(1, 1, &registry).is_none());
+}
@@ -0,0 +1,83 @@
+use gridsystem::pathfinding::astar;
+use gridsystem::{AsciiOverlay, Grid, Highlight};
+
+#[test]
+fn test_render_rows_par_matches_serial_render() {
+    let mut grid: Grid<u8> = Grid::new(37, 23);
+    grid.map_inplace(|x, y, v| *v = (x * 7 + y * 13) as u8);
+    let stride = 37 * 4 + 5; // padded rows
+
+    let mut parallel = vec![0xAA; stride * 23 + 3];
+    grid.render_rows_par(&mut parallel, stride, |y, row, out| {
+        for (x, &v) in row.iter().enumerate() {
+            out[x * 4..x * 4 + 4].copy_from_slice(&[v, y as u8, 0, 255]);
+        }
+    })
+    .unwrap();
+
+    for (x, y, &v) in grid.iter() {
+        let at = y as usize * stride + x as usize * 4;
+        assert_eq!(&parallel[at..at + 4], &[v, y as u8, 0, 255]);
+    }
+    // Row padding and trailing bytes are untouched.
+    assert_eq!(parallel[37 * 4], 0xAA);
+    assert_eq!(&parallel[stride * 23..], &[0xAA; 3]);
+}
+
+#[test]
+fn test_render_rows_par_rejects_small_framebuffer() {
+    let grid: Grid<u8> = Grid::new(4, 4);
+    let mut small = vec![0u8; 15];
+    assert!(grid.render_rows_par(&mut small, 4, |_, _, _| {}).is_err());
+    assert!(grid.render_rows_par(&mut small, 0, |_, _, _| {}).is_err());
+    assert!(grid.render_rows_par(&mut small, 3, |_, _, _| {}).is_ok());
+}
+
+#[test]
+fn test_ascii_axes_use_vertical_digits_on_wide_maps() {
+    let grid: Grid<u8> = Grid::new(12, 11);
+    let overlay = AsciiOverlay {
+        axes: true,
+        ..AsciiOverlay::default()
+    };
+    let text = grid.render_ascii(|_| ('.', ""), &overlay);
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines[0], "   0         1 ");
+    assert_eq!(lines[1], "   012345678901");
+    assert_eq!(lines[2], " 0 ............");
+    assert_eq!(lines[12], "10 ............");
+    assert_eq!(lines.len(), 13);
+}
+
+#[test]
+fn test_path_snapshot_over_terrain() {
+    let rows = ["......", ".####.", "......"];
+    let cells = rows
+        .iter()
+        .flat_map(|r| r.chars().map(|c| c == '#'))
+        .collect();
+    let walls = Grid::from_raw_parts(6, 3, cells).unwrap();
+    let path = astar(&walls, (0, 1), (5, 1), |_, _, &wall| (!wall).then_some(1)).unwrap();
+
+    let overlay = AsciiOverlay {
+        legend: true,
+        highlights: vec![
+            Highlight::new('*', "path", path),
+            Highlight::new('@', "start", [(0, 1)]),
+            Highlight::new('?', "ignored", [(9, 9)]),
+        ],
+        ..AsciiOverlay::default()
+    };
+    let text = walls.render_ascii(
+        |&w| if w { ('#', "wall") } else { ('.', "floor") },
+        &overlay,
+    );
+    let map: Vec<&str> = text.lines().take(3).collect();
+    assert_eq!(map[1], "@####*");
+    assert_eq!(map.iter().map(|l| l.matches('*').count()).sum::<usize>(), 7);
+    assert!(text.ends_with("Legend: . floor, # wall, * path, @ start, ? ignored\n"));
+    assert_eq!(
+        walls.to_ascii(|&w| if w { '#' } else { '.' }),
+        rows.join("\n") + "\n"
+    );
+}
@@ -0,0 +1,70 @@
+use gridsystem::{Grid, RowReader, RowWriter};
+
+fn sample() -> Grid<f32> {
+    let mut grid = Grid::new(7, 5);
+    grid.map_inplace(|x, y, v| *v = x as f32 * 0.5 - y as f32);
+    grid
+}
+
+#[test]
+fn test_rows_roundtrip_in_bands() {
+    let grid = sample();
+    let mut bytes = Vec::new();
+    grid.write_rows_to(&mut bytes).unwrap();
+
+    let mut reader = RowReader::<_, f32>::new(bytes.as_slice()).unwrap();
+    assert_eq!((reader.width(), reader.height()), (7, 5));
+    let mut restored = Vec::new();
+    loop {
+        let band = reader.read_band(2).unwrap();
+        if band.is_empty() {
+            break;
+        }
+        assert!(band.height() <= 2);
+        restored.extend(band.into_vec());
+    }
+    assert_eq!(restored.as_slice(), grid.as_slice());
+    assert_eq!(reader.read_row().unwrap(), None);
+}
+
+#[test]
+fn test_row_writer_matches_write_rows_to() {
+    let grid = sample();
+    let mut expected = Vec::new();
+    grid.write_rows_to(&mut expected).unwrap();
+
+    let mut writer = RowWriter::new(Vec::new(), 7, 5).unwrap();
+    for y in 0..5 {
+        writer.write_row(grid.row(y).unwrap()).unwrap();
+    }
+    assert!(writer.write_row(grid.row(0).unwrap()).is_err());
+    assert_eq!(writer.finish().unwrap(), expected);
+
+    let mut short = RowWriter::new(Vec::new(), 3, 2).unwrap();
+    assert!(short.write_row(&[1u8, 2]).is_err());
+    short.write_row(&[1u8, 2, 3]).unwrap();
+    assert!(short.finish().is_err());
+}
+
+#[test]
+fn test_row_reader_errors() {
+    assert!(RowReader::<_, u8>::new(&b"GRX1\x01\x00\x01\x00\x00"[..]).is_err());
+
+    let truncated = b"GRW1\x02\x00\x02\x00\x01\x02\x03";
+    let mut reader = RowReader::<_, u8>::new(&truncated[..]).unwrap();
+    assert_eq!(reader.read_row().unwrap(), Some(vec![1, 2]));
+    assert!(reader.read_row().is_err());
+
+    let bad_bool = b"GRW1\x01\x00\x01\x00\x07";
+    let mut reader = RowReader::<_, bool>::new(&bad_bool[..]).unwrap();
+    assert!(reader.read_band(1).is_err());
+}
+
+#[test]
+fn test_rows_zero_width_grid() {
+    let grid: Grid<u8> = Grid::new(0, 3);
+    let mut bytes = Vec::new();
+    grid.write_rows_to(&mut bytes).unwrap();
+    let mut reader = RowReader::<_, u8>::new(bytes.as_slice()).unwrap();
+    assert_eq!(reader.read_band(10).unwrap().height(), 3);
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "serde")]
+
+use gridsystem::Grid;
+use serde::de::value::{Error, MapDeserializer, SeqDeserializer};
+use serde::de::{Deserialize, Deserializer, IntoDeserializer, Visitor};
+use serde::forward_to_deserialize_any;
+
+/// A tiny self-describing format, standing in for JSON and friends.
+enum Value {
+    Num(u64),
+    Str(&'static str),
+    Seq(Vec<Value>),
+    Map(Vec<(Value, Value)>),
+}
+
+impl<'de> IntoDeserializer<'de, Error> for Value {
+    type Deserializer = Value;
+
+    fn into_deserializer(self) -> Value {
+        self
+    }
+}
+
+impl<'de> Deserializer<'de> for Value {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self {
+            Value::Num(n) => visitor.visit_u64(n),
+            Value::Str(s) => visitor.visit_borrowed_str(s),
+            Value::Seq(items) => visitor.visit_seq(SeqDeserializer::new(items.into_iter())),
+            Value::Map(entries) => visitor.visit_map(MapDeserializer::new(entries.into_iter())),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+fn nums(values: &[u64]) -> Value {
+    Value::Seq(values.iter().map(|&n| Value::Num(n)).collect())
+}
+
+fn map(width: u64, height: u64, tiles: &[u64]) -> Value {
+    Value::Map(vec![
+        (Value::Str("width"), Value::Num(width)),
+        (Value::Str("height"), Value::Num(height)),
+        (Value::Str("tiles"), nums(tiles)),
+    ])
+}
+
+#[test]
+fn test_deserialize_from_map_and_sequence() {
+    let grid = Grid::<u8>::deserialize(map(3, 2, &[1, 2, 3, 4, 5, 6])).unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.get(0, 1), Some(&4));
+
+    let compact = Value::Seq(vec![Value::Num(2), Value::Num(1), nums(&[7, 8])]);
+    let grid = Grid::<u32>::deserialize(compact).unwrap();
+    assert_eq!(grid.as_slice(), &[7, 8]);
+}
+
+#[test]
+fn test_deserialize_validates_tile_count() {
+    let Err(err) = Grid::<u8>::deserialize(map(3, 2, &[1, 2, 3])) else {
+        panic!("short tiles accepted");
+    };
+    assert!(err.to_string().contains("6"), "{err}");
+
+    let missing = Value::Map(vec![(Value::Str("width"), Value::Num(1))]);
+    assert!(Grid::<u8>::deserialize(missing).is_err());
+    let unknown = Value::Map(vec![(Value::Str("depth"), Value::Num(1))]);
+    assert!(Grid::<u8>::deserialize(unknown).is_err());
+    assert!(Grid::<u8>::deserialize(map(70_000, 1, &[0])).is_err());
+}
+
+#[test]
+fn test_grid_is_serialize() {
+    fn assert_serialize<S: serde::Serialize>(_: &S) {}
+    assert_serialize(&Grid::<u8>::new(2, 2));
+}
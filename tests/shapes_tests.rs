@@ -0,0 +1,54 @@
+use gridsystem::{Direction, Grid};
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+fn coords<'a>(iter: impl Iterator<Item = (u16, u16, &'a u8)>) -> Vec<(u16, u16)> {
+    iter.map(|(x, y, _)| (x, y)).collect()
+}
+
+#[test]
+fn test_disk_matches_brute_force() {
+    let grid: Grid<u8> = Grid::new(15, 11);
+    for (cx, cy, r) in [(7, 5, 3), (0, 0, 4), (14, 10, 2), (3, 9, 20), (5, 5, 0)] {
+        let expected: Vec<_> = grid
+            .iter()
+            .filter(|&(x, y, _)| {
+                let (dx, dy) = (x as i32 - cx as i32, y as i32 - cy as i32);
+                dx * dx + dy * dy <= (r * r) as i32
+            })
+            .map(|(x, y, _)| (x, y))
+            .collect();
+        assert_eq!(coords(grid.iter_disk(cx, cy, r)), expected);
+    }
+}
+
+#[test]
+fn test_rings_partition_disk() {
+    let grid: Grid<u8> = Grid::new(30, 30);
+    let mut union: Vec<_> = (0..=6)
+        .flat_map(|r| coords(grid.iter_ring(12, 15, r)))
+        .collect();
+    union.sort_by_key(|&(x, y)| (y, x));
+    assert_eq!(union, coords(grid.iter_disk(12, 15, 6)));
+}
+
+#[test]
+fn test_cone_directions_and_angles() {
+    let grid: Grid<u8> = Grid::new(21, 21);
+    let north = coords(grid.iter_cone(10, 10, Direction::North, 5, FRAC_PI_4));
+    assert!(north.iter().all(|&(_, y)| y <= 10));
+    assert!(north.contains(&(10, 5)));
+    assert!(north.contains(&(7, 7)));
+    assert!(!north.contains(&(7, 8)));
+
+    let diagonal = coords(grid.iter_cone(10, 10, Direction::SouthEast, 4, 0.01));
+    assert_eq!(diagonal, vec![(10, 10), (11, 11), (12, 12)]);
+
+    let half = grid
+        .iter_cone(10, 10, Direction::West, 3, FRAC_PI_2)
+        .count();
+    assert_eq!(half, 18);
+    assert_eq!(
+        grid.iter_cone(10, 10, Direction::West, 3, PI).count(),
+        grid.iter_disk(10, 10, 3).count()
+    );
+}
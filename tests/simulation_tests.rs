@@ -0,0 +1,238 @@
+use gridsystem::{
+    BitGrid, Boundary, Grid, GridError, GridLike, GridLikeMut, Neighborhood, Simulation, TorusGrid,
+};
+
+fn conway(_: u16, _: u16, &alive: &bool, n: Neighborhood<'_, bool>) -> bool {
+    matches!((alive, n.count(|&c| c)), (true, 2) | (_, 3))
+}
+
+fn copy<T: Default + Clone>(grid: &Grid<T>) -> Grid<T> {
+    Grid::from_raw_parts(grid.width(), grid.height(), grid.as_slice().to_vec()).unwrap()
+}
+
+fn glider() -> Grid<bool> {
+    let mut grid: Grid<bool> = Grid::new(8, 8);
+    for (x, y) in [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)] {
+        let _ = grid.set(x, y, true);
+    }
+    grid
+}
+
+#[test]
+fn test_glider_wraps_around_torus() {
+    let mut life = Simulation::new(glider()).with_boundary(Boundary::Wrap);
+    life.run(32, conway);
+    // A glider moves one cell diagonally every four generations: 8 cells
+    // in 32 generations brings it back to where it started on an 8x8 torus.
+    assert_eq!(life.generation(), 32);
+    assert_eq!(life.grid().as_slice(), glider().as_slice());
+
+    life.run(4, conway);
+    let alive: Vec<_> = life
+        .grid()
+        .iter()
+        .filter(|(_, _, a)| **a)
+        .map(|(x, y, _)| (x, y))
+        .collect();
+    assert_eq!(alive, [(2, 1), (3, 2), (1, 3), (2, 3), (3, 3)]);
+}
+
+#[test]
+fn test_parallel_matches_serial() {
+    let mut noise: Grid<u8> = Grid::new(40, 30);
+    noise.map_inplace(|x, y, v| *v = ((x as u32 * 31 + y as u32 * 17) % 7) as u8);
+    let rule = |_: u16, _: u16, &v: &u8, n: Neighborhood<'_, u8>| {
+        (n.moore().map(|&c| c as u32).sum::<u32>() / 8) as u8 + (v & 1)
+    };
+
+    let mut serial = Simulation::new(copy(&noise)).with_boundary(Boundary::Clamp);
+    let mut parallel = Simulation::new(copy(&noise)).with_boundary(Boundary::Clamp);
+    for _ in 0..3 {
+        serial.step(rule);
+        parallel.par_step(rule);
+    }
+    assert_eq!(serial.grid().as_slice(), parallel.grid().as_slice());
+
+    let mut a = copy(&noise);
+    let mut b = noise;
+    a.step(rule);
+    b.par_step(rule);
+    assert_eq!(a.as_slice(), b.as_slice());
+}
+
+#[test]
+fn test_mask_freezes_cells_outside_it() {
+    let mut mask = BitGrid::new(8, 8);
+    for y in 0..8 {
+        for x in 0..4 {
+            let _ = mask.set(x, y, true);
+        }
+    }
+    let mut grid: Grid<u32> = Grid::new(8, 8);
+    grid.map_inplace(|x, _, v| *v = x as u32);
+    let mut counter = Simulation::new(grid).with_mask(mask).unwrap();
+    counter.run(3, |_, _, &v, _| v + 10);
+    let row = counter.into_grid().row(5).unwrap().to_vec();
+    assert_eq!(row, [30, 31, 32, 33, 4, 5, 6, 7]);
+
+    assert!(matches!(
+        Simulation::new(Grid::<u8>::new(3, 3)).with_mask(BitGrid::new(3, 4)),
+        Err(GridError::DimensionMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_checkerboard_matches_serial_red_black_sweep() {
+    let values: Vec<i64> = (0..35).map(|i| (i * 37) % 11).collect();
+    let mut grid = Grid::from_raw_parts(7, 5, values).unwrap();
+    let mut expected = copy(&grid);
+    let rule = |n: Neighborhood<'_, i64>| n.center() + n.count(|&v| v > 4) as i64;
+    let boundary = Boundary::Constant(0);
+    grid.par_update_checkerboard(&boundary, |_, _, n| rule(n));
+
+    // Serial reference: red cells read the old grid, black cells the half-updated one.
+    for colour in 0..2 {
+        let before = copy(&expected);
+        for y in 0..5u16 {
+            for x in (0..7u16).filter(|x| (x + y) % 2 == colour) {
+                let mut total = *before.get(x, y).unwrap();
+                for (dx, dy) in [
+                    (-1, -1),
+                    (0, -1),
+                    (1, -1),
+                    (-1, 0),
+                    (1, 0),
+                    (-1, 1),
+                    (0, 1),
+                    (1, 1),
+                ] {
+                    let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+                    if nx >= 0
+                        && ny >= 0
+                        && before.get(nx as u16, ny as u16).is_some_and(|&v| v > 4)
+                    {
+                        total += 1;
+                    }
+                }
+                let _ = expected.set(x, y, total);
+            }
+        }
+    }
+    assert_eq!(grid.as_slice(), expected.as_slice());
+}
+
+#[test]
+fn test_checkerboard_red_cells_see_old_black_values() {
+    let mut grid: Grid<u8> = Grid::new(3, 1);
+    // Every cell copies its left neighbour plus one; cell 1 (black) sees the new cell 0.
+    grid.par_update_checkerboard(&Boundary::Constant(10), |_, _, n| n.get(-1, 0).unwrap() + 1);
+    assert_eq!(grid.as_slice(), &[11, 12, 1]);
+}
+
+#[test]
+fn test_grid_mut_edits_between_steps() {
+    let mut sim = Simulation::new(Grid::with_value(8, 8, 0u8));
+    sim.grid_mut().set(7, 7, 3).unwrap();
+    sim.step(|_, _, &v, _| v + 1);
+    assert_eq!((sim.grid().width(), sim.grid().height()), (8, 8));
+    assert_eq!(sim.grid().get(7, 7), Some(&4));
+    assert_eq!(sim.grid().get(0, 0), Some(&1));
+}
+
+#[test]
+fn test_run_cancellable_reports_generations() {
+    use gridsystem::CancellationToken;
+    use std::sync::Mutex;
+
+    let mut sim = Simulation::new(Grid::with_value(4, 4, 0u32));
+    let reports = Mutex::new(Vec::new());
+    let sink = |done: usize, total: usize| reports.lock().unwrap().push((done, total));
+    let cancel = CancellationToken::new();
+    sim.run_cancellable(3, |_, _, &v, _| v + 1, &sink, &cancel)
+        .unwrap();
+    assert_eq!(reports.into_inner().unwrap(), vec![(1, 3), (2, 3), (3, 3)]);
+    assert_eq!(sim.grid().get(2, 2), Some(&3));
+
+    cancel.cancel();
+    assert_eq!(
+        sim.run_cancellable(3, |_, _, &v, _| v + 1, &(), &cancel),
+        Err(GridError::Cancelled)
+    );
+    assert_eq!(sim.generation(), 3);
+}
+
+#[test]
+fn test_generic_step_on_torus_and_view() {
+    let mut world: TorusGrid<bool> = TorusGrid::new(5, 5);
+    // A blinker straddling the east-west seam.
+    for x in [4, 0, 1] {
+        world.set(x, 2, true).unwrap();
+    }
+    gridsystem::simulation::step(&mut world, &Boundary::Wrap, conway).unwrap();
+    let alive: Vec<_> = (0..5)
+        .flat_map(|y| (0..5).map(move |x| (x, y)))
+        .filter(|&(x, y)| world.get(x, y) == Some(&true))
+        .collect();
+    assert_eq!(alive, vec![(0, 1), (0, 2), (0, 3)]);
+
+    let sim = Simulation::from_grid_like(&world);
+    assert_eq!(sim.grid().get(0, 3), Some(&true));
+
+    let mut grid = Grid::with_value(4, 4, 1u32);
+    let mut view = grid.view_mut(1, 1, 2, 2).unwrap();
+    gridsystem::stencil::step_stencil(&mut view, &Boundary::Skip, |_, _, n| {
+        n.count(|_| true) as u32
+    })
+    .unwrap();
+    assert_eq!(grid.get(1, 1), Some(&3));
+    assert_eq!(grid.get(0, 0), Some(&1));
+}
+
+/// A 3x1 strip whose middle cell is missing.
+struct Holey(Vec<u8>);
+
+impl GridLike<u8> for Holey {
+    fn width(&self) -> u16 {
+        3
+    }
+
+    fn height(&self) -> u16 {
+        1
+    }
+
+    fn get(&self, x: u16, y: u16) -> Option<&u8> {
+        (x != 1 && y == 0).then(|| &self.0[x as usize])
+    }
+}
+
+impl GridLikeMut<u8> for Holey {
+    fn get_mut(&mut self, x: u16, y: u16) -> Option<&mut u8> {
+        (x != 1 && y == 0).then(|| &mut self.0[x as usize])
+    }
+}
+
+#[test]
+fn test_generic_step_reports_missing_cells_and_simulation_stencil() {
+    let mut strip = Holey(vec![1, 2, 3]);
+    let err =
+        gridsystem::stencil::step_stencil(&mut strip, &Boundary::Skip, |_, _, _| 9).unwrap_err();
+    assert_eq!(
+        err,
+        GridError::LengthMismatch {
+            expected: 3,
+            found: 2
+        }
+    );
+    assert_eq!(strip.0, vec![1, 2, 3]);
+
+    let grid = Grid::from_raw_parts(3, 1, vec![1u32, 2, 3]).unwrap();
+    let mut plain = grid.clone();
+    plain.step_stencil(&Boundary::Wrap, |_, _, n| n.von_neumann().sum());
+    let mut sim = Simulation::new(grid.clone()).with_boundary(Boundary::Wrap);
+    sim.step_stencil(|_, _, n| n.von_neumann().sum());
+    assert_eq!(sim.grid().as_slice(), plain.as_slice());
+    let mut par = Simulation::new(grid).with_boundary(Boundary::Wrap);
+    par.par_step_stencil(|_, _, n| n.von_neumann().sum());
+    assert_eq!(par.grid().as_slice(), plain.as_slice());
+    assert_eq!(par.generation(), 1);
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "snapshot")]
+
+use std::panic;
+
+use gridsystem::snapshot::{assert_snapshot, snapshot_diff};
+use gridsystem::{Grid, assert_grid_snapshot};
+
+fn glyph(wall: &bool) -> char {
+    if *wall { '#' } else { '.' }
+}
+
+#[test]
+fn test_snapshot_string_format() {
+    let mut map: Grid<bool> = Grid::new(3, 2);
+    map.set(0, 1, true).unwrap();
+    assert_eq!(map.to_snapshot_string(glyph), "3x2\n...\n#..\n");
+
+    let empty: Grid<bool> = Grid::new(0, 3);
+    assert_eq!(empty.to_snapshot_string(glyph), "0x3\n");
+}
+
+#[test]
+fn test_assert_snapshot_records_then_compares() {
+    let dir = std::env::temp_dir().join(format!("gridsystem-snapshots-{}", std::process::id()));
+    let path = dir.join("nested").join("room.snap");
+    let mut map: Grid<bool> = Grid::new(4, 3);
+    map.set(1, 1, true).unwrap();
+    let text = map.to_snapshot_string(glyph);
+
+    assert_snapshot(&path, &text);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), text);
+    assert_snapshot(&path, &text);
+
+    std::fs::write(&path, text.replace('\n', "\r\n")).unwrap();
+    assert_snapshot(&path, &text);
+
+    map.set(3, 2, true).unwrap();
+    let changed = map.to_snapshot_string(glyph);
+    let err = panic::catch_unwind(|| assert_snapshot(&path, &changed)).unwrap_err();
+    let message = err.downcast_ref::<String>().unwrap();
+    assert!(message.contains("4 - ....\n4 + ...#\n"), "{message}");
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_snapshot_diff_reports_added_and_missing_lines() {
+    assert_eq!(
+        snapshot_diff("1x1\n.\n", "1x2\n.\n#\n").unwrap(),
+        "1 - 1x1\n1 + 1x2\n3 + #\n"
+    );
+    assert_eq!(
+        snapshot_diff("a\nb", "a\nc\n").unwrap(),
+        "2 - b (no newline)\n2 + c\n"
+    );
+}
+
+#[test]
+fn test_assert_grid_snapshot_with_insta() {
+    let mut map: Grid<bool> = Grid::new(5, 3);
+    for x in 0..5 {
+        map.set(x, 0, true).unwrap();
+    }
+    map.set(2, 2, true).unwrap();
+    assert_grid_snapshot!("corridor", map, glyph);
+    assert_grid_snapshot!(map, glyph, @r"
+    5x3
+    #####
+    .....
+    ..#..
+    ");
+}
@@ -0,0 +1,90 @@
+use gridsystem::{Grid, GridLike, SparseGrid};
+
+#[test]
+fn test_sparse_set_get_with_negative_coordinates() {
+    let mut world: SparseGrid<i32> = SparseGrid::new();
+    assert!(world.is_empty());
+    assert_eq!(world.set(-3, -8, 5), None);
+    assert_eq!(world.set(-3, -8, 6), Some(5));
+    world.set(i32::MAX, i32::MIN, 1);
+
+    assert_eq!(world.get(-3, -8), &6);
+    assert_eq!(world.get(i32::MAX, i32::MIN), &1);
+    assert_eq!(world.get(3, 8), &0);
+    assert_eq!(world.bounds(), Some((-3, i32::MIN, i32::MAX, -8)));
+
+    assert_eq!(world.set(-3, -8, 0), Some(6));
+    assert_eq!(world.remove(i32::MAX, i32::MIN), Some(1));
+    assert!(world.is_empty());
+    assert_eq!(world.bounds(), None);
+}
+
+#[test]
+fn test_sparse_dense_round_trip() {
+    let mut dense: Grid<u8> = Grid::new(6, 5);
+    dense.set(0, 0, 1).unwrap();
+    dense.set(5, 4, 2).unwrap();
+    dense.set(2, 3, 3).unwrap();
+
+    let mut world = SparseGrid::from_grid(&dense, -10, 20);
+    assert_eq!(world.len(), 3);
+    let mut stored: Vec<_> = world.iter().map(|(x, y, v)| (x, y, *v)).collect();
+    stored.sort();
+    assert_eq!(stored, vec![(-10, 20, 1), (-8, 23, 3), (-5, 24, 2)]);
+
+    let back = world.to_grid(-10, 20, 6, 5);
+    assert_eq!(back.as_slice(), dense.as_slice());
+
+    // A region smaller than the stored cell count takes the probing path.
+    let corner = world.to_grid(-6, 23, 2, 1);
+    assert_eq!(corner.as_slice(), &[0, 0]);
+
+    // Pasting default cells clears what was stored there.
+    world.paste(&Grid::new(1, 1), -10, 20);
+    assert_eq!(world.get(-10, 20), &0);
+    assert_eq!(world.len(), 2);
+}
+
+#[test]
+fn test_sparse_window_and_points_csv() {
+    let mut world: SparseGrid<i64> = SparseGrid::new();
+    world.set(-1, -1, 7);
+    world.set(2, 0, -4);
+
+    let window = world.window(-1, -1, 4, 2);
+    assert_eq!((window.width(), window.height()), (4, 2));
+    assert_eq!(window.get(0, 0), Some(&7));
+    assert_eq!(window.get(3, 1), Some(&-4));
+    assert_eq!(window.get(0, 2), None);
+
+    let mut out = Vec::new();
+    world.write_points_csv(&mut out).unwrap();
+    let text = String::from_utf8(out).unwrap();
+    assert_eq!(text, "x,y,value\n-1,-1,7\n2,0,-4\n");
+
+    let restored: SparseGrid<i64> = SparseGrid::read_points_csv(text.as_bytes()).unwrap();
+    assert_eq!(restored, world);
+    assert!(SparseGrid::<i64>::read_points_csv("1,-x,3\n".as_bytes()).is_err());
+}
+
+#[test]
+fn test_sparse_grid_like_covers_stored_quadrant() {
+    use gridsystem::pathfinding::astar;
+
+    let mut world: SparseGrid<u8> = SparseGrid::new();
+    assert_eq!((GridLike::width(&world), GridLike::height(&world)), (0, 0));
+    world.set(-4, 2, 1);
+    world.set(5, 3, 1);
+    world.set(2, 1, 1);
+    assert_eq!((GridLike::width(&world), GridLike::height(&world)), (6, 4));
+    assert_eq!(GridLike::get(&world, 2, 1), Some(&1));
+    assert_eq!(GridLike::get(&world, 0, 0), Some(&0));
+    assert_eq!(GridLike::get(&world, 6, 0), None);
+
+    let path = astar(&world, (0, 1), (4, 1), |_, _, &c| (c == 0).then_some(1)).unwrap();
+    assert_eq!(path.len(), 7);
+    assert!(!path.contains(&(2, 1)));
+
+    world.clear();
+    assert_eq!(GridLike::width(&world), 0);
+}
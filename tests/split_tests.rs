@@ -0,0 +1,36 @@
+use gridsystem::{Axis, Grid};
+
+mod common;
+
+use common::numbered;
+
+fn labels(width: u16, height: u16) -> Grid<String> {
+    numbered(width, height).map(|_, _, i| i.to_string())
+}
+
+#[test]
+fn test_split_and_join_round_trip_without_clone() {
+    // String is not Copy, so this also checks that cells are moved.
+    let (left, right) = labels(5, 4).split_vertically(2).unwrap();
+    assert_eq!((left.width(), right.width(), right.height()), (2, 3, 4));
+    assert_eq!(right.get(0, 1).map(String::as_str), Some("7"));
+    let joined = Grid::join(left, right, Axis::Vertical).unwrap();
+    assert_eq!(joined.as_slice(), labels(5, 4).as_slice());
+
+    let (top, bottom) = joined.split_horizontally(3).unwrap();
+    assert_eq!((top.height(), bottom.height()), (3, 1));
+    let joined = Grid::join(top, bottom, Axis::Horizontal).unwrap();
+    assert_eq!(joined.as_slice(), labels(5, 4).as_slice());
+}
+
+#[test]
+fn test_split_edges_and_errors() {
+    let (empty, all) = labels(3, 2).split_vertically(0).unwrap();
+    assert_eq!((empty.width(), empty.height(), all.width()), (0, 2, 3));
+    assert!(labels(3, 2).split_vertically(4).is_err());
+    assert!(labels(3, 2).split_horizontally(3).is_err());
+
+    assert!(Grid::join(labels(3, 2), labels(3, 3), Axis::Vertical).is_err());
+    assert!(Grid::join(labels(3, 2), labels(2, 2), Axis::Horizontal).is_err());
+    assert!(Grid::join(labels(u16::MAX, 0), labels(1, 0), Axis::Vertical).is_err());
+}
@@ -0,0 +1,117 @@
+use gridsystem::{Boundary, Direction, Grid};
+
+fn life(alive: bool, neighbours: usize) -> bool {
+    matches!((alive, neighbours), (true, 2) | (_, 3))
+}
+
+#[test]
+fn test_blinker_oscillates() {
+    let mut grid: Grid<bool> = Grid::new(5, 5);
+    for x in 1..4 {
+        let _ = grid.set(x, 2, true);
+    }
+
+    grid.step_stencil(&Boundary::Skip, |_, _, n| {
+        life(*n.center(), n.count(|&c| c))
+    });
+    let alive: Vec<_> = grid
+        .iter()
+        .filter(|(_, _, c)| **c)
+        .map(|(x, y, _)| (x, y))
+        .collect();
+    assert_eq!(alive, vec![(2, 1), (2, 2), (2, 3)]);
+
+    grid.par_step_stencil(&Boundary::Skip, |_, _, n| {
+        life(*n.center(), n.count(|&c| c))
+    });
+    let alive: Vec<_> = grid
+        .iter()
+        .filter(|(_, _, c)| **c)
+        .map(|(x, y, _)| (x, y))
+        .collect();
+    assert_eq!(alive, vec![(1, 2), (2, 2), (3, 2)]);
+}
+
+/// Replaces every cell with the neighbour at (dx, dy), or -99 if it is absent.
+fn shifted(grid: &Grid<i32>, boundary: Boundary<i32>, dx: i32, dy: i32) -> Vec<i32> {
+    let mut out = grid.map(|_, _, v| *v);
+    out.step_stencil(&boundary, |_, _, n| n.get(dx, dy).copied().unwrap_or(-99));
+    out.into_vec()
+}
+
+#[test]
+fn test_boundary_policies() {
+    // 0 1 2
+    // 3 4 5
+    let mut grid: Grid<i32> = Grid::new(3, 2);
+    grid.map_inplace(|x, y, v| *v = (y as i32) * 3 + x as i32);
+
+    assert_eq!(
+        shifted(&grid, Boundary::Skip, -1, 0),
+        vec![-99, 0, 1, -99, 3, 4]
+    );
+    assert_eq!(
+        shifted(&grid, Boundary::Clamp, -1, -1),
+        vec![0, 0, 1, 0, 0, 1]
+    );
+    assert_eq!(shifted(&grid, Boundary::Wrap, 1, 1), vec![4, 5, 3, 1, 2, 0]);
+    assert_eq!(
+        shifted(&grid, Boundary::Constant(7), 0, 1),
+        vec![3, 4, 5, 7, 7, 7]
+    );
+
+    let mut counts: Grid<i32> = grid.map(|_, _, v| *v);
+    counts.step_stencil(&Boundary::Skip, |_, _, n| n.moore().count() as i32);
+    assert_eq!(counts.as_slice(), &[3, 5, 3, 3, 5, 3]);
+    counts.step_stencil(&Boundary::Skip, |_, _, n| {
+        n.dir(Direction::North).copied().unwrap_or(-1)
+    });
+    assert_eq!(counts.as_slice(), &[-1, -1, -1, 3, 5, 3]);
+}
+
+#[test]
+fn test_parallel_matches_sequential() {
+    let mut seq: Grid<u32> = Grid::new(40, 30);
+    seq.map_inplace(|x, y, v| *v = (x as u32 * 7 + y as u32 * 13) % 10);
+    let mut par = seq.map(|_, _, v| *v);
+
+    for _ in 0..5 {
+        seq.step_stencil(&Boundary::Wrap, |_, _, n| n.moore().sum::<u32>() % 10);
+        par.par_step_stencil(&Boundary::Wrap, |_, _, n| n.moore().sum::<u32>() % 10);
+    }
+    assert_eq!(seq.as_slice(), par.as_slice());
+}
+
+#[test]
+fn test_cancellable_stencil_matches_and_aborts() {
+    use gridsystem::{CancellationToken, GridError};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let mut plain: Grid<u32> = Grid::new(9, 7);
+    plain.map_inplace(|x, y, v| *v = x as u32 * 3 + y as u32 * 5);
+    let mut sequential = Grid::from_raw_parts(9, 7, plain.as_slice().to_vec()).unwrap();
+    let mut parallel = Grid::from_raw_parts(9, 7, plain.as_slice().to_vec()).unwrap();
+    let sum = |_: u16, _: u16, n: gridsystem::Neighborhood<'_, u32>| n.moore().sum::<u32>();
+
+    let rows = AtomicUsize::new(0);
+    let sink = |_: usize, total: usize| {
+        assert_eq!(total, 7);
+        rows.fetch_add(1, Ordering::Relaxed);
+    };
+    let cancel = CancellationToken::new();
+    plain.step_stencil(&Boundary::Wrap, sum);
+    sequential
+        .step_stencil_cancellable(&Boundary::Wrap, sum, &sink, &cancel)
+        .unwrap();
+    parallel
+        .par_step_stencil_cancellable(&Boundary::Wrap, sum, &sink, &cancel)
+        .unwrap();
+    assert_eq!(sequential.as_slice(), plain.as_slice());
+    assert_eq!(parallel.as_slice(), plain.as_slice());
+    assert_eq!(rows.load(Ordering::Relaxed), 14);
+
+    cancel.cancel();
+    let result = parallel.par_step_stencil_cancellable(&Boundary::Wrap, sum, &(), &cancel);
+    assert_eq!(result, Err(GridError::Cancelled));
+    assert_eq!(parallel.as_slice(), plain.as_slice());
+}
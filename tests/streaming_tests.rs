@@ -0,0 +1,153 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gridsystem::{ChunkStreamer, Grid, GridError};
+
+#[test]
+fn test_loads_chunks_around_viewer() {
+    let mut streamer = ChunkStreamer::new(4, 1, |(cx, cy), size| {
+        Grid::with_value(size, size, (cx, cy))
+    });
+    streamer.set_viewer(5, 5);
+    assert_eq!(streamer.wait_all(), 9);
+
+    assert!(streamer.is_loaded((0, 0)));
+    assert!(streamer.is_loaded((2, 2)));
+    assert!(!streamer.is_loaded((3, 1)));
+    assert_eq!(streamer.get(0, 11), Some(&(0, 2)));
+    assert_eq!(streamer.get(-1, 11), None);
+    assert_eq!(streamer.pending_count(), 0);
+}
+
+#[test]
+fn test_evicts_distant_chunks() {
+    let unloaded = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&unloaded);
+    let mut streamer = ChunkStreamer::new(8, 0, |_, size| Grid::<u8>::new(size, size))
+        .on_unload(move |coord, chunk| sink.borrow_mut().push((coord, chunk.area())));
+
+    streamer.set_viewer(0, 0);
+    streamer.wait_all();
+    streamer.set_viewer(8, 0);
+    streamer.wait_all();
+
+    assert!(!streamer.is_loaded((0, 0)));
+    assert!(streamer.is_loaded((1, 0)));
+
+    streamer.unload_all();
+    assert_eq!(*unloaded.borrow(), vec![((0, 0), 64), ((1, 0), 64)]);
+}
+
+#[test]
+fn test_drops_chunks_that_arrive_out_of_range() {
+    let mut streamer = ChunkStreamer::new(2, 0, |_, size| Grid::<u8>::new(size, size));
+    streamer.set_viewer(0, 0);
+    streamer.set_viewer(100, 100);
+    streamer.wait_all();
+
+    assert_eq!(streamer.loaded_count(), 1);
+    assert!(streamer.is_loaded((50, 50)));
+}
+
+#[test]
+fn test_stitch_borders_visits_each_seam_once() {
+    let mut streamer = ChunkStreamer::new(4, 1, |_, size| Grid::<u32>::new(size, size));
+    streamer.set_viewer(0, 0);
+    streamer.wait_all();
+
+    let mut pairs = 0;
+    let seams = streamer.stitch_borders(|a, b| {
+        *a += 1;
+        *b += 1;
+        pairs += 1;
+    });
+    assert_eq!((seams, pairs), (12, 48));
+    // Interior edge cells touch one seam, chunk corners shared by two seams touch two.
+    assert_eq!(streamer.get(3, 1), Some(&1));
+    assert_eq!(streamer.get(3, 3), Some(&2));
+    assert_eq!(streamer.get(1, 1), Some(&0));
+    assert_eq!(
+        streamer.stitch_borders(|_, _| panic!("already stitched")),
+        0
+    );
+}
+
+#[test]
+fn test_stitch_borders_restitches_after_reload() {
+    let mut streamer = ChunkStreamer::new(4, 0, |(cx, _), size| Grid::with_value(size, size, cx));
+    streamer.set_viewer(0, 0);
+    streamer.wait_all();
+    assert_eq!(streamer.stitch_borders(|_, _| {}), 0);
+
+    let mut streamer = ChunkStreamer::new(4, 1, |(cx, _), size| Grid::with_value(size, size, cx));
+    streamer.set_viewer(0, 0);
+    streamer.wait_all();
+    assert_eq!(streamer.stitch_borders(|a, b| *b = *a), 12);
+    assert_eq!(streamer.get(4, 0), Some(&0));
+
+    // Moving one chunk east evicts the west column and loads a new east column.
+    streamer.set_viewer(4, 0);
+    streamer.wait_all();
+    let mut seen = Vec::new();
+    assert_eq!(streamer.stitch_borders(|a, b| seen.push((*a, *b))), 5);
+    assert!(seen.contains(&(1, 2)));
+}
+
+#[test]
+fn test_failed_loads_settle_and_are_reported() {
+    let mut streamer = ChunkStreamer::new(4, 1, |(cx, cy), size| {
+        if (cx, cy) == (1, 0) {
+            panic!("disk on fire");
+        }
+        let size = if (cx, cy) == (0, 1) { size - 1 } else { size };
+        Grid::<u8>::new(size, size)
+    });
+    streamer.set_viewer(0, 0);
+    assert_eq!(streamer.wait_all(), 7);
+    assert_eq!(streamer.pending_count(), 0);
+    assert!(!streamer.is_loaded((1, 0)) && !streamer.is_loaded((0, 1)));
+
+    let mut failures = streamer.take_failures();
+    failures.sort_by_key(|&(coord, _)| coord);
+    assert_eq!(
+        failures,
+        vec![
+            (
+                (0, 1),
+                GridError::DimensionMismatch {
+                    expected: (4, 4),
+                    found: (3, 3)
+                }
+            ),
+            ((1, 0), GridError::Panicked("disk on fire".to_string())),
+        ]
+    );
+    assert!(streamer.take_failures().is_empty());
+}
+
+#[test]
+fn test_viewer_and_lookup_at_the_ends_of_i32() {
+    let streamer = ChunkStreamer::new(3, 0, |_, size| Grid::<u8>::new(size, size));
+    assert_eq!(streamer.get(i32::MIN, i32::MAX), None);
+
+    let mut streamer = ChunkStreamer::new(1, 1, |(cx, cy), size| {
+        Grid::with_value(size, size, (cx, cy))
+    });
+    streamer.set_viewer(i32::MAX, i32::MIN);
+    assert_eq!(streamer.wait_all(), 4);
+    assert_eq!(
+        streamer.get(i32::MAX, i32::MIN),
+        Some(&(i32::MAX, i32::MIN))
+    );
+    assert_eq!(streamer.stitch_borders(|_, _| {}), 4);
+
+    let mut streamer = ChunkStreamer::new(3, 0, |(cx, cy), size| {
+        Grid::with_value(size, size, (cx, cy))
+    });
+    streamer.set_viewer(i32::MIN, i32::MIN);
+    streamer.wait_all();
+    assert_eq!(
+        streamer.get(i32::MIN, i32::MIN),
+        Some(&(i32::MIN.div_euclid(3), i32::MIN.div_euclid(3)))
+    );
+}
@@ -0,0 +1,48 @@
+use gridsystem::Axis;
+
+mod common;
+
+use common::numbered;
+
+#[test]
+fn test_mirror_into_both_axes_odd_and_even() {
+    for (w, h) in [(4, 3), (5, 6), (1, 1)] {
+        let mut grid = numbered(w, h);
+        grid.mirror_into(Axis::Vertical);
+        grid.mirror_into(Axis::Horizontal);
+        for (x, y, &v) in grid.iter() {
+            assert_eq!(grid.get(w - 1 - x, y), Some(&v));
+            assert_eq!(grid.get(x, h - 1 - y), Some(&v));
+        }
+        // The top-left quarter (including a middle line) is untouched.
+        assert_eq!(grid.get(0, 0), Some(&0));
+        assert_eq!(
+            grid.get((w - 1) / 2, (h - 1) / 2),
+            numbered(w, h).get((w - 1) / 2, (h - 1) / 2)
+        );
+    }
+}
+
+#[test]
+fn test_rotational_symmetry_orders() {
+    for size in [4, 5, 7] {
+        let mut grid = numbered(size, size);
+        grid.rotational_symmetrize(4).unwrap();
+        for (x, y, &v) in grid.iter() {
+            assert_eq!(grid.get(size - 1 - y, x), Some(&v));
+        }
+        if size % 2 == 1 {
+            let c = size / 2;
+            assert_eq!(grid.get(c, c), Some(&(c as u32 * size as u32 + c as u32)));
+        }
+    }
+
+    let mut grid = numbered(5, 3);
+    grid.rotational_symmetrize(2).unwrap();
+    for (x, y, &v) in grid.iter() {
+        assert_eq!(grid.get(4 - x, 2 - y), Some(&v));
+    }
+    assert_eq!(grid.get(2, 1), Some(&7));
+    assert!(grid.rotational_symmetrize(3).is_err());
+    assert!(grid.rotational_symmetrize(0).is_err());
+}
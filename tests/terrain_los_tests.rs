@@ -0,0 +1,31 @@
+use gridsystem::Grid;
+
+#[test]
+fn test_terrain_line_of_sight_is_symmetric_for_equal_heights() {
+    let mut terrain: Grid<f32> = Grid::new(16, 16);
+    terrain.map_inplace(|x, y, h| *h = ((x as f32 * 0.7).sin() + (y as f32 * 0.4).cos()) * 3.0);
+    let eye = 1.5;
+    for &a in &[(0, 0), (3, 12), (15, 7), (8, 8)] {
+        for &b in &[(15, 15), (0, 9), (10, 1), (8, 8)] {
+            assert_eq!(
+                terrain.terrain_line_of_sight(a, eye, b, eye),
+                terrain.terrain_line_of_sight(b, eye, a, eye),
+                "{a:?} <-> {b:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_terrain_line_of_sight_diagonal_ridge_and_bounds() {
+    // A ridge along the anti-diagonal; a diagonal sight line crosses it at corners.
+    let mut terrain: Grid<f32> = Grid::new(6, 6);
+    terrain.map_inplace(|x, y, h| *h = if x + y == 5 { 10.0 } else { 0.0 });
+
+    assert!(!terrain.terrain_line_of_sight((0, 0), 1.0, (5, 5), 1.0));
+    assert!(terrain.terrain_line_of_sight((0, 0), 12.0, (5, 5), 12.0));
+    // Adjacent cells always see each other.
+    assert!(terrain.terrain_line_of_sight((2, 2), 0.0, (2, 3), 0.0));
+    assert!(terrain.terrain_line_of_sight((4, 4), 0.0, (4, 4), 0.0));
+    assert!(!terrain.terrain_line_of_sight((0, 0), 1.0, (6, 0), 1.0));
+}
@@ -0,0 +1,39 @@
+use gridsystem::Grid;
+
+#[test]
+fn test_equal_factions_split_like_voronoi() {
+    let plains: Grid<u8> = Grid::with_value(20, 20, 1);
+    let map = plains.expand_territories(&[(2, 10), (17, 10)], |_| 1000, |_, _, _| Some(1));
+    assert!(map.iter().all(|(_, _, &o)| o != 0));
+    for (x, _, &owner) in map.iter() {
+        if x < 9 {
+            assert_eq!(owner, 1);
+        } else if x > 10 {
+            assert_eq!(owner, 2);
+        }
+    }
+}
+
+#[test]
+fn test_territories_respect_costs_and_walls() {
+    // Mountains ('9') are expensive and '#' is impassable.
+    let rows = ["....9....", "....9....", "....#....", "....#...."];
+    let mut terrain: Grid<u8> = Grid::new(9, 4);
+    terrain.map_inplace(|x, y, c| *c = rows[y as usize].as_bytes()[x as usize]);
+    let cost = |_: u16, _: u16, &c: &u8| match c {
+        b'#' => None,
+        b'9' => Some(9),
+        _ => Some(1),
+    };
+    let map = terrain.expand_territories(&[(0, 3), (8, 3)], |i| [30, 30][i], cost);
+    assert_eq!(map.get(4, 3), Some(&0));
+    assert_eq!(map.get(4, 2), Some(&0));
+    assert!(map.get(4, 0) == Some(&1) || map.get(4, 0) == Some(&2));
+    assert!((0..4).all(|x| map.get(x, 3) == Some(&1)));
+    assert!((5..9).all(|x| map.get(x, 3) == Some(&2)));
+
+    // Out-of-bounds and duplicate seeds are ignored; zero strength keeps the capital only.
+    let map = terrain.expand_territories(&[(50, 50), (0, 0), (0, 0)], |i| [5, 0, 5][i], cost);
+    assert_eq!(map.get(0, 0), Some(&2));
+    assert_eq!(map.iter().filter(|&(_, _, &o)| o != 0).count(), 1);
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "testing")]
+
+use gridsystem::Grid;
+use gridsystem::testing::GridStrategy;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+#[test]
+fn test_generate_respects_ranges_and_seed() {
+    let strategy = GridStrategy::new(2..=5, 0..=3);
+    let mut rng = StdRng::seed_from_u64(11);
+    let mut sizes = Vec::new();
+    for _ in 0..200 {
+        let grid: Grid<bool> = strategy.generate_standard(&mut rng);
+        assert!((2..=5).contains(&grid.width()));
+        assert!((0..=3).contains(&grid.height()));
+        assert_eq!(
+            grid.as_slice().len(),
+            grid.width() as usize * grid.height() as usize
+        );
+        sizes.push((grid.width(), grid.height()));
+    }
+    assert!(sizes.contains(&(2, 0)) && sizes.contains(&(5, 3)));
+
+    let first: Grid<u32> = strategy.generate_standard(&mut StdRng::seed_from_u64(5));
+    let again: Grid<u32> = strategy.generate_standard(&mut StdRng::seed_from_u64(5));
+    assert_eq!(first.as_slice(), again.as_slice());
+}
+
+#[test]
+fn test_shrink_stays_within_strategy() {
+    let strategy = GridStrategy::new(3..=8, 2..=8);
+    let mut grid: Grid<u8> = Grid::new(4, 2);
+    grid.set(1, 1, 5).unwrap();
+
+    let candidates = strategy.shrink(&grid);
+    let sizes: Vec<_> = candidates.iter().map(|g| (g.width(), g.height())).collect();
+    assert_eq!(sizes, vec![(3, 2), (4, 2)]);
+    assert_eq!(candidates[0].as_slice(), &[0, 0, 0, 0, 5, 0]);
+    assert!(candidates[1].as_slice().iter().all(|&v| v == 0));
+
+    let narrow: Grid<u8> = Grid::new(2, 2);
+    let sizes: Vec<_> = GridStrategy::new(1..=8, 2..=8)
+        .shrink(&narrow)
+        .iter()
+        .map(|g| (g.width(), g.height()))
+        .collect();
+    assert_eq!(sizes, vec![(1, 2)]);
+
+    let minimal: Grid<u8> = Grid::new(3, 2);
+    assert!(strategy.shrink(&minimal).is_empty());
+}
+
+proptest::proptest! {
+    #[test]
+    fn test_proptest_arbitrary_grid(grid in proptest::arbitrary::any::<Grid<u8>>()) {
+        proptest::prop_assert!((1..=32).contains(&grid.width()));
+        proptest::prop_assert!((1..=32).contains(&grid.height()));
+        proptest::prop_assert_eq!(
+            grid.as_slice().len(),
+            grid.width() as usize * grid.height() as usize
+        );
+    }
+
+    #[test]
+    fn test_proptest_configured_sizes_and_cells(
+        grid in proptest::arbitrary::any_with::<Grid<bool>>((GridStrategy::new(3..=3, 0..=4), ())),
+        digits in GridStrategy::new(1..=6, 2..=2).proptest_strategy(0..10u32),
+    ) {
+        proptest::prop_assert_eq!(grid.width(), 3);
+        proptest::prop_assert!(grid.height() <= 4);
+        proptest::prop_assert_eq!(digits.height(), 2);
+        proptest::prop_assert!(digits.as_slice().iter().all(|&d| d < 10));
+    }
+}
+
+#[test]
+fn test_quickcheck_arbitrary_grid() {
+    use quickcheck::{Arbitrary, Gen, QuickCheck};
+
+    fn area_matches(grid: Grid<u16>) -> bool {
+        grid.as_slice().len() == grid.width() as usize * grid.height() as usize
+            && (1..=8).contains(&grid.width())
+    }
+    QuickCheck::new()
+        .rng(Gen::new(8))
+        .quickcheck(area_matches as fn(Grid<u16>) -> bool);
+
+    // Shrinking drops the only non-default cell or trims the grid.
+    let mut grid: Grid<u8> = Grid::new(2, 1);
+    grid.set(1, 0, 9).unwrap();
+    let sizes: Vec<_> = grid.shrink().map(|g| (g.width(), g.height())).collect();
+    assert_eq!(sizes, vec![(1, 1), (2, 1)]);
+}
@@ -0,0 +1,47 @@
+use gridsystem::{Grid, IterState};
+
+#[test]
+fn test_budgeted_traversal_visits_each_cell_once_per_pass() {
+    let grid: Grid<u8> = Grid::new(7, 5);
+    let mut state = IterState::new();
+    let mut seen = Vec::new();
+
+    let mut calls = 0;
+    loop {
+        calls += 1;
+        let finished = grid.for_each_budgeted(&mut state, 4, |x, y, _| seen.push((x, y)));
+        if finished {
+            break;
+        }
+        assert_eq!(state.cells_done(), calls * 4);
+    }
+    assert_eq!(calls, 9);
+    let expected: Vec<_> = grid.iter().map(|(x, y, _)| (x, y)).collect();
+    assert_eq!(seen, expected);
+    assert_eq!((state.passes(), state.cells_done()), (1, 0));
+
+    // The next call starts a new pass.
+    grid.for_each_budgeted(&mut state, 1, |x, y, _| assert_eq!((x, y), (0, 0)));
+}
+
+#[test]
+fn test_budgeted_traversal_edge_cases() {
+    let mut grid: Grid<u8> = Grid::new(3, 3);
+    let mut state = IterState::new();
+
+    assert!(!grid.for_each_budgeted_mut(&mut state, 0, |_, _, v| *v = 1));
+    assert!(grid.iter().all(|(_, _, &v)| v == 0));
+    assert!(grid.for_each_budgeted_mut(&mut state, usize::MAX, |_, _, v| *v = 1));
+    assert!(grid.iter().all(|(_, _, &v)| v == 1));
+
+    // A state left mid-pass on a larger grid finishes immediately on a smaller one.
+    let big: Grid<u8> = Grid::new(10, 10);
+    let mut state = IterState::new();
+    big.for_each_budgeted(&mut state, 50, |_, _, _| {});
+    let mut visited = 0;
+    assert!(grid.for_each_budgeted(&mut state, 5, |_, _, _| visited += 1));
+    assert_eq!(visited, 0);
+
+    let empty: Grid<u8> = Grid::new(0, 0);
+    assert!(empty.for_each_budgeted(&mut IterState::new(), 5, |_, _, _| {}));
+}
@@ -0,0 +1,33 @@
+use gridsystem::fov::field_of_view;
+use gridsystem::pathfinding::astar;
+use gridsystem::{GridLike, TorusGrid};
+
+#[test]
+fn test_torus_wraps_coordinates_and_distance() {
+    let mut world: TorusGrid<i32> = TorusGrid::new(5, 3);
+    world.set(-1, -1, 9).unwrap();
+    assert_eq!(world.get(4, 2), Some(&9));
+    assert_eq!(world.wrap(12, -7), Some((2, 2)));
+    assert_eq!(world.offset(0, 0, -1, 4), Some((4, 1)));
+    assert_eq!(world.offset(5, 0, 1, 0), None);
+    assert_eq!(world.distance((0, 0), (4, 2)), 2);
+
+    let empty: TorusGrid<i32> = TorusGrid::new(0, 3);
+    assert_eq!(empty.wrap(1, 1), None);
+    assert!(TorusGrid::<i32>::new(0, 0).set(0, 0, 1).is_err());
+}
+
+#[test]
+fn test_torus_paths_and_sight_cross_the_seams() {
+    let mut world: TorusGrid<bool> = TorusGrid::new(8, 8);
+    // Wall off the direct route along row 4.
+    for x in 2..7 {
+        world.set(x, 4, true).unwrap();
+    }
+    let path = astar(&world, (1, 4), (7, 4), |_, _, &wall| (!wall).then_some(1)).unwrap();
+    assert_eq!(path, vec![(1, 4), (0, 4), (7, 4)]);
+
+    let visible = field_of_view(&world, (0, 0), 2, |&wall| wall);
+    assert_eq!(visible.get(7, 7), Some(&true));
+    assert_eq!(visible.get(6, 0), Some(&true));
+}
@@ -0,0 +1,83 @@
+use gridsystem::{Grid, GridTransform};
+
+#[test]
+fn test_segment_blocked_matches_dense_sampling() {
+    let mut walls: Grid<bool> = Grid::new(12, 12);
+    for (x, y) in [(3, 3), (7, 2), (5, 8), (9, 9), (2, 10), (10, 4)] {
+        let _ = walls.set(x, y, true);
+    }
+    let world = GridTransform::new(-5.0, 3.0, 2.0, 3.0);
+    let blocks = |_: u16, _: u16, &wall: &bool| wall;
+    let to_world = |(gx, gy): (f32, f32)| (-5.0 + gx * 2.0, 3.0 + gy * 3.0);
+
+    let points = [
+        (0.3, 0.7),
+        (11.6, 11.2),
+        (0.5, 11.3),
+        (11.9, 0.1),
+        (6.2, 6.8),
+        (4.4, 1.3),
+    ];
+    for &a in &points {
+        for &b in &points {
+            // Reference: the first blocking cell hit by many small steps.
+            let expected = (0..=20_000).find_map(|i| {
+                let t = i as f32 / 20_000.0;
+                let (x, y) = (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t);
+                let cell = (x.floor() as u16, y.floor() as u16);
+                walls[cell].then_some(cell)
+            });
+            let found = walls.segment_blocked(&world, to_world(a), to_world(b), blocks);
+            assert_eq!(found, expected, "{a:?} -> {b:?}");
+        }
+    }
+}
+
+#[test]
+fn test_segment_blocked_outside_grid_and_endpoints() {
+    let mut walls: Grid<bool> = Grid::new(4, 4);
+    let _ = walls.set(0, 0, true);
+    let world = GridTransform::new(0.0, 0.0, 1.0, 1.0);
+    let blocks = |_: u16, _: u16, &wall: &bool| wall;
+
+    // Starting inside the blocking cell reports it immediately.
+    assert_eq!(
+        walls.segment_blocked(&world, (0.5, 0.5), (3.5, 3.5), blocks),
+        Some((0, 0))
+    );
+    // Entering the grid from outside.
+    assert_eq!(
+        walls.segment_blocked(&world, (-10.0, 0.5), (2.0, 0.5), blocks),
+        Some((0, 0))
+    );
+    // Entirely outside the grid.
+    assert_eq!(
+        walls.segment_blocked(&world, (-3.0, -3.0), (-1.0, 9.0), blocks),
+        None
+    );
+}
+
+#[test]
+fn test_segment_blocked_distant_and_non_finite_endpoints() {
+    let mut walls: Grid<bool> = Grid::new(8, 8);
+    let _ = walls.set(5, 3, true);
+    let world = GridTransform::new(0.0, 0.0, 1.0, 1.0);
+    let blocks = |_: u16, _: u16, &wall: &bool| wall;
+
+    assert_eq!(
+        walls.segment_blocked(&world, (0.5, 3.5), (1e10, 3.5), blocks),
+        Some((5, 3))
+    );
+    assert_eq!(
+        walls.segment_blocked(&world, (-1e10, 3.5), (1e10, 3.5), blocks),
+        Some((5, 3))
+    );
+    assert_eq!(
+        walls.segment_blocked(&world, (1e10, 3.5), (7.5, 3.5), blocks),
+        None
+    );
+    assert_eq!(
+        walls.segment_blocked(&world, (0.5, 3.5), (f32::INFINITY, 3.5), blocks),
+        None
+    );
+}
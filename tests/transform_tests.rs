@@ -0,0 +1,84 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use gridsystem::{Affine, Grid, Interpolation};
+
+mod common;
+
+use common::numbered;
+
+#[test]
+fn test_quarter_turns_are_exact() {
+    let grid = numbered(5, 3);
+
+    let cw = grid.rotate_by(FRAC_PI_2, Interpolation::Nearest, 0);
+    assert_eq!((cw.width(), cw.height()), (3, 5));
+    for (x, y, v) in cw.iter() {
+        assert_eq!(Some(v), grid.get(y, 2 - x));
+    }
+
+    let half = grid.rotate_by(PI, Interpolation::Nearest, 0);
+    assert_eq!((half.width(), half.height()), (5, 3));
+    for (x, y, v) in half.iter() {
+        assert_eq!(Some(v), grid.get(4 - x, 2 - y));
+    }
+}
+
+#[test]
+fn test_rotation_grows_canvas_and_fills_corners() {
+    let grid: Grid<u8> = Grid::with_value(10, 10, 1);
+    let rotated = grid.rotate_by(PI / 4.0, Interpolation::Nearest, 0);
+    assert_eq!((rotated.width(), rotated.height()), (15, 15));
+    assert_eq!(rotated.get(0, 0), Some(&0));
+    assert_eq!(rotated.get(7, 7), Some(&1));
+}
+
+#[test]
+fn test_warp_affine_bilinear_and_errors() {
+    let mut grid: Grid<f32> = Grid::new(2, 1);
+    let _ = grid.set(1, 0, 10.0);
+
+    // Half-cell shift samples midway between the two cells.
+    let shifted = grid
+        .warp_affine(
+            &Affine::translation(-0.5, 0.0),
+            1,
+            1,
+            Interpolation::Bilinear,
+            0.0,
+        )
+        .unwrap();
+    assert_eq!(shifted.get(0, 0), Some(&5.0));
+
+    let singular = Affine::scale(0.0, 1.0);
+    assert!(
+        grid.warp_affine(&singular, 2, 1, Interpolation::Nearest, 0.0)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_affine_composition() {
+    let t = Affine::rotation(FRAC_PI_2).then(&Affine::translation(1.0, 2.0));
+    let (x, y) = t.apply(1.0, 0.0);
+    assert!((x - 1.0).abs() < 1e-6 && (y - 3.0).abs() < 1e-6);
+
+    let round_trip = t.then(&t.inverse().unwrap());
+    let (x, y) = round_trip.apply(4.0, -7.0);
+    assert!((x - 4.0).abs() < 1e-5 && (y + 7.0).abs() < 1e-5);
+}
+
+#[test]
+fn test_affine_inverse_tolerance_scales_with_magnitude() {
+    let tiny = Affine::scale(1e-4, 1e-4).inverse().unwrap();
+    let (x, y) = tiny.apply(1e-4, -2e-4);
+    assert!((x - 1.0).abs() < 1e-4 && (y + 2.0).abs() < 1e-4);
+
+    // Rank-deficient up to f32 rounding: the rows are parallel.
+    assert!(
+        Affine::new(3000.0, 6000.0, 0.0, 1000.0, 2000.0001, 0.0)
+            .inverse()
+            .is_none()
+    );
+    assert!(Affine::scale(f32::INFINITY, 1.0).inverse().is_none());
+    assert!(Affine::scale(0.0, 0.0).inverse().is_none());
+}
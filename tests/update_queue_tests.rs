@@ -0,0 +1,75 @@
+use gridsystem::{Grid, GridError, UpdateQueue};
+
+#[test]
+fn test_updates_fire_in_tick_then_schedule_order() {
+    let mut queue = UpdateQueue::new(4, 4);
+    queue.schedule(3, 3, 2).unwrap();
+    queue.schedule(0, 0, 1).unwrap();
+    queue.schedule(1, 0, 2).unwrap();
+    queue.schedule(2, 0, 0).unwrap();
+    assert_eq!(queue.len(), 4);
+    assert_eq!(queue.due(2, 0), Some(1));
+
+    assert_eq!(queue.advance(), vec![(0, 0), (2, 0)]);
+    assert_eq!(queue.advance(), vec![(3, 3), (1, 0)]);
+    assert!(queue.advance().is_empty());
+    assert_eq!(queue.now(), 3);
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn test_rescheduling_and_cancelling_replace_earlier_entries() {
+    let mut queue = UpdateQueue::new(2, 1);
+    queue.schedule(0, 0, 1).unwrap();
+    queue.schedule(0, 0, 3).unwrap();
+    queue.schedule(1, 0, 1).unwrap();
+    assert!(queue.cancel(1, 0).unwrap());
+    assert!(!queue.cancel(1, 0).unwrap());
+    assert_eq!(queue.len(), 1);
+
+    assert!(queue.advance().is_empty());
+    assert!(queue.advance().is_empty());
+    assert_eq!(queue.advance(), vec![(0, 0)]);
+    assert_eq!(queue.due(0, 0), None);
+}
+
+#[test]
+fn test_step_reschedules_and_overwrite_cancels() {
+    let mut growth: Grid<u8> = Grid::new(3, 1);
+    let mut queue = UpdateQueue::new(3, 1);
+    for x in 0..3 {
+        queue.schedule(x, 0, 1).unwrap();
+    }
+    let grow = |_: u16, _: u16, stage: &mut u8| {
+        *stage += 1;
+        (*stage < 3).then_some(1)
+    };
+    assert_eq!(queue.step(&mut growth, grow), Ok(3));
+    assert_eq!(queue.overwrite(&mut growth, 1, 0, 0, None), Ok(1));
+    assert_eq!(queue.overwrite(&mut growth, 2, 0, 9, Some(5)), Ok(1));
+    while queue.step(&mut growth, grow).unwrap() > 0 {}
+    assert_eq!(growth.as_slice(), &[3, 0, 9]);
+    assert_eq!(queue.due(2, 0), Some(6));
+}
+
+#[test]
+fn test_bounds_and_sizes_are_checked() {
+    let mut queue = UpdateQueue::new(2, 2);
+    let Err(e) = queue.schedule(2, 0, 1) else {
+        panic!("expected an error");
+    };
+    assert!(matches!(e, GridError::OutOfBounds { x: 2, y: 0, .. }));
+
+    let mut wrong: Grid<u8> = Grid::new(3, 2);
+    let Err(e) = queue.step(&mut wrong, |_, _, _| None) else {
+        panic!("expected an error");
+    };
+    assert!(matches!(
+        e,
+        GridError::DimensionMismatch {
+            expected: (2, 2),
+            found: (3, 2)
+        }
+    ));
+    assert_eq!(queue.now(), 0);
+}
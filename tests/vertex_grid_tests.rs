@@ -0,0 +1,54 @@
+use gridsystem::{Grid, GridError, VertexGrid};
+
+#[test]
+fn test_vertex_grid_dimensions_and_errors() {
+    let corners: VertexGrid<u8> = VertexGrid::new(4, 2).unwrap();
+    assert_eq!((corners.width(), corners.height()), (5, 3));
+    assert_eq!((corners.cell_width(), corners.cell_height()), (4, 2));
+    assert!(corners.corners(3, 1).is_some());
+    assert!(corners.corners(4, 0).is_none());
+    assert!(corners.get(4, 2).is_some());
+
+    assert!(matches!(
+        VertexGrid::<u8>::new(u16::MAX, 1),
+        Err(GridError::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        VertexGrid::from_grid(Grid::<u8>::new(0, 3)),
+        Err(GridError::EmptyGrid)
+    ));
+    assert!(matches!(
+        Grid::<f32>::new(3, 0).to_vertices(),
+        Err(GridError::EmptyGrid)
+    ));
+}
+
+#[test]
+fn test_conversions_preserve_linear_fields() {
+    // A linear ramp in x: cell centres at x + 0.5 hold 2 * (x + 0.5).
+    let mut cells: Grid<f32> = Grid::new(6, 4);
+    cells.map_inplace(|x, _, v| *v = 2.0 * (x as f32 + 0.5));
+    let corners = cells.to_vertices().unwrap();
+
+    // Interior corners sit exactly on the ramp; only the borders clamp.
+    for x in 1..6 {
+        assert_eq!(corners.get(x, 2), Some(&(2.0 * x as f32)));
+    }
+    assert_eq!(corners.get(0, 0), Some(&1.0));
+    assert_eq!(corners.interpolate(2.5, 1.7), Some(5.0));
+    assert_eq!(corners.interpolate(6.0, 4.0), corners.get(6, 4).copied());
+    assert_eq!(corners.interpolate(-0.1, 1.0), None);
+
+    let back = corners.to_cells();
+    for x in 1..5 {
+        assert_eq!(back.get(x, 1), cells.get(x, 1));
+    }
+}
+
+#[test]
+fn test_integer_vertices_round() {
+    let cells = Grid::from_raw_parts(2, 2, vec![0u8, 1, 1, 1]).unwrap();
+    let corners = cells.to_vertices().unwrap();
+    assert_eq!(corners.get(1, 1), Some(&1));
+    assert_eq!(corners.into_grid().as_slice()[0], 0);
+}
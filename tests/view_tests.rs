@@ -0,0 +1,91 @@
+use gridsystem::pathfinding::astar;
+use gridsystem::{Grid, GridLike, GridLikeMut};
+
+mod common;
+
+use common::numbered;
+
+#[test]
+fn test_view_reads_window_without_copying() {
+    let grid = numbered(10, 8);
+    let view = grid.view(3, 2, 4, 3).unwrap();
+    assert_eq!(view.row(1).unwrap(), &[33, 34, 35, 36]);
+    assert_eq!(
+        view.row(1).unwrap().as_ptr(),
+        &grid.as_slice()[33] as *const u32
+    );
+    let cells: Vec<_> = view.iter().map(|(x, y, &v)| (x, y, v)).collect();
+    assert_eq!(cells.len(), 12);
+    assert_eq!(cells[5], (1, 1, 34));
+
+    let inner = view.view(1, 1, 2, 2).unwrap();
+    assert_eq!(inner.to_grid().as_slice(), &[34, 35, 44, 45]);
+    assert_eq!(inner.rect().x, 4);
+    assert!(view.view(3, 0, 2, 1).is_err());
+    assert!(grid.view(7, 0, 4, 1).is_err());
+    assert!(grid.view(10, 8, 0, 0).is_ok());
+}
+
+#[test]
+fn test_view_mut_writes_stay_inside_window() {
+    let mut grid: Grid<u32> = Grid::new(6, 6);
+    {
+        let mut view = grid.view_mut(2, 1, 3, 2).unwrap();
+        for (x, y, cell) in view.iter_mut() {
+            *cell = 10 * y as u32 + x as u32 + 1;
+        }
+        assert!(view.set(3, 0, 99).is_err());
+        assert!(GridLikeMut::set(&mut view, 0, 1, 50).is_ok());
+        assert_eq!(view.as_view().get(0, 1), Some(&50));
+    }
+    assert_eq!(grid.iter().filter(|(_, _, v)| **v != 0).count(), 6);
+    assert_eq!(grid.row(1).unwrap(), &[0, 0, 1, 2, 3, 0]);
+    assert_eq!(grid.row(2).unwrap(), &[0, 0, 50, 12, 13, 0]);
+}
+
+#[test]
+fn test_algorithms_run_on_views() {
+    let mut walls: Grid<bool> = Grid::new(20, 20);
+    for y in 0..20 {
+        let _ = walls.set(10, y, y != 15);
+    }
+    // Inside a window that excludes the gap, the halves are disconnected.
+    let window = walls.view(5, 0, 10, 12).unwrap();
+    let passable = |_: u16, _: u16, &wall: &bool| (!wall).then_some(1);
+    assert_eq!(GridLike::width(&window), 10);
+    assert!(astar(&window, (0, 0), (9, 0), passable).is_none());
+    let taller = walls.view(5, 0, 10, 20).unwrap();
+    assert!(astar(&taller, (0, 0), (9, 0), passable).is_some());
+}
+
+#[test]
+fn test_strided_views_cover_sub_lattice() {
+    let grid = numbered(7, 5);
+    let lattice = grid.view_strided(1, 2, 3, 2).unwrap();
+    assert_eq!((lattice.width(), lattice.height()), (2, 2));
+    let values: Vec<u32> = lattice.iter().map(|(_, _, &v)| v).collect();
+    assert_eq!(values, [15, 18, 29, 32]);
+    assert_eq!(lattice.get(2, 0), None);
+    assert_eq!(grid.view_strided(9, 0, 1, 1).unwrap().width(), 0);
+    assert!(grid.view_strided(0, 0, 0, 1).is_err());
+
+    // Red-black sweep: the two colours of a checkerboard, one pair of views each.
+    let mut board: Grid<u8> = Grid::new(5, 4);
+    for (ox, oy) in [(0, 0), (1, 1)] {
+        let mut red = board.view_strided_mut(ox, oy, 2, 2).unwrap();
+        for (_, _, cell) in red.iter_mut() {
+            *cell += 1;
+        }
+        assert!(red.set(red.width(), 0, 9).is_err());
+    }
+    for (x, y, &v) in board.iter() {
+        assert_eq!(v, u8::from((x + y) % 2 == 0), "cell ({x}, {y})");
+    }
+    let mut black = board.view_strided_mut(1, 0, 2, 2).unwrap();
+    GridLikeMut::set(&mut black, 1, 1, 7).unwrap();
+    assert_eq!(black.to_parent(1, 1), Some((3, 2)));
+    assert_eq!(black.to_parent(black.width(), 0), None);
+    assert_eq!(black.to_parent(u16::MAX, u16::MAX), None);
+    assert_eq!(black.as_view().get(1, 1), Some(&7));
+    assert_eq!(board[(3, 2)], 7);
+}
@@ -0,0 +1,53 @@
+use gridsystem::{Grid, GridTransform, Rect, WorldRect};
+
+#[test]
+fn test_viewport_matches_brute_force() {
+    let mut grid: Grid<u32> = Grid::new(40, 30);
+    grid.map_inplace(|x, y, v| *v = y as u32 * 40 + x as u32);
+    let transform = GridTransform::new(5.0, -3.0, 8.0, 6.0);
+    let view = WorldRect::new(30.5, 17.0, 50.0, 33.0);
+
+    let visible: Vec<_> = grid.iter_viewport(&view, &transform).collect();
+    let expected: Vec<_> = grid
+        .iter()
+        .filter(|&(x, y, _)| {
+            let (wx, wy) = transform.cell_to_world(x, y);
+            wx < view.x + view.width
+                && wx + 8.0 > view.x
+                && wy < view.y + view.height
+                && wy + 6.0 > view.y
+        })
+        .collect();
+    assert_eq!(visible.len(), expected.len());
+    for ((x, y, pos, v), (ex, ey, ev)) in visible.into_iter().zip(expected) {
+        assert_eq!((x, y, v), (ex, ey, ev));
+        assert_eq!(pos, transform.cell_to_world(x, y));
+    }
+}
+
+#[test]
+fn test_viewport_clipping() {
+    let grid: Grid<u8> = Grid::new(10, 10);
+    let transform = GridTransform::new(0.0, 0.0, 1.0, 1.0);
+
+    assert_eq!(
+        grid.iter_viewport(&WorldRect::new(-50.0, -50.0, 10.0, 10.0), &transform)
+            .count(),
+        0
+    );
+    assert_eq!(
+        grid.iter_viewport(&WorldRect::new(8.5, 9.0, 100.0, 100.0), &transform)
+            .count(),
+        2
+    );
+    assert_eq!(
+        grid.iter_viewport(&WorldRect::new(-1.0, -1.0, 1.0, 100.0), &transform)
+            .count(),
+        0
+    );
+    assert_eq!(
+        transform.visible_cells(&WorldRect::new(-5.0, 2.0, 7.5, 0.5), 10, 10),
+        Some(Rect::new(0, 2, 3, 1))
+    );
+    assert_eq!(transform.world_to_cell(-0.5, 3.2), (-1, 3));
+}
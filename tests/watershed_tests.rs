@@ -0,0 +1,49 @@
+use gridsystem::Grid;
+
+#[test]
+fn test_watershed_splits_two_pits() {
+    // Two cone-shaped pits; the basin boundary lies on the ridge between them.
+    let mut heights: Grid<f32> = Grid::new(21, 11);
+    heights.map_inplace(|x, y, h| {
+        let left = (x as f32 - 5.0).hypot(y as f32 - 5.0);
+        let right = (x as f32 - 15.0).hypot(y as f32 - 5.0);
+        *h = left.min(right);
+    });
+    let mut markers: Grid<u32> = Grid::new(21, 11);
+    markers.set(5, 5, 7).unwrap();
+    markers.set(15, 5, 9).unwrap();
+
+    let basins = heights.watershed(&markers).unwrap();
+    for (x, _, &label) in basins.iter() {
+        match x {
+            10 => assert!(label == 7 || label == 9),
+            0..10 => assert_eq!(label, 7),
+            _ => assert_eq!(label, 9),
+        }
+    }
+}
+
+#[test]
+fn test_watershed_unreachable_and_errors() {
+    let heights: Grid<f32> = Grid::new(3, 3);
+    let markers: Grid<u32> = Grid::new(3, 3);
+    assert!(
+        heights
+            .watershed(&markers)
+            .unwrap()
+            .iter()
+            .all(|(_, _, &l)| l == 0)
+    );
+
+    let wrong: Grid<u32> = Grid::new(3, 4);
+    assert!(heights.watershed(&wrong).is_err());
+
+    let mut heights: Grid<f32> = Grid::with_value(4, 1, 1.0);
+    heights.set(2, 0, f32::NAN).unwrap();
+    let mut markers: Grid<u32> = Grid::new(4, 1);
+    markers.set(0, 0, 3).unwrap();
+    assert_eq!(
+        heights.watershed(&markers).unwrap().as_slice(),
+        &[3, 3, 3, 3]
+    );
+}
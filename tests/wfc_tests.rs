@@ -0,0 +1,161 @@
+use gridsystem::wfc::{
+    AdjacencyRules, generate, generate_cancellable, generate_masked, generate_masked_cancellable,
+};
+use gridsystem::{BitGrid, CancellationToken, Direction, Grid, GridError, Rect};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+fn sample() -> Grid<char> {
+    let rows = ["~~~~..##", "~~~..###", "~~..##^#", "~...#^^#", "~~..##^#"];
+    let tiles: Vec<char> = rows.iter().flat_map(|row| row.chars()).collect();
+    Grid::from_raw_parts(8, 5, tiles).unwrap()
+}
+
+fn assert_follows_rules(map: &Grid<char>, rules: &AdjacencyRules<char>) {
+    for (x, y, tile) in map.iter() {
+        for direction in [Direction::East, Direction::South] {
+            let (dx, dy) = direction.offset();
+            if let Some(next) = map.get(x + dx as u16, y + dy as u16) {
+                assert!(
+                    rules.is_allowed(tile, direction, next),
+                    "{tile:?} then {next:?} at ({x}, {y})"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_learned_rules_are_followed_and_seeded() {
+    let rules = AdjacencyRules::from_sample(&sample());
+    assert_eq!(rules.tiles(), &['~', '.', '#', '^']);
+    assert!(!rules.is_allowed(&'~', Direction::East, &'#'));
+
+    let map = generate(30, 20, &rules, 1000, &mut StdRng::seed_from_u64(11)).unwrap();
+    assert_follows_rules(&map, &rules);
+    let again = generate(30, 20, &rules, 1000, &mut StdRng::seed_from_u64(11)).unwrap();
+    assert_eq!(map.as_slice(), again.as_slice());
+}
+
+#[test]
+fn test_unsatisfiable_rules_report_failure() {
+    let mut rules = AdjacencyRules::new();
+    // Only `b` directly below `a`: a column of two fits, nothing wider or taller.
+    rules.allow('a', Direction::South, 'b').unwrap();
+    assert!(generate(1, 2, &rules, 100, &mut StdRng::seed_from_u64(0)).is_some());
+    assert!(generate(1, 3, &rules, 100, &mut StdRng::seed_from_u64(0)).is_none());
+    assert!(generate(2, 1, &rules, 100, &mut StdRng::seed_from_u64(0)).is_none());
+    assert!(
+        generate(
+            3,
+            3,
+            &AdjacencyRules::<char>::new(),
+            100,
+            &mut StdRng::seed_from_u64(0)
+        )
+        .is_none()
+    );
+
+    let Err(e) = rules.allow('a', Direction::NorthEast, 'b') else {
+        panic!("expected an error");
+    };
+    assert!(matches!(e, GridError::InvalidArgument(_)));
+}
+
+#[test]
+fn test_backtracking_solves_a_constrained_strip() {
+    // Tiles 0..4 must step up by one going east and may only wrap from 3
+    // back to 0, so a free 4 wide patch between fixed ends has one answer.
+    let mut rules = AdjacencyRules::new();
+    for tile in 0..4u8 {
+        rules.allow(tile, Direction::East, (tile + 1) % 4).unwrap();
+        rules.allow(tile, Direction::South, tile).unwrap();
+    }
+    let strip = Grid::from_raw_parts(6, 1, vec![1, 9, 9, 9, 9, 2]).unwrap();
+    let mut mask = BitGrid::new(6, 1);
+    for x in 1..5 {
+        let _ = mask.set(x, 0, true);
+    }
+    let filled = generate_masked(&strip, &mask, &rules, 100, &mut StdRng::seed_from_u64(4))
+        .unwrap()
+        .unwrap();
+    assert_eq!(filled.as_slice(), &[1, 2, 3, 0, 1, 2]);
+
+    let impossible = Grid::from_raw_parts(6, 1, vec![1, 9, 9, 9, 9, 3]).unwrap();
+    let result = generate_masked(
+        &impossible,
+        &mask,
+        &rules,
+        100,
+        &mut StdRng::seed_from_u64(4),
+    );
+    assert!(result.unwrap().is_none());
+}
+
+#[test]
+fn test_masked_patch_blends_into_existing_map() {
+    let rules = AdjacencyRules::from_sample(&sample());
+    let map = generate(24, 16, &rules, 1000, &mut StdRng::seed_from_u64(2)).unwrap();
+    let mut mask = BitGrid::new(24, 16);
+    for (x, y) in Rect::new(6, 4, 10, 6).cells() {
+        let _ = mask.set(x, y, true);
+    }
+    let patched = generate_masked(&map, &mask, &rules, 1000, &mut StdRng::seed_from_u64(8))
+        .unwrap()
+        .unwrap();
+    assert_follows_rules(&patched, &rules);
+    assert!(
+        patched
+            .iter()
+            .all(|(x, y, t)| mask.get(x, y) == Some(true) || map.get(x, y) == Some(t))
+    );
+
+    let Err(e) = generate_masked(
+        &map,
+        &BitGrid::new(3, 3),
+        &rules,
+        10,
+        &mut StdRng::seed_from_u64(0),
+    ) else {
+        panic!("expected an error");
+    };
+    assert!(matches!(e, GridError::DimensionMismatch { .. }));
+}
+
+#[test]
+fn test_progress_and_cancellation() {
+    use std::sync::Mutex;
+
+    let rules = AdjacencyRules::from_sample(&sample());
+    let reports = Mutex::new(Vec::new());
+    let sink = |done: usize, total: usize| reports.lock().unwrap().push((done, total));
+    let mut rng = StdRng::seed_from_u64(5);
+    let map = generate_cancellable(
+        12,
+        10,
+        &rules,
+        1000,
+        &mut rng,
+        &sink,
+        &CancellationToken::new(),
+    );
+    assert!(map.unwrap().is_some());
+    let reports = reports.into_inner().unwrap();
+    assert!(
+        reports
+            .iter()
+            .all(|&(done, total)| total == 120 && done <= total)
+    );
+    assert_eq!(reports.last(), Some(&(120, 120)));
+
+    let cancel = CancellationToken::new();
+    cancel.cancel();
+    let mut rng = StdRng::seed_from_u64(5);
+    let result = generate_cancellable(12, 10, &rules, 1000, &mut rng, &(), &cancel);
+    assert!(matches!(result, Err(GridError::Cancelled)));
+    let mut mask = BitGrid::new(12, 10);
+    let _ = mask.set(3, 3, true);
+    let board = Grid::with_value(12, 10, '.');
+    let result = generate_masked_cancellable(&board, &mask, &rules, 10, &mut rng, &(), &cancel);
+    assert!(matches!(result, Err(GridError::Cancelled)));
+}